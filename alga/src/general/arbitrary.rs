@@ -0,0 +1,158 @@
+//! Implementations of `quickcheck::Arbitrary` for the structures defined by alga, so that
+//! property tests can generate them directly.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::general::wrapper::Wrapper;
+use crate::general::{
+    Bits, Discrete, FreeGroup, GaussianInt, Id, Inclusion, Letter, MontgomeryFp, Operator, Padic,
+    Zn, ZnUnit,
+};
+
+impl<O: Operator + Send + 'static> Arbitrary for Id<O> {
+    #[inline]
+    fn arbitrary<G: Gen>(_: &mut G) -> Self {
+        Id::new()
+    }
+}
+
+impl<T: Arbitrary, A: Send + 'static, M: Send + 'static> Arbitrary for Wrapper<T, A, M> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Wrapper::new(T::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.val.shrink().map(Wrapper::new))
+    }
+}
+
+macro_rules! impl_arbitrary_newtype(
+    ($($T:ident),*) => {$(
+        impl<N: Arbitrary> Arbitrary for $T<N> {
+            #[inline]
+            fn arbitrary<G: Gen>(g: &mut G) -> Self {
+                $T(N::arbitrary(g))
+            }
+
+            #[inline]
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.0.shrink().map($T))
+            }
+        }
+    )*}
+);
+
+impl_arbitrary_newtype!(Bits, Discrete, Inclusion);
+
+impl<const N: u64> Arbitrary for Zn<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Zn::new(u64::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value().shrink().map(Zn::new))
+    }
+}
+
+impl<const P: u64> Arbitrary for MontgomeryFp<P> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        MontgomeryFp::new(u64::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value().shrink().map(MontgomeryFp::new))
+    }
+}
+
+impl<const P: u64> Arbitrary for Padic<P> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Padic::new(u64::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value().shrink().map(Padic::new))
+    }
+}
+
+impl<const N: u64> Arbitrary for ZnUnit<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        // NOTE: one is always a unit, so this terminates quickly.
+        loop {
+            if let Some(unit) = ZnUnit::new(Zn::arbitrary(g)) {
+                return unit;
+            }
+        }
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.residue().shrink().filter_map(ZnUnit::new))
+    }
+}
+
+impl<N: Arbitrary> Arbitrary for GaussianInt<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        GaussianInt::new(N::arbitrary(g), N::arbitrary(g))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.re.clone(), self.im.clone())
+                .shrink()
+                .map(|(re, im)| GaussianInt::new(re, im)),
+        )
+    }
+}
+
+impl<N: Arbitrary> Arbitrary for Letter<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        if bool::arbitrary(g) {
+            Letter::Generator(N::arbitrary(g))
+        } else {
+            Letter::Inverse(N::arbitrary(g))
+        }
+    }
+
+    /// Shrinks the inverse of a generator to the generator itself, then shrinks the generator.
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Letter::Generator(g) => Box::new(g.shrink().map(Letter::Generator)),
+            Letter::Inverse(g) => Box::new(
+                Some(Letter::Generator(g.clone()))
+                    .into_iter()
+                    .chain(g.shrink().map(Letter::Inverse)),
+            ),
+        }
+    }
+}
+
+impl<N: Arbitrary + PartialEq> Arbitrary for FreeGroup<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        FreeGroup::from_letters(Vec::<Letter<N>>::arbitrary(g))
+    }
+
+    /// Shrinks to shorter words first, then to words with simpler letters.
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.letters()
+                .to_vec()
+                .shrink()
+                .map(FreeGroup::from_letters),
+        )
+    }
+}
@@ -0,0 +1,113 @@
+//! Canonical byte encodings of algebraic elements.
+
+use crate::general::{MontgomeryFp, Zn, ZnUnit};
+
+/// Elements with a canonical encoding as a fixed number of bytes.
+///
+/// Two elements are equal iff their encodings are equal, whatever their internal representation.
+/// This makes the encoding suitable for hashing, transcripts, and serialization formats.
+pub trait ToCanonicalBytes {
+    /// The encoding, usually a byte array of fixed length.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    /// The canonical encoding of `self`.
+    fn to_canonical_bytes(&self) -> Self::Bytes;
+}
+
+/// Elements that can be decoded from their canonical encoding.
+///
+/// Decoding rejects the encodings that are not canonical, so that every element has exactly one
+/// accepted encoding:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, from_canonical_bytes(to_canonical_bytes(a)) = a
+/// ∀ b, from_canonical_bytes(b) = a ⇒ to_canonical_bytes(a) = b
+/// ~~~
+pub trait FromCanonicalBytes: ToCanonicalBytes + Sized {
+    /// The element encoded by `bytes`, or `None` if `bytes` is not the canonical encoding of an
+    /// element.
+    fn from_canonical_bytes(bytes: &Self::Bytes) -> Option<Self>;
+
+    /// Returns `true` if the given argument is decoded from its encoding.
+    fn prop_canonical_bytes_round_trip(args: (Self,)) -> bool
+    where
+        Self: PartialEq,
+    {
+        let (a,) = args;
+        Self::from_canonical_bytes(&a.to_canonical_bytes()) == Some(a)
+    }
+
+    /// Returns `true` if the given bytes are either rejected or the encoding of the element they
+    /// are decoded to.
+    fn prop_canonical_bytes_are_unique(args: (Self::Bytes,)) -> bool {
+        let (bytes,) = args;
+
+        match Self::from_canonical_bytes(&bytes) {
+            Some(a) => a.to_canonical_bytes().as_ref() == bytes.as_ref(),
+            None => true,
+        }
+    }
+}
+
+/// The little-endian encoding of `value`, rejected if it is not smaller than `modulus`.
+#[inline]
+fn decode_below(bytes: &[u8; 8], modulus: u64) -> Option<u64> {
+    let value = u64::from_le_bytes(*bytes);
+
+    if value < modulus {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+// NOTE: the residues are encoded as their canonical representative, in little-endian order.
+impl<const N: u64> ToCanonicalBytes for Zn<N> {
+    type Bytes = [u8; 8];
+
+    #[inline]
+    fn to_canonical_bytes(&self) -> [u8; 8] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl<const N: u64> FromCanonicalBytes for Zn<N> {
+    #[inline]
+    fn from_canonical_bytes(bytes: &[u8; 8]) -> Option<Self> {
+        decode_below(bytes, N).map(Zn::new)
+    }
+}
+
+impl<const N: u64> ToCanonicalBytes for ZnUnit<N> {
+    type Bytes = [u8; 8];
+
+    #[inline]
+    fn to_canonical_bytes(&self) -> [u8; 8] {
+        self.residue().to_canonical_bytes()
+    }
+}
+
+impl<const N: u64> FromCanonicalBytes for ZnUnit<N> {
+    #[inline]
+    fn from_canonical_bytes(bytes: &[u8; 8]) -> Option<Self> {
+        Zn::from_canonical_bytes(bytes).and_then(ZnUnit::new)
+    }
+}
+
+// NOTE: this is the canonical representative, not the Montgomery form, so that it matches the
+// encoding of `Zn<P>`.
+impl<const P: u64> ToCanonicalBytes for MontgomeryFp<P> {
+    type Bytes = [u8; 8];
+
+    #[inline]
+    fn to_canonical_bytes(&self) -> [u8; 8] {
+        self.value().to_le_bytes()
+    }
+}
+
+impl<const P: u64> FromCanonicalBytes for MontgomeryFp<P> {
+    #[inline]
+    fn from_canonical_bytes(bytes: &[u8; 8]) -> Option<Self> {
+        decode_below(bytes, P).map(MontgomeryFp::new)
+    }
+}
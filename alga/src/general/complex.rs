@@ -1,6 +1,9 @@
 use num::{FromPrimitive, Num, NumAssign, One, Signed, Zero};
+#[cfg(feature = "scalar-send-sync")]
 use std::any::Any;
+#[cfg(feature = "scalar-fmt")]
 use std::fmt::{Debug, Display};
+use std::hash::Hasher;
 use std::ops::Neg;
 use std::{f32, f64};
 
@@ -13,32 +16,30 @@ use num::Float;
 
 #[allow(missing_docs)]
 
-/// Trait shared by all complex fields and its subfields (like real numbers).
+/// The functions shared by all complex fields and their subfields (like real numbers), without the
+/// `num-traits` bounds of `ComplexField`.
+///
+/// This can be implemented by types that cannot or should not implement `Rem`, `FromPrimitive`,
+/// or the compound assignment operators of `NumAssign`, e.g., symbolic scalars or intervals. Every
+/// type that additionally implements `Num + NumAssign + FromPrimitive` is a `ComplexField`.
 ///
-/// Complex numbers are equipped with functions that are commonly used on complex numbers and reals.
 /// The results of those functions only have to be approximately equal to the actual theoretical values.
-// FIXME: SubsetOf should be removed when specialization will be supported by rustc. This will
-// allow a blanket impl: impl<T: Clone> SubsetOf<T> for T { ... }
+// NOTE: SubsetOf<Self> cannot be provided by a blanket impl, even with specialization, because it
+// would overlap with the generic inclusions of `Id` and `Complex`. Use `impl_reflexive_subset!`.
 // NOTE: make all types debuggable/'static/Any ? This seems essential for any kind of generic programming.
-pub trait ComplexField:
+pub trait ComplexFieldCore:
     SubsetOf<Self>
     + SupersetOf<f64>
     + Field
     + Copy
-    + Num
-    + NumAssign
-    + FromPrimitive
     + Neg<Output = Self>
     + MeetSemilattice
     + JoinSemilattice
 //    + RelativeEq<Epsilon = Self>
 //    + UlpsEq<Epsilon = Self>
-    + Send
-    + Sync
-    + Any
-    + 'static
-    + Debug
-    + Display
+    + ScalarSendSync
+    + ScalarDisplay
+    + ComplexFromParts<<Self as ComplexFieldCore>::RealField>
 {
     /// Type of the coefficients of a complex number.
     type RealField: RealField;
@@ -129,6 +130,37 @@ pub trait ComplexField:
 
     fn is_finite(&self) -> bool;
 
+    /// Returns `true` if this number is NaN, or if one of its parts is NaN for complex numbers.
+    fn is_nan(&self) -> bool;
+
+    /// Returns `true` if this number is infinite, or if one of its parts is infinite for complex
+    /// numbers, even if the other one is NaN.
+    fn is_infinite(&self) -> bool;
+
+    /// Returns `true` if this number is subnormal, or if one of its parts is subnormal for complex
+    /// numbers.
+    fn is_subnormal(&self) -> bool;
+
+    /// Returns `true` if the modulus of `self - other` is at most `tol`.
+    ///
+    /// This is the natural tolerance for complex numbers, as it does not depend on the orientation
+    /// of the complex plane. NaNs are never approximately equal to anything.
+    #[inline]
+    fn approx_eq_modulus(&self, other: &Self, tol: Self::RealField) -> bool {
+        (*self - *other).modulus() <= tol
+    }
+
+    /// Returns `true` if both the real and the imaginary parts of `self - other` are at most `tol`
+    /// in absolute value.
+    ///
+    /// This is the same tolerance as `approx_eq_modulus` up to a factor `√2`. NaNs are never
+    /// approximately equal to anything.
+    #[inline]
+    fn approx_eq_components(&self, other: &Self, tol: Self::RealField) -> bool {
+        let diff = *self - *other;
+        diff.real().abs() <= tol && diff.imaginary().abs() <= tol
+    }
+
     /// Cardinal sine
     #[inline]
     fn sinc(self) -> Self {
@@ -183,9 +215,103 @@ pub trait ComplexField:
     fn cbrt(self) -> Self;
 }
 
+/// Conversions between a complex number and its real and imaginary parts.
+///
+/// This does not assume any memory layout, so that complex numbers stored as separate arrays of
+/// real and imaginary parts (planar or struct-of-arrays layouts) can implement `ComplexFieldCore`
+/// as well as the interleaved `num_complex::Complex`.
+pub trait ComplexFromParts<R>: Sized {
+    /// Builds a complex number from its real and imaginary parts.
+    ///
+    /// Real types discard the imaginary part.
+    fn from_re_im(re: R, im: R) -> Self;
+
+    /// The real and imaginary parts of this complex number.
+    fn into_re_im(self) -> (R, R);
+}
+
+/// [Alias] Trait alias for `Debug + Display` if the `scalar-fmt` feature is enabled, and for
+/// nothing otherwise.
+///
+/// This is a bound of `ComplexFieldCore`. Disabling the default `scalar-fmt` feature allows
+/// scalars that cannot be formatted, e.g., SIMD masks or handles to foreign values, to implement
+/// it.
+#[cfg(feature = "scalar-fmt")]
+pub trait ScalarDisplay: Debug + Display {}
+
+#[cfg(feature = "scalar-fmt")]
+impl<T: Debug + Display> ScalarDisplay for T {}
+
+/// [Alias] Trait alias for `Debug + Display` if the `scalar-fmt` feature is enabled, and for
+/// nothing otherwise.
+///
+/// This is a bound of `ComplexFieldCore`. Disabling the default `scalar-fmt` feature allows
+/// scalars that cannot be formatted, e.g., SIMD masks or handles to foreign values, to implement
+/// it.
+#[cfg(not(feature = "scalar-fmt"))]
+pub trait ScalarDisplay {}
+
+#[cfg(not(feature = "scalar-fmt"))]
+impl<T> ScalarDisplay for T {}
+
+/// [Alias] Trait alias for `Send + Sync + Any + 'static` if the `scalar-send-sync` feature is
+/// enabled, and for nothing otherwise.
+///
+/// This is a bound of `ComplexFieldCore`. Disabling the default `scalar-send-sync` feature allows
+/// scalars that borrow from an arena or that are not thread-safe, e.g., interned symbolic terms or
+/// garbage-collected handles, to implement it.
+#[cfg(feature = "scalar-send-sync")]
+pub trait ScalarSendSync: Send + Sync + Any + 'static {}
+
+#[cfg(feature = "scalar-send-sync")]
+impl<T: Send + Sync + Any + 'static> ScalarSendSync for T {}
+
+/// [Alias] Trait alias for `Send + Sync + Any + 'static` if the `scalar-send-sync` feature is
+/// enabled, and for nothing otherwise.
+///
+/// This is a bound of `ComplexFieldCore`. Disabling the default `scalar-send-sync` feature allows
+/// scalars that borrow from an arena or that are not thread-safe, e.g., interned symbolic terms or
+/// garbage-collected handles, to implement it.
+#[cfg(not(feature = "scalar-send-sync"))]
+pub trait ScalarSendSync {}
+
+#[cfg(not(feature = "scalar-send-sync"))]
+impl<T> ScalarSendSync for T {}
+
+/// Trait shared by all complex fields and its subfields (like real numbers).
+///
+/// This is a `ComplexFieldCore` with the arithmetic traits of `num-traits`, that can be hashed with
+/// `CanonicalHash`. It is implemented automatically for every type satisfying these bounds.
+pub trait ComplexField: ComplexFieldCore + Num + NumAssign + FromPrimitive + CanonicalHash {}
+
+impl<T: ComplexFieldCore + Num + NumAssign + FromPrimitive + CanonicalHash> ComplexField for T {}
+
+/// Scalars that can be hashed consistently with their equality, even if they do not implement
+/// `Hash`.
+///
+/// Numbers that compare equal, e.g., `0.0` and `-0.0`, are hashed identically. All NaNs are hashed
+/// identically, whatever their sign and payload. Scalar-generic data structures can use it to hash
+/// floating-point or complex keys.
+pub trait CanonicalHash {
+    /// Feeds a canonical form of this value into the given hasher.
+    fn canonical_hash<H: Hasher>(&self, state: &mut H);
+}
+
 macro_rules! impl_complex(
     ($($T:ty, $M:ident, $libm: ident);*) => ($(
-        impl ComplexField for $T {
+        impl ComplexFromParts<$T> for $T {
+            #[inline]
+            fn from_re_im(re: $T, _: $T) -> Self {
+                re
+            }
+
+            #[inline]
+            fn into_re_im(self) -> ($T, $T) {
+                (self, 0.0)
+            }
+        }
+
+        impl ComplexFieldCore for $T {
             type RealField = $T;
 
             #[inline]
@@ -464,6 +590,21 @@ macro_rules! impl_complex(
             fn is_finite(&self) -> bool {
                 $M::is_finite(*self)
             }
+
+            #[inline]
+            fn is_nan(&self) -> bool {
+                $M::is_nan(*self)
+            }
+
+            #[inline]
+            fn is_infinite(&self) -> bool {
+                $M::is_infinite(*self)
+            }
+
+            #[inline]
+            fn is_subnormal(&self) -> bool {
+                $M::is_subnormal(*self)
+            }
         }
     )*)
 );
@@ -483,7 +624,48 @@ impl_complex!(
 //#[cfg(feature = "decimal")]
 //impl_real!(d128, d128, d128);
 
-impl<N: RealField> ComplexField for num_complex::Complex<N> {
+macro_rules! impl_canonical_hash(
+    ($($T:ty),*) => ($(
+        impl CanonicalHash for $T {
+            #[inline]
+            fn canonical_hash<H: Hasher>(&self, state: &mut H) {
+                let bits = if self.is_nan() {
+                    <$T>::NAN.to_bits()
+                } else if *self == 0.0 {
+                    0
+                } else {
+                    self.to_bits()
+                };
+
+                state.write_u64(bits as u64)
+            }
+        }
+    )*)
+);
+
+impl_canonical_hash!(f32, f64);
+
+impl<N: CanonicalHash> CanonicalHash for num_complex::Complex<N> {
+    #[inline]
+    fn canonical_hash<H: Hasher>(&self, state: &mut H) {
+        self.re.canonical_hash(state);
+        self.im.canonical_hash(state)
+    }
+}
+
+impl<N> ComplexFromParts<N> for num_complex::Complex<N> {
+    #[inline]
+    fn from_re_im(re: N, im: N) -> Self {
+        Self::new(re, im)
+    }
+
+    #[inline]
+    fn into_re_im(self) -> (N, N) {
+        (self.re, self.im)
+    }
+}
+
+impl<N: RealField> ComplexFieldCore for num_complex::Complex<N> {
     type RealField = N;
 
     #[inline]
@@ -622,6 +804,21 @@ impl<N: RealField> ComplexField for num_complex::Complex<N> {
         self.re.is_finite() && self.im.is_finite()
     }
 
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(&self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    #[inline]
+    fn is_subnormal(&self) -> bool {
+        self.re.is_subnormal() || self.im.is_subnormal()
+    }
+
     /*
      *
      *
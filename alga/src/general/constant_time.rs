@@ -0,0 +1,21 @@
+//! Constant-time operations on secret algebraic elements.
+
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// Types whose elements can be handled as secrets.
+///
+/// # Contract
+///
+/// Implementing this trait is a promise that the ring or group operations of the type, its
+/// conversions to and from its representation, `ct_eq`, and `conditional_select`, neither branch
+/// on nor access memory at indices that depend on the values of their operands. The only
+/// information they may leak through their timing is the type of the operands.
+///
+/// This does not cover `PartialEq`, `Debug`, `Display`, or any method that returns an `Option`
+/// depending on the operands (e.g. an inverse, which is `None` exactly for zero), which may return
+/// early. Generic code handling secrets should compare them with `ct_eq` and choose between them
+/// with `conditional_select`.
+///
+/// The compiler may still break these guarantees, e.g., by turning a masking operation back into
+/// a branch, so they should be checked on the generated code for sensitive uses.
+pub trait ConstantTimeOps: ConstantTimeEq + ConditionallySelectable {}
@@ -0,0 +1,288 @@
+use approx::{AbsDiffEq, RelativeEq};
+use num_complex::Complex;
+
+use crate::general::{
+    AbstractAlternativeMagma, AbstractGroupAbelian, AbstractMagma, AbstractModule, Additive,
+    ComplexFieldCore, Identity, Multiplicative, RealField, TwoSidedInverse,
+};
+
+/// An alternative algebra is a module equipped with a multiplication that is bilinear and
+/// alternative, but not necessarily associative.
+///
+/// # Distributivity
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ Self, a × (b + c) = a × b + a × c and (a + b) × c = a × c + b × c
+/// ~~~
+///
+/// # Compatibility with the scalars
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, ∀ r ∈ Self::AbstractRing, (r a) × b = a × (r b) = r (a × b)
+/// ~~~
+///
+/// Every associative algebra, like the complex numbers or the quaternions, is alternative. The
+/// octonions are alternative without being associative.
+pub trait AlternativeAlgebra:
+    AbstractGroupAbelian<Additive>
+    + AbstractAlternativeMagma<Multiplicative>
+    + Identity<Multiplicative>
+    + AbstractModule
+{
+    /// Returns `true` if the multiplication is distributive over the addition for the given
+    /// arguments. Approximate equality is used for verifications.
+    fn prop_mul_and_add_are_distributive_approx(args: (Self, Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_mul_and_add_are_distributive_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the multiplication is distributive over the addition for the given
+    /// arguments. Approximate equality, up to the given `epsilon` and `max_relative` tolerances, is
+    /// used for verifications.
+    fn prop_mul_and_add_are_distributive_approx_with(
+        args: (Self, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b, c) = args;
+        let mul = |x: &Self, y: &Self| AbstractMagma::<Multiplicative>::operate(x, y);
+        let add = |x: &Self, y: &Self| AbstractMagma::<Additive>::operate(x, y);
+
+        relative_eq!(
+            mul(&a, &add(&b, &c)),
+            add(&mul(&a, &b), &mul(&a, &c)),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            mul(&add(&a, &b), &c),
+            add(&mul(&a, &c), &mul(&b, &c)),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if the multiplication is distributive over the addition for the given
+    /// arguments.
+    fn prop_mul_and_add_are_distributive(args: (Self, Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b, c) = args;
+        let mul = |x: &Self, y: &Self| AbstractMagma::<Multiplicative>::operate(x, y);
+        let add = |x: &Self, y: &Self| AbstractMagma::<Additive>::operate(x, y);
+
+        mul(&a, &add(&b, &c)) == add(&mul(&a, &b), &mul(&a, &c))
+            && mul(&add(&a, &b), &c) == add(&mul(&a, &c), &mul(&b, &c))
+    }
+
+    /// Returns `true` if the multiplication is compatible with the multiplication by a scalar
+    /// for the given arguments. Approximate equality is used for verifications.
+    fn prop_mul_is_compatible_with_scalars_approx(args: (Self, Self, Self::AbstractRing)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_mul_is_compatible_with_scalars_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the multiplication is compatible with the multiplication by a scalar for
+    /// the given arguments. Approximate equality, up to the given `epsilon` and `max_relative`
+    /// tolerances, is used for verifications.
+    fn prop_mul_is_compatible_with_scalars_approx_with(
+        args: (Self, Self, Self::AbstractRing),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b, r) = args;
+        let mul = |x: &Self, y: &Self| AbstractMagma::<Multiplicative>::operate(x, y);
+        let rab = mul(&a, &b).multiply_by(r.clone());
+
+        relative_eq!(
+            mul(&a.multiply_by(r.clone()), &b),
+            rab,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            mul(&a, &b.multiply_by(r)),
+            rab,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if the multiplication is compatible with the multiplication by a scalar
+    /// for the given arguments.
+    fn prop_mul_is_compatible_with_scalars(args: (Self, Self, Self::AbstractRing)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b, r) = args;
+        let mul = |x: &Self, y: &Self| AbstractMagma::<Multiplicative>::operate(x, y);
+        let rab = mul(&a, &b).multiply_by(r.clone());
+
+        mul(&a.multiply_by(r.clone()), &b) == rab && mul(&a, &b.multiply_by(r)) == rab
+    }
+}
+
+/// A normed division algebra over the reals, i.e., a possibly non-commutative ring where every
+/// nonzero element is invertible, equipped with a conjugation and a norm that is multiplicative:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, |a × b| = |a| |b|
+/// ∀ a ∈ Self,    a × ā = ā × a = |a|²
+/// ~~~
+///
+/// By Hurwitz's theorem, the only ones are the reals, the complex numbers, the quaternions and the
+/// octonions. Following the convention used for fields, the multiplicative inverse is not defined
+/// for zero.
+pub trait NormedDivisionAlgebra<R: RealField>:
+    AlternativeAlgebra<AbstractRing = R> + TwoSidedInverse<Multiplicative>
+{
+    /// The conjugate of `self`.
+    fn conjugate(&self) -> Self;
+
+    /// The squared norm of `self`.
+    fn norm_squared(&self) -> R;
+
+    /// The norm of `self`.
+    #[inline]
+    fn norm(&self) -> R {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns `true` if the norm is multiplicative for the given argument tuple. Approximate
+    /// equality is used for verifications.
+    fn prop_norm_is_multiplicative_approx(args: (Self, Self)) -> bool {
+        Self::prop_norm_is_multiplicative_approx_with(
+            args,
+            <R as AbsDiffEq>::default_epsilon(),
+            <R as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the norm is multiplicative for the given argument tuple. Approximate
+    /// equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_norm_is_multiplicative_approx_with(
+        args: (Self, Self),
+        epsilon: R,
+        max_relative: R,
+    ) -> bool {
+        let (a, b) = args;
+        let ab = AbstractMagma::<Multiplicative>::operate(&a, &b);
+
+        relative_eq!(
+            ab.norm_squared(),
+            a.norm_squared() * b.norm_squared(),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if the product of the given argument with its conjugate, on both sides, is
+    /// its squared norm. Approximate equality is used for verifications.
+    fn prop_conjugate_product_is_norm_squared_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_conjugate_product_is_norm_squared_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the product of the given argument with its conjugate, on both sides, is
+    /// its squared norm. Approximate equality, up to the given `epsilon` and `max_relative`
+    /// tolerances, is used for verifications.
+    fn prop_conjugate_product_is_norm_squared_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a,) = args;
+        let conj = a.conjugate();
+        let one: Self = Identity::<Multiplicative>::identity();
+        let norm_squared = one.multiply_by(a.norm_squared());
+
+        relative_eq!(
+            AbstractMagma::<Multiplicative>::operate(&a, &conj),
+            norm_squared,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            AbstractMagma::<Multiplicative>::operate(&conj, &a),
+            norm_squared,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+}
+
+macro_rules! impl_real_division_algebra(
+    ($($T: ty),*) => {$(
+        impl AlternativeAlgebra for $T {}
+
+        impl NormedDivisionAlgebra<$T> for $T {
+            #[inline]
+            fn conjugate(&self) -> Self {
+                *self
+            }
+
+            #[inline]
+            fn norm_squared(&self) -> $T {
+                *self * *self
+            }
+
+            #[inline]
+            fn norm(&self) -> $T {
+                ComplexFieldCore::abs(*self)
+            }
+        }
+    )*}
+);
+
+impl_real_division_algebra!(f32, f64);
+
+impl<N: RealField> AlternativeAlgebra for Complex<N> {}
+
+impl<N: RealField> NormedDivisionAlgebra<N> for Complex<N> {
+    #[inline]
+    fn conjugate(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    #[inline]
+    fn norm_squared(&self) -> N {
+        self.re * self.re + self.im * self.im
+    }
+
+    #[inline]
+    fn norm(&self) -> N {
+        ComplexFieldCore::modulus(*self)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::general::{
+    AbstractMagma, AbstractRingCommutative, Additive, Identity, Multiplicative, TwoSidedInverse,
+};
+
+/// A commutative ring with a division with remainder.
+///
+/// *A **Euclidean domain** is an integral domain equipped with a Euclidean function `N` to the
+/// natural numbers such that the remainder of the division by any nonzero element is either zero
+/// or smaller than the divisor.*
+///
+/// # Euclidean division
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, b ≠ 0 ⇒ ∃ q, r ∈ Self such that a = q × b + r and (r = 0 or N(r) < N(b))
+/// ~~~
+///
+/// # Monotonicity of the Euclidean function
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, a ≠ 0 and b ≠ 0 ⇒ N(a) ≤ N(a × b)
+/// ~~~
+///
+/// The quotient and the remainder need not be unique: the integers round towards negative infinity
+/// so that the remainder is nonnegative, while the Gaussian integers round to the nearest.
+pub trait EuclideanDomain: AbstractRingCommutative {
+    /// The Euclidean function of this element.
+    fn euclidean_norm(&self) -> u128;
+
+    /// The quotient and the remainder of the Euclidean division of `self` by `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self);
+
+    /// A greatest common divisor of `self` and `other`, computed with the Euclidean algorithm.
+    ///
+    /// It is unique up to a multiplication by a unit, and zero only if both arguments are zero.
+    fn gcd(&self, other: &Self) -> Self {
+        let zero: Self = Identity::<Additive>::identity();
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while b != zero {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+
+        a
+    }
+
+    /// A greatest common divisor `g` of `self` and `other` together with Bézout coefficients `s`
+    /// and `t` such that `g = s × self + t × other`, computed with the extended Euclidean
+    /// algorithm.
+    ///
+    /// The greatest common divisor is the one returned by `gcd`. In particular, `self` is
+    /// invertible modulo `other` iff `g` is a unit, and its inverse is then `s × g⁻¹`.
+    fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let zero: Self = Identity::<Additive>::identity();
+        let one: Self = Identity::<Multiplicative>::identity();
+        // NOTE: a - q × b.
+        let sub_mul = |a: &Self, q: &Self, b: &Self| {
+            let qb = AbstractMagma::<Multiplicative>::operate(q, b);
+            AbstractMagma::<Additive>::operate(
+                a,
+                &TwoSidedInverse::<Additive>::two_sided_inverse(&qb),
+            )
+        };
+
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (one.clone(), zero.clone());
+        let (mut t0, mut t1) = (zero.clone(), one);
+
+        while r1 != zero {
+            let (q, r2) = r0.div_rem(&r1);
+            let s2 = sub_mul(&s0, &q, &s1);
+            let t2 = sub_mul(&t0, &q, &t1);
+
+            r0 = r1;
+            r1 = r2;
+            s0 = s1;
+            s1 = s2;
+            t0 = t1;
+            t1 = t2;
+        }
+
+        (r0, s0, t0)
+    }
+
+    /// Returns `true` if the Euclidean division of the given arguments satisfies the definition.
+    fn prop_div_rem_is_euclidean(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let zero: Self = Identity::<Additive>::identity();
+
+        if b == zero {
+            return true;
+        }
+
+        let (q, r) = a.div_rem(&b);
+        let qb = AbstractMagma::<Multiplicative>::operate(&q, &b);
+
+        AbstractMagma::<Additive>::operate(&qb, &r) == a
+            && (r == zero || r.euclidean_norm() < b.euclidean_norm())
+    }
+
+    /// Returns `true` if the Euclidean function does not decrease under multiplication by the
+    /// given nonzero arguments.
+    fn prop_norm_is_monotone(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let zero: Self = Identity::<Additive>::identity();
+
+        a == zero
+            || b == zero
+            || a.euclidean_norm()
+                <= AbstractMagma::<Multiplicative>::operate(&a, &b).euclidean_norm()
+    }
+
+    /// Returns `true` if the greatest common divisor of the given arguments divides both of them.
+    fn prop_gcd_divides(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let zero: Self = Identity::<Additive>::identity();
+        let g = a.gcd(&b);
+
+        if g == zero {
+            a == zero && b == zero
+        } else {
+            a.div_rem(&g).1 == zero && b.div_rem(&g).1 == zero
+        }
+    }
+
+    /// Returns `true` if the extended Euclidean algorithm returns the greatest common divisor of
+    /// the given arguments together with Bézout coefficients.
+    fn prop_extended_gcd_is_bezout(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let (g, s, t) = a.extended_gcd(&b);
+        let sa = AbstractMagma::<Multiplicative>::operate(&s, &a);
+        let tb = AbstractMagma::<Multiplicative>::operate(&t, &b);
+
+        g == a.gcd(&b) && AbstractMagma::<Additive>::operate(&sa, &tb) == g
+    }
+}
+
+macro_rules! impl_euclidean_domain_for_int(
+    ($($T: ty),* $(,)*) => {$(
+        impl EuclideanDomain for $T {
+            #[inline]
+            fn euclidean_norm(&self) -> u128 {
+                self.unsigned_abs() as u128
+            }
+
+            #[inline]
+            fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+                // NOTE: `MIN / -1` overflows, so its quotient wraps back to `MIN`.
+                if *divisor == -1 {
+                    (self.wrapping_neg(), 0)
+                } else {
+                    (self.div_euclid(*divisor), self.rem_euclid(*divisor))
+                }
+            }
+        }
+    )*}
+);
+
+impl_euclidean_domain_for_int!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,200 @@
+use crate::general::{
+    AbstractField, AbstractMagma, AbstractMonoid, Additive, FiniteGroup, Identity, Multiplicative,
+    Operator,
+};
+
+/// A field with a finite number of elements.
+///
+/// The cardinality of a finite field is `q = pⁿ`, where the prime `p` is its characteristic and
+/// `n` is its degree over its prime subfield. The Frobenius endomorphism `x ↦ xᵖ` is then a ring
+/// automorphism of order `n`:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, (a + b)ᵖ = aᵖ + bᵖ and (a × b)ᵖ = aᵖ × bᵖ
+/// ∀ a ∈ Self, a^(pⁿ) = a
+/// ~~~
+pub trait FiniteField<A: Operator = Additive, M: Operator = Multiplicative>:
+    AbstractField<A, M> + FiniteGroup<A>
+{
+    /// The degree `n` of the field over its prime subfield, i.e., the number such that the
+    /// cardinality of the field is the characteristic raised to the power `n`.
+    fn degree() -> u32 {
+        let p = Self::characteristic();
+        let mut q = <Self as FiniteGroup<A>>::cardinality();
+        let mut n = 0;
+
+        while q > 1 {
+            q /= p;
+            n += 1;
+        }
+
+        n
+    }
+
+    /// The Frobenius endomorphism, i.e., `self` raised to the power of the characteristic.
+    ///
+    /// The default implementation uses exponentiation by squaring.
+    fn frobenius(&self) -> Self {
+        pow::<M, _>(self, Self::characteristic())
+    }
+
+    /// The Frobenius endomorphism applied `k` times, i.e., `self` raised to the power `pᵏ`.
+    ///
+    /// Because the Frobenius endomorphism has order `n`, `k` is reduced modulo the degree first.
+    fn frobenius_pow(&self, k: u32) -> Self {
+        let mut result = self.clone();
+
+        for _ in 0..k % Self::degree() {
+            result = result.frobenius();
+        }
+
+        result
+    }
+
+    /// The quadratic character of `self`: `0` for zero, `1` for the other squares and `-1` for the
+    /// non-squares. For prime fields, this is the Legendre symbol `(self / p)`.
+    ///
+    /// The default implementation uses Euler's criterion, `self^((q - 1) / 2) = ±1`. In
+    /// characteristic 2, every element is a square.
+    fn legendre_symbol(&self) -> i8 {
+        let q = <Self as FiniteGroup<A>>::cardinality();
+
+        if *self == Identity::<A>::identity() {
+            0
+        } else if q & 1 == 0 || pow::<M, _>(self, (q - 1) / 2) == Identity::<M>::identity() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Returns `true` if `self` is a square, i.e., if `self = r × r` for some `r`. Zero is a
+    /// square.
+    fn is_quadratic_residue(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// A square root of `self`, or `None` if `self` is not a square. The other square root, if
+    /// any, is its additive inverse.
+    ///
+    /// The default implementation uses the Tonelli–Shanks algorithm, which looks for a
+    /// non-square among the elements of the field first. In characteristic 2, the square root is
+    /// `self^(q / 2)`.
+    fn sqrt(&self) -> Option<Self> {
+        let q = <Self as FiniteGroup<A>>::cardinality();
+        let one: Self = Identity::<M>::identity();
+        let mul = |a: &Self, b: &Self| AbstractMagma::<M>::operate(a, b);
+
+        if q & 1 == 0 {
+            return Some(pow::<M, _>(self, q / 2));
+        }
+
+        match self.legendre_symbol() {
+            0 => return Some(self.clone()),
+            -1 => return None,
+            _ => {}
+        }
+
+        // NOTE: q - 1 = odd × 2ˢ.
+        let s = (q - 1).trailing_zeros();
+        let odd = (q - 1) >> s;
+        let non_square = <Self as FiniteGroup<A>>::elements()
+            .find(|z| z.legendre_symbol() < 0)
+            .expect("A finite field of odd characteristic has non-squares.");
+
+        let mut m = s;
+        let mut c = pow::<M, _>(&non_square, odd);
+        let mut t = pow::<M, _>(self, odd);
+        let mut r = pow::<M, _>(self, odd / 2 + 1);
+
+        // NOTE: r² = self × t and t has order 2ⁱ for some i < m.
+        while t != one {
+            let mut i = 0;
+            let mut t2i = t.clone();
+
+            while t2i != one {
+                t2i = mul(&t2i, &t2i);
+                i += 1;
+            }
+
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = mul(&b, &b);
+            }
+
+            m = i;
+            c = mul(&b, &b);
+            t = mul(&t, &c);
+            r = mul(&r, &b);
+        }
+
+        Some(r)
+    }
+
+    /// Returns `true` if the quadratic character is multiplicative for the given argument tuple.
+    fn prop_legendre_symbol_is_multiplicative(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        AbstractMagma::<M>::operate(&a, &b).legendre_symbol()
+            == a.legendre_symbol() * b.legendre_symbol()
+    }
+
+    /// Returns `true` if `sqrt` returns a square root of the given argument exactly when it is a
+    /// square.
+    fn prop_sqrt_is_square_root(args: (Self,)) -> bool {
+        let (a,) = args;
+
+        match a.sqrt() {
+            Some(r) => a.is_quadratic_residue() && AbstractMagma::<M>::operate(&r, &r) == a,
+            None => !a.is_quadratic_residue(),
+        }
+    }
+
+    /// Returns `true` if the Frobenius endomorphism preserves the addition, the multiplication,
+    /// and the multiplicative identity for the given argument tuple.
+    fn prop_frobenius_is_ring_homomorphism(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let (fa, fb) = (a.frobenius(), b.frobenius());
+        let one: Self = Identity::<M>::identity();
+
+        AbstractMagma::<A>::operate(&a, &b).frobenius() == AbstractMagma::<A>::operate(&fa, &fb)
+            && AbstractMagma::<M>::operate(&a, &b).frobenius()
+                == AbstractMagma::<M>::operate(&fa, &fb)
+            && one.frobenius() == one
+    }
+
+    /// Returns `true` if the Frobenius endomorphism applied `degree` times is the identity map for
+    /// the given argument.
+    fn prop_frobenius_has_order_degree(args: (Self,)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a,) = args;
+        let mut b = a.clone();
+
+        for _ in 0..Self::degree() {
+            b = b.frobenius();
+        }
+
+        b == a
+    }
+}
+
+/// `a` operated `k` times with itself, by exponentiation by squaring.
+fn pow<M: Operator, T: AbstractMonoid<M>>(a: &T, mut k: u64) -> T {
+    let mut base = a.clone();
+    let mut result: T = Identity::<M>::identity();
+
+    while k > 0 {
+        if k & 1 == 1 {
+            AbstractMagma::<M>::operate_assign(&mut result, &base);
+        }
+
+        base = AbstractMagma::<M>::operate(&base, &base);
+        k >>= 1;
+    }
+
+    result
+}
@@ -0,0 +1,248 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+use std::iter;
+
+#[cfg(feature = "alloc")]
+use num::Float;
+
+use crate::general::{AbstractGroup, AbstractGroupAbelian, Id, Operator};
+#[cfg(feature = "alloc")]
+use crate::general::{AbstractMagma, Identity, TwoSidedInverse};
+
+/// A group with a finite number of elements.
+///
+/// The elements of a finite group can be enumerated, which allows the group axioms to be checked
+/// exhaustively instead of on random samples. By Lagrange's theorem, the order of every element
+/// divides the cardinality of the group:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, ord(a) | |Self|
+/// ~~~
+pub trait FiniteGroup<O: Operator>: AbstractGroup<O> {
+    /// The type of the iterator over the elements of the group.
+    type Elements: Iterator<Item = Self>;
+
+    /// The number of elements of the group.
+    fn cardinality() -> u64;
+
+    /// An iterator that yields every element of the group exactly once.
+    fn elements() -> Self::Elements;
+
+    /// The order of `self`, i.e., the smallest `k > 0` such that `self` operated `k` times with
+    /// itself is the identity element.
+    ///
+    /// The default implementation operates `self` repeatedly with itself.
+    fn element_order(&self) -> u64 {
+        let mut acc = self.clone();
+
+        for k in 1..=Self::cardinality() {
+            if acc == Self::identity() {
+                return k;
+            }
+
+            acc.operate_assign(self);
+        }
+
+        panic!("The order of an element cannot exceed the cardinality of its group.")
+    }
+
+    /// Returns `true` if `elements` yields `cardinality` distinct elements, including the
+    /// identity element.
+    fn prop_elements_are_exhaustive() -> bool {
+        let mut count = 0;
+        let mut has_identity = false;
+
+        for (i, a) in Self::elements().enumerate() {
+            if Self::elements().skip(i + 1).any(|b| a == b) {
+                return false;
+            }
+
+            count += 1;
+            has_identity = has_identity || a == Self::identity();
+        }
+
+        count == Self::cardinality() && has_identity
+    }
+
+    /// Returns `true` if the order of the given argument divides the cardinality of the group
+    /// (Lagrange's theorem).
+    fn prop_element_order_divides_cardinality(args: (Self,)) -> bool {
+        let (a,) = args;
+        Self::cardinality() % a.element_order() == 0
+    }
+
+    /// Returns `true` if the order of every element of the group divides its cardinality.
+    fn prop_lagrange_holds_exhaustively() -> bool {
+        Self::elements().all(|a| Self::prop_element_order_divides_cardinality((a,)))
+    }
+}
+
+impl<O: Operator> FiniteGroup<O> for Id<O> {
+    type Elements = iter::Once<Id<O>>;
+
+    #[inline]
+    fn cardinality() -> u64 {
+        1
+    }
+
+    #[inline]
+    fn elements() -> Self::Elements {
+        iter::once(Id::new())
+    }
+
+    #[inline]
+    fn element_order(&self) -> u64 {
+        1
+    }
+}
+
+/// A finite group generated by a single element.
+///
+/// Every element is the generator `g` operated `k` times with itself, for a unique `k` smaller
+/// than the cardinality of the group, called its index (or discrete logarithm):
+///
+/// ~~~notrust
+/// ∃ g ∈ Self, ∀ a ∈ Self, ∃! k ∈ [0, |Self|) such that a = gᵏ
+/// ~~~
+pub trait CyclicGroup<O: Operator>: FiniteGroup<O> + AbstractGroupAbelian<O> {
+    /// A generator of the group.
+    fn generator() -> Self;
+
+    /// The generator operated `k` times with itself, i.e., the identity element if `k` is zero.
+    ///
+    /// The default implementation uses exponentiation by squaring.
+    fn generator_pow(k: u64) -> Self {
+        let mut base = Self::generator();
+        let mut result = Self::identity();
+        let mut k = k % Self::cardinality();
+
+        while k > 0 {
+            if k & 1 == 1 {
+                result.operate_assign(&base);
+            }
+
+            base = base.operate(&base);
+            k >>= 1;
+        }
+
+        result
+    }
+
+    /// The index of `self`, i.e., the unique `k` smaller than the cardinality of the group such
+    /// that `Self::generator_pow(k) == *self`.
+    ///
+    /// The default implementation is an exhaustive search.
+    fn index_of(&self) -> u64 {
+        let generator = Self::generator();
+        let mut acc = Self::identity();
+
+        for k in 0..Self::cardinality() {
+            if acc == *self {
+                return k;
+            }
+
+            acc.operate_assign(&generator);
+        }
+
+        panic!("The element is not generated by the generator of its group.")
+    }
+
+    /// Returns `true` if the order of the generator is the cardinality of the group.
+    fn prop_generator_order_is_cardinality() -> bool {
+        Self::generator().element_order() == Self::cardinality()
+    }
+
+    /// Returns `true` if `index_of` is the inverse of `generator_pow` for the given argument.
+    fn prop_index_of_is_inverse_of_generator_pow(args: (Self,)) -> bool {
+        let (a,) = args;
+        let k = a.index_of();
+
+        k < Self::cardinality() && Self::generator_pow(k) == a
+    }
+
+    /// Returns `true` if `generator_pow` maps sums of exponents to operations for the given
+    /// argument tuple.
+    fn prop_generator_pow_is_homomorphism(args: (u64, u64)) -> bool {
+        let (j, k) = args;
+        let n = Self::cardinality();
+        let sum = (j % n + k % n) % n;
+
+        Self::generator_pow(sum) == Self::generator_pow(j).operate(&Self::generator_pow(k))
+    }
+}
+
+/// The smallest `k` such that `base` operated `k` times with itself is `target`, or `None` if
+/// `target` is not a power of `base`.
+///
+/// This is `discrete_log_bounded` with about `√|G|` baby steps, i.e., in `O(√|G|)` time and
+/// memory.
+#[cfg(feature = "alloc")]
+pub fn discrete_log<O, G>(base: &G, target: &G) -> Option<u64>
+where
+    O: Operator,
+    G: FiniteGroup<O> + Ord,
+{
+    discrete_log_bounded(base, target, usize::MAX)
+}
+
+/// The smallest `k` such that `base` operated `k` times with itself is `target`, or `None` if
+/// `target` is not a power of `base`, storing at most `max_baby_steps` elements.
+///
+/// This is the baby-step giant-step algorithm: with `m` baby steps, the powers `baseʲ` for
+/// `j < m` are stored, then `target ∘ base⁻ⁱᵐ` is looked up among them for increasing `i`. It
+/// performs `O(m + |G| / m)` operations, which is minimal for `m = ⌈√|G|⌉`, the number of baby
+/// steps used when `max_baby_steps` is larger.
+#[cfg(feature = "alloc")]
+pub fn discrete_log_bounded<O, G>(base: &G, target: &G, max_baby_steps: usize) -> Option<u64>
+where
+    O: Operator,
+    G: FiniteGroup<O> + Ord,
+{
+    let n = G::cardinality();
+    let m = ceil_sqrt(n).min(max_baby_steps.max(1) as u64);
+    let identity: G = Identity::<O>::identity();
+    let mut baby_steps = BTreeMap::new();
+    let mut acc = identity.clone();
+
+    for j in 0..m {
+        // NOTE: the order of `base` is `j`, so the baby steps already contain all its powers.
+        if j > 0 && acc == identity {
+            return baby_steps.get(target).cloned();
+        }
+
+        let _ = baby_steps.insert(acc.clone(), j);
+        AbstractMagma::<O>::operate_assign(&mut acc, base);
+    }
+
+    let giant_step = TwoSidedInverse::<O>::two_sided_inverse(&acc);
+    let mut gamma = target.clone();
+
+    for i in 0..n / m + (n % m != 0) as u64 {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * m + j);
+        }
+
+        AbstractMagma::<O>::operate_assign(&mut gamma, &giant_step);
+    }
+
+    None
+}
+
+/// The smallest `m` such that `m² ≥ n`.
+#[cfg(feature = "alloc")]
+fn ceil_sqrt(n: u64) -> u64 {
+    let n = n as u128;
+    let mut m = Float::sqrt(n as f64) as u128;
+
+    while m * m < n {
+        m += 1;
+    }
+
+    while m > 0 && (m - 1) * (m - 1) >= n {
+        m -= 1;
+    }
+
+    m as u64
+}
@@ -0,0 +1,113 @@
+//! Conversions between fixed-point numbers from the `fixed` crate and other scalars.
+
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+use num::Float;
+
+use crate::general::SubsetOf;
+
+macro_rules! impl_fixed_subset_of_float(
+    ($($fixed: ident, $frac_bound: ident);* $(;)*) => {$(
+        impl_fixed_subset_of_float!(@float $fixed, $frac_bound, f32);
+        impl_fixed_subset_of_float!(@float $fixed, $frac_bound, f64);
+    )*};
+    (@float $fixed: ident, $frac_bound: ident, $float: ty) => {
+        impl<Frac: $frac_bound> SubsetOf<$float> for $fixed<Frac> {
+            #[inline]
+            fn to_superset(&self) -> $float {
+                self.to_num::<$float>()
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$float) -> $fixed<Frac> {
+                $fixed::<Frac>::wrapping_from_num(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$float) -> bool {
+                // NOTE: multiplying by a power of two is exact (the product cannot overflow an
+                // `f64` if the element is in range), so `element` is representable iff the scaled
+                // value is an integer that fits the fixed-point storage.
+                let scale = Float::powi(2.0f64, $fixed::<Frac>::FRAC_NBITS as i32);
+                Float::fract(*element as f64 * scale) == 0.0
+                    && $fixed::<Frac>::checked_from_num(*element).is_some()
+            }
+        }
+
+        impl<Frac: $frac_bound> SubsetOf<$fixed<Frac>> for $float {
+            #[inline]
+            fn to_superset(&self) -> $fixed<Frac> {
+                $fixed::<Frac>::wrapping_from_num(*self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$fixed<Frac>) -> $float {
+                element.to_num::<$float>()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$fixed<Frac>) -> bool {
+                $fixed::<Frac>::checked_from_num(element.to_num::<$float>()) == Some(*element)
+            }
+        }
+    };
+);
+
+macro_rules! impl_fixed_subset_of_fixed(
+    ($($fixed: ident, $frac_bound: ident);* $(;)*) => {
+        impl_fixed_subset_of_fixed!(@subsets [$($fixed, $frac_bound);*] [$($fixed, $frac_bound);*]);
+    };
+    (@subsets [$($subset: ident, $sub_bound: ident);*] $supersets: tt) => {$(
+        impl_fixed_subset_of_fixed!(@supersets $subset, $sub_bound; $supersets);
+    )*};
+    (@supersets $subset: ident, $sub_bound: ident; [$($superset: ident, $super_bound: ident);*]) => {$(
+        impl<Frac1: $sub_bound, Frac2: $super_bound> SubsetOf<$superset<Frac2>> for $subset<Frac1> {
+            #[inline]
+            fn to_superset(&self) -> $superset<Frac2> {
+                $superset::<Frac2>::wrapping_from_num(*self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset<Frac2>) -> $subset<Frac1> {
+                $subset::<Frac1>::wrapping_from_num(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset<Frac2>) -> bool {
+                match $subset::<Frac1>::checked_from_num(*element) {
+                    Some(e) => $superset::<Frac2>::checked_from_num(e) == Some(*element),
+                    None => false,
+                }
+            }
+        }
+    )*};
+);
+
+impl_fixed_subset_of_float!(
+    FixedI8, LeEqU8;
+    FixedI16, LeEqU16;
+    FixedI32, LeEqU32;
+    FixedI64, LeEqU64;
+    FixedI128, LeEqU128;
+    FixedU8, LeEqU8;
+    FixedU16, LeEqU16;
+    FixedU32, LeEqU32;
+    FixedU64, LeEqU64;
+    FixedU128, LeEqU128;
+);
+
+impl_fixed_subset_of_fixed!(
+    FixedI8, LeEqU8;
+    FixedI16, LeEqU16;
+    FixedI32, LeEqU32;
+    FixedI64, LeEqU64;
+    FixedI128, LeEqU128;
+    FixedU8, LeEqU8;
+    FixedU16, LeEqU16;
+    FixedU32, LeEqU32;
+    FixedU64, LeEqU64;
+    FixedU128, LeEqU128;
+);
@@ -0,0 +1,195 @@
+//! Free groups on arbitrary sets of generators.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::general::{
+    AbstractGroup, AbstractLoop, AbstractMagma, AbstractMonoid, AbstractQuasigroup,
+    AbstractSemigroup, Identity, Multiplicative, Operator, TwoSidedInverse,
+};
+
+/// A letter of a word of a free group: either a generator, or the inverse of a generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Letter<G> {
+    /// A generator.
+    Generator(G),
+    /// The inverse of a generator.
+    Inverse(G),
+}
+
+impl<G> Letter<G> {
+    /// The generator this letter refers to.
+    #[inline]
+    pub fn generator(&self) -> &G {
+        match self {
+            Letter::Generator(g) | Letter::Inverse(g) => g,
+        }
+    }
+
+    /// The inverse of this letter.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        match self {
+            Letter::Generator(g) => Letter::Inverse(g),
+            Letter::Inverse(g) => Letter::Generator(g),
+        }
+    }
+}
+
+impl<G: PartialEq> Letter<G> {
+    /// Returns `true` if `self` and `other` cancel each other out, i.e., are a generator and its
+    /// inverse.
+    #[inline]
+    pub fn cancels(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Letter::Generator(a), Letter::Inverse(b))
+            | (Letter::Inverse(a), Letter::Generator(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The free group on the generators of type `G`.
+///
+/// An element is a word, i.e., a sequence of generators and of their inverses. Words are always
+/// kept reduced: no generator is ever adjacent to its own inverse. Because reduced words are
+/// unique, two elements are equal if and only if they have the same letters. The group operation
+/// is the concatenation of words, followed by the reduction of the letters that cancel out at
+/// their junction.
+///
+/// This allows group identities to be stated and checked symbolically, before they are evaluated
+/// on a concrete group with `evaluate`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FreeGroup<G> {
+    letters: Vec<Letter<G>>,
+}
+
+impl<G: PartialEq> FreeGroup<G> {
+    /// The word made of the single generator `g`.
+    #[inline]
+    pub fn generator(g: G) -> Self {
+        FreeGroup::from_letters(Some(Letter::Generator(g)))
+    }
+
+    /// The reduced word obtained from the given sequence of letters.
+    pub fn from_letters<I: IntoIterator<Item = Letter<G>>>(letters: I) -> Self {
+        let mut res = FreeGroup {
+            letters: Vec::new(),
+        };
+        res.extend(letters);
+        res
+    }
+
+    /// The letters of this reduced word.
+    #[inline]
+    pub fn letters(&self) -> &[Letter<G>] {
+        &self.letters
+    }
+
+    /// The length of this reduced word.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Returns `true` if this is the empty word, i.e., the identity element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /// Evaluates this word on a concrete group, given the images of the generators.
+    ///
+    /// This is the unique group homomorphism from the free group to `T`, relative to the operator
+    /// `O`, that maps each generator `g` to `images(g)`.
+    pub fn evaluate<O, T, F>(&self, mut images: F) -> T
+    where
+        O: Operator,
+        T: AbstractGroup<O>,
+        F: FnMut(&G) -> T,
+    {
+        let mut res = T::identity();
+
+        for letter in &self.letters {
+            let image = images(letter.generator());
+
+            match letter {
+                Letter::Generator(_) => res.operate_assign(&image),
+                Letter::Inverse(_) => res.operate_assign(&image.two_sided_inverse()),
+            }
+        }
+
+        res
+    }
+
+    /// Appends the given letters to this word, reducing it at the junction.
+    fn extend<I: IntoIterator<Item = Letter<G>>>(&mut self, letters: I) {
+        for letter in letters {
+            match self.letters.last() {
+                Some(last) if last.cancels(&letter) => {
+                    let _ = self.letters.pop();
+                }
+                _ => self.letters.push(letter),
+            }
+        }
+    }
+}
+
+impl<G: Clone + PartialEq> AbstractMagma<Multiplicative> for FreeGroup<G> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut res = self.clone();
+        res.extend(right.letters.iter().cloned());
+        res
+    }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        self.extend(right.letters.iter().cloned())
+    }
+
+    #[inline]
+    fn operate_owned(mut self, right: Self) -> Self {
+        self.extend(right.letters);
+        self
+    }
+}
+
+impl<G: Clone + PartialEq> TwoSidedInverse<Multiplicative> for FreeGroup<G> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        FreeGroup {
+            letters: self
+                .letters
+                .iter()
+                .rev()
+                .cloned()
+                .map(Letter::inverse)
+                .collect(),
+        }
+    }
+
+    #[inline]
+    fn two_sided_inverse_mut(&mut self) {
+        self.letters.reverse();
+
+        for letter in &mut self.letters {
+            *letter = letter.clone().inverse();
+        }
+    }
+}
+
+impl<G> Identity<Multiplicative> for FreeGroup<G> {
+    #[inline]
+    fn identity() -> Self {
+        FreeGroup {
+            letters: Vec::new(),
+        }
+    }
+}
+
+impl<G: Clone + PartialEq> AbstractSemigroup<Multiplicative> for FreeGroup<G> {}
+impl<G: Clone + PartialEq> AbstractQuasigroup<Multiplicative> for FreeGroup<G> {}
+impl<G: Clone + PartialEq> AbstractMonoid<Multiplicative> for FreeGroup<G> {}
+impl<G: Clone + PartialEq> AbstractLoop<Multiplicative> for FreeGroup<G> {}
+impl<G: Clone + PartialEq> AbstractGroup<Multiplicative> for FreeGroup<G> {}
@@ -0,0 +1,312 @@
+//! Gaussian integers.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num::{One, Zero};
+use num_complex::Complex;
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, EuclideanDomain, Identity, Module, Multiplicative, PartialInverse, RingCommutative,
+    SubsetOf, SupersetOf, TwoSidedInverse,
+};
+
+/// A Gaussian integer `re + im i`, i.e., a complex number with integer parts.
+///
+/// The Gaussian integers `ℤ[i]` form a Euclidean domain for the norm `re² + im²`: the quotient of
+/// a division is the exact complex quotient with both parts rounded to the nearest integer, which
+/// leaves a remainder of at most half the norm of the divisor. Their only units are `±1` and `±i`.
+///
+/// The norm of the operands of a division must fit into the integer type `N`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GaussianInt<N> {
+    /// The real part.
+    pub re: N,
+    /// The imaginary part.
+    pub im: N,
+}
+
+impl<N> GaussianInt<N> {
+    /// Creates a Gaussian integer from its parts.
+    #[inline]
+    pub const fn new(re: N, im: N) -> Self {
+        GaussianInt { re, im }
+    }
+}
+
+impl<N: RingCommutative> GaussianInt<N> {
+    /// The imaginary unit `i`.
+    #[inline]
+    pub fn i() -> Self {
+        GaussianInt::new(N::zero(), N::one())
+    }
+
+    /// The conjugate `re - im i`.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        GaussianInt::new(self.re.clone(), -self.im.clone())
+    }
+
+    /// The norm `re² + im²`, i.e., the product of this number with its conjugate.
+    #[inline]
+    pub fn norm(&self) -> N {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for GaussianInt<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}i", self.re, self.im)
+    }
+}
+
+impl<N: RingCommutative> Add for GaussianInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        GaussianInt::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<N: RingCommutative> Sub for GaussianInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        GaussianInt::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<N: RingCommutative> Mul for GaussianInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        GaussianInt::new(
+            self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone(),
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<N: RingCommutative> Mul<N> for GaussianInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Self {
+        GaussianInt::new(self.re * rhs.clone(), self.im * rhs)
+    }
+}
+
+impl<N: RingCommutative> Neg for GaussianInt<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        GaussianInt::new(-self.re, -self.im)
+    }
+}
+
+impl<N: RingCommutative> AddAssign for GaussianInt<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs
+    }
+}
+
+impl<N: RingCommutative> SubAssign for GaussianInt<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs
+    }
+}
+
+impl<N: RingCommutative> MulAssign for GaussianInt<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<N: RingCommutative> MulAssign<N> for GaussianInt<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<N: RingCommutative> Zero for GaussianInt<N> {
+    #[inline]
+    fn zero() -> Self {
+        GaussianInt::new(N::zero(), N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<N: RingCommutative> One for GaussianInt<N> {
+    #[inline]
+    fn one() -> Self {
+        GaussianInt::new(N::one(), N::zero())
+    }
+}
+
+impl<N: RingCommutative> AbstractMagma<Additive> for GaussianInt<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+}
+
+impl<N: RingCommutative> AbstractMagma<Multiplicative> for GaussianInt<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() * right.clone()
+    }
+}
+
+impl<N: RingCommutative> TwoSidedInverse<Additive> for GaussianInt<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<N: RingCommutative> Identity<Additive> for GaussianInt<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<N: RingCommutative> Identity<Multiplicative> for GaussianInt<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<N: RingCommutative> AbstractSemigroup<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractQuasigroup<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractMonoid<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractLoop<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractGroup<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractGroupAbelian<Additive> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractSemigroup<Multiplicative> for GaussianInt<N> {}
+impl<N: RingCommutative> AbstractMonoid<Multiplicative> for GaussianInt<N> {}
+
+impl<N: RingCommutative> AbstractRing for GaussianInt<N> {
+    #[inline]
+    fn characteristic() -> u64 {
+        N::characteristic()
+    }
+}
+
+impl<N: RingCommutative> AbstractRingCommutative for GaussianInt<N> {}
+
+impl<N: RingCommutative> PartialInverse<Multiplicative> for GaussianInt<N> {
+    /// The inverse of the units `±1` and `±i`, i.e., their conjugate, or `None` for any other
+    /// Gaussian integer.
+    #[inline]
+    fn partial_inverse(&self) -> Option<Self> {
+        if self.norm().is_one() {
+            Some(self.conjugate())
+        } else {
+            None
+        }
+    }
+}
+
+impl<N: RingCommutative> AbstractModule for GaussianInt<N> {
+    type AbstractRing = N;
+
+    #[inline]
+    fn multiply_by(&self, r: N) -> Self {
+        self.clone() * r
+    }
+}
+
+impl<N: RingCommutative> Module for GaussianInt<N> {
+    type Ring = N;
+}
+
+macro_rules! impl_euclidean_domain_for_gaussian_int(
+    ($($T: ty),* $(,)*) => {$(
+        impl EuclideanDomain for GaussianInt<$T> {
+            #[inline]
+            fn euclidean_norm(&self) -> u128 {
+                self.norm() as u128
+            }
+
+            #[inline]
+            fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+                let n = divisor.norm();
+                assert!(n != 0, "Gaussian integer division by zero.");
+
+                // The integer nearest to `x / n`, for `n > 0`.
+                let nearest = |x: $T| {
+                    let (q, r) = (x.div_euclid(n), x.rem_euclid(n));
+
+                    if r > n - r {
+                        q + 1
+                    } else {
+                        q
+                    }
+                };
+
+                let num = *self * divisor.conjugate();
+                let q = GaussianInt::new(nearest(num.re), nearest(num.im));
+
+                (q, *self - q * *divisor)
+            }
+        }
+    )*}
+);
+
+impl_euclidean_domain_for_gaussian_int!(i8, i16, i32, i64, i128, isize);
+
+impl<N1, N2: SupersetOf<N1>> SubsetOf<GaussianInt<N2>> for GaussianInt<N1> {
+    #[inline]
+    fn to_superset(&self) -> GaussianInt<N2> {
+        GaussianInt::new(N2::from_subset(&self.re), N2::from_subset(&self.im))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &GaussianInt<N2>) -> GaussianInt<N1> {
+        GaussianInt::new(
+            element.re.to_subset_unchecked(),
+            element.im.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(c: &GaussianInt<N2>) -> bool {
+        c.re.is_in_subset() && c.im.is_in_subset()
+    }
+}
+
+impl<N1, N2: SupersetOf<N1>> SubsetOf<Complex<N2>> for GaussianInt<N1> {
+    #[inline]
+    fn to_superset(&self) -> Complex<N2> {
+        Complex::new(N2::from_subset(&self.re), N2::from_subset(&self.im))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Complex<N2>) -> GaussianInt<N1> {
+        GaussianInt::new(
+            element.re.to_subset_unchecked(),
+            element.im.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(c: &Complex<N2>) -> bool {
+        c.re.is_in_subset() && c.im.is_in_subset()
+    }
+}
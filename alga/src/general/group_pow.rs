@@ -0,0 +1,207 @@
+//! Exponentiation in groups by integers of any size.
+
+use approx::RelativeEq;
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::general::{AbstractGroup, AbstractMagma, Identity, Operator, TwoSidedInverse};
+
+/// An integer usable as the exponent of `GroupPow::pow`.
+///
+/// The exponent is read as its sign and the bits of its magnitude, from the most significant one.
+pub trait Exponent {
+    /// Returns `true` if this exponent is negative.
+    fn is_negative(&self) -> bool;
+
+    /// An upper bound of the number of bits of the magnitude of this exponent.
+    ///
+    /// The primitive integers return their width, so that the number of operations performed by
+    /// `GroupPow::pow` does not depend on the value of the exponent. Arbitrary-precision integers
+    /// return their actual bit length, which leaks their magnitude.
+    fn bit_len(&self) -> u64;
+
+    /// The bit `i` of the magnitude of this exponent. `i` is smaller than `self.bit_len()`.
+    fn bit(&self, i: u64) -> bool;
+}
+
+macro_rules! impl_exponent_unsigned(
+    ($($T: ty),* $(,)*) => {$(
+        impl Exponent for $T {
+            #[inline]
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn bit_len(&self) -> u64 {
+                <$T>::max_value().count_ones() as u64
+            }
+
+            #[inline]
+            fn bit(&self, i: u64) -> bool {
+                (*self >> i) & 1 == 1
+            }
+        }
+    )*}
+);
+
+macro_rules! impl_exponent_signed(
+    ($($T: ty),* $(,)*) => {$(
+        impl Exponent for $T {
+            #[inline]
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+
+            #[inline]
+            fn bit_len(&self) -> u64 {
+                <$T>::max_value().count_ones() as u64 + 1
+            }
+
+            #[inline]
+            fn bit(&self, i: u64) -> bool {
+                (self.unsigned_abs() >> i) & 1 == 1
+            }
+        }
+    )*}
+);
+
+impl_exponent_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_exponent_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "num-bigint")]
+impl Exponent for BigUint {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn bit_len(&self) -> u64 {
+        self.bits()
+    }
+
+    #[inline]
+    fn bit(&self, i: u64) -> bool {
+        BigUint::bit(self, i)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl Exponent for BigInt {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.sign() == Sign::Minus
+    }
+
+    #[inline]
+    fn bit_len(&self) -> u64 {
+        self.magnitude().bit_len()
+    }
+
+    #[inline]
+    fn bit(&self, i: u64) -> bool {
+        self.magnitude().bit(i)
+    }
+}
+
+/// Exponentiation by integers of any type in a group, implemented for every `AbstractGroup`.
+///
+/// ```notrust
+/// g⁰ = e, gⁿ⁺¹ = gⁿ ∘ g and g⁻ⁿ = (g⁻¹)ⁿ
+/// ```
+pub trait GroupPow<O: Operator>: AbstractGroup<O> {
+    /// This element operated `|e|` times with itself, or with its inverse if `e` is negative.
+    ///
+    /// This uses the Montgomery ladder: each bit of the exponent costs exactly two operations,
+    /// whatever its value, and the number of bits is fixed for the primitive integers. The timing
+    /// of the result then only depends on the exponent through the branch selecting the operands,
+    /// and through the operations of the group themselves, which are not required to run in
+    /// constant time.
+    #[inline]
+    fn pow<E: Exponent + ?Sized>(&self, e: &E) -> Self {
+        let mut r1 = if e.is_negative() {
+            TwoSidedInverse::<O>::two_sided_inverse(self)
+        } else {
+            self.clone()
+        };
+        let mut r0 = Identity::<O>::identity();
+
+        // NOTE: r0 and r1 are powers of the same element, so they commute.
+        for i in (0..e.bit_len()).rev() {
+            if e.bit(i) {
+                AbstractMagma::<O>::operate_assign(&mut r0, &r1);
+                r1 = AbstractMagma::<O>::operate(&r1, &r1);
+            } else {
+                AbstractMagma::<O>::operate_assign(&mut r1, &r0);
+                r0 = AbstractMagma::<O>::operate(&r0, &r0);
+            }
+        }
+
+        r0
+    }
+
+    /// Returns `true` if exponentiation is a group homomorphism from the integers, i.e., if
+    /// `gᵃ⁺ᵇ = gᵃ ∘ gᵇ` and `g⁻ᵃ = (gᵃ)⁻¹`. Approximate equality is used for verifications.
+    fn prop_pow_is_homomorphism_approx(args: (Self, i32, i32)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_pow_is_homomorphism_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if exponentiation is a group homomorphism from the integers, i.e., if `gᵃ⁺ᵇ =
+    /// gᵃ ∘ gᵇ` and `g⁻ᵃ = (gᵃ)⁻¹`. Approximate equality, up to the given `epsilon` and
+    /// `max_relative` tolerances, is used for verifications.
+    fn prop_pow_is_homomorphism_approx_with(
+        args: (Self, i32, i32),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (g, a, b) = args;
+        let (a, b) = (a as i64, b as i64);
+        let lhs = GroupPow::<O>::pow(&g, &(a + b));
+        let rhs =
+            AbstractMagma::<O>::operate(&GroupPow::<O>::pow(&g, &a), &GroupPow::<O>::pow(&g, &b));
+        let inv = TwoSidedInverse::<O>::two_sided_inverse(&GroupPow::<O>::pow(&g, &a));
+
+        relative_eq!(
+            lhs,
+            rhs,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            GroupPow::<O>::pow(&g, &-a),
+            inv,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if exponentiation is a group homomorphism from the integers, i.e., if
+    /// `gᵃ⁺ᵇ = gᵃ ∘ gᵇ` and `g⁻ᵃ = (gᵃ)⁻¹`. Using `Eq` for verifications.
+    fn prop_pow_is_homomorphism(args: (Self, i32, i32)) -> bool
+    where
+        Self: Eq,
+    {
+        let (g, a, b) = args;
+        let (a, b) = (a as i64, b as i64);
+        let lhs = GroupPow::<O>::pow(&g, &(a + b));
+        let rhs =
+            AbstractMagma::<O>::operate(&GroupPow::<O>::pow(&g, &a), &GroupPow::<O>::pow(&g, &b));
+        let inv = TwoSidedInverse::<O>::two_sided_inverse(&GroupPow::<O>::pow(&g, &a));
+
+        lhs == rhs && GroupPow::<O>::pow(&g, &-a) == inv
+    }
+}
+
+impl<O: Operator, T: AbstractGroup<O>> GroupPow<O> for T {}
@@ -2,6 +2,7 @@
 use decimal::d128;
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
 
@@ -13,8 +14,10 @@ use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use crate::general::{
     AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
-    AbstractQuasigroup, AbstractSemigroup, Additive, JoinSemilattice, Lattice, MeetSemilattice,
-    Multiplicative, Operator, SubsetOf, TwoSidedInverse,
+    AbstractQuasigroup, AbstractSemigroup, Additive, BoundedLattice, CanonicalHash,
+    CompleteLattice, Composition, DistributiveLattice, ExactSubsetOf, HeytingAlgebra,
+    JoinSemilattice, Lattice, LowerBoundedLattice, MeetSemilattice, Multiplicative,
+    MultiplicativeComposition, Operator, SubsetOf, TwoSidedInverse,
 };
 
 /// A type that is equipped with identity.
@@ -32,12 +35,21 @@ pub trait Identity<O: Operator> {
     }
 }
 
-impl_ident!(Additive; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-impl_ident!(Additive; 0.; f32, f64);
+/// A type whose identity element is known at compile-time.
+///
+/// This is the `const` counterpart of `Identity`: the identity element can be used to initialize
+/// `const` and `static` items. It must be equal to `Identity::identity()`.
+pub trait ConstIdentity<O: Operator>: Identity<O> {
+    /// The identity element.
+    const IDENTITY: Self;
+}
+
+impl_const_ident!(Additive; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(Additive; 0.; f32, f64);
 #[cfg(feature = "decimal")]
 impl_ident!(Additive; d128!(0.); d128);
-impl_ident!(Multiplicative; 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-impl_ident!(Multiplicative; 1.; f32, f64);
+impl_const_ident!(Multiplicative; 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(Multiplicative; 1.; f32, f64);
 #[cfg(feature = "decimal")]
 impl_ident!(Multiplicative; d128!(1.); d128);
 
@@ -58,6 +70,37 @@ impl<N: Num + Clone> Identity<Multiplicative> for Complex<N> {
     }
 }
 
+impl<N: ConstIdentity<Additive>> ConstIdentity<Additive> for Complex<N> {
+    const IDENTITY: Self = Complex {
+        re: N::IDENTITY,
+        im: N::IDENTITY,
+    };
+}
+
+impl<N> ConstIdentity<Multiplicative> for Complex<N>
+where
+    N: Num + Clone + ConstIdentity<Additive> + ConstIdentity<Multiplicative>,
+{
+    const IDENTITY: Self = Complex {
+        re: <N as ConstIdentity<Multiplicative>>::IDENTITY,
+        im: <N as ConstIdentity<Additive>>::IDENTITY,
+    };
+}
+
+impl<T: MultiplicativeComposition + Identity<Multiplicative>> Identity<Composition> for T {
+    #[inline]
+    fn identity() -> Self {
+        Identity::<Multiplicative>::identity()
+    }
+}
+
+impl<T> ConstIdentity<Composition> for T
+where
+    T: MultiplicativeComposition + ConstIdentity<Multiplicative>,
+{
+    const IDENTITY: Self = <T as ConstIdentity<Multiplicative>>::IDENTITY;
+}
+
 /// The universal identity element wrt. a given operator, usually noted `Id` with a
 /// context-dependent subscript.
 ///
@@ -73,7 +116,7 @@ pub struct Id<O: Operator = Multiplicative> {
 impl<O: Operator> Id<O> {
     /// Creates a new identity element.
     #[inline]
-    pub fn new() -> Id<O> {
+    pub const fn new() -> Id<O> {
         Id { _op: PhantomData }
     }
 }
@@ -102,6 +145,20 @@ impl<O: Operator> PartialEq for Id<O> {
 
 impl<O: Operator> Eq for Id<O> {}
 
+impl<O: Operator> Hash for Id<O> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {
+        // no-op
+    }
+}
+
+impl<O: Operator> CanonicalHash for Id<O> {
+    #[inline]
+    fn canonical_hash<H: Hasher>(&self, _: &mut H) {
+        // no-op
+    }
+}
+
 impl<O: Operator> PartialOrd for Id<O> {
     #[inline]
     fn partial_cmp(&self, _: &Id<O>) -> Option<Ordering> {
@@ -116,6 +173,10 @@ impl<O: Operator> Identity<O> for Id<O> {
     }
 }
 
+impl<O: Operator> ConstIdentity<O> for Id<O> {
+    const IDENTITY: Self = Id::new();
+}
+
 impl<O: Operator> AbsDiffEq for Id<O> {
     type Epsilon = Id<O>;
 
@@ -206,6 +267,11 @@ impl<O: Operator> AbstractMagma<O> for Id<O> {
     fn operate(&self, _: &Self) -> Id<O> {
         Id::new()
     }
+
+    #[inline]
+    fn operate_assign(&mut self, _: &Self) {
+        // no-op
+    }
 }
 
 impl<O: Operator> TwoSidedInverse<O> for Id<O> {
@@ -268,6 +334,8 @@ impl<O: Operator, T: PartialEq + Identity<O>> SubsetOf<T> for Id<O> {
     }
 }
 
+impl<O: Operator, T: PartialEq + Identity<O>> ExactSubsetOf<T> for Id<O> {}
+
 impl<O: Operator> MeetSemilattice for Id<O> {
     #[inline]
     fn meet(&self, _: &Self) -> Self {
@@ -283,3 +351,27 @@ impl<O: Operator> JoinSemilattice for Id<O> {
 }
 
 impl<O: Operator> Lattice for Id<O> {}
+
+impl<O: Operator> LowerBoundedLattice for Id<O> {
+    #[inline]
+    fn bottom() -> Self {
+        Id::new()
+    }
+}
+
+impl<O: Operator> BoundedLattice for Id<O> {
+    #[inline]
+    fn top() -> Self {
+        Id::new()
+    }
+}
+
+impl<O: Operator> DistributiveLattice for Id<O> {}
+impl<O: Operator> CompleteLattice for Id<O> {}
+
+impl<O: Operator> HeytingAlgebra for Id<O> {
+    #[inline]
+    fn implies(&self, _: &Self) -> Self {
+        Id::new()
+    }
+}
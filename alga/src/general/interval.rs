@@ -0,0 +1,236 @@
+//! Closed intervals, ordered by inclusion.
+
+use approx::{AbsDiffEq, RelativeEq};
+use std::cmp::Ordering;
+
+use crate::general::{
+    BoundedLattice, CompleteLattice, JoinSemilattice, Lattice, LowerBoundedLattice,
+    MeetSemilattice, RealField,
+};
+use crate::linear::ConvexSpace;
+
+/// A closed interval `[inf, sup] = { x | inf ≤ x ≤ sup }` of a lattice, possibly empty.
+///
+/// The intervals are ordered by inclusion and form a lattice, with the empty interval as `bottom`:
+/// the meet is the intersection, and the join is the convex hull, i.e., the smallest interval
+/// containing both operands.
+///
+/// ~~~notrust
+/// [a, b] ∧ [c, d] = [a ∨ c, b ∧ d], or ∅ if a ∨ c ≰ b ∧ d
+/// [a, b] ∨ [c, d] = [a ∧ c, b ∨ d]
+/// ~~~
+///
+/// The hull may contain points that are in neither operand, so this lattice is not distributive.
+/// It is bounded, and complete, whenever the lattice of the endpoints is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<N> {
+    // NOTE: `None` stands for the empty interval, so that it has a unique representation.
+    bounds: Option<(N, N)>,
+}
+
+impl<N> Interval<N> {
+    /// The empty interval.
+    #[inline]
+    pub const fn empty() -> Self {
+        Interval { bounds: None }
+    }
+
+    /// Returns `true` if this interval contains no element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_none()
+    }
+
+    /// The lower endpoint of this interval, or `None` if it is empty.
+    #[inline]
+    pub fn inf(&self) -> Option<&N> {
+        self.bounds.as_ref().map(|b| &b.0)
+    }
+
+    /// The upper endpoint of this interval, or `None` if it is empty.
+    #[inline]
+    pub fn sup(&self) -> Option<&N> {
+        self.bounds.as_ref().map(|b| &b.1)
+    }
+
+    /// The endpoints of this interval, or `None` if it is empty.
+    #[inline]
+    pub fn into_bounds(self) -> Option<(N, N)> {
+        self.bounds
+    }
+}
+
+impl<N: PartialOrd> Interval<N> {
+    /// The interval `[inf, sup]`, which is empty unless `inf ≤ sup`.
+    ///
+    /// In particular, the interval is empty if one of its endpoints is NaN.
+    #[inline]
+    pub fn new(inf: N, sup: N) -> Self {
+        if inf <= sup {
+            Interval {
+                bounds: Some((inf, sup)),
+            }
+        } else {
+            Interval::empty()
+        }
+    }
+
+    /// Returns `true` if `x` lies in this interval.
+    #[inline]
+    pub fn contains(&self, x: &N) -> bool {
+        match &self.bounds {
+            Some((inf, sup)) => inf <= x && x <= sup,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if every element of `self` lies in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        match (&self.bounds, &other.bounds) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some((a, b)), Some((c, d))) => c <= a && b <= d,
+        }
+    }
+}
+
+impl<N: Clone> Interval<N> {
+    /// The interval `[x, x]`, containing only `x`.
+    #[inline]
+    pub fn point(x: N) -> Self {
+        Interval {
+            bounds: Some((x.clone(), x)),
+        }
+    }
+}
+
+impl<N> Default for Interval<N> {
+    #[inline]
+    fn default() -> Self {
+        Interval::empty()
+    }
+}
+
+impl<N: PartialOrd> PartialOrd for Interval<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.is_subset(other), other.is_subset(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+impl<N: Lattice + Clone> MeetSemilattice for Interval<N> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        match (&self.bounds, &other.bounds) {
+            (Some((a, b)), Some((c, d))) => Interval::new(a.join(c), b.meet(d)),
+            _ => Interval::empty(),
+        }
+    }
+}
+
+impl<N: Lattice + Clone> JoinSemilattice for Interval<N> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        match (&self.bounds, &other.bounds) {
+            (Some((a, b)), Some((c, d))) => Interval {
+                bounds: Some((a.meet(c), b.join(d))),
+            },
+            (Some(_), None) => self.clone(),
+            (None, _) => other.clone(),
+        }
+    }
+}
+
+impl<N: Lattice + Clone> Lattice for Interval<N> {}
+
+impl<N: Lattice + Clone> LowerBoundedLattice for Interval<N> {
+    #[inline]
+    fn bottom() -> Self {
+        Interval::empty()
+    }
+}
+
+impl<N: BoundedLattice + Clone> BoundedLattice for Interval<N> {
+    #[inline]
+    fn top() -> Self {
+        Interval::new(N::bottom(), N::top())
+    }
+}
+
+impl<N: CompleteLattice + Clone> CompleteLattice for Interval<N> {}
+
+impl<N: AbsDiffEq> AbsDiffEq for Interval<N>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match (&self.bounds, &other.bounds) {
+            (Some((a, b)), Some((c, d))) => {
+                a.abs_diff_eq(c, epsilon.clone()) && b.abs_diff_eq(d, epsilon)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<N: RelativeEq> RelativeEq for Interval<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        match (&self.bounds, &other.bounds) {
+            (Some((a, b)), Some((c, d))) => {
+                a.relative_eq(c, epsilon.clone(), max_relative.clone())
+                    && b.relative_eq(d, epsilon, max_relative)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The convex combinations of intervals interpolate their endpoints independently.
+///
+/// ~~~notrust
+/// [a, b] ⊕ₜ [c, d] = [a ⊕ₜ c, b ⊕ₜ d]
+/// ~~~
+///
+/// Any combination involving the empty interval is empty, so the laws of `ConvexSpace` only hold
+/// for non-empty intervals.
+impl<N: RealField> ConvexSpace for Interval<N> {
+    type Weight = N;
+
+    #[inline]
+    fn convex_combination(&self, other: &Self, t: N) -> Self {
+        match (&self.bounds, &other.bounds) {
+            (Some((a, b)), Some((c, d))) => Interval::new(*a + (*c - *a) * t, *b + (*d - *b) * t),
+            _ => Interval::empty(),
+        }
+    }
+}
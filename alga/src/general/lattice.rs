@@ -1,6 +1,12 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
 use std::cmp::{Ordering, PartialOrd};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "std")]
+use std::collections::{BTreeSet, HashSet};
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
 
 /// A set where every two elements have an infimum (i.e. greatest lower bound).
 pub trait MeetSemilattice: Sized {
@@ -15,6 +21,10 @@ pub trait JoinSemilattice: Sized {
 }
 
 /// Partially orderable sets where every two elements have a supremum and infimum.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, a ≤ b ⇔ a ∧ b = a ⇔ a ∨ b = b
+/// ~~~
 pub trait Lattice: MeetSemilattice + JoinSemilattice + PartialOrd {
     /// Returns the infimum and the supremum simultaneously.
     #[inline]
@@ -77,6 +87,179 @@ pub trait Lattice: MeetSemilattice + JoinSemilattice + PartialOrd {
             None
         }
     }
+
+    /// Returns `true` if the meet and the join are commutative for the given argument tuple.
+    fn prop_meet_and_join_are_commutative(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        a.meet(&b) == b.meet(&a) && a.join(&b) == b.join(&a)
+    }
+
+    /// Returns `true` if the meet and the join are associative for the given argument tuple.
+    fn prop_meet_and_join_are_associative(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+        a.meet(&b).meet(&c) == a.meet(&b.meet(&c)) && a.join(&b).join(&c) == a.join(&b.join(&c))
+    }
+
+    /// Returns `true` if the absorption laws `a ∧ (a ∨ b) = a` and `a ∨ (a ∧ b) = a` hold for the
+    /// given argument tuple.
+    fn prop_meet_and_join_are_absorptive(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        a.meet(&a.join(&b)) == a && a.join(&a.meet(&b)) == a
+    }
+
+    /// Returns `true` if the partial order agrees with the meet and the join, i.e.,
+    /// `a ≤ b ⇔ a ∧ b = a ⇔ a ∨ b = b`, for the given argument tuple.
+    fn prop_order_agrees_with_meet_and_join(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        let le = a <= b;
+        le == (a.meet(&b) == a) && le == (a.join(&b) == b)
+    }
+}
+
+/// A lattice with a least element, `bottom`.
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, a ∨ ⊥ = a
+/// ~~~
+pub trait LowerBoundedLattice: Lattice {
+    /// The least element of this lattice, usually noted `⊥`.
+    fn bottom() -> Self;
+
+    /// Returns `true` if `bottom` is the identity element of the join for the given argument.
+    fn prop_bottom_is_join_identity(args: (Self,)) -> bool {
+        let (a,) = args;
+        a.join(&Self::bottom()) == a
+    }
+}
+
+/// A lattice with a greatest element, `top`, and a least element, `bottom`.
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, a ∧ ⊤ = a and a ∨ ⊥ = a
+/// ~~~
+pub trait BoundedLattice: LowerBoundedLattice {
+    /// The greatest element of this lattice, usually noted `⊤`.
+    fn top() -> Self;
+
+    /// Returns `true` if `top` and `bottom` are respectively the identity elements of the meet and
+    /// the join for the given argument.
+    fn prop_top_and_bottom_are_identities(args: (Self,)) -> bool {
+        let (a,) = args;
+        a.meet(&Self::top()) == a && a.join(&Self::bottom()) == a
+    }
+}
+
+/// A lattice where the meet and join distribute over each other.
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ Self, a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)
+/// ∀ a, b, c ∈ Self, a ∨ (b ∧ c) = (a ∨ b) ∧ (a ∨ c)
+/// ~~~
+pub trait DistributiveLattice: Lattice {
+    /// Returns `true` if the meet distributes over the join for the given argument tuple.
+    fn prop_meet_distributes_over_join(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+        a.meet(&b.join(&c)) == a.meet(&b).join(&a.meet(&c))
+    }
+
+    /// Returns `true` if the join distributes over the meet for the given argument tuple.
+    fn prop_join_distributes_over_meet(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+        a.join(&b.meet(&c)) == a.join(&b).meet(&a.join(&c))
+    }
+}
+
+/// A lattice where every subset has an infimum and a supremum.
+///
+/// The infimum of the empty set is `top` and its supremum is `bottom`.
+pub trait CompleteLattice: BoundedLattice {
+    /// Returns the meet (aka. infimum) of all the given values.
+    #[inline]
+    fn meet_all<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::top(), |acc, x| acc.meet(&x))
+    }
+
+    /// Returns the join (aka. supremum) of all the given values.
+    #[inline]
+    fn join_all<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::bottom(), |acc, x| acc.join(&x))
+    }
+}
+
+/// A bounded distributive lattice equipped with a relative pseudo-complement, noted `→`.
+///
+/// Heyting algebras are the algebraic models of intuitionistic logic: the meet, join, and
+/// pseudo-complement respectively play the role of the conjunction, disjunction and implication.
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ Self, c ∧ a ≤ b ⇔ c ≤ (a → b)
+/// ~~~
+pub trait HeytingAlgebra: BoundedLattice + DistributiveLattice {
+    /// The relative pseudo-complement of `self` with respect to `other`, i.e., the greatest
+    /// element `c` such that `c ∧ self ≤ other`.
+    fn implies(&self, other: &Self) -> Self;
+
+    /// The pseudo-complement of `self`, i.e., `self → ⊥`.
+    #[inline]
+    fn pseudo_complement(&self) -> Self {
+        self.implies(&Self::bottom())
+    }
+
+    /// Returns `true` if `implies` is the residual of the meet for the given argument tuple.
+    fn prop_implies_is_residual(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+        (c.meet(&a) <= b) == (c <= a.implies(&b))
+    }
+
+    /// Returns `true` if `a → a = ⊤` and `a ∧ (a → b) = a ∧ b` for the given argument tuple.
+    fn prop_implies_is_modus_ponens(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        a.implies(&a) == Self::top() && a.meet(&a.implies(&b)) == a.meet(&b)
+    }
+}
+
+/// A pair of monotone maps between two partially ordered sets, `Self` (the concrete domain) and
+/// `A` (the abstract domain), that are adjoint to each other.
+///
+/// ~~~notrust
+/// ∀ c ∈ Self, ∀ a ∈ A, α(c) ≤ a ⇔ c ≤ γ(a)
+/// ~~~
+///
+/// This is the usual way of relating a concrete domain to its approximation in abstract
+/// interpretation.
+pub trait GaloisConnection<A: PartialOrd>: PartialOrd + Sized {
+    /// The abstraction map `α`: the most precise element of `A` approximating `self`.
+    fn abstraction(&self) -> A;
+
+    /// The concretization map `γ`: the greatest element of `Self` approximated by `element`.
+    fn concretization(element: &A) -> Self;
+
+    /// Returns `true` if the abstraction and concretization maps are adjoint for the given
+    /// argument tuple.
+    fn prop_is_adjunction(args: (Self, A)) -> bool {
+        let (c, a) = args;
+        (c.abstraction() <= a) == (c <= Self::concretization(&a))
+    }
+
+    /// Returns `true` if the abstraction map is monotone for the given argument tuple.
+    fn prop_abstraction_is_monotone(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        if a <= b {
+            a.abstraction() <= b.abstraction()
+        } else {
+            true
+        }
+    }
+
+    /// Returns `true` if the concretization map is monotone for the given argument tuple.
+    fn prop_concretization_is_monotone(args: (A, A)) -> bool {
+        let (a, b) = args;
+        if a <= b {
+            Self::concretization(&a) <= Self::concretization(&b)
+        } else {
+            true
+        }
+    }
 }
 
 macro_rules! impl_lattice(
@@ -119,10 +302,186 @@ macro_rules! impl_lattice(
     )*}
 );
 
-impl_lattice!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+macro_rules! impl_bounded_lattice(
+    ($($T:ident, $top: expr, $bottom: expr);* $(;)*) => {$(
+        impl LowerBoundedLattice for $T {
+            #[inline]
+            fn bottom() -> Self {
+                $bottom
+            }
+        }
+
+        impl BoundedLattice for $T {
+            #[inline]
+            fn top() -> Self {
+                $top
+            }
+        }
+
+        // NOTE: totally ordered sets are distributive lattices.
+        impl DistributiveLattice for $T {}
+        impl CompleteLattice for $T {}
+
+        // NOTE: bounded chains are Heyting algebras (aka. Gödel algebras).
+        impl HeytingAlgebra for $T {
+            #[inline]
+            fn implies(&self, other: &Self) -> Self {
+                if *self <= *other {
+                    $top
+                } else {
+                    *other
+                }
+            }
+        }
+    )*}
+);
+
+impl_lattice!(bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_bounded_lattice!(
+    bool,  true,            false;
+    u8,    u8::MAX,         u8::MIN;
+    u16,   u16::MAX,        u16::MIN;
+    u32,   u32::MAX,        u32::MIN;
+    u64,   u64::MAX,        u64::MIN;
+    u128,  u128::MAX,       u128::MIN;
+    usize, usize::MAX,      usize::MIN;
+    i8,    i8::MAX,         i8::MIN;
+    i16,   i16::MAX,        i16::MIN;
+    i32,   i32::MAX,        i32::MIN;
+    i64,   i64::MAX,        i64::MIN;
+    i128,  i128::MAX,       i128::MIN;
+    isize, isize::MAX,      isize::MIN;
+    f32,   f32::INFINITY,   f32::NEG_INFINITY;
+    f64,   f64::INFINITY,   f64::NEG_INFINITY;
+);
 #[cfg(feature = "decimal")]
 impl_lattice!(d128);
 
+/// An unsigned integer seen as a set of bits.
+///
+/// Unlike the plain integers which are ordered numerically, so that their meet and join are the
+/// minimum and maximum, the bits are ordered by inclusion: the meet is the bitwise `&` and the
+/// join is the bitwise `|`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bits<N>(pub N);
+
+macro_rules! impl_bits_lattice(
+    ($($T:ident),*) => {$(
+        impl PartialOrd for Bits<$T> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                let meet = self.0 & other.0;
+
+                if self.0 == other.0 {
+                    Some(Ordering::Equal)
+                } else if meet == self.0 {
+                    Some(Ordering::Less)
+                } else if meet == other.0 {
+                    Some(Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl MeetSemilattice for Bits<$T> {
+            #[inline]
+            fn meet(&self, other: &Self) -> Self {
+                Bits(self.0 & other.0)
+            }
+        }
+
+        impl JoinSemilattice for Bits<$T> {
+            #[inline]
+            fn join(&self, other: &Self) -> Self {
+                Bits(self.0 | other.0)
+            }
+        }
+
+        impl Lattice for Bits<$T> {}
+
+        impl LowerBoundedLattice for Bits<$T> {
+            #[inline]
+            fn bottom() -> Self {
+                Bits(0)
+            }
+        }
+
+        impl BoundedLattice for Bits<$T> {
+            #[inline]
+            fn top() -> Self {
+                Bits($T::MAX)
+            }
+        }
+
+        impl DistributiveLattice for Bits<$T> {}
+        impl CompleteLattice for Bits<$T> {}
+
+        // NOTE: this is a boolean algebra so the implication is the classical one.
+        impl HeytingAlgebra for Bits<$T> {
+            #[inline]
+            fn implies(&self, other: &Self) -> Self {
+                Bits(!self.0 | other.0)
+            }
+        }
+    )*}
+);
+
+impl_bits_lattice!(u8, u16, u32, u64, u128, usize);
+
+// NOTE: `None` is adjoined as a new least element, consistently with the `PartialOrd` impl of
+// `Option`.
+impl<T: MeetSemilattice> MeetSemilattice for Option<T> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.meet(b)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: JoinSemilattice + Clone> JoinSemilattice for Option<T> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.join(b)),
+            (Some(a), None) | (None, Some(a)) => Some(a.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<T: Lattice + Clone> Lattice for Option<T> {}
+
+impl<T: Lattice + Clone> LowerBoundedLattice for Option<T> {
+    #[inline]
+    fn bottom() -> Self {
+        None
+    }
+}
+
+impl<T: BoundedLattice + Clone> BoundedLattice for Option<T> {
+    #[inline]
+    fn top() -> Self {
+        Some(T::top())
+    }
+}
+
+impl<T: DistributiveLattice + Clone> DistributiveLattice for Option<T> {}
+impl<T: CompleteLattice + Clone> CompleteLattice for Option<T> {}
+
+impl<T: HeytingAlgebra + Clone> HeytingAlgebra for Option<T> {
+    #[inline]
+    fn implies(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.implies(b)),
+            (Some(_), None) => None,
+            (None, _) => Self::top(),
+        }
+    }
+}
+
 impl<N: MeetSemilattice> MeetSemilattice for num_complex::Complex<N> {
     #[inline]
     fn meet(&self, other: &Self) -> Self {
@@ -142,3 +501,97 @@ impl<N: JoinSemilattice> JoinSemilattice for num_complex::Complex<N> {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<T: Ord + Clone> MeetSemilattice for BTreeSet<T> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(other).cloned().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord + Clone> JoinSemilattice for BTreeSet<T> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        self.union(other).cloned().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Clone, S: BuildHasher + Default> MeetSemilattice for HashSet<T, S> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(other).cloned().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash + Clone, S: BuildHasher + Default> JoinSemilattice for HashSet<T, S> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        self.union(other).cloned().collect()
+    }
+}
+
+/// A set partially ordered by inclusion.
+///
+/// The standard sets either are not `PartialOrd` at all, or are ordered lexicographically. This
+/// wrapper gives them the inclusion order so that they form a lattice under intersection and
+/// union, with the empty set as `bottom`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inclusion<S>(pub S);
+
+impl<S: MeetSemilattice> MeetSemilattice for Inclusion<S> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        Inclusion(self.0.meet(&other.0))
+    }
+}
+
+impl<S: JoinSemilattice> JoinSemilattice for Inclusion<S> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        Inclusion(self.0.join(&other.0))
+    }
+}
+
+macro_rules! impl_inclusion_lattice(
+    ($($feature: literal => $Set: ty, [$($bounds: tt)*]);* $(;)*) => {$(
+        #[cfg(feature = $feature)]
+        impl<$($bounds)*> PartialOrd for Inclusion<$Set> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                if self.0 == other.0 {
+                    Some(Ordering::Equal)
+                } else if self.0.is_subset(&other.0) {
+                    Some(Ordering::Less)
+                } else if self.0.is_superset(&other.0) {
+                    Some(Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<$($bounds)*> Lattice for Inclusion<$Set> {}
+
+        #[cfg(feature = $feature)]
+        impl<$($bounds)*> LowerBoundedLattice for Inclusion<$Set> {
+            #[inline]
+            fn bottom() -> Self {
+                Inclusion(<$Set>::default())
+            }
+        }
+
+        // NOTE: the powerset lattice is distributive.
+        #[cfg(feature = $feature)]
+        impl<$($bounds)*> DistributiveLattice for Inclusion<$Set> {}
+    )*}
+);
+
+impl_inclusion_lattice!(
+    "alloc" => BTreeSet<T>, [T: Ord + Clone];
+    "std" => HashSet<T, S>, [T: Eq + Hash + Clone, S: BuildHasher + Default];
+);
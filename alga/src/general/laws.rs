@@ -0,0 +1,767 @@
+//! The axioms of the algebraic structures, as data.
+//!
+//! Each function of this module returns the catalog of the laws that must hold for a type
+//! implementing the structure trait it is named after, including the laws inherited from its
+//! supertraits. Tools like test generators or fuzzers can enumerate and check them without
+//! hard-coding the list of `prop_*` functions:
+//!
+//! ```
+//! use alga::general::{laws, Additive};
+//!
+//! for law in laws::abstract_group_abelian::<Additive, i32>() {
+//!     let args = [1, -7, 42];
+//!     assert!(law.check(&args[..law.arity]), "{} does not hold", law.name);
+//! }
+//! ```
+//!
+//! The verifiers like `check_ring` or `check_field_with` check a whole catalog at once, on random
+//! arguments or on those of a given generator, and report the first law that does not hold
+//! together with its arguments, shrunk to a simpler counterexample when possible.
+//!
+//! Most structures come with two catalogs: one using the exact equality, and one suffixed by
+//! `_approx` using the approximate equality of the `approx` crate, for floating-point types.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::fmt;
+use std::mem;
+
+use approx::RelativeEq;
+#[cfg(feature = "quickcheck")]
+use quickcheck::{Arbitrary, StdThreadGen};
+
+use crate::general::{
+    AbstractField, AbstractGroupAbelian, AbstractMonoid, AbstractQuasigroup, AbstractRing,
+    AbstractRingCommutative, AbstractSemigroup, Additive, BoundedLattice, DistributiveLattice,
+    EuclideanDomain, HeytingAlgebra, Identity, Lattice, LowerBoundedLattice, Multiplicative,
+    Operator, OrderedField, OrderedRing, SignedAlgebra,
+};
+
+/// An axiom of an algebraic structure, universally quantified over `arity` elements of `T`.
+pub struct Law<T> {
+    /// The name of this law, e.g., `"associativity"`.
+    pub name: &'static str,
+    /// The number of arguments of `check_fn`.
+    pub arity: usize,
+    /// Returns `true` if this law holds for the given arguments, of length `arity`.
+    pub check_fn: fn(&[T]) -> bool,
+}
+
+impl<T> Law<T> {
+    /// Creates a new law.
+    #[inline]
+    pub const fn new(name: &'static str, arity: usize, check_fn: fn(&[T]) -> bool) -> Self {
+        Law {
+            name,
+            arity,
+            check_fn,
+        }
+    }
+
+    /// Returns `true` if this law holds for the given arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments is not the arity of this law.
+    #[inline]
+    pub fn check(&self, args: &[T]) -> bool {
+        assert!(
+            args.len() == self.arity,
+            "The law `{}` expects {} arguments, got {}.",
+            self.name,
+            self.arity,
+            args.len()
+        );
+
+        (self.check_fn)(args)
+    }
+}
+
+impl<T> Clone for Law<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Law::new(self.name, self.arity, self.check_fn)
+    }
+}
+
+impl<T> fmt::Debug for Law<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Law")
+            .field("name", &self.name)
+            .field("arity", &self.arity)
+            .finish()
+    }
+}
+
+// Builds a law from a `prop_*` function taking a tuple of `arity` arguments.
+macro_rules! law(
+    ($name: expr, 0, $prop: expr) => {
+        Law::new($name, 0, |_| $prop())
+    };
+    ($name: expr, 1, $prop: expr) => {
+        Law::new($name, 1, |a| $prop((a[0].clone(),)))
+    };
+    ($name: expr, 2, $prop: expr) => {
+        Law::new($name, 2, |a| $prop((a[0].clone(), a[1].clone())))
+    };
+    ($name: expr, 3, $prop: expr) => {
+        Law::new($name, 3, |a| $prop((a[0].clone(), a[1].clone(), a[2].clone())))
+    };
+);
+
+/*
+ *
+ * Structures with one operator.
+ *
+ */
+/// The laws of `AbstractQuasigroup<O>`.
+pub fn abstract_quasigroup<O: Operator, T: AbstractQuasigroup<O> + Eq>() -> Vec<Law<T>> {
+    vec![law!(
+        "latin square",
+        2,
+        AbstractQuasigroup::<O>::prop_inv_is_latin_square
+    )]
+}
+
+/// The laws of `AbstractQuasigroup<O>`, with approximate equality.
+pub fn abstract_quasigroup_approx<O: Operator, T: AbstractQuasigroup<O> + RelativeEq>(
+) -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    vec![law!(
+        "latin square",
+        2,
+        AbstractQuasigroup::<O>::prop_inv_is_latin_square_approx
+    )]
+}
+
+/// The laws of `AbstractSemigroup<O>`.
+pub fn abstract_semigroup<O: Operator, T: AbstractSemigroup<O> + Eq>() -> Vec<Law<T>> {
+    vec![law!(
+        "associativity",
+        3,
+        AbstractSemigroup::<O>::prop_is_associative
+    )]
+}
+
+/// The laws of `AbstractSemigroup<O>`, with approximate equality.
+pub fn abstract_semigroup_approx<O: Operator, T: AbstractSemigroup<O> + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    vec![law!(
+        "associativity",
+        3,
+        AbstractSemigroup::<O>::prop_is_associative_approx
+    )]
+}
+
+/// The laws of `AbstractLoop<O>`, i.e., those of `AbstractQuasigroup<O>`.
+pub fn abstract_loop<O: Operator, T: AbstractQuasigroup<O> + Eq>() -> Vec<Law<T>> {
+    abstract_quasigroup::<O, T>()
+}
+
+/// The laws of `AbstractLoop<O>`, with approximate equality.
+pub fn abstract_loop_approx<O: Operator, T: AbstractQuasigroup<O> + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    abstract_quasigroup_approx::<O, T>()
+}
+
+/// The laws of `AbstractMonoid<O>`.
+pub fn abstract_monoid<O: Operator, T: AbstractMonoid<O> + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_semigroup::<O, T>();
+    laws.push(law!(
+        "identity",
+        1,
+        AbstractMonoid::<O>::prop_operating_identity_element_is_noop
+    ));
+    laws
+}
+
+/// The laws of `AbstractMonoid<O>`, with approximate equality.
+pub fn abstract_monoid_approx<O: Operator, T: AbstractMonoid<O> + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_semigroup_approx::<O, T>();
+    laws.push(law!(
+        "identity",
+        1,
+        AbstractMonoid::<O>::prop_operating_identity_element_is_noop_approx
+    ));
+    laws
+}
+
+/// The laws of `AbstractGroup<O>`, i.e., those of `AbstractLoop<O>` and `AbstractMonoid<O>`.
+pub fn abstract_group<O: Operator, T: AbstractQuasigroup<O> + AbstractMonoid<O> + Eq>(
+) -> Vec<Law<T>> {
+    let mut laws = abstract_loop::<O, T>();
+    laws.extend(abstract_monoid::<O, T>());
+    laws
+}
+
+/// The laws of `AbstractGroup<O>`, with approximate equality.
+pub fn abstract_group_approx<
+    O: Operator,
+    T: AbstractQuasigroup<O> + AbstractMonoid<O> + RelativeEq,
+>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_loop_approx::<O, T>();
+    laws.extend(abstract_monoid_approx::<O, T>());
+    laws
+}
+
+/// The laws of `AbstractGroupAbelian<O>`.
+pub fn abstract_group_abelian<O: Operator, T: AbstractGroupAbelian<O> + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_group::<O, T>();
+    laws.push(law!(
+        "commutativity",
+        2,
+        AbstractGroupAbelian::<O>::prop_is_commutative
+    ));
+    laws
+}
+
+/// The laws of `AbstractGroupAbelian<O>`, with approximate equality.
+pub fn abstract_group_abelian_approx<O: Operator, T: AbstractGroupAbelian<O> + RelativeEq>(
+) -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_group_approx::<O, T>();
+    laws.push(law!(
+        "commutativity",
+        2,
+        AbstractGroupAbelian::<O>::prop_is_commutative_approx
+    ));
+    laws
+}
+
+/*
+ *
+ * Structures with two operators.
+ *
+ */
+/// The laws of `AbstractRing`.
+///
+/// The laws of the underlying additive group and multiplicative monoid are prefixed by
+/// `"additive"` and `"multiplicative"`.
+pub fn abstract_ring<T: AbstractRing + Eq>() -> Vec<Law<T>> {
+    vec![
+        law!(
+            "additive latin square",
+            2,
+            AbstractQuasigroup::<Additive>::prop_inv_is_latin_square
+        ),
+        law!(
+            "additive associativity",
+            3,
+            AbstractSemigroup::<Additive>::prop_is_associative
+        ),
+        law!(
+            "additive identity",
+            1,
+            AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop
+        ),
+        law!(
+            "additive commutativity",
+            2,
+            AbstractGroupAbelian::<Additive>::prop_is_commutative
+        ),
+        law!(
+            "multiplicative associativity",
+            3,
+            AbstractSemigroup::<Multiplicative>::prop_is_associative
+        ),
+        law!(
+            "multiplicative identity",
+            1,
+            AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop
+        ),
+        law!(
+            "distributivity",
+            3,
+            AbstractRing::prop_mul_and_add_are_distributive
+        ),
+        law!(
+            "characteristic",
+            0,
+            <T as AbstractRing>::prop_characteristic_is_additive_order_of_one
+        ),
+    ]
+}
+
+/// The laws of `AbstractRing`, with approximate equality.
+///
+/// The characteristic is not checked, since it requires the exact equality.
+pub fn abstract_ring_approx<T: AbstractRing + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    vec![
+        law!(
+            "additive latin square",
+            2,
+            AbstractQuasigroup::<Additive>::prop_inv_is_latin_square_approx
+        ),
+        law!(
+            "additive associativity",
+            3,
+            AbstractSemigroup::<Additive>::prop_is_associative_approx
+        ),
+        law!(
+            "additive identity",
+            1,
+            AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop_approx
+        ),
+        law!(
+            "additive commutativity",
+            2,
+            AbstractGroupAbelian::<Additive>::prop_is_commutative_approx
+        ),
+        law!(
+            "multiplicative associativity",
+            3,
+            AbstractSemigroup::<Multiplicative>::prop_is_associative_approx
+        ),
+        law!(
+            "multiplicative identity",
+            1,
+            AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop_approx
+        ),
+        law!(
+            "distributivity",
+            3,
+            AbstractRing::prop_mul_and_add_are_distributive_approx
+        ),
+    ]
+}
+
+/// The laws of `AbstractRingCommutative`.
+pub fn abstract_ring_commutative<T: AbstractRingCommutative + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_ring::<T>();
+    laws.push(law!(
+        "multiplicative commutativity",
+        2,
+        AbstractRingCommutative::prop_mul_is_commutative
+    ));
+    laws
+}
+
+/// The laws of `AbstractRingCommutative`, with approximate equality.
+pub fn abstract_ring_commutative_approx<T: AbstractRingCommutative + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_ring_approx::<T>();
+    laws.push(law!(
+        "multiplicative commutativity",
+        2,
+        AbstractRingCommutative::prop_mul_is_commutative_approx
+    ));
+    laws
+}
+
+/// The laws of `AbstractField`.
+///
+/// The multiplicative latin square only holds for a nonzero divisor, and is vacuously true
+/// otherwise.
+pub fn abstract_field<T: AbstractField + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_ring_commutative::<T>();
+    laws.push(Law::new("multiplicative latin square", 2, |a| {
+        a[1] == Identity::<Additive>::identity()
+            || AbstractQuasigroup::<Multiplicative>::prop_inv_is_latin_square((
+                a[0].clone(),
+                a[1].clone(),
+            ))
+    }));
+    laws
+}
+
+/// The laws of `AbstractField`, with approximate equality.
+pub fn abstract_field_approx<T: AbstractField + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_ring_commutative_approx::<T>();
+    laws.push(Law::new("multiplicative latin square", 2, |a| {
+        a[1] == Identity::<Additive>::identity()
+            || AbstractQuasigroup::<Multiplicative>::prop_inv_is_latin_square_approx((
+                a[0].clone(),
+                a[1].clone(),
+            ))
+    }));
+    laws
+}
+
+/// The laws of `EuclideanDomain`.
+pub fn euclidean_domain<T: EuclideanDomain + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_ring_commutative::<T>();
+    laws.push(law!(
+        "euclidean division",
+        2,
+        EuclideanDomain::prop_div_rem_is_euclidean
+    ));
+    laws.push(law!(
+        "monotone norm",
+        2,
+        EuclideanDomain::prop_norm_is_monotone
+    ));
+    laws.push(law!("gcd divides", 2, EuclideanDomain::prop_gcd_divides));
+    laws
+}
+
+/// The laws of `OrderedRing`.
+pub fn ordered_ring<T: OrderedRing + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_ring_commutative::<T>();
+    laws.extend(order_compatibility::<T>());
+    laws
+}
+
+/// The laws of `OrderedRing`, with approximate equality.
+pub fn ordered_ring_approx<T: OrderedRing + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_ring_commutative_approx::<T>();
+    laws.extend(order_compatibility::<T>());
+    laws
+}
+
+/// The laws of `OrderedField`.
+pub fn ordered_field<T: OrderedField + Eq>() -> Vec<Law<T>> {
+    let mut laws = abstract_field::<T>();
+    laws.extend(order_compatibility::<T>());
+    laws.push(law!(
+        "positive inverse",
+        1,
+        OrderedField::prop_inv_preserves_positivity
+    ));
+    laws
+}
+
+/// The laws of `OrderedField`, with approximate equality.
+pub fn ordered_field_approx<T: OrderedField + RelativeEq>() -> Vec<Law<T>>
+where
+    T::Epsilon: Clone,
+{
+    let mut laws = abstract_field_approx::<T>();
+    laws.extend(order_compatibility::<T>());
+    laws.push(law!(
+        "positive inverse",
+        1,
+        OrderedField::prop_inv_preserves_positivity
+    ));
+    laws
+}
+
+/// The laws of `SignedAlgebra`, for a ring.
+pub fn signed_algebra<T: SignedAlgebra + AbstractRing>() -> Vec<Law<T>> {
+    vec![
+        law!("nonnegativity", 1, SignedAlgebra::prop_abs_is_nonnegative),
+        law!(
+            "multiplicativity",
+            2,
+            SignedAlgebra::prop_abs_is_multiplicative
+        ),
+        law!(
+            "triangle inequality",
+            2,
+            SignedAlgebra::prop_triangle_inequality
+        ),
+    ]
+}
+
+/// The laws of `SignedAlgebra`, for a ring, with approximate equality.
+pub fn signed_algebra_approx<T: SignedAlgebra + AbstractRing>() -> Vec<Law<T>>
+where
+    T::Magnitude: RelativeEq,
+{
+    vec![
+        law!("nonnegativity", 1, SignedAlgebra::prop_abs_is_nonnegative),
+        law!(
+            "multiplicativity",
+            2,
+            SignedAlgebra::prop_abs_is_multiplicative_approx
+        ),
+        law!(
+            "triangle inequality",
+            2,
+            SignedAlgebra::prop_triangle_inequality_approx
+        ),
+    ]
+}
+
+// NOTE: these laws do not involve any equality, so they are shared by the exact and the
+// approximate catalogs.
+fn order_compatibility<T: OrderedRing>() -> Vec<Law<T>> {
+    vec![
+        law!(
+            "additive order compatibility",
+            3,
+            OrderedRing::prop_add_preserves_order
+        ),
+        law!(
+            "nonnegative products",
+            2,
+            OrderedRing::prop_mul_preserves_nonnegativity
+        ),
+    ]
+}
+
+/*
+ *
+ * Lattices.
+ *
+ */
+/// The laws of `Lattice`.
+pub fn lattice<T: Lattice + Clone>() -> Vec<Law<T>> {
+    vec![
+        law!(
+            "commutativity",
+            2,
+            Lattice::prop_meet_and_join_are_commutative
+        ),
+        law!(
+            "associativity",
+            3,
+            Lattice::prop_meet_and_join_are_associative
+        ),
+        law!("absorption", 2, Lattice::prop_meet_and_join_are_absorptive),
+        law!(
+            "order consistency",
+            2,
+            Lattice::prop_order_agrees_with_meet_and_join
+        ),
+    ]
+}
+
+/// The laws of `LowerBoundedLattice`.
+pub fn lower_bounded_lattice<T: LowerBoundedLattice + Clone>() -> Vec<Law<T>> {
+    let mut laws = lattice::<T>();
+    laws.push(law!(
+        "bottom is join identity",
+        1,
+        LowerBoundedLattice::prop_bottom_is_join_identity
+    ));
+    laws
+}
+
+/// The laws of `BoundedLattice`.
+pub fn bounded_lattice<T: BoundedLattice + Clone>() -> Vec<Law<T>> {
+    let mut laws = lower_bounded_lattice::<T>();
+    laws.push(law!(
+        "top and bottom are identities",
+        1,
+        BoundedLattice::prop_top_and_bottom_are_identities
+    ));
+    laws
+}
+
+/// The laws of `DistributiveLattice`.
+pub fn distributive_lattice<T: DistributiveLattice + Clone>() -> Vec<Law<T>> {
+    let mut laws = lattice::<T>();
+    laws.push(law!(
+        "meet distributes over join",
+        3,
+        DistributiveLattice::prop_meet_distributes_over_join
+    ));
+    laws.push(law!(
+        "join distributes over meet",
+        3,
+        DistributiveLattice::prop_join_distributes_over_meet
+    ));
+    laws
+}
+
+/// The laws of `HeytingAlgebra`.
+pub fn heyting_algebra<T: HeytingAlgebra + Clone>() -> Vec<Law<T>> {
+    let mut laws = bounded_lattice::<T>();
+    // NOTE: the laws of `Lattice` are inherited from both `BoundedLattice` and
+    // `DistributiveLattice`, and are only kept once.
+    let mut distributive = distributive_lattice::<T>();
+    laws.extend(distributive.drain(lattice::<T>().len()..));
+    laws.push(law!(
+        "implication is residual",
+        3,
+        HeytingAlgebra::prop_implies_is_residual
+    ));
+    laws.push(law!(
+        "modus ponens",
+        2,
+        HeytingAlgebra::prop_implies_is_modus_ponens
+    ));
+    laws
+}
+
+/*
+ *
+ * Verifiers.
+ *
+ */
+/// The number of argument tuples drawn for each law by the verifiers without a `tests` argument.
+pub const DEFAULT_TESTS: usize = 100;
+
+/// A law that does not hold, together with a counterexample.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LawViolation<T> {
+    /// The name of the law that does not hold.
+    pub law: &'static str,
+    /// The arguments for which the law does not hold.
+    pub args: Vec<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for LawViolation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the law `{}` does not hold for {:?}",
+            self.law, self.args
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for LawViolation<T> {}
+
+/// Checks every law of `laws` on `tests` tuples of arguments drawn from `gen`.
+///
+/// Laws without arguments are checked only once. Returns the first violation found, with its
+/// arguments.
+pub fn check_laws_with<T: Clone, G: FnMut() -> T>(
+    laws: &[Law<T>],
+    gen: G,
+    tests: usize,
+) -> Result<(), LawViolation<T>> {
+    check_laws_with_shrinker(laws, gen, tests, |_| None)
+}
+
+/// Checks every law of `laws` on `tests` tuples of arguments drawn from `gen`, and shrinks the
+/// first violation found with `shrink_counterexample` before returning it.
+///
+/// `shrink` returns simpler candidates for a given argument, e.g., halves of a number, shorter
+/// words, or elements closer to the identity.
+pub fn check_laws_with_shrinker<T, G, S, I>(
+    laws: &[Law<T>],
+    mut gen: G,
+    tests: usize,
+    shrink: S,
+) -> Result<(), LawViolation<T>>
+where
+    T: Clone,
+    G: FnMut() -> T,
+    S: FnMut(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    for law in laws {
+        let tests = if law.arity == 0 { 1 } else { tests };
+
+        for _ in 0..tests {
+            let args: Vec<T> = (0..law.arity).map(|_| gen()).collect();
+
+            if !law.check(&args) {
+                return Err(LawViolation {
+                    law: law.name,
+                    args: shrink_counterexample(law, args, shrink),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The maximal number of simplifications applied by `shrink_counterexample`.
+pub const MAX_SHRINK_STEPS: usize = 1000;
+
+/// Simplifies the arguments `args` for which `law` does not hold.
+///
+/// Each argument is greedily replaced by the first of its candidates given by `shrink` for which
+/// the law still does not hold, until no candidate of any argument does, or `MAX_SHRINK_STEPS`
+/// replacements have been made.
+pub fn shrink_counterexample<T, S, I>(law: &Law<T>, mut args: Vec<T>, mut shrink: S) -> Vec<T>
+where
+    S: FnMut(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut steps = 0;
+
+    'shrink: while steps < MAX_SHRINK_STEPS {
+        for i in 0..args.len() {
+            for candidate in shrink(&args[i]) {
+                let arg = mem::replace(&mut args[i], candidate);
+
+                if law.check(&args) {
+                    args[i] = arg;
+                } else {
+                    steps += 1;
+                    continue 'shrink;
+                }
+            }
+        }
+
+        break;
+    }
+
+    args
+}
+
+/// Checks every law of `laws` on `DEFAULT_TESTS` tuples of random arguments, and shrinks the first
+/// violation found with `Arbitrary::shrink`.
+#[cfg(feature = "quickcheck")]
+pub fn check_laws<T: Arbitrary>(laws: &[Law<T>]) -> Result<(), LawViolation<T>> {
+    let mut g = StdThreadGen::new(100);
+    check_laws_with_shrinker(laws, || T::arbitrary(&mut g), DEFAULT_TESTS, T::shrink)
+}
+
+/// Checks all the laws of `AbstractRing` on `tests` tuples of arguments drawn from `gen`.
+pub fn check_ring_with<T: AbstractRing + Eq, G: FnMut() -> T>(
+    gen: G,
+    tests: usize,
+) -> Result<(), LawViolation<T>> {
+    check_laws_with(&abstract_ring::<T>(), gen, tests)
+}
+
+/// Checks all the laws of `AbstractRingCommutative` on `tests` tuples of arguments drawn from
+/// `gen`.
+pub fn check_ring_commutative_with<T: AbstractRingCommutative + Eq, G: FnMut() -> T>(
+    gen: G,
+    tests: usize,
+) -> Result<(), LawViolation<T>> {
+    check_laws_with(&abstract_ring_commutative::<T>(), gen, tests)
+}
+
+/// Checks all the laws of `AbstractField` on `tests` tuples of arguments drawn from `gen`.
+pub fn check_field_with<T: AbstractField + Eq, G: FnMut() -> T>(
+    gen: G,
+    tests: usize,
+) -> Result<(), LawViolation<T>> {
+    check_laws_with(&abstract_field::<T>(), gen, tests)
+}
+
+/// Checks all the laws of `AbstractRing` on `DEFAULT_TESTS` tuples of random arguments.
+#[cfg(feature = "quickcheck")]
+pub fn check_ring<T: AbstractRing + Eq + Arbitrary>() -> Result<(), LawViolation<T>> {
+    check_laws(&abstract_ring::<T>())
+}
+
+/// Checks all the laws of `AbstractRingCommutative` on `DEFAULT_TESTS` tuples of random
+/// arguments.
+#[cfg(feature = "quickcheck")]
+pub fn check_ring_commutative<T: AbstractRingCommutative + Eq + Arbitrary>(
+) -> Result<(), LawViolation<T>> {
+    check_laws(&abstract_ring_commutative::<T>())
+}
+
+/// Checks all the laws of `AbstractField` on `DEFAULT_TESTS` tuples of random arguments.
+#[cfg(feature = "quickcheck")]
+pub fn check_field<T: AbstractField + Eq + Arbitrary>() -> Result<(), LawViolation<T>> {
+    check_laws(&abstract_field::<T>())
+}
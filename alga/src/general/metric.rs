@@ -0,0 +1,187 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::Zero;
+
+use crate::general::Bits;
+
+/// A set equipped with a distance.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self,    d(a, b) = d(b, a)
+/// ∀ a ∈ Self,       d(a, a) = 0
+/// ∀ a, b, c ∈ Self, d(a, c) ≤ d(a, b) + d(b, c)
+/// ~~~
+///
+/// This is implemented automatically by every euclidean space, but also by, e.g., bit sets with
+/// the Hamming distance, so that generic nearest-neighbor code does not need to require an
+/// euclidean structure.
+pub trait MetricSpace: Sized {
+    /// The type of the distance between two elements.
+    type Distance: PartialOrd + Zero;
+
+    /// The distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> Self::Distance;
+
+    /// Returns `true` if the distance is approximately symmetric, and approximately zero between
+    /// an element and itself, for the given argument tuple.
+    fn prop_distance_is_symmetric_approx(args: (Self, Self)) -> bool
+    where
+        Self::Distance: RelativeEq,
+        <Self::Distance as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_distance_is_symmetric_approx_with(
+            args,
+            <Self::Distance as AbsDiffEq>::default_epsilon(),
+            <Self::Distance as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the distance is approximately symmetric, and approximately zero between an
+    /// element and itself, for the given argument tuple. The approximate equalities use the given
+    /// `epsilon` and `max_relative` tolerances.
+    fn prop_distance_is_symmetric_approx_with(
+        args: (Self, Self),
+        epsilon: <Self::Distance as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Distance as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Distance: RelativeEq,
+        <Self::Distance as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            a.distance(&b),
+            b.distance(&a),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a.distance(&a),
+            Zero::zero(),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if the distance is symmetric, and zero between an element and itself, for
+    /// the given argument tuple.
+    fn prop_distance_is_symmetric(args: (Self, Self)) -> bool
+    where
+        Self::Distance: Eq,
+    {
+        let (a, b) = args;
+        a.distance(&b) == b.distance(&a) && a.distance(&a).is_zero()
+    }
+
+    /// Returns `true` if the triangle inequality approximately holds for the given argument tuple.
+    fn prop_triangle_inequality_approx(args: (Self, Self, Self)) -> bool
+    where
+        Self::Distance: RelativeEq + Clone,
+    {
+        Self::prop_triangle_inequality_approx_with(
+            args,
+            <Self::Distance as AbsDiffEq>::default_epsilon(),
+            <Self::Distance as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the triangle inequality approximately holds for the given argument tuple.
+    /// The approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_triangle_inequality_approx_with(
+        args: (Self, Self, Self),
+        epsilon: <Self::Distance as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Distance as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Distance: RelativeEq + Clone,
+    {
+        let (a, b, c) = args;
+        let lhs = a.distance(&c);
+        let rhs = a.distance(&b) + b.distance(&c);
+
+        lhs <= rhs || relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given argument tuple.
+    fn prop_triangle_inequality(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+        a.distance(&c) <= a.distance(&b) + b.distance(&c)
+    }
+}
+
+/// A wrapper equipping any set with the discrete metric: the distance between two distinct
+/// elements is one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Discrete<T>(pub T);
+
+// NOTE: the approximate equalities are those of the wrapped values, and do not depend on the
+// discrete metric.
+impl<T: AbsDiffEq> AbsDiffEq for Discrete<T> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Discrete<T> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Discrete<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+impl<T: PartialEq> MetricSpace for Discrete<T> {
+    type Distance = u32;
+
+    #[inline]
+    fn distance(&self, other: &Self) -> u32 {
+        if self.0 == other.0 {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+macro_rules! impl_hamming_metric(
+    ($($T:ident),*) => {$(
+        // NOTE: the Hamming distance, i.e., the number of differing bits.
+        impl MetricSpace for Bits<$T> {
+            type Distance = u32;
+
+            #[inline]
+            fn distance(&self, other: &Self) -> u32 {
+                (self.0 ^ other.0).count_ones()
+            }
+        }
+    )*}
+);
+
+impl_hamming_metric!(u8, u16, u32, u64, u128, usize);
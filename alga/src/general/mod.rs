@@ -87,6 +87,8 @@
 //! - (`Abstract`|`Additive`|`Multiplicative`)`Group`
 //! - (`Abstract`|`Additive`|`Multiplicative`)`GroupAbelian`
 //!
+//! Every group element can be raised to an integer power of any type with `GroupPow`.
+//!
 //! ## Ring-like structures
 //!
 //! These can be derived automatically by `alga_traits` attribute from `alga_derive` crate.
@@ -114,7 +116,10 @@
 //!
 //! - `Ring`
 //! - `RingCommutative`
+//! - `EuclideanDomain`
 //! - `Field`
+//! - `FiniteField`
+//! - `PrimeField`
 //!
 //! ## Module-like structures
 //!
@@ -156,40 +161,129 @@
 //! }
 //! ~~~
 
-pub use self::identity::{Id, Identity};
+pub use self::identity::{ConstIdentity, Id, Identity};
 pub use self::operator::{
-    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Multiplicative, Operator,
-    TwoSidedInverse,
+    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Composition, Multiplicative,
+    MultiplicativeComposition, Operator, TwoSidedInverse,
+};
+pub use self::subset::{
+    convert, convert_ref, convert_ref_unchecked, convert_unchecked, is_convertible, try_convert,
+    try_convert_ref, ExactSubsetOf, LossySubsetOf, NotInSubsetError, SubsetOf, SupersetOf,
 };
-pub use self::subset::{SubsetOf, SupersetOf};
 
-pub use self::complex::ComplexField;
-pub use self::lattice::{JoinSemilattice, Lattice, MeetSemilattice};
+pub use self::canonical_bytes::{FromCanonicalBytes, ToCanonicalBytes};
+pub use self::complex::{
+    CanonicalHash, ComplexField, ComplexFieldCore, ComplexFromParts, ScalarDisplay, ScalarSendSync,
+};
+#[cfg(feature = "subtle")]
+pub use self::constant_time::ConstantTimeOps;
+pub use self::division_algebra::{AlternativeAlgebra, NormedDivisionAlgebra};
+pub use self::euclidean_domain::EuclideanDomain;
+pub use self::finite_field::FiniteField;
+#[cfg(feature = "alloc")]
+pub use self::finite_group::{discrete_log, discrete_log_bounded};
+pub use self::finite_group::{CyclicGroup, FiniteGroup};
+#[cfg(feature = "alloc")]
+pub use self::free_group::{FreeGroup, Letter};
+pub use self::gaussian_int::GaussianInt;
+pub use self::group_pow::{Exponent, GroupPow};
+pub use self::interval::Interval;
+pub use self::lattice::{
+    Bits, BoundedLattice, CompleteLattice, DistributiveLattice, GaloisConnection, HeytingAlgebra,
+    Inclusion, JoinSemilattice, Lattice, LowerBoundedLattice, MeetSemilattice,
+};
+pub use self::metric::{Discrete, MetricSpace};
+pub use self::modular::{crt, Crt, Zn, ZnUnit};
 pub use self::module::AbstractModule;
+pub use self::montgomery::MontgomeryFp;
 pub use self::one_operator::{
-    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
-    AbstractQuasigroup, AbstractSemigroup,
+    AbstractAlternativeMagma, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, PartialInverse,
 };
-pub use self::real::RealField;
+pub use self::octonion::Octonion;
+pub use self::ordered::{ArchimedeanField, OrderedField, OrderedRing};
+pub use self::padic::Padic;
+#[cfg(feature = "alloc")]
+pub use self::pointwise::Pointwise;
+#[cfg(feature = "alloc")]
+pub use self::polynomial::{Monomial, MultivariatePolynomial};
+pub use self::prime_field::PrimeField;
+pub use self::quaternion::Quaternion;
+#[cfg(feature = "rand")]
+pub use self::random::{RandomElement, RandomElements, UnitModulus};
+pub use self::real::{RealField, RoundingMode};
+pub use self::registry::{has_structure, structures_of, OperatorId, StructureId, Structures};
+#[cfg(feature = "zeroize")]
+pub use self::secret::SecretElement;
+pub use self::signed::SignedAlgebra;
 pub use self::specialized::{
     AdditiveGroup, AdditiveGroupAbelian, AdditiveLoop, AdditiveMagma, AdditiveMonoid,
     AdditiveQuasigroup, AdditiveSemigroup, Field, Module, MultiplicativeGroup,
     MultiplicativeGroupAbelian, MultiplicativeLoop, MultiplicativeMagma, MultiplicativeMonoid,
     MultiplicativeQuasigroup, MultiplicativeSemigroup, Ring, RingCommutative,
 };
+pub use self::split_complex::SplitComplex;
+pub use self::square_matrix::MatrixRing;
+pub use self::truncated_series::TruncatedSeries;
 pub use self::two_operators::{AbstractField, AbstractRing, AbstractRingCommutative};
+pub use self::valuation::{
+    ArchimedeanValuation, Degree, Modulus, PAdicValuation, UltrametricValuation, Valuation,
+};
 
 #[macro_use]
 mod one_operator;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+mod canonical_bytes;
 mod complex;
+#[cfg(feature = "subtle")]
+mod constant_time;
+mod division_algebra;
+mod euclidean_domain;
+mod finite_field;
+mod finite_group;
+#[cfg(feature = "alloc")]
+mod free_group;
+#[cfg(feature = "fixed")]
+mod fixed_point;
+mod gaussian_int;
+mod group_pow;
 mod identity;
+mod interval;
 mod lattice;
+#[cfg(feature = "alloc")]
+pub mod laws;
+mod metric;
+mod modular;
 mod module;
+mod montgomery;
+mod octonion;
 mod operator;
+mod ordered;
+pub mod ops;
+mod padic;
+#[cfg(feature = "alloc")]
+mod pointwise;
+#[cfg(feature = "alloc")]
+mod polynomial;
+mod prime_field;
+mod quaternion;
+#[cfg(feature = "rand")]
+mod random;
 mod real;
+mod registry;
+#[cfg(feature = "rug")]
+mod rug_impls;
+#[cfg(feature = "zeroize")]
+mod secret;
+mod signed;
 mod specialized;
+mod split_complex;
+mod square_matrix;
 mod subset;
+mod truncated_series;
 mod two_operators;
+mod valuation;
 #[doc(hidden)]
 pub mod wrapper;
 
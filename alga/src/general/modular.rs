@@ -0,0 +1,602 @@
+//! Integers modulo `N` and their group of units.
+
+use std::fmt;
+use std::iter::{FilterMap, Map};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Range, Sub, SubAssign};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, ConstIdentity, CyclicGroup, EuclideanDomain, FiniteField, FiniteGroup, Identity,
+    Multiplicative, TwoSidedInverse,
+};
+
+/// The ring `ℤ/Nℤ` of integers modulo `N`.
+///
+/// Elements are stored, and ordered, by their canonical representative in `0..N`, and `N` must be
+/// positive. The additive group is cyclic, generated by `1`.
+///
+/// This is a field only when `N` is prime. The methods that only make sense in a field, namely
+/// `two_sided_inverse`, `FiniteField::degree` and `PrimeField::modulus`, fail to compile for a
+/// composite `N`. For any `N`, `try_two_sided_inverse` returns `None` for the elements that are
+/// not coprime with `N`, and `ZnUnit<N>` is the multiplicative group of the invertible ones.
+///
+/// ```compile_fail
+/// use alga::general::{Multiplicative, TwoSidedInverse, Zn};
+///
+/// let _ = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&Zn::<6>::new(5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Zn<const N: u64>(u64);
+
+impl<const N: u64> Zn<N> {
+    /// The residue class of `value` modulo `N`.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Zn(value % N)
+    }
+
+    /// The canonical representative of this residue class, in `0..N`.
+    #[inline]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if this element is coprime with `N`, i.e., has a multiplicative inverse.
+    #[inline]
+    pub fn is_unit(self) -> bool {
+        gcd(self.0, N) == 1
+    }
+
+    /// Fails to compile when `N` is not prime, i.e., when `Zn<N>` is not a field.
+    #[inline]
+    pub(crate) const fn assert_field() {
+        const { assert!(is_prime(N), "Zn<N> is a field only when N is prime.") }
+    }
+}
+
+impl<const N: u64> fmt::Display for Zn<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mod {}", self.0, N)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: u64> Zeroize for Zn<N> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/// The greatest common divisor of `a` and `b`.
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// The inverse of `a` modulo `n`, computed with the extended Euclidean algorithm.
+pub(crate) fn inverse_mod(a: u64, n: u64) -> Option<u64> {
+    let (g, s, _) = (a as i128).extended_gcd(&(n as i128));
+
+    if g == 1 {
+        Some(s.rem_euclid(n as i128) as u64)
+    } else {
+        None
+    }
+}
+
+/// `a × b` modulo `n`.
+const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+/// `a` to the power `k` modulo `n`, by exponentiation by squaring.
+const fn pow_mod(mut a: u64, mut k: u64, n: u64) -> u64 {
+    let mut result = 1 % n;
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result = mul_mod(result, a, n);
+        }
+
+        a = mul_mod(a, a, n);
+        k >>= 1;
+    }
+
+    result
+}
+
+/// Returns `true` if `n` is prime, using the Miller–Rabin test.
+pub(crate) const fn is_prime(n: u64) -> bool {
+    // NOTE: these bases make the test deterministic for every 64-bit integer.
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+
+        i += 1;
+    }
+
+    // NOTE: n - 1 = d × 2ˢ with d odd.
+    let mut d = n - 1;
+    let mut s = 0;
+
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+
+    'bases: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        let mut r = 1;
+
+        while r < s {
+            x = mul_mod(x, x, n);
+
+            if x == n - 1 {
+                continue 'bases;
+            }
+
+            r += 1;
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Euler's totient of `n`, i.e., the number of elements of `0..n` coprime with `n`.
+fn totient(mut n: u64) -> u64 {
+    let mut result = n;
+    let mut p = 2;
+
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+
+            result -= result / p;
+        }
+
+        p += 1;
+    }
+
+    if n > 1 {
+        result -= result / n;
+    }
+
+    result
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+impl<const N: u64> Add for Zn<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Zn(((self.0 as u128 + rhs.0 as u128) % N as u128) as u64)
+    }
+}
+
+impl<const N: u64> AddAssign for Zn<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const N: u64> Neg for Zn<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Zn(N - self.0)
+        }
+    }
+}
+
+impl<const N: u64> Sub for Zn<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: u64> SubAssign for Zn<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const N: u64> Mul for Zn<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Zn(((self.0 as u128 * rhs.0 as u128) % N as u128) as u64)
+    }
+}
+
+impl<const N: u64> MulAssign for Zn<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+/*
+ *
+ * Algebraic structures.
+ *
+ */
+impl<const N: u64> AbstractMagma<Additive> for Zn<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<const N: u64> TwoSidedInverse<Additive> for Zn<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<const N: u64> Identity<Additive> for Zn<N> {
+    #[inline]
+    fn identity() -> Self {
+        Zn(0)
+    }
+}
+
+impl<const N: u64> ConstIdentity<Additive> for Zn<N> {
+    const IDENTITY: Self = Zn(0);
+}
+
+impl<const N: u64> AbstractMagma<Multiplicative> for Zn<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<const N: u64> TwoSidedInverse<Multiplicative> for Zn<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Self::assert_field();
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(self)
+            .expect("Zero has no multiplicative inverse.")
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        inverse_mod(self.0, N).map(Zn)
+    }
+}
+
+impl<const N: u64> Identity<Multiplicative> for Zn<N> {
+    #[inline]
+    fn identity() -> Self {
+        Zn::new(1)
+    }
+}
+
+impl<const N: u64> ConstIdentity<Multiplicative> for Zn<N> {
+    const IDENTITY: Self = Zn::new(1);
+}
+
+impl<const N: u64> AbstractSemigroup<Additive> for Zn<N> {}
+impl<const N: u64> AbstractQuasigroup<Additive> for Zn<N> {}
+impl<const N: u64> AbstractMonoid<Additive> for Zn<N> {}
+impl<const N: u64> AbstractLoop<Additive> for Zn<N> {}
+impl<const N: u64> AbstractGroup<Additive> for Zn<N> {}
+impl<const N: u64> AbstractGroupAbelian<Additive> for Zn<N> {}
+
+impl<const N: u64> AbstractSemigroup<Multiplicative> for Zn<N> {}
+impl<const N: u64> AbstractQuasigroup<Multiplicative> for Zn<N> {}
+impl<const N: u64> AbstractMonoid<Multiplicative> for Zn<N> {}
+impl<const N: u64> AbstractLoop<Multiplicative> for Zn<N> {}
+impl<const N: u64> AbstractGroup<Multiplicative> for Zn<N> {}
+impl<const N: u64> AbstractGroupAbelian<Multiplicative> for Zn<N> {}
+
+impl<const N: u64> AbstractRing for Zn<N> {
+    #[inline]
+    fn characteristic() -> u64 {
+        N
+    }
+}
+impl<const N: u64> AbstractRingCommutative for Zn<N> {}
+impl<const N: u64> AbstractField for Zn<N> {}
+
+impl<const N: u64> FiniteGroup<Additive> for Zn<N> {
+    type Elements = Map<Range<u64>, fn(u64) -> Self>;
+
+    #[inline]
+    fn cardinality() -> u64 {
+        N
+    }
+
+    #[inline]
+    fn elements() -> Self::Elements {
+        (0..N).map(Zn)
+    }
+
+    #[inline]
+    fn element_order(&self) -> u64 {
+        N / gcd(self.0, N)
+    }
+}
+
+impl<const N: u64> FiniteField for Zn<N> {
+    #[inline]
+    fn degree() -> u32 {
+        Self::assert_field();
+        1
+    }
+}
+
+impl<const N: u64> CyclicGroup<Additive> for Zn<N> {
+    #[inline]
+    fn generator() -> Self {
+        Zn::new(1)
+    }
+
+    #[inline]
+    fn generator_pow(k: u64) -> Self {
+        Zn::new(k)
+    }
+
+    #[inline]
+    fn index_of(&self) -> u64 {
+        self.0
+    }
+}
+
+/// An element of the group `(ℤ/Nℤ)ˣ` of units modulo `N`, i.e., of the residues coprime with
+/// `N` under multiplication.
+///
+/// When `N` is prime, this is the multiplicative group of the prime field `Zn<N>`, which is
+/// cyclic. More generally, this group is cyclic if and only if `N` is 1, 2, 4, `pᵏ` or `2pᵏ` for
+/// some odd prime `p`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZnUnit<const N: u64>(Zn<N>);
+
+impl<const N: u64> ZnUnit<N> {
+    /// Creates a unit from a residue, or returns `None` if it is not coprime with `N`.
+    #[inline]
+    pub fn new(residue: Zn<N>) -> Option<Self> {
+        if residue.is_unit() {
+            Some(ZnUnit(residue))
+        } else {
+            None
+        }
+    }
+
+    /// The residue class of this unit.
+    #[inline]
+    pub const fn residue(self) -> Zn<N> {
+        self.0
+    }
+}
+
+impl<const N: u64> fmt::Display for ZnUnit<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: u64> AbstractMagma<Multiplicative> for ZnUnit<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        ZnUnit(self.0 * right.0)
+    }
+}
+
+impl<const N: u64> TwoSidedInverse<Multiplicative> for ZnUnit<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        let inverse = inverse_mod(self.0.value(), N).expect("Units are coprime with the modulus.");
+        ZnUnit(Zn(inverse))
+    }
+}
+
+impl<const N: u64> Identity<Multiplicative> for ZnUnit<N> {
+    #[inline]
+    fn identity() -> Self {
+        ZnUnit(Zn::new(1))
+    }
+}
+
+impl<const N: u64> ConstIdentity<Multiplicative> for ZnUnit<N> {
+    const IDENTITY: Self = ZnUnit(Zn::new(1));
+}
+
+impl<const N: u64> AbstractSemigroup<Multiplicative> for ZnUnit<N> {}
+impl<const N: u64> AbstractQuasigroup<Multiplicative> for ZnUnit<N> {}
+impl<const N: u64> AbstractMonoid<Multiplicative> for ZnUnit<N> {}
+impl<const N: u64> AbstractLoop<Multiplicative> for ZnUnit<N> {}
+impl<const N: u64> AbstractGroup<Multiplicative> for ZnUnit<N> {}
+impl<const N: u64> AbstractGroupAbelian<Multiplicative> for ZnUnit<N> {}
+
+impl<const N: u64> FiniteGroup<Multiplicative> for ZnUnit<N> {
+    type Elements = FilterMap<Range<u64>, fn(u64) -> Option<Self>>;
+
+    #[inline]
+    fn cardinality() -> u64 {
+        totient(N)
+    }
+
+    #[inline]
+    fn elements() -> Self::Elements {
+        (0..N).filter_map(|a| ZnUnit::new(Zn(a)))
+    }
+}
+
+impl<const N: u64> CyclicGroup<Multiplicative> for ZnUnit<N> {
+    // NOTE: this is the smallest primitive root modulo `N`. It does not exist if the group of
+    // units modulo `N` is not cyclic.
+    fn generator() -> Self {
+        let order = Self::cardinality();
+        let mut factors = order;
+        let mut p = 2;
+        let mut prime_factors = [0u64; 16];
+        let mut nfactors = 0;
+
+        while factors > 1 {
+            if p > factors / p {
+                p = factors;
+            }
+
+            if factors % p == 0 {
+                prime_factors[nfactors] = p;
+                nfactors += 1;
+
+                while factors % p == 0 {
+                    factors /= p;
+                }
+            }
+
+            p += 1;
+        }
+
+        // NOTE: g is a generator iff g^(φ(N) / q) ≠ 1 for every prime factor q of φ(N).
+        Self::elements()
+            .find(|g| {
+                prime_factors[..nfactors]
+                    .iter()
+                    .all(|q| pow(*g, order / q) != Identity::<Multiplicative>::identity())
+            })
+            .expect("The group of units modulo N is not cyclic.")
+    }
+}
+
+/// `a` operated `k` times with itself, by exponentiation by squaring.
+fn pow<const N: u64>(mut a: ZnUnit<N>, mut k: u64) -> ZnUnit<N> {
+    let mut result: ZnUnit<N> = Identity::<Multiplicative>::identity();
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result = AbstractMagma::<Multiplicative>::operate(&result, &a);
+        }
+
+        a = AbstractMagma::<Multiplicative>::operate(&a, &a);
+        k >>= 1;
+    }
+
+    result
+}
+
+/*
+ *
+ * Chinese remainder theorem.
+ *
+ */
+/// The ring isomorphism `ℤ/Cℤ ≅ ℤ/Aℤ × ℤ/Bℤ` of the Chinese remainder theorem, where `A` and `B`
+/// are coprime and `C = A × B`.
+///
+/// `split` reduces a residue modulo `C` to its residues modulo `A` and `B`, and `combine` is its
+/// inverse. Both preserve the addition and the multiplication, so that arithmetic modulo `C` can
+/// be performed independently modulo `A` and `B` (the residue number system).
+///
+/// Stable Rust cannot compute `A × B` in a const generic argument, so the product `C` is a
+/// separate parameter. Using `Crt` fails to compile if `C ≠ A × B` or if `A` and `B` are not
+/// coprime:
+///
+/// ```compile_fail
+/// use alga::general::{crt, Zn};
+///
+/// let _: Zn<24> = crt(Zn::<4>::new(1), Zn::<6>::new(1));
+/// ```
+///
+/// NOTE: this is not wrapped as an isomorphism of a morphism module, which the crate does not
+/// have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Crt<const A: u64, const B: u64, const C: u64>;
+
+impl<const A: u64, const B: u64, const C: u64> Crt<A, B, C> {
+    #[inline]
+    const fn check() {
+        const {
+            assert!(
+                A as u128 * B as u128 == C as u128 && gcd(A, B) == 1,
+                "The Chinese remainder theorem requires coprime moduli whose product is C."
+            )
+        }
+    }
+
+    /// The unique residue modulo `C` that reduces to `a` modulo `A` and to `b` modulo `B`.
+    pub fn combine(a: Zn<A>, b: Zn<B>) -> Zn<C> {
+        Self::check();
+
+        // NOTE: x = a + A × ((b - a) × A⁻¹ mod B) is smaller than A × B.
+        let a_inv = inverse_mod(A % B, B).expect("A is invertible modulo B.");
+        let diff = (b - Zn::new(a.value())).value() as u128;
+        let k = diff * a_inv as u128 % B as u128;
+
+        Zn((a.value() as u128 + A as u128 * k) as u64)
+    }
+
+    /// The residues modulo `A` and `B` of a residue modulo `C`.
+    pub fn split(c: Zn<C>) -> (Zn<A>, Zn<B>) {
+        Self::check();
+        (Zn::new(c.value()), Zn::new(c.value()))
+    }
+
+    /// Returns `true` if `split` and `combine` are inverse ring homomorphisms for the given
+    /// argument tuple.
+    pub fn prop_is_ring_isomorphism(args: (Zn<C>, Zn<C>)) -> bool {
+        let (x, y) = args;
+        let (sx, sy) = (Self::split(x), Self::split(y));
+        let one: Zn<C> = Identity::<Multiplicative>::identity();
+
+        Self::split(x + y) == (sx.0 + sy.0, sx.1 + sy.1)
+            && Self::split(x * y) == (sx.0 * sy.0, sx.1 * sy.1)
+            && Self::split(one) == (Zn::new(1), Zn::new(1))
+            && Self::combine(sx.0, sx.1) == x
+    }
+}
+
+/// The unique residue modulo `C = A × B` that reduces to `a` modulo `A` and to `b` modulo `B`,
+/// for coprime `A` and `B`. See `Crt::combine`.
+#[inline]
+pub fn crt<const A: u64, const B: u64, const C: u64>(a: Zn<A>, b: Zn<B>) -> Zn<C> {
+    Crt::<A, B, C>::combine(a, b)
+}
@@ -0,0 +1,368 @@
+//! Prime fields with elements in Montgomery form.
+
+use std::fmt;
+use std::iter::Map;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Range, Sub, SubAssign};
+
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::general::modular::is_prime;
+#[cfg(feature = "subtle")]
+use crate::general::ConstantTimeOps;
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, ConstIdentity, FiniteField, FiniteGroup, Identity, Multiplicative, PrimeField,
+    TwoSidedInverse,
+};
+
+/// The prime field `𝔽_P`, with elements in Montgomery form.
+///
+/// The element `x` is stored as `x × R mod P`, where `R = 2⁶⁴`. Products are then reduced with
+/// Montgomery's REDC algorithm, which only needs multiplications and shifts, instead of a division
+/// by `P` like `Zn<P>`. Both types represent the same field, and `PrimeField` converts between
+/// them through the canonical representatives.
+///
+/// The ring operations, the conversions, and the inversion do not branch on the values of their
+/// operands, except for the final check that the inverse exists: see `ConstantTimeOps`.
+///
+/// `P` must be an odd prime smaller than `2⁶³`, which is checked at compile-time:
+///
+/// ```compile_fail
+/// use alga::general::MontgomeryFp;
+///
+/// let _ = MontgomeryFp::<91>::new(5);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryFp<const P: u64>(u64);
+
+impl<const P: u64> MontgomeryFp<P> {
+    /// `P`, checked to be an odd prime smaller than `2⁶³`.
+    const MODULUS: u64 = {
+        assert!(
+            P % 2 == 1 && P < 1 << 63,
+            "The modulus of a Montgomery field must be odd and smaller than 2⁶³."
+        );
+        assert!(
+            is_prime(P),
+            "The modulus of a Montgomery field must be prime."
+        );
+        P
+    };
+    /// `-P⁻¹ mod R`.
+    const NEG_INV: u64 = neg_inv(Self::MODULUS);
+    /// `R mod P`, i.e., one in Montgomery form.
+    const R1: u64 = ((1u128 << 64) % Self::MODULUS as u128) as u64;
+    /// `R² mod P`, which converts to Montgomery form.
+    const R2: u64 = ((Self::R1 as u128 * Self::R1 as u128) % Self::MODULUS as u128) as u64;
+
+    /// The element whose canonical representative is `value` reduced modulo `P`.
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        // NOTE: no division is needed, and none is done because its latency may depend on
+        // `value`: `value × R² mod P < R × P` already is in the domain of `redc`.
+        MontgomeryFp(Self::redc(value as u128 * Self::R2 as u128))
+    }
+
+    /// The canonical representative of this element, in `0..P`.
+    #[inline]
+    pub fn value(self) -> u64 {
+        Self::redc(self.0 as u128)
+    }
+
+    /// Montgomery's reduction: `t × R⁻¹ mod P`, for `t < P × R`.
+    #[inline]
+    fn redc(t: u128) -> u64 {
+        let p = Self::MODULUS as u128;
+        let m = (t as u64).wrapping_mul(Self::NEG_INV);
+        // NOTE: t + m × P < 2 × P × R does not overflow because P < R / 2.
+        let u = (t + m as u128 * p) >> 64;
+
+        Self::reduce_once(u as u64)
+    }
+
+    /// `x mod P`, for `x < 2 × P`, without branching on `x`.
+    #[inline]
+    fn reduce_once(x: u64) -> u64 {
+        Self::add_if_borrow(x.overflowing_sub(Self::MODULUS))
+    }
+
+    /// `x + P` if `borrow` is set, `x` otherwise, without branching on `borrow`.
+    #[inline]
+    fn add_if_borrow((x, borrow): (u64, bool)) -> u64 {
+        x.wrapping_add(Self::MODULUS & (borrow as u64).wrapping_neg())
+    }
+}
+
+/// `-p⁻¹ mod 2⁶⁴` for odd `p`, computed with Newton's iteration.
+const fn neg_inv(p: u64) -> u64 {
+    // NOTE: p × p = 1 mod 8, and each iteration doubles the number of correct bits.
+    let mut inv = p;
+    let mut i = 0;
+
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        i += 1;
+    }
+
+    inv.wrapping_neg()
+}
+
+impl<const P: u64> fmt::Debug for MontgomeryFp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MontgomeryFp").field(&self.value()).finish()
+    }
+}
+
+impl<const P: u64> fmt::Display for MontgomeryFp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mod {}", self.value(), P)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const P: u64> ConstantTimeEq for MontgomeryFp<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // NOTE: the Montgomery form is unique, so this is the equality of the representations.
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const P: u64> ConditionallySelectable for MontgomeryFp<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        MontgomeryFp(u64::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const P: u64> ConstantTimeOps for MontgomeryFp<P> {}
+
+#[cfg(feature = "zeroize")]
+impl<const P: u64> Zeroize for MontgomeryFp<P> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+impl<const P: u64> Add for MontgomeryFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        // NOTE: both operands are smaller than P < 2⁶³, so the sum does not overflow.
+        MontgomeryFp(Self::reduce_once(self.0 + rhs.0))
+    }
+}
+
+impl<const P: u64> AddAssign for MontgomeryFp<P> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const P: u64> Neg for MontgomeryFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        MontgomeryFp(0) - self
+    }
+}
+
+impl<const P: u64> Sub for MontgomeryFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        MontgomeryFp(Self::add_if_borrow(self.0.overflowing_sub(rhs.0)))
+    }
+}
+
+impl<const P: u64> SubAssign for MontgomeryFp<P> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const P: u64> Mul for MontgomeryFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        MontgomeryFp(Self::redc(self.0 as u128 * rhs.0 as u128))
+    }
+}
+
+impl<const P: u64> MulAssign for MontgomeryFp<P> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+/*
+ *
+ * Algebraic structures.
+ *
+ */
+impl<const P: u64> AbstractMagma<Additive> for MontgomeryFp<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<const P: u64> AbstractMagma<Multiplicative> for MontgomeryFp<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Additive> for MontgomeryFp<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Multiplicative> for MontgomeryFp<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        PrimeField::inverse(self).expect("Zero has no multiplicative inverse.")
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        PrimeField::inverse(self)
+    }
+}
+
+impl<const P: u64> Identity<Additive> for MontgomeryFp<P> {
+    #[inline]
+    fn identity() -> Self {
+        MontgomeryFp(0)
+    }
+}
+
+impl<const P: u64> ConstIdentity<Additive> for MontgomeryFp<P> {
+    const IDENTITY: Self = MontgomeryFp(0);
+}
+
+impl<const P: u64> Identity<Multiplicative> for MontgomeryFp<P> {
+    #[inline]
+    fn identity() -> Self {
+        MontgomeryFp(Self::R1)
+    }
+}
+
+impl<const P: u64> ConstIdentity<Multiplicative> for MontgomeryFp<P> {
+    const IDENTITY: Self = MontgomeryFp(Self::R1);
+}
+
+impl<const P: u64> AbstractSemigroup<Additive> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractQuasigroup<Additive> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractMonoid<Additive> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractLoop<Additive> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractGroup<Additive> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractGroupAbelian<Additive> for MontgomeryFp<P> {}
+
+impl<const P: u64> AbstractSemigroup<Multiplicative> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractQuasigroup<Multiplicative> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractMonoid<Multiplicative> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractLoop<Multiplicative> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractGroup<Multiplicative> for MontgomeryFp<P> {}
+impl<const P: u64> AbstractGroupAbelian<Multiplicative> for MontgomeryFp<P> {}
+
+impl<const P: u64> AbstractRing for MontgomeryFp<P> {
+    #[inline]
+    fn characteristic() -> u64 {
+        P
+    }
+}
+impl<const P: u64> AbstractRingCommutative for MontgomeryFp<P> {}
+impl<const P: u64> AbstractField for MontgomeryFp<P> {}
+
+impl<const P: u64> FiniteGroup<Additive> for MontgomeryFp<P> {
+    type Elements = Map<Range<u64>, fn(u64) -> Self>;
+
+    #[inline]
+    fn cardinality() -> u64 {
+        P
+    }
+
+    #[inline]
+    fn elements() -> Self::Elements {
+        (0..P).map(MontgomeryFp::new)
+    }
+
+    #[inline]
+    fn element_order(&self) -> u64 {
+        if self.0 == 0 {
+            1
+        } else {
+            P
+        }
+    }
+}
+
+impl<const P: u64> FiniteField for MontgomeryFp<P> {
+    #[inline]
+    fn degree() -> u32 {
+        1
+    }
+}
+
+impl<const P: u64> PrimeField for MontgomeryFp<P> {
+    #[inline]
+    fn modulus() -> u64 {
+        Self::MODULUS
+    }
+
+    #[inline]
+    fn from_canonical(value: u64) -> Self {
+        MontgomeryFp::new(value)
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> u64 {
+        self.value()
+    }
+
+    // NOTE: this only branches on the bits of the public exponent P - 2, and on whether `self` is
+    // zero once the inverse has been computed.
+    fn inverse(&self) -> Option<Self> {
+        let mut base = *self;
+        let mut result = MontgomeryFp(Self::R1);
+        let mut k = P - 2;
+
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= base;
+            }
+
+            base *= base;
+            k >>= 1;
+        }
+
+        if self.0 == 0 {
+            None
+        } else {
+            Some(result)
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! Octonions.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractAlternativeMagma, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractModule, AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive,
+    AlternativeAlgebra, Identity, Module, Multiplicative, NormedDivisionAlgebra, Quaternion,
+    RealField, SubsetOf, SupersetOf, TwoSidedInverse,
+};
+
+/// An octonion, built from a pair of quaternions `(a, b)` by the Cayley–Dickson construction.
+///
+/// Its components on the basis `1, e₁, …, e₇` are the components of `a` followed by those of `b`.
+/// The product is:
+///
+/// ~~~notrust
+/// (a, b) × (c, d) = (a c - d̄ b, d a + b c̄)
+/// ~~~
+///
+/// Octonions form a normed division algebra whose multiplication is neither commutative nor
+/// associative, but only alternative. Thus, they implement `AbstractAlternativeMagma` and
+/// `AbstractLoop`, but not `AbstractSemigroup`, for the multiplication. The product of pure
+/// imaginary octonions defines the cross product of the 7-dimensional space, see
+/// `cross_product`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Octonion<N> {
+    /// The first half of the components.
+    pub a: Quaternion<N>,
+    /// The second half of the components.
+    pub b: Quaternion<N>,
+}
+
+impl<N> Octonion<N> {
+    /// Creates an octonion from its two quaternion halves.
+    #[inline]
+    pub const fn new(a: Quaternion<N>, b: Quaternion<N>) -> Self {
+        Octonion { a, b }
+    }
+}
+
+impl<N: RealField> Octonion<N> {
+    /// Creates an octonion from its components on the basis `1, e₁, …, e₇`.
+    #[inline]
+    pub fn from_components(c: [N; 8]) -> Self {
+        Octonion::new(
+            Quaternion::new(c[0], c[1], c[2], c[3]),
+            Quaternion::new(c[4], c[5], c[6], c[7]),
+        )
+    }
+
+    /// The octonion with real part `re` and zero imaginary part.
+    #[inline]
+    pub fn from_real(re: N) -> Self {
+        Octonion::new(Quaternion::from_real(re), Quaternion::from_real(N::zero()))
+    }
+
+    /// The pure imaginary octonion with the components `v` on the basis `e₁, …, e₇`.
+    #[inline]
+    pub fn from_imag(v: [N; 7]) -> Self {
+        Octonion::from_components([N::zero(), v[0], v[1], v[2], v[3], v[4], v[5], v[6]])
+    }
+
+    /// The components of this octonion on the basis `1, e₁, …, e₇`.
+    #[inline]
+    pub fn components(&self) -> [N; 8] {
+        let (a, b) = (self.a, self.b);
+        [a.w, a.i, a.j, a.k, b.w, b.i, b.j, b.k]
+    }
+
+    /// The real part of this octonion.
+    #[inline]
+    pub fn real(&self) -> N {
+        self.a.w
+    }
+
+    /// The imaginary part of this octonion, as a 7-dimensional vector.
+    #[inline]
+    pub fn imag(&self) -> [N; 7] {
+        let c = self.components();
+        [c[1], c[2], c[3], c[4], c[5], c[6], c[7]]
+    }
+
+    /// The cross product of two 7-dimensional vectors, i.e., the imaginary part of the product
+    /// of the corresponding pure imaginary octonions.
+    ///
+    /// It is bilinear, anticommutative, orthogonal to both of its arguments, and its norm is
+    /// `|u| |v| sin θ` where `θ` is the angle between `u` and `v`.
+    #[inline]
+    pub fn cross_product(u: [N; 7], v: [N; 7]) -> [N; 7] {
+        (Octonion::from_imag(u) * Octonion::from_imag(v)).imag()
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for Octonion<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b) = (&self.a, &self.b);
+        write!(
+            f,
+            "{} + {}e₁ + {}e₂ + {}e₃ + {}e₄ + {}e₅ + {}e₆ + {}e₇",
+            a.w, a.i, a.j, a.k, b.w, b.i, b.j, b.k
+        )
+    }
+}
+
+impl<N: RealField> Add for Octonion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Octonion::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+impl<N: RealField> Sub for Octonion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Octonion::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+impl<N: RealField> Mul for Octonion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b, c, d) = (self.a, self.b, rhs.a, rhs.b);
+
+        Octonion::new(
+            a * c - NormedDivisionAlgebra::conjugate(&d) * b,
+            d * a + b * NormedDivisionAlgebra::conjugate(&c),
+        )
+    }
+}
+
+impl<N: RealField> Mul<N> for Octonion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Self {
+        Octonion::new(self.a * rhs, self.b * rhs)
+    }
+}
+
+impl<N: RealField> Div for Octonion<N> {
+    type Output = Self;
+
+    /// The right division `self × rhs⁻¹`.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&rhs);
+        AbstractMagma::<Multiplicative>::operate(&self, &inv)
+    }
+}
+
+impl<N: RealField> Neg for Octonion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Octonion::new(-self.a, -self.b)
+    }
+}
+
+impl<N: RealField> AddAssign for Octonion<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<N: RealField> SubAssign for Octonion<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<N: RealField> MulAssign for Octonion<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> MulAssign<N> for Octonion<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> DivAssign for Octonion<N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<N: RealField> Zero for Octonion<N> {
+    #[inline]
+    fn zero() -> Self {
+        Octonion::from_real(N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+}
+
+impl<N: RealField> One for Octonion<N> {
+    #[inline]
+    fn one() -> Self {
+        Octonion::from_real(N::one())
+    }
+}
+
+impl<N: RealField> AbstractMagma<Additive> for Octonion<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<N: RealField> AbstractMagma<Multiplicative> for Octonion<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Additive> for Octonion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Multiplicative> for Octonion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        NormedDivisionAlgebra::conjugate(self) * (N::one() / self.norm_squared())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(TwoSidedInverse::<Multiplicative>::two_sided_inverse(self))
+        }
+    }
+}
+
+impl<N: RealField> Identity<Additive> for Octonion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<N: RealField> Identity<Multiplicative> for Octonion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<N: RealField> AbstractSemigroup<Additive> for Octonion<N> {}
+impl<N: RealField> AbstractQuasigroup<Additive> for Octonion<N> {}
+impl<N: RealField> AbstractMonoid<Additive> for Octonion<N> {}
+impl<N: RealField> AbstractLoop<Additive> for Octonion<N> {}
+impl<N: RealField> AbstractGroup<Additive> for Octonion<N> {}
+impl<N: RealField> AbstractGroupAbelian<Additive> for Octonion<N> {}
+// NOTE: the nonzero octonions form a Moufang loop, which is not a group.
+impl<N: RealField> AbstractAlternativeMagma<Multiplicative> for Octonion<N> {}
+impl<N: RealField> AbstractQuasigroup<Multiplicative> for Octonion<N> {}
+impl<N: RealField> AbstractLoop<Multiplicative> for Octonion<N> {}
+
+impl<N: RealField> AbstractModule for Octonion<N> {
+    type AbstractRing = N;
+
+    #[inline]
+    fn multiply_by(&self, r: N) -> Self {
+        *self * r
+    }
+}
+
+impl<N: RealField> Module for Octonion<N> {
+    type Ring = N;
+}
+
+impl<N: RealField> AlternativeAlgebra for Octonion<N> {}
+
+impl<N: RealField> NormedDivisionAlgebra<N> for Octonion<N> {
+    #[inline]
+    fn conjugate(&self) -> Self {
+        Octonion::new(NormedDivisionAlgebra::conjugate(&self.a), -self.b)
+    }
+
+    #[inline]
+    fn norm_squared(&self) -> N {
+        self.a.norm_squared() + self.b.norm_squared()
+    }
+}
+
+impl<N: AbsDiffEq> AbsDiffEq for Octonion<N>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.a.abs_diff_eq(&other.a, epsilon.clone()) && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+impl<N: RelativeEq> RelativeEq for Octonion<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.a
+            .relative_eq(&other.a, epsilon.clone(), max_relative.clone())
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+    }
+}
+
+impl<N: UlpsEq> UlpsEq for Octonion<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.a.ulps_eq(&other.a, epsilon.clone(), max_ulps)
+            && self.b.ulps_eq(&other.b, epsilon, max_ulps)
+    }
+}
+
+impl<N1, N2: SupersetOf<N1>> SubsetOf<Octonion<N2>> for Octonion<N1> {
+    #[inline]
+    fn to_superset(&self) -> Octonion<N2> {
+        Octonion::new(self.a.to_superset(), self.b.to_superset())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Octonion<N2>) -> Octonion<N1> {
+        Octonion::new(
+            element.a.to_subset_unchecked(),
+            element.b.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(o: &Octonion<N2>) -> bool {
+        <Quaternion<N1> as SubsetOf<Quaternion<N2>>>::is_in_subset(&o.a)
+            && <Quaternion<N1> as SubsetOf<Quaternion<N2>>>::is_in_subset(&o.b)
+    }
+}
+
+impl<N: RealField, N2: RealField + SupersetOf<N>> SubsetOf<Octonion<N2>> for N {
+    #[inline]
+    fn to_superset(&self) -> Octonion<N2> {
+        Octonion::from_real(N2::from_subset(self))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Octonion<N2>) -> N {
+        element.a.w.to_subset_unchecked()
+    }
+
+    #[inline]
+    fn is_in_subset(o: &Octonion<N2>) -> bool {
+        <N2 as SupersetOf<N>>::is_in_subset(&o.a.w) && o.imag().iter().all(|x| x.is_zero())
+    }
+}
+
+impl<N1, N2: RealField + SupersetOf<N1>> SubsetOf<Octonion<N2>> for Quaternion<N1> {
+    #[inline]
+    fn to_superset(&self) -> Octonion<N2> {
+        Octonion::new(self.to_superset(), Quaternion::from_real(N2::zero()))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Octonion<N2>) -> Quaternion<N1> {
+        element.a.to_subset_unchecked()
+    }
+
+    #[inline]
+    fn is_in_subset(o: &Octonion<N2>) -> bool {
+        <Quaternion<N1> as SubsetOf<Quaternion<N2>>>::is_in_subset(&o.a) && o.b.is_zero()
+    }
+}
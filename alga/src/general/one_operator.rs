@@ -2,11 +2,14 @@
 use decimal::d128;
 use num::Num;
 use num_complex::Complex;
-use std::ops::{Add, Mul};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
 
 use approx::RelativeEq;
 
-use crate::general::{Additive, ClosedNeg, Identity, Multiplicative, Operator, TwoSidedInverse};
+use crate::general::{
+    Additive, ClosedNeg, Composition, Identity, Multiplicative, MultiplicativeComposition,
+    Operator, TwoSidedInverse,
+};
 
 /// A magma is an algebraic structure which consists of a set equipped with a binary operation, ∘,
 /// which must be closed.
@@ -20,6 +23,35 @@ pub trait AbstractMagma<O: Operator>: Sized + Clone {
     /// Performs an operation.
     fn operate(&self, right: &Self) -> Self;
 
+    /// Performs an operation, storing the result into `out`.
+    ///
+    /// The default implementation assigns the result of `operate` to `out`. Types with a costly
+    /// representation (bignums, polynomials, permutations) should override it to reuse the
+    /// storage of `out`. The property checks use this method for their intermediate results.
+    #[inline]
+    fn operate_into(&self, right: &Self, out: &mut Self) {
+        *out = self.operate(right)
+    }
+
+    /// Performs an operation in-place, replacing `self` by `self ∘ right`.
+    ///
+    /// The default implementation assigns the result of `operate` to `self`. Types that are not
+    /// `Copy` should override it to avoid allocating or copying a new value at each step of, e.g.,
+    /// a fold over a monoid.
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        *self = self.operate(right)
+    }
+
+    /// Performs an operation, consuming both operands.
+    ///
+    /// The default implementation calls `operate` on references. Types that are not `Copy` should
+    /// override it to reuse the storage of the operands instead of cloning them.
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        self.operate(&right)
+    }
+
     /// Performs specific operation.
     #[inline]
     fn op(&self, _: O, lhs: &Self) -> Self {
@@ -54,6 +86,8 @@ pub trait AbstractQuasigroup<O: Operator>:
     /// ```notrust
     /// a ~= a / b ∘ b && a ~= a ∘ b / b
     /// ```
+    // NOTE: this does not forward to `prop_inv_is_latin_square_approx_with`, which would require
+    // `Self::Epsilon: Clone`.
     fn prop_inv_is_latin_square_approx(args: (Self, Self)) -> bool
     where
         Self: RelativeEq,
@@ -61,6 +95,35 @@ pub trait AbstractQuasigroup<O: Operator>:
         let (a, b) = args;
         relative_eq!(a, a.operate(&b.two_sided_inverse()).operate(&b))
             && relative_eq!(a, a.operate(&b.operate(&b.two_sided_inverse())))
+    }
+
+    /// Returns `true` if latin squareness holds for the given arguments. Approximate equality, up
+    /// to the given `epsilon` and `max_relative` tolerances, is used for verifications.
+    ///
+    /// ```notrust
+    /// a ~= a / b ∘ b && a ~= a ∘ b / b
+    /// ```
+    fn prop_inv_is_latin_square_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            a,
+            a.operate(&b.two_sided_inverse()).operate(&b),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a,
+            a.operate(&b.operate(&b.two_sided_inverse())),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
 
         // TODO: pseudo inverse?
     }
@@ -113,6 +176,120 @@ macro_rules! impl_quasigroup(
     }
 );
 
+/// An alternative magma is a magma whose operation is **alternative**, a weak form of
+/// associativity.
+///
+/// # Alternativity
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, (a ∘ a) ∘ b = a ∘ (a ∘ b) and (b ∘ a) ∘ a = b ∘ (a ∘ a)
+/// ~~~
+///
+/// Alternativity implies **flexibility**:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, (a ∘ b) ∘ a = a ∘ (b ∘ a)
+/// ~~~
+///
+/// Every semigroup is an alternative magma. The multiplication of the octonions is the main
+/// example of an alternative operation that is not associative.
+pub trait AbstractAlternativeMagma<O: Operator>: PartialEq + AbstractMagma<O> {
+    /// Returns `true` if alternativity holds for the given arguments. Approximate equality is used
+    /// for verifications.
+    fn prop_is_alternative_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_is_alternative_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if alternativity holds for the given arguments. Approximate equality, up to
+    /// the given `epsilon` and `max_relative` tolerances, is used for verifications.
+    fn prop_is_alternative_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b) = args;
+        let aa = a.operate(&a);
+
+        relative_eq!(
+            aa.operate(&b),
+            a.operate(&a.operate(&b)),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            b.operate(&aa),
+            b.operate(&a).operate(&a),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if alternativity holds for the given arguments.
+    fn prop_is_alternative(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        let aa = a.operate(&a);
+
+        aa.operate(&b) == a.operate(&a.operate(&b)) && b.operate(&aa) == b.operate(&a).operate(&a)
+    }
+
+    /// Returns `true` if flexibility holds for the given arguments. Approximate equality is used
+    /// for verifications.
+    fn prop_is_flexible_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_is_flexible_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if flexibility holds for the given arguments. Approximate equality, up to the
+    /// given `epsilon` and `max_relative` tolerances, is used for verifications.
+    fn prop_is_flexible_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            a.operate(&b).operate(&a),
+            a.operate(&b.operate(&a)),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if flexibility holds for the given arguments.
+    fn prop_is_flexible(args: (Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a, b) = args;
+        a.operate(&b).operate(&a) == a.operate(&b.operate(&a))
+    }
+}
+
+impl<O: Operator, T: AbstractSemigroup<O>> AbstractAlternativeMagma<O> for T {}
+
 /// A semigroup is a quasigroup that is **associative**.
 ///
 /// *A semigroup is a set equipped with a closed associative binary operation and that has the divisibility property.*
@@ -126,11 +303,33 @@ pub trait AbstractSemigroup<O: Operator>: PartialEq + AbstractMagma<O> {
     /// Returns `true` if associativity holds for the given arguments. Approximate equality is used
     /// for verifications.
     fn prop_is_associative_approx(args: (Self, Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_is_associative_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if associativity holds for the given arguments. Approximate equality, up to
+    /// the given `epsilon` and `max_relative` tolerances, is used for verifications.
+    fn prop_is_associative_approx_with(
+        args: (Self, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
     where
         Self: RelativeEq,
     {
         let (a, b, c) = args;
-        relative_eq!(a.operate(&b).operate(&c), a.operate(&b.operate(&c)))
+        let mut lhs = b.operate(&c);
+        let rhs = a.operate(&lhs);
+        // Reuse the storage of `b ∘ c` for `(a ∘ b) ∘ c`.
+        a.operate(&b).operate_into(&c, &mut lhs);
+
+        relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
     }
 
     /// Returns `true` if associativity holds for the given arguments.
@@ -139,7 +338,12 @@ pub trait AbstractSemigroup<O: Operator>: PartialEq + AbstractMagma<O> {
         Self: Eq,
     {
         let (a, b, c) = args;
-        a.operate(&b).operate(&c) == a.operate(&b.operate(&c))
+        let mut lhs = b.operate(&c);
+        let rhs = a.operate(&lhs);
+        // Reuse the storage of `b ∘ c` for `(a ∘ b) ∘ c`.
+        a.operate(&b).operate_into(&c, &mut lhs);
+
+        lhs == rhs
     }
 }
 
@@ -239,6 +443,8 @@ macro_rules! impl_loop(
 pub trait AbstractMonoid<O: Operator>: AbstractSemigroup<O> + Identity<O> {
     /// Checks whether operating with the identity element is a no-op for the given
     /// argument. Approximate equality is used for verifications.
+    // NOTE: this does not forward to `prop_operating_identity_element_is_noop_approx_with`, which
+    // would require `Self::Epsilon: Clone`.
     fn prop_operating_identity_element_is_noop_approx(args: (Self,)) -> bool
     where
         Self: RelativeEq,
@@ -248,6 +454,32 @@ pub trait AbstractMonoid<O: Operator>: AbstractSemigroup<O> + Identity<O> {
             && relative_eq!(Self::identity().operate(&a), a)
     }
 
+    /// Checks whether operating with the identity element is a no-op for the given argument.
+    /// Approximate equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_operating_identity_element_is_noop_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a,) = args;
+        relative_eq!(
+            a.operate(&Self::identity()),
+            a,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            Self::identity().operate(&a),
+            a,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
     /// Checks whether operating with the identity element is a no-op for the given
     /// argument.
     fn prop_operating_identity_element_is_noop(args: (Self,)) -> bool
@@ -291,6 +523,101 @@ macro_rules! impl_monoid(
     }
 );
 
+/// A monoid where only some elements have a two-sided inverse.
+///
+/// *A set equipped with a closed associative binary operation and an identity element, where the
+/// inverse of an element is only defined if it is invertible.*
+///
+/// Square matrices, endofunctions, or the integers under multiplication are such monoids. Every
+/// group implements this trait automatically, all its elements being invertible.
+///
+/// # Partial inverse
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, a is invertible ⇔ ∃ a⁻¹ ∈ Self such that a ∘ a⁻¹ = a⁻¹ ∘ a = e
+/// ~~~
+pub trait PartialInverse<O: Operator>: AbstractMonoid<O> {
+    /// Returns the two-sided inverse of `self`, or `None` if it is not invertible.
+    fn partial_inverse(&self) -> Option<Self>;
+
+    /// Returns `true` if `self` has a two-sided inverse.
+    #[inline]
+    fn is_invertible(&self) -> bool {
+        self.partial_inverse().is_some()
+    }
+
+    /// Returns `true` if the identity element is invertible.
+    fn prop_identity_is_invertible() -> bool {
+        Self::identity().is_invertible()
+    }
+
+    /// Returns `true` if the partial inverse of the given argument, when it exists, is both a left
+    /// and right inverse. Approximate equality is used for verifications.
+    fn prop_partial_inverse_is_inverse_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_partial_inverse_is_inverse_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the partial inverse of the given argument, when it exists, is both a left
+    /// and right inverse. Approximate equality, up to the given `epsilon` and `max_relative`
+    /// tolerances, is used for verifications.
+    fn prop_partial_inverse_is_inverse_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a,) = args;
+
+        match a.partial_inverse() {
+            Some(inv) => {
+                a.is_invertible()
+                    && relative_eq!(
+                        a.operate(&inv),
+                        Self::identity(),
+                        epsilon = epsilon.clone(),
+                        max_relative = max_relative.clone()
+                    )
+                    && relative_eq!(
+                        inv.operate(&a),
+                        Self::identity(),
+                        epsilon = epsilon.clone(),
+                        max_relative = max_relative.clone()
+                    )
+            }
+            None => !a.is_invertible(),
+        }
+    }
+
+    /// Returns `true` if the partial inverse of the given argument, when it exists, is both a left
+    /// and right inverse.
+    fn prop_partial_inverse_is_inverse(args: (Self,)) -> bool
+    where
+        Self: Eq,
+    {
+        let (a,) = args;
+
+        match a.partial_inverse() {
+            Some(inv) => {
+                a.is_invertible()
+                    && a.operate(&inv) == Self::identity()
+                    && inv.operate(&a) == Self::identity()
+            }
+            None => !a.is_invertible(),
+        }
+    }
+}
+
 /// A group is a loop and a monoid  at the same time.
 ///
 /// *A groups is a set with a closed associative binary operation with the divisibility property and an identity element.*
@@ -349,11 +676,34 @@ pub trait AbstractGroupAbelian<O: Operator>: AbstractGroup<O> {
     /// Returns `true` if the operator is commutative for the given argument tuple. Approximate
     /// equality is used for verifications.
     fn prop_is_commutative_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_is_commutative_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the operator is commutative for the given argument tuple. Approximate
+    /// equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_is_commutative_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
     where
         Self: RelativeEq,
     {
         let (a, b) = args;
-        relative_eq!(a.operate(&b), b.operate(&a))
+        relative_eq!(
+            a.operate(&b),
+            b.operate(&a),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
     }
 
     /// Returns `true` if the operator is commutative for the given argument tuple.
@@ -413,26 +763,68 @@ macro_rules! impl_abelian(
  *
  */
 macro_rules! impl_magma(
-    ($M:ty; $op: ident; $($T:ty),* $(,)*) => {
+    ($M:ty; $op: ident, $op_assign: ident; $($T:ty),* $(,)*) => {
         $(impl AbstractMagma<$M> for $T {
             #[inline]
             fn operate(&self, lhs: &Self) -> Self {
                 self.$op(*lhs)
             }
+
+            #[inline]
+            fn operate_assign(&mut self, right: &Self) {
+                self.$op_assign(*right)
+            }
+
+            #[inline]
+            fn operate_owned(self, right: Self) -> Self {
+                self.$op(right)
+            }
         })*
     }
 );
 
-impl_magma!(Additive; add; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_magma!(Additive; add, add_assign; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 #[cfg(feature = "decimal")]
-impl_magma!(Additive; add; d128);
-impl_magma!(Multiplicative; mul; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_magma!(Additive; add, add_assign; d128);
+impl_magma!(Multiplicative; mul, mul_assign; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
 #[cfg(feature = "decimal")]
-impl_magma!(Multiplicative; mul; d128);
+impl_magma!(Multiplicative; mul, mul_assign; d128);
 
 impl_monoid!(<Additive> for u8; u16; u32; u64; u128; usize);
 impl_monoid!(<Multiplicative> for u8; u16; u32; u64; u128; usize);
 
+impl<O: Operator, T: AbstractGroup<O>> PartialInverse<O> for T {
+    #[inline]
+    fn partial_inverse(&self) -> Option<Self> {
+        self.try_two_sided_inverse()
+    }
+}
+
+// NOTE: the invertible elements of those monoids are their own inverse.
+macro_rules! impl_partial_inverse(
+    ($M:ty; $invertibles: tt; $($T:ty),* $(,)*) => {$(
+        impl PartialInverse<$M> for $T {
+            #[inline]
+            fn partial_inverse(&self) -> Option<Self> {
+                if PartialInverse::<$M>::is_invertible(self) {
+                    Some(*self)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn is_invertible(&self) -> bool {
+                $invertibles.contains(self)
+            }
+        }
+    )*}
+);
+
+impl_partial_inverse!(Additive; [0]; u8, u16, u32, u64, u128, usize);
+impl_partial_inverse!(Multiplicative; [1]; u8, u16, u32, u64, u128, usize);
+impl_partial_inverse!(Multiplicative; [1, -1]; i8, i16, i32, i64, i128, isize);
+
 impl<N: AbstractMagma<Additive>> AbstractMagma<Additive> for Complex<N> {
     #[inline]
     fn operate(&self, lhs: &Self) -> Self {
@@ -441,6 +833,20 @@ impl<N: AbstractMagma<Additive>> AbstractMagma<Additive> for Complex<N> {
             im: self.im.operate(&lhs.im),
         }
     }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        self.re.operate_assign(&right.re);
+        self.im.operate_assign(&right.im);
+    }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        Complex {
+            re: self.re.operate_owned(right.re),
+            im: self.im.operate_owned(right.im),
+        }
+    }
 }
 
 impl<N: Num + Clone> AbstractMagma<Multiplicative> for Complex<N> {
@@ -448,7 +854,58 @@ impl<N: Num + Clone> AbstractMagma<Multiplicative> for Complex<N> {
     fn operate(&self, lhs: &Self) -> Self {
         self * lhs
     }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        self * right
+    }
 }
 
 impl_abelian!(<Multiplicative> for Complex<N> where N: Num + Clone + ClosedNeg);
 impl_abelian!(<Additive> for Complex<N> where N: AbstractGroupAbelian<Additive>);
+
+impl<T: MultiplicativeComposition + AbstractMagma<Multiplicative>> AbstractMagma<Composition>
+    for T
+{
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        AbstractMagma::<Multiplicative>::operate(self, right)
+    }
+
+    #[inline]
+    fn operate_into(&self, right: &Self, out: &mut Self) {
+        AbstractMagma::<Multiplicative>::operate_into(self, right, out)
+    }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        AbstractMagma::<Multiplicative>::operate_assign(self, right)
+    }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        AbstractMagma::<Multiplicative>::operate_owned(self, right)
+    }
+}
+
+impl<T: MultiplicativeComposition + AbstractQuasigroup<Multiplicative>>
+    AbstractQuasigroup<Composition> for T
+{
+}
+impl<T: MultiplicativeComposition + AbstractSemigroup<Multiplicative>>
+    AbstractSemigroup<Composition> for T
+{
+}
+impl<T: MultiplicativeComposition + AbstractLoop<Multiplicative>> AbstractLoop<Composition> for T {}
+impl<T: MultiplicativeComposition + AbstractMonoid<Multiplicative>> AbstractMonoid<Composition>
+    for T
+{
+}
+impl<T: MultiplicativeComposition + AbstractGroup<Multiplicative>> AbstractGroup<Composition>
+    for T
+{
+}
+impl<T: MultiplicativeComposition + AbstractGroupAbelian<Multiplicative>>
+    AbstractGroupAbelian<Composition> for T
+{
+}
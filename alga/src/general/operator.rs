@@ -3,7 +3,7 @@
 use decimal::d128;
 pub use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
-use num::Num;
+use num::{Num, Zero};
 use num_complex::Complex;
 
 /// Trait implemented by types representing abstract operators.
@@ -28,6 +28,16 @@ pub trait TwoSidedInverse<O: Operator>: Sized {
     fn two_sided_inverse_mut(&mut self) {
         *self = self.two_sided_inverse()
     }
+
+    /// Returns the two_sided_inverse of `self` if it exists, relative to the operator `O`.
+    ///
+    /// Structures whose inverse is only partial in practice (e.g. zero in a field under
+    /// multiplication) return `None` instead of panicking or returning a meaningless value. The
+    /// default implementation always returns `Some(self.two_sided_inverse())`.
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        Some(self.two_sided_inverse())
+    }
 }
 
 /*
@@ -36,18 +46,33 @@ pub trait TwoSidedInverse<O: Operator>: Sized {
  *
  */
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /// The addition operator, commonly symbolized by `+`.
 pub struct Additive;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /// The multiplication operator, commonly symbolized by `×`.
 pub struct Multiplicative;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /// The default abstract operator.
 pub struct AbstractOperator;
 
+#[derive(Clone, Copy, Debug)]
+/// The composition operator, commonly symbolized by `∘`.
+///
+/// Every type implementing `MultiplicativeComposition` gets the structures of its multiplication
+/// for the composition operator, `a ∘ b` being `a × b`. This is mostly useful for transformations,
+/// so that they can be handled by code generic over the operator.
+pub struct Composition;
+
+/// Marker trait for transformations that compose by multiplication.
+///
+/// Implementing it gives `Composition` the same algebraic structures as `Multiplicative`, so the
+/// group machinery applies to the transformation. Types that do not implement it are free to
+/// implement the `Composition` structures themselves.
+pub trait MultiplicativeComposition {}
+
 impl Operator for Additive {
     #[inline]
     fn operator_token() -> Self {
@@ -69,6 +94,13 @@ impl Operator for AbstractOperator {
     }
 }
 
+impl Operator for Composition {
+    #[inline]
+    fn operator_token() -> Self {
+        Composition
+    }
+}
+
 macro_rules! impl_additive_inverse(
     ($($T:ty),* $(,)*) => {$(
         impl TwoSidedInverse<Additive> for $T {
@@ -98,6 +130,15 @@ impl TwoSidedInverse<Multiplicative> for f32 {
     fn two_sided_inverse(&self) -> f32 {
         1.0 / self
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<f32> {
+        if *self == 0.0 {
+            None
+        } else {
+            Some(1.0 / self)
+        }
+    }
 }
 
 impl TwoSidedInverse<Multiplicative> for f64 {
@@ -105,6 +146,15 @@ impl TwoSidedInverse<Multiplicative> for f64 {
     fn two_sided_inverse(&self) -> f64 {
         1.0 / self
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<f64> {
+        if *self == 0.0 {
+            None
+        } else {
+            Some(1.0 / self)
+        }
+    }
 }
 
 #[cfg(feature = "decimal")]
@@ -113,6 +163,15 @@ impl TwoSidedInverse<Multiplicative> for d128 {
     fn two_sided_inverse(&self) -> d128 {
         d128!(1.0) / self
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<d128> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(d128!(1.0) / self)
+        }
+    }
 }
 
 impl<N: Num + Clone + ClosedNeg> TwoSidedInverse<Multiplicative> for Complex<N> {
@@ -120,6 +179,35 @@ impl<N: Num + Clone + ClosedNeg> TwoSidedInverse<Multiplicative> for Complex<N>
     fn two_sided_inverse(&self) -> Self {
         self.inv()
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.inv())
+        }
+    }
+}
+
+impl<T> TwoSidedInverse<Composition> for T
+where
+    T: MultiplicativeComposition + TwoSidedInverse<Multiplicative>,
+{
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse(self)
+    }
+
+    #[inline]
+    fn two_sided_inverse_mut(&mut self) {
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse_mut(self)
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(self)
+    }
 }
 
 /// [Alias] Trait alias for `Add` and `AddAssign` with result of type `Self`.
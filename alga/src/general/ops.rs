@@ -0,0 +1,6 @@
+//! Trait aliases for the operators of `std::ops` whose result has the type of their left operand.
+//!
+//! These are also re-exported by the `general` module. Downstream crates should bound on them
+//! instead of defining their own copies, so that their bounds remain compatible.
+
+pub use crate::general::operator::{ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub};
@@ -0,0 +1,144 @@
+#[cfg(feature = "decimal")]
+use decimal::d128;
+
+use crate::general::{
+    AbstractField, AbstractMagma, AbstractRingCommutative, Additive, Identity, Multiplicative,
+    TwoSidedInverse,
+};
+
+/// A commutative ring with a total order compatible with its operations.
+///
+/// *An **ordered ring** is a commutative ring where adding the same element to both sides of an
+/// inequality preserves it, and where the product of two nonnegative elements is nonnegative.*
+///
+/// # Compatibility with the addition
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ Self, a ≤ b ⇒ a + c ≤ b + c
+/// ~~~
+///
+/// # Positivity of the products
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, 0 ≤ a and 0 ≤ b ⇒ 0 ≤ a × b
+/// ~~~
+///
+/// The order is only required to be partial so that floating-point numbers qualify: it must be
+/// total on every element comparable to itself, i.e., except for NaN.
+pub trait OrderedRing: AbstractRingCommutative + PartialOrd {
+    /// Returns `true` if adding the same element to both sides of an inequality preserves it, for
+    /// the given argument tuple.
+    fn prop_add_preserves_order(args: (Self, Self, Self)) -> bool {
+        let (a, b, c) = args;
+
+        if a <= b {
+            AbstractMagma::<Additive>::operate(&a, &c) <= AbstractMagma::<Additive>::operate(&b, &c)
+        } else {
+            true
+        }
+    }
+
+    /// Returns `true` if the product of the given arguments is nonnegative whenever both of them
+    /// are.
+    fn prop_mul_preserves_nonnegativity(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        let zero: Self = Identity::<Additive>::identity();
+
+        if zero <= a && zero <= b {
+            zero <= AbstractMagma::<Multiplicative>::operate(&a, &b)
+        } else {
+            true
+        }
+    }
+}
+
+/// A field with a total order compatible with its operations.
+///
+/// The order of an ordered field is inherited from `OrderedRing`. It follows that the inverse of a
+/// positive element is positive:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, 0 < a ⇒ 0 < a⁻¹
+/// ~~~
+pub trait OrderedField: OrderedRing + AbstractField {
+    /// Returns `true` if the inverse of the given argument is positive whenever it is.
+    fn prop_inv_preserves_positivity(args: (Self,)) -> bool {
+        let (a,) = args;
+        let zero: Self = Identity::<Additive>::identity();
+
+        if zero < a {
+            zero < TwoSidedInverse::<Multiplicative>::two_sided_inverse(&a)
+        } else {
+            true
+        }
+    }
+}
+
+/// An ordered field where every element lies between two consecutive integers.
+///
+/// *An ordered field is **Archimedean** if it has no infinitely large element: for every `a`,
+/// there is an integer `n` such that `a < n`.* Every element then has an integer part, which is
+/// the largest integer not greater than it:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, ⌊a⌋ ≤ a < ⌊a⌋ + 1
+/// ~~~
+pub trait ArchimedeanField: OrderedField {
+    /// The integer part `⌊self⌋` of this element, or `None` if it does not fit in an `i64`, or if
+    /// `self` is not a finite number.
+    fn floor_to_integer(&self) -> Option<i64>;
+
+    /// The element corresponding to the given integer, i.e., `1` added `n` times to `0`.
+    ///
+    /// The result may be rounded if `n` is not exactly representable.
+    fn from_integer(n: i64) -> Self;
+
+    /// Returns `true` if the integer part of the given argument is the largest integer not
+    /// greater than it.
+    fn prop_floor_is_integer_part(args: (Self,)) -> bool {
+        let (a,) = args;
+        let zero: Self = Identity::<Additive>::identity();
+        let one: Self = Identity::<Multiplicative>::identity();
+
+        // NOTE: the fractional part is compared to one instead of the integer part plus one, which
+        // could be rounded to the integer part itself.
+        match a.floor_to_integer() {
+            Some(n) => {
+                let floor = Self::from_integer(n);
+                let fract = AbstractMagma::<Additive>::operate(
+                    &a,
+                    &TwoSidedInverse::<Additive>::two_sided_inverse(&floor),
+                );
+
+                zero <= fract && fract < one
+            }
+            None => true,
+        }
+    }
+
+    /// Returns `true` if the integer part of the given integer is itself.
+    fn prop_floor_of_integer_is_identity(args: (i64,)) -> bool {
+        let (n,) = args;
+        let a = Self::from_integer(n);
+
+        match a.floor_to_integer() {
+            Some(m) => Self::from_integer(m) == a,
+            None => true,
+        }
+    }
+}
+
+/*
+ *
+ * Implementations.
+ *
+ */
+// NOTE: like for the ring laws, the compatibility of the order with the operations only holds for
+// primitive integers as long as the operations do not overflow.
+impl_marker!(OrderedRing; i8; i16; i32; i64; i128; isize; f32; f64);
+impl_marker!(OrderedField; f32; f64);
+
+#[cfg(feature = "decimal")]
+impl_marker!(OrderedRing; d128);
+#[cfg(feature = "decimal")]
+impl_marker!(OrderedField; d128);
@@ -0,0 +1,263 @@
+//! p-adic integers with a fixed precision.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::modular::inverse_mod;
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup, Additive,
+    ConstIdentity, Identity, Multiplicative, PAdicValuation, TwoSidedInverse, UltrametricValuation,
+    Valuation,
+};
+
+/// A `P`-adic integer `a₀ + a₁P + a₂P² + …`, truncated to its first `precision()` digits.
+///
+/// The precision is the largest `k` such that `Pᵏ` fits in a `u64`, and the digits beyond it are
+/// dropped: this is the ring `ℤ/Pᵏℤ`, which approximates the ring `ℤₚ` of `P`-adic integers. The
+/// negative integers have infinitely many nonzero digits, e.g., `-1 = (P - 1) + (P - 1)P + …`.
+///
+/// The units are the elements whose first digit is nonzero, and they are invertible:
+/// `two_sided_inverse` panics for the other ones while `try_two_sided_inverse` returns `None`.
+///
+/// `P` must be a prime. Whether it is at least two is checked at compile-time, but its primality
+/// is not checked: for a composite `P`, some elements with a nonzero first digit have no inverse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Padic<const P: u64>(u64);
+
+impl<const P: u64> Padic<P> {
+    /// The number of digits, i.e., the largest `k` such that `Pᵏ` fits in a `u64`.
+    const PRECISION: u32 = {
+        assert!(
+            P >= 2,
+            "The prime of a p-adic integer must be at least two."
+        );
+        let mut k = 0;
+        let mut m = 1;
+
+        while m <= u64::MAX / P {
+            m *= P;
+            k += 1;
+        }
+
+        k
+    };
+    /// `P^PRECISION`.
+    const MODULUS: u64 = P.pow(Self::PRECISION);
+
+    /// The `P`-adic integer `value`, truncated to the precision.
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        Padic(value % Self::MODULUS)
+    }
+
+    /// The number of `P`-adic digits kept by this type.
+    #[inline]
+    pub fn precision() -> u32 {
+        Self::PRECISION
+    }
+
+    /// The modulus `P^precision()` of the truncation.
+    #[inline]
+    pub fn modulus() -> u64 {
+        Self::MODULUS
+    }
+
+    /// The integer `a₀ + a₁P + …` in `0..modulus()` with the same digits as this element.
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// The `P`-adic digits of this element, starting with `a₀`.
+    #[inline]
+    pub fn digits(self) -> impl Iterator<Item = u64> {
+        let mut rest = self.0;
+
+        (0..Self::PRECISION).map(move |_| {
+            let digit = rest % P;
+            rest /= P;
+            digit
+        })
+    }
+
+    /// The `P`-adic valuation of this element, i.e., the number of its trailing zero digits, or
+    /// `None` if it is zero to the precision.
+    #[inline]
+    pub fn order(self) -> Option<u32> {
+        PAdicValuation::<P>::order(self.0 as u128)
+    }
+
+    /// Returns `true` if this element is invertible, i.e., if its first digit is nonzero.
+    #[inline]
+    pub fn is_unit(self) -> bool {
+        !self.0.is_multiple_of(P)
+    }
+}
+
+impl<const P: u64> fmt::Display for Padic<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + O({}^{})", self.0, P, Self::PRECISION)
+    }
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+impl<const P: u64> Add for Padic<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Padic(((self.0 as u128 + rhs.0 as u128) % Self::MODULUS as u128) as u64)
+    }
+}
+
+impl<const P: u64> AddAssign for Padic<P> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const P: u64> Neg for Padic<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Padic(Self::MODULUS - self.0)
+        }
+    }
+}
+
+impl<const P: u64> Sub for Padic<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> SubAssign for Padic<P> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const P: u64> Mul for Padic<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Padic(((self.0 as u128 * rhs.0 as u128) % Self::MODULUS as u128) as u64)
+    }
+}
+
+impl<const P: u64> MulAssign for Padic<P> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+/*
+ *
+ * Algebraic structures.
+ *
+ */
+impl<const P: u64> AbstractMagma<Additive> for Padic<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Additive> for Padic<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<const P: u64> Identity<Additive> for Padic<P> {
+    #[inline]
+    fn identity() -> Self {
+        Padic(0)
+    }
+}
+
+impl<const P: u64> ConstIdentity<Additive> for Padic<P> {
+    const IDENTITY: Self = Padic(0);
+}
+
+impl<const P: u64> AbstractMagma<Multiplicative> for Padic<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Multiplicative> for Padic<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(self)
+            .expect("Only the p-adic units are invertible.")
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        inverse_mod(self.0, Self::MODULUS).map(Padic)
+    }
+}
+
+impl<const P: u64> Identity<Multiplicative> for Padic<P> {
+    #[inline]
+    fn identity() -> Self {
+        Padic(1)
+    }
+}
+
+impl<const P: u64> ConstIdentity<Multiplicative> for Padic<P> {
+    const IDENTITY: Self = Padic(1);
+}
+
+impl<const P: u64> AbstractSemigroup<Additive> for Padic<P> {}
+impl<const P: u64> AbstractQuasigroup<Additive> for Padic<P> {}
+impl<const P: u64> AbstractMonoid<Additive> for Padic<P> {}
+impl<const P: u64> AbstractLoop<Additive> for Padic<P> {}
+impl<const P: u64> AbstractGroup<Additive> for Padic<P> {}
+impl<const P: u64> AbstractGroupAbelian<Additive> for Padic<P> {}
+
+impl<const P: u64> AbstractSemigroup<Multiplicative> for Padic<P> {}
+impl<const P: u64> AbstractMonoid<Multiplicative> for Padic<P> {}
+
+// NOTE: this is the characteristic of the truncation `ℤ/Pᵏℤ`: the ring `ℤₚ` has characteristic
+// zero.
+impl<const P: u64> AbstractRing for Padic<P> {
+    #[inline]
+    fn characteristic() -> u64 {
+        Self::MODULUS
+    }
+}
+impl<const P: u64> AbstractRingCommutative for Padic<P> {}
+
+// NOTE: the valuation of a product is truncated to zero, and thus not multiplicative, as soon as
+// the valuations of its factors add up to the precision.
+impl<const P: u64> Valuation<Padic<P>> for PAdicValuation<P> {
+    type Operator = Additive;
+    type Value = i32;
+
+    #[inline]
+    fn value(element: &Padic<P>) -> Option<i32> {
+        element.order().map(|order| -(order as i32))
+    }
+}
+
+impl<const P: u64> UltrametricValuation<Padic<P>> for PAdicValuation<P> {}
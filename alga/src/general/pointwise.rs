@@ -0,0 +1,257 @@
+//! Spaces of functions, with the operations defined pointwise.
+
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "std")]
+use std::rc::Rc;
+
+use num::Zero;
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, AdditiveGroupAbelian,
+    Identity, Module, TwoSidedInverse,
+};
+use crate::linear::VectorSpace;
+
+/// A function from `A` to `V`, with the algebraic operations of `V` lifted pointwise:
+///
+/// ~~~notrust
+/// (f + g)(a) = f(a) + g(a)
+/// (-f)(a)    = -f(a)
+/// (f r)(a)   = f(a) r
+/// ~~~
+///
+/// The functions from any set to an abelian group, a module or a vector space, form an abelian
+/// group, a module or a vector space. This is the canonical example of an infinite-dimensional
+/// vector space.
+///
+/// Functions cannot be compared in general, so two `Pointwise` are equal only if they are the zero
+/// function, or share the same underlying closure, e.g., one is a clone of the other. Thus,
+/// equality is sound but incomplete: algebraic laws must be checked by evaluating both sides at
+/// some points, with `eval`.
+pub struct Pointwise<A, V> {
+    // NOTE: `None` stands for the zero function, so that it can be recognized.
+    f: Option<Rc<dyn Fn(A) -> V>>,
+}
+
+impl<A, V> Pointwise<A, V> {
+    /// Wraps the function `f`.
+    #[inline]
+    pub fn new<F: Fn(A) -> V + 'static>(f: F) -> Self {
+        Pointwise {
+            f: Some(Rc::new(f)),
+        }
+    }
+
+    /// The function that maps everything to `v`.
+    #[inline]
+    pub fn constant(v: V) -> Self
+    where
+        V: Clone + 'static,
+    {
+        Pointwise::new(move |_| v.clone())
+    }
+}
+
+impl<A, V: Zero> Pointwise<A, V> {
+    /// The value of this function at `a`.
+    #[inline]
+    pub fn eval(&self, a: A) -> V {
+        match &self.f {
+            Some(f) => f(a),
+            None => V::zero(),
+        }
+    }
+}
+
+impl<A, V> Clone for Pointwise<A, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Pointwise { f: self.f.clone() }
+    }
+}
+
+impl<A, V> fmt::Debug for Pointwise<A, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.f {
+            Some(_) => f.write_str("Pointwise(<function>)"),
+            None => f.write_str("Pointwise(0)"),
+        }
+    }
+}
+
+impl<A, V> PartialEq for Pointwise<A, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.f, &other.f) {
+            (Some(f), Some(g)) => Rc::ptr_eq(f, g),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> Add for Pointwise<A, V> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match (self.f, rhs.f) {
+            (Some(f), Some(g)) => Pointwise::new(move |a: A| f(a.clone()) + g(a)),
+            (f, None) | (None, f) => Pointwise { f },
+        }
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> Sub for Pointwise<A, V> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        match (self.f, rhs.f) {
+            (Some(f), Some(g)) => Pointwise::new(move |a: A| f(a.clone()) - g(a)),
+            (f, None) => Pointwise { f },
+            (None, g) => -Pointwise { f: g },
+        }
+    }
+}
+
+impl<A: 'static, V: AdditiveGroupAbelian + 'static> Neg for Pointwise<A, V> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        match self.f {
+            Some(f) => Pointwise::new(move |a| -f(a)),
+            None => self,
+        }
+    }
+}
+
+impl<A: 'static, V: Module + 'static> Mul<V::Ring> for Pointwise<A, V>
+where
+    V::Ring: 'static,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: V::Ring) -> Self {
+        match self.f {
+            Some(f) => Pointwise::new(move |a| f(a) * rhs.clone()),
+            None => self,
+        }
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> AddAssign for Pointwise<A, V> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> SubAssign for Pointwise<A, V> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs
+    }
+}
+
+impl<A: 'static, V: Module + 'static> MulAssign<V::Ring> for Pointwise<A, V>
+where
+    V::Ring: 'static,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: V::Ring) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> Zero for Pointwise<A, V> {
+    #[inline]
+    fn zero() -> Self {
+        Pointwise { f: None }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.f.is_none()
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> AbstractMagma<Additive>
+    for Pointwise<A, V>
+{
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        self + right
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> TwoSidedInverse<Additive>
+    for Pointwise<A, V>
+{
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> Identity<Additive> for Pointwise<A, V> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+macro_rules! impl_pointwise_additive(
+    ($($marker: ident),*) => {$(
+        impl<A: Clone + 'static, V: AdditiveGroupAbelian + 'static> $marker<Additive>
+            for Pointwise<A, V>
+        {
+        }
+    )*}
+);
+
+impl_pointwise_additive!(
+    AbstractSemigroup,
+    AbstractQuasigroup,
+    AbstractMonoid,
+    AbstractLoop,
+    AbstractGroup,
+    AbstractGroupAbelian
+);
+
+impl<A: Clone + 'static, V: Module + 'static> AbstractModule for Pointwise<A, V>
+where
+    V::Ring: 'static,
+{
+    type AbstractRing = V::Ring;
+
+    #[inline]
+    fn multiply_by(&self, r: V::Ring) -> Self {
+        self.clone() * r
+    }
+}
+
+impl<A: Clone + 'static, V: Module + 'static> Module for Pointwise<A, V>
+where
+    V::Ring: 'static,
+{
+    type Ring = V::Ring;
+}
+
+impl<A: Clone + 'static, V: VectorSpace + 'static> VectorSpace for Pointwise<A, V>
+where
+    V::Field: 'static,
+{
+    type Field = V::Field;
+}
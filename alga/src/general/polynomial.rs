@@ -0,0 +1,496 @@
+//! Sparse multivariate polynomials.
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::{btree_map, BTreeMap};
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::{btree_map, BTreeMap};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Identity, Module, Multiplicative, RingCommutative, TwoSidedInverse,
+};
+
+/// A monomial `x₀^e₀ x₁^e₁ … xₙ^eₙ`, i.e., a product of powers of variables.
+///
+/// The exponents are indexed by variable, without trailing zeros, so that equal monomials have
+/// equal representations.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Monomial {
+    exponents: Vec<u32>,
+}
+
+impl Monomial {
+    /// The monomial with the given exponents, indexed by variable.
+    #[inline]
+    pub fn new(mut exponents: Vec<u32>) -> Self {
+        while exponents.last() == Some(&0) {
+            let _ = exponents.pop();
+        }
+
+        Monomial { exponents }
+    }
+
+    /// The monomial `1`, with all exponents equal to zero.
+    #[inline]
+    pub fn one() -> Self {
+        Monomial {
+            exponents: Vec::new(),
+        }
+    }
+
+    /// The monomial made of the `i`-th variable alone.
+    #[inline]
+    pub fn variable(i: usize) -> Self {
+        let mut exponents = vec![0; i + 1];
+        exponents[i] = 1;
+        Monomial { exponents }
+    }
+
+    /// The exponents of this monomial, indexed by variable, without trailing zeros.
+    #[inline]
+    pub fn exponents(&self) -> &[u32] {
+        &self.exponents
+    }
+
+    /// The exponent of the `i`-th variable.
+    #[inline]
+    pub fn exponent(&self, i: usize) -> u32 {
+        self.exponents.get(i).cloned().unwrap_or(0)
+    }
+
+    /// The total degree of this monomial, i.e., the sum of its exponents.
+    #[inline]
+    pub fn degree(&self) -> u32 {
+        self.exponents.iter().sum()
+    }
+
+    /// The product of two monomials, i.e., the sum of their exponents.
+    #[inline]
+    pub fn mul(&self, other: &Self) -> Self {
+        let (long, short) = if self.exponents.len() >= other.exponents.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut exponents = long.exponents.clone();
+
+        for (e, f) in exponents.iter_mut().zip(short.exponents.iter()) {
+            *e += *f;
+        }
+
+        Monomial { exponents }
+    }
+}
+
+impl fmt::Display for Monomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+
+        for (i, e) in self.exponents.iter().enumerate() {
+            if *e == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, "*")?;
+            }
+
+            first = false;
+
+            match e {
+                1 => write!(f, "x{}", i)?,
+                _ => write!(f, "x{}^{}", i, e)?,
+            }
+        }
+
+        if first {
+            write!(f, "1")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A sparse polynomial in the variables `x₀, x₁, …` with coefficients in the commutative ring `R`.
+///
+/// The polynomials form a commutative ring, and an algebra over `R`. They are graded by the total
+/// degree: every polynomial is the sum of its homogeneous components, and the total degree of a
+/// product is the sum of the total degrees of the factors whenever `R` has no zero divisors.
+///
+/// Only the terms with nonzero coefficients are stored, so that equal polynomials have equal
+/// representations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultivariatePolynomial<R> {
+    terms: BTreeMap<Monomial, R>,
+}
+
+impl<R: RingCommutative> MultivariatePolynomial<R> {
+    /// The constant polynomial `c`.
+    #[inline]
+    pub fn constant(c: R) -> Self {
+        Self::monomial(Monomial::one(), c)
+    }
+
+    /// The polynomial made of the `i`-th variable alone.
+    #[inline]
+    pub fn variable(i: usize) -> Self {
+        Self::monomial(Monomial::variable(i), R::one())
+    }
+
+    /// The polynomial `c m`.
+    #[inline]
+    pub fn monomial(m: Monomial, c: R) -> Self {
+        Self::from_terms(Some((m, c)))
+    }
+
+    /// The sum of the given terms.
+    pub fn from_terms<I: IntoIterator<Item = (Monomial, R)>>(terms: I) -> Self {
+        let mut res = Self::zero();
+
+        for (m, c) in terms {
+            res.add_term(m, c);
+        }
+
+        res
+    }
+
+    /// The terms of this polynomial, i.e., its monomials with their nonzero coefficients.
+    #[inline]
+    pub fn terms(&self) -> btree_map::Iter<'_, Monomial, R> {
+        self.terms.iter()
+    }
+
+    /// The coefficient of the monomial `m`.
+    #[inline]
+    pub fn coefficient(&self, m: &Monomial) -> R {
+        self.terms.get(m).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// The total degree of this polynomial, i.e., the largest total degree of its monomials, or
+    /// `None` for the zero polynomial.
+    #[inline]
+    pub fn total_degree(&self) -> Option<u32> {
+        self.terms.keys().map(Monomial::degree).max()
+    }
+
+    /// The homogeneous component of total degree `d` of this polynomial.
+    #[inline]
+    pub fn homogeneous_component(&self, d: u32) -> Self {
+        MultivariatePolynomial {
+            terms: self
+                .terms
+                .iter()
+                .filter(|(m, _)| m.degree() == d)
+                .map(|(m, c)| (m.clone(), c.clone()))
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if all the monomials of this polynomial have the same total degree.
+    #[inline]
+    pub fn is_homogeneous(&self) -> bool {
+        let mut degrees = self.terms.keys().map(Monomial::degree);
+
+        match degrees.next() {
+            Some(d) => degrees.all(|e| e == d),
+            None => true,
+        }
+    }
+
+    /// Evaluates this polynomial at the point `values`, in any commutative algebra over `R`.
+    ///
+    /// The `i`-th variable is replaced by `values[i]`. Substituting polynomials for the variables
+    /// is the special case where the algebra is `MultivariatePolynomial<R>` itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable of this polynomial has no value.
+    pub fn evaluate<A>(&self, values: &[A]) -> A
+    where
+        A: AbstractRingCommutative + AbstractModule<AbstractRing = R>,
+    {
+        let mut res: A = Identity::<Additive>::identity();
+
+        for (m, c) in &self.terms {
+            let mut term: A = Identity::<Multiplicative>::identity();
+
+            for (i, e) in m.exponents.iter().enumerate() {
+                if *e != 0 {
+                    let value = values.get(i).unwrap_or_else(|| {
+                        panic!("Polynomial evaluation: no value for the variable x{}.", i)
+                    });
+                    term = AbstractMagma::<Multiplicative>::operate(&term, &pow(value, *e));
+                }
+            }
+
+            res = AbstractMagma::<Additive>::operate(&res, &term.multiply_by(c.clone()));
+        }
+
+        res
+    }
+
+    fn add_term(&mut self, m: Monomial, c: R) {
+        match self.terms.entry(m) {
+            btree_map::Entry::Vacant(entry) => {
+                if !c.is_zero() {
+                    let _ = entry.insert(c);
+                }
+            }
+            btree_map::Entry::Occupied(mut entry) => {
+                *entry.get_mut() += c;
+
+                if entry.get().is_zero() {
+                    let _ = entry.remove();
+                }
+            }
+        }
+    }
+}
+
+// Exponentiation by squaring, for `e > 0`.
+fn pow<A: AbstractMagma<Multiplicative>>(a: &A, mut e: u32) -> A {
+    let mut base = a.clone();
+    let mut res: Option<A> = None;
+
+    loop {
+        if e & 1 == 1 {
+            res = Some(match res {
+                Some(r) => AbstractMagma::<Multiplicative>::operate(&r, &base),
+                None => base.clone(),
+            });
+        }
+
+        e >>= 1;
+
+        if e == 0 {
+            return res.expect("Polynomial evaluation: the exponent must not be zero.");
+        }
+
+        base = AbstractMagma::<Multiplicative>::operate(&base, &base);
+    }
+}
+
+impl<R: fmt::Display> fmt::Display for MultivariatePolynomial<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (k, (m, c)) in self.terms.iter().enumerate() {
+            if k != 0 {
+                write!(f, " + ")?;
+            }
+
+            if m.exponents.is_empty() {
+                write!(f, "{}", c)?;
+            } else {
+                write!(f, "{}*{}", c, m)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: RingCommutative> Add for MultivariatePolynomial<R> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative> Sub for MultivariatePolynomial<R> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative> Neg for MultivariatePolynomial<R> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        for c in self.terms.values_mut() {
+            *c = -c.clone();
+        }
+
+        self
+    }
+}
+
+impl<R: RingCommutative> Mul for MultivariatePolynomial<R> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let mut res = Self::zero();
+
+        for (m1, c1) in &self.terms {
+            for (m2, c2) in &rhs.terms {
+                res.add_term(m1.mul(m2), c1.clone() * c2.clone());
+            }
+        }
+
+        res
+    }
+}
+
+impl<R: RingCommutative> Mul<R> for MultivariatePolynomial<R> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: R) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative> AddAssign for MultivariatePolynomial<R> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (m, c) in rhs.terms {
+            self.add_term(m, c);
+        }
+    }
+}
+
+impl<R: RingCommutative> SubAssign for MultivariatePolynomial<R> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (m, c) in rhs.terms {
+            self.add_term(m, -c);
+        }
+    }
+}
+
+impl<R: RingCommutative> MulAssign for MultivariatePolynomial<R> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<R: RingCommutative> MulAssign<R> for MultivariatePolynomial<R> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: R) {
+        for c in self.terms.values_mut() {
+            *c *= rhs.clone();
+        }
+
+        // The product of nonzero coefficients may vanish if `R` has zero divisors.
+        self.terms.retain(|_, c| !c.is_zero());
+    }
+}
+
+impl<R: RingCommutative> Zero for MultivariatePolynomial<R> {
+    #[inline]
+    fn zero() -> Self {
+        MultivariatePolynomial {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<R: RingCommutative> One for MultivariatePolynomial<R> {
+    #[inline]
+    fn one() -> Self {
+        Self::constant(R::one())
+    }
+}
+
+impl<R: RingCommutative> AbstractMagma<Additive> for MultivariatePolynomial<R> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        *self += right.clone()
+    }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        self + right
+    }
+}
+
+impl<R: RingCommutative> AbstractMagma<Multiplicative> for MultivariatePolynomial<R> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() * right.clone()
+    }
+}
+
+impl<R: RingCommutative> TwoSidedInverse<Additive> for MultivariatePolynomial<R> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<R: RingCommutative> Identity<Additive> for MultivariatePolynomial<R> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<R: RingCommutative> Identity<Multiplicative> for MultivariatePolynomial<R> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<R: RingCommutative> AbstractSemigroup<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractQuasigroup<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractMonoid<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractLoop<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractGroup<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractGroupAbelian<Additive> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractSemigroup<Multiplicative> for MultivariatePolynomial<R> {}
+impl<R: RingCommutative> AbstractMonoid<Multiplicative> for MultivariatePolynomial<R> {}
+
+impl<R: RingCommutative> AbstractRing for MultivariatePolynomial<R> {
+    #[inline]
+    fn characteristic() -> u64 {
+        R::characteristic()
+    }
+}
+
+impl<R: RingCommutative> AbstractRingCommutative for MultivariatePolynomial<R> {}
+
+impl<R: RingCommutative> AbstractModule for MultivariatePolynomial<R> {
+    type AbstractRing = R;
+
+    #[inline]
+    fn multiply_by(&self, r: R) -> Self {
+        self.clone() * r
+    }
+}
+
+impl<R: RingCommutative> Module for MultivariatePolynomial<R> {
+    type Ring = R;
+}
@@ -0,0 +1,124 @@
+use crate::general::{
+    AbstractMagma, Additive, FiniteField, Identity, Multiplicative, TwoSidedInverse, Zn,
+};
+
+/// A finite field of prime cardinality `p`, i.e., the integers modulo `p`.
+///
+/// Every element has a canonical representative in `0..p`, and the map from the integers to the
+/// field that reduces them modulo `p` is a ring homomorphism:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, from_canonical(to_canonical(a)) = a and to_canonical(a) < p
+/// ∀ m, n ∈ ℕ, from_canonical(m + n) = from_canonical(m) + from_canonical(n)
+/// ∀ m, n ∈ ℕ, from_canonical(m × n) = from_canonical(m) × from_canonical(n)
+/// ~~~
+///
+/// The implementations may represent the elements differently, e.g., in Montgomery form, but are
+/// all isomorphic to `Zn<p>`.
+pub trait PrimeField: FiniteField {
+    /// The prime modulus `p`, which is both the characteristic and the cardinality of the field.
+    #[inline]
+    fn modulus() -> u64 {
+        Self::characteristic()
+    }
+
+    /// The element whose canonical representative is `value` reduced modulo `p`.
+    fn from_canonical(value: u64) -> Self;
+
+    /// The canonical representative of `self`, in `0..p`.
+    fn to_canonical(&self) -> u64;
+
+    /// The multiplicative inverse of `self`, or `None` if `self` is zero.
+    ///
+    /// The default implementation uses Fermat's little theorem, `a⁻¹ = aᵖ⁻²`. It checks the result
+    /// and returns `None` if it is not an inverse, which can only happen if the modulus is not
+    /// actually prime.
+    fn inverse(&self) -> Option<Self> {
+        let zero: Self = Identity::<Additive>::identity();
+        let one: Self = Identity::<Multiplicative>::identity();
+
+        if *self == zero {
+            return None;
+        }
+
+        let mut base = self.clone();
+        let mut result = one.clone();
+        let mut k = Self::modulus() - 2;
+
+        while k > 0 {
+            if k & 1 == 1 {
+                AbstractMagma::<Multiplicative>::operate_assign(&mut result, &base);
+            }
+
+            base = AbstractMagma::<Multiplicative>::operate(&base, &base);
+            k >>= 1;
+        }
+
+        if AbstractMagma::<Multiplicative>::operate(self, &result) == one {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// The element of `Zn<N>` with the same canonical representative as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the modulus of this field.
+    #[inline]
+    fn to_zn<const N: u64>(&self) -> Zn<N> {
+        assert_eq!(N, Self::modulus(), "The moduli of the two fields differ.");
+        Zn::new(self.to_canonical())
+    }
+
+    /// Returns `true` if the reduction modulo `p` of the canonical representatives of the given
+    /// arguments is a ring isomorphism.
+    fn prop_canonical_is_isomorphism(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        let p = Self::modulus() as u128;
+        let (m, n) = (a.to_canonical(), b.to_canonical());
+        let sum = ((m as u128 + n as u128) % p) as u64;
+        let product = ((m as u128 * n as u128) % p) as u64;
+
+        m < Self::modulus()
+            && Self::from_canonical(m) == a
+            && Self::from_canonical(sum) == AbstractMagma::<Additive>::operate(&a, &b)
+            && Self::from_canonical(product) == AbstractMagma::<Multiplicative>::operate(&a, &b)
+    }
+
+    /// Returns `true` if `inverse` returns the multiplicative inverse of the given argument
+    /// exactly when it is nonzero.
+    fn prop_inverse_is_inverse(args: (Self,)) -> bool {
+        let (a,) = args;
+        let one: Self = Identity::<Multiplicative>::identity();
+
+        match a.inverse() {
+            Some(inv) => AbstractMagma::<Multiplicative>::operate(&a, &inv) == one,
+            None => a == Identity::<Additive>::identity(),
+        }
+    }
+}
+
+impl<const N: u64> PrimeField for Zn<N> {
+    #[inline]
+    fn modulus() -> u64 {
+        Self::assert_field();
+        N
+    }
+
+    #[inline]
+    fn from_canonical(value: u64) -> Self {
+        Zn::new(value)
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> u64 {
+        self.value()
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(self)
+    }
+}
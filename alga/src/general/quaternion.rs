@@ -0,0 +1,453 @@
+//! Quaternions.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+use num_complex::Complex;
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractSemigroup, Additive,
+    AlternativeAlgebra, Identity, Module, Multiplicative, MultiplicativeComposition,
+    NormedDivisionAlgebra, RealField, SubsetOf, SupersetOf, TwoSidedInverse,
+};
+use crate::linear::{FreeModule, Point, Transformation};
+
+/// A quaternion `w + i 𝐢 + j 𝐣 + k 𝐤`, where `𝐢² = 𝐣² = 𝐤² = 𝐢𝐣𝐤 = -1`.
+///
+/// Quaternions form a normed division algebra whose multiplication is not commutative. The unit
+/// quaternions represent the rotations of the 3-dimensional space: the quaternion `q` acts on a
+/// vector `v`, seen as a pure imaginary quaternion, as `q v q̄`. For other quaternions, this
+/// action is a rotation followed by a uniform scaling by the squared norm of `q`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Quaternion<N> {
+    /// The real part.
+    pub w: N,
+    /// The coefficient of `𝐢`.
+    pub i: N,
+    /// The coefficient of `𝐣`.
+    pub j: N,
+    /// The coefficient of `𝐤`.
+    pub k: N,
+}
+
+impl<N> Quaternion<N> {
+    /// Creates a quaternion from its components.
+    #[inline]
+    pub const fn new(w: N, i: N, j: N, k: N) -> Self {
+        Quaternion { w, i, j, k }
+    }
+}
+
+impl<N: RealField> Quaternion<N> {
+    /// The quaternion with real part `w` and zero imaginary part.
+    #[inline]
+    pub fn from_real(w: N) -> Self {
+        Quaternion::new(w, N::zero(), N::zero(), N::zero())
+    }
+
+    /// The pure imaginary quaternion `v[0] 𝐢 + v[1] 𝐣 + v[2] 𝐤`.
+    #[inline]
+    pub fn from_imag(v: [N; 3]) -> Self {
+        Quaternion::new(N::zero(), v[0], v[1], v[2])
+    }
+
+    /// The unit quaternion representing the rotation of angle `angle` around the unit vector
+    /// `axis`.
+    #[inline]
+    pub fn from_axis_angle(axis: [N; 3], angle: N) -> Self {
+        let two = N::one() + N::one();
+        let (sin, cos) = (angle / two).sin_cos();
+        Quaternion::new(cos, axis[0] * sin, axis[1] * sin, axis[2] * sin)
+    }
+
+    /// The imaginary part of this quaternion, as a vector.
+    #[inline]
+    pub fn imag(&self) -> [N; 3] {
+        [self.i, self.j, self.k]
+    }
+
+    /// The action `self v self̄` of this quaternion on the vector `v`.
+    ///
+    /// This is a rotation of `v` if `self` is a unit quaternion.
+    #[inline]
+    pub fn rotate_vector(&self, v: [N; 3]) -> [N; 3] {
+        (*self * Quaternion::from_imag(v) * NormedDivisionAlgebra::conjugate(self)).imag()
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for Quaternion<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}i + {}j + {}k", self.w, self.i, self.j, self.k)
+    }
+}
+
+impl<N: RealField> Add for Quaternion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Quaternion::new(
+            self.w + rhs.w,
+            self.i + rhs.i,
+            self.j + rhs.j,
+            self.k + rhs.k,
+        )
+    }
+}
+
+impl<N: RealField> Sub for Quaternion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Quaternion::new(
+            self.w - rhs.w,
+            self.i - rhs.i,
+            self.j - rhs.j,
+            self.k - rhs.k,
+        )
+    }
+}
+
+impl<N: RealField> Mul for Quaternion<N> {
+    type Output = Self;
+
+    /// The Hamilton product.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+
+        Quaternion::new(
+            a.w * b.w - a.i * b.i - a.j * b.j - a.k * b.k,
+            a.w * b.i + a.i * b.w + a.j * b.k - a.k * b.j,
+            a.w * b.j - a.i * b.k + a.j * b.w + a.k * b.i,
+            a.w * b.k + a.i * b.j - a.j * b.i + a.k * b.w,
+        )
+    }
+}
+
+impl<N: RealField> Mul<N> for Quaternion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Self {
+        Quaternion::new(self.w * rhs, self.i * rhs, self.j * rhs, self.k * rhs)
+    }
+}
+
+impl<N: RealField> Div for Quaternion<N> {
+    type Output = Self;
+
+    /// The right division `self × rhs⁻¹`.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&rhs);
+        AbstractMagma::<Multiplicative>::operate(&self, &inv)
+    }
+}
+
+impl<N: RealField> Neg for Quaternion<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Quaternion::new(-self.w, -self.i, -self.j, -self.k)
+    }
+}
+
+impl<N: RealField> AddAssign for Quaternion<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<N: RealField> SubAssign for Quaternion<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<N: RealField> MulAssign for Quaternion<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> MulAssign<N> for Quaternion<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> DivAssign for Quaternion<N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<N: RealField> Zero for Quaternion<N> {
+    #[inline]
+    fn zero() -> Self {
+        Quaternion::from_real(N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.w.is_zero() && self.i.is_zero() && self.j.is_zero() && self.k.is_zero()
+    }
+}
+
+impl<N: RealField> One for Quaternion<N> {
+    #[inline]
+    fn one() -> Self {
+        Quaternion::from_real(N::one())
+    }
+}
+
+impl<N: RealField> AbstractMagma<Additive> for Quaternion<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<N: RealField> AbstractMagma<Multiplicative> for Quaternion<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Additive> for Quaternion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Multiplicative> for Quaternion<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        NormedDivisionAlgebra::conjugate(self) * (N::one() / self.norm_squared())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(TwoSidedInverse::<Multiplicative>::two_sided_inverse(self))
+        }
+    }
+}
+
+impl<N: RealField> Identity<Additive> for Quaternion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<N: RealField> Identity<Multiplicative> for Quaternion<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<N: RealField> AbstractSemigroup<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractQuasigroup<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractMonoid<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractLoop<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractGroup<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractGroupAbelian<Additive> for Quaternion<N> {}
+impl<N: RealField> AbstractSemigroup<Multiplicative> for Quaternion<N> {}
+impl<N: RealField> AbstractQuasigroup<Multiplicative> for Quaternion<N> {}
+impl<N: RealField> AbstractMonoid<Multiplicative> for Quaternion<N> {}
+impl<N: RealField> AbstractLoop<Multiplicative> for Quaternion<N> {}
+impl<N: RealField> AbstractGroup<Multiplicative> for Quaternion<N> {}
+impl<N: RealField> AbstractRing for Quaternion<N> {}
+
+impl<N: RealField> AbstractModule for Quaternion<N> {
+    type AbstractRing = N;
+
+    #[inline]
+    fn multiply_by(&self, r: N) -> Self {
+        *self * r
+    }
+}
+
+impl<N: RealField> Module for Quaternion<N> {
+    type Ring = N;
+}
+
+impl<N: RealField> AlternativeAlgebra for Quaternion<N> {}
+
+impl<N: RealField> NormedDivisionAlgebra<N> for Quaternion<N> {
+    #[inline]
+    fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.i, -self.j, -self.k)
+    }
+
+    #[inline]
+    fn norm_squared(&self) -> N {
+        self.w * self.w + self.i * self.i + self.j * self.j + self.k * self.k
+    }
+}
+
+impl<N: RealField> MultiplicativeComposition for Quaternion<N> {}
+
+impl<N: RealField> Transformation<Point<[N; 3]>> for Quaternion<N> {
+    #[inline]
+    fn transform_point(&self, pt: &Point<[N; 3]>) -> Point<[N; 3]> {
+        Point(self.rotate_vector(pt.0))
+    }
+
+    #[inline]
+    fn transform_vector(&self, v: &FreeModule<N, 3>) -> FreeModule<N, 3> {
+        FreeModule(self.rotate_vector(v.0))
+    }
+}
+
+impl<N: AbsDiffEq> AbsDiffEq for Quaternion<N>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.w.abs_diff_eq(&other.w, epsilon.clone())
+            && self.i.abs_diff_eq(&other.i, epsilon.clone())
+            && self.j.abs_diff_eq(&other.j, epsilon.clone())
+            && self.k.abs_diff_eq(&other.k, epsilon)
+    }
+}
+
+impl<N: RelativeEq> RelativeEq for Quaternion<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.w
+            .relative_eq(&other.w, epsilon.clone(), max_relative.clone())
+            && self
+                .i
+                .relative_eq(&other.i, epsilon.clone(), max_relative.clone())
+            && self
+                .j
+                .relative_eq(&other.j, epsilon.clone(), max_relative.clone())
+            && self.k.relative_eq(&other.k, epsilon, max_relative)
+    }
+}
+
+impl<N: UlpsEq> UlpsEq for Quaternion<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.w.ulps_eq(&other.w, epsilon.clone(), max_ulps)
+            && self.i.ulps_eq(&other.i, epsilon.clone(), max_ulps)
+            && self.j.ulps_eq(&other.j, epsilon.clone(), max_ulps)
+            && self.k.ulps_eq(&other.k, epsilon, max_ulps)
+    }
+}
+
+impl<N1, N2: SupersetOf<N1>> SubsetOf<Quaternion<N2>> for Quaternion<N1> {
+    #[inline]
+    fn to_superset(&self) -> Quaternion<N2> {
+        Quaternion::new(
+            N2::from_subset(&self.w),
+            N2::from_subset(&self.i),
+            N2::from_subset(&self.j),
+            N2::from_subset(&self.k),
+        )
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Quaternion<N2>) -> Quaternion<N1> {
+        Quaternion::new(
+            element.w.to_subset_unchecked(),
+            element.i.to_subset_unchecked(),
+            element.j.to_subset_unchecked(),
+            element.k.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(q: &Quaternion<N2>) -> bool {
+        q.w.is_in_subset() && q.i.is_in_subset() && q.j.is_in_subset() && q.k.is_in_subset()
+    }
+}
+
+impl<N: RealField, N2: Zero + SupersetOf<N>> SubsetOf<Quaternion<N2>> for N {
+    #[inline]
+    fn to_superset(&self) -> Quaternion<N2> {
+        Quaternion::new(N2::from_subset(self), N2::zero(), N2::zero(), N2::zero())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Quaternion<N2>) -> N {
+        element.w.to_subset_unchecked()
+    }
+
+    #[inline]
+    fn is_in_subset(q: &Quaternion<N2>) -> bool {
+        q.w.is_in_subset() && q.i.is_zero() && q.j.is_zero() && q.k.is_zero()
+    }
+}
+
+impl<N1, N2: Zero + SupersetOf<N1>> SubsetOf<Quaternion<N2>> for Complex<N1> {
+    #[inline]
+    fn to_superset(&self) -> Quaternion<N2> {
+        Quaternion::new(
+            N2::from_subset(&self.re),
+            N2::from_subset(&self.im),
+            N2::zero(),
+            N2::zero(),
+        )
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Quaternion<N2>) -> Complex<N1> {
+        Complex::new(
+            element.w.to_subset_unchecked(),
+            element.i.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(q: &Quaternion<N2>) -> bool {
+        q.w.is_in_subset() && q.i.is_in_subset() && q.j.is_zero() && q.k.is_zero()
+    }
+}
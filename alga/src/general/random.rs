@@ -0,0 +1,113 @@
+//! Sampling of elements of algebraic structures.
+
+use num_complex::Complex;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::general::{Bits, Id, MontgomeryFp, Operator, Padic, RealField, Zn, ZnUnit};
+
+/// Structures whose elements can be sampled at random.
+///
+/// The distribution must be uniform for finite structures, and should be the (normalized) Haar
+/// measure for compact groups, i.e., the only distribution invariant under the group operation.
+/// This is what property tests and Monte-Carlo methods usually expect from "random elements".
+pub trait RandomElement: Sized {
+    /// Samples a random element of this structure.
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+/// The distribution of the random elements of a structure implementing `RandomElement`.
+///
+/// This allows generic code to sample structure elements with `rng.sample(RandomElements)`.
+#[derive(Clone, Copy, Debug)]
+pub struct RandomElements;
+
+impl<T: RandomElement> Distribution<T> for RandomElements {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        T::random_element(rng)
+    }
+}
+
+/// The uniform distribution on the complex numbers of modulus one, i.e., the Haar measure of the
+/// circle group.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitModulus;
+
+impl<N: RealField> Distribution<Complex<N>> for UnitModulus {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<N> {
+        let angle = N::two_pi() * N::from_subset(&rng.gen::<f64>());
+        let (sin, cos) = angle.sin_cos();
+        Complex::new(cos, sin)
+    }
+}
+
+impl<O: Operator> RandomElement for Id<O> {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(_: &mut R) -> Self {
+        Id::new()
+    }
+}
+
+impl RandomElement for bool {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+macro_rules! impl_random_bits(
+    ($($T:ident),*) => {$(
+        // NOTE: every bit is set independently with probability 1/2, which is the uniform
+        // distribution on the bounded lattice.
+        impl RandomElement for Bits<$T> {
+            #[inline]
+            fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Bits(rng.gen())
+            }
+        }
+
+        impl Distribution<Bits<$T>> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Bits<$T> {
+                Bits::random_element(rng)
+            }
+        }
+    )*}
+);
+
+impl_random_bits!(u8, u16, u32, u64, u128, usize);
+
+impl<const N: u64> RandomElement for Zn<N> {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Zn::new(rng.gen_range(0, N))
+    }
+}
+
+impl<const P: u64> RandomElement for MontgomeryFp<P> {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        MontgomeryFp::new(rng.gen_range(0, P))
+    }
+}
+
+impl<const P: u64> RandomElement for Padic<P> {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Padic::new(rng.gen_range(0, Padic::<P>::modulus()))
+    }
+}
+
+impl<const N: u64> RandomElement for ZnUnit<N> {
+    #[inline]
+    fn random_element<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        // NOTE: rejection sampling keeps the distribution uniform on the units.
+        loop {
+            if let Some(unit) = ZnUnit::new(Zn::random_element(rng)) {
+                return unit;
+            }
+        }
+    }
+}
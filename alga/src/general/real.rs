@@ -1,9 +1,10 @@
 use num::{Bounded, Signed};
+use std::num::FpCategory;
 use std::{f32, f64};
 
 use approx::{RelativeEq, UlpsEq};
 
-use crate::general::{ComplexField, Lattice};
+use crate::general::{ArchimedeanField, ComplexField, Lattice};
 
 #[cfg(not(feature = "std"))]
 use num::Float;
@@ -16,8 +17,8 @@ use num::Float;
 ///
 /// Reals are equipped with functions that are commonly used on reals. The results of those
 /// functions only have to be approximately equal to the actual theoretical values.
-// FIXME: SubsetOf should be removed when specialization will be supported by rustc. This will
-// allow a blanket impl: impl<T: Clone> SubsetOf<T> for T { ... }
+// NOTE: SubsetOf<Self> cannot be provided by a blanket impl, even with specialization, because it
+// would overlap with the generic inclusions of `Id` and `Complex`. Use `impl_reflexive_subset!`.
 // NOTE: make all types debuggable/'static/Any ? This seems essential for any kind of generic programming.
 pub trait RealField:
     ComplexField<RealField = Self>
@@ -51,10 +52,191 @@ pub trait RealField:
     fn log10_e() -> Self;
     fn ln_2() -> Self;
     fn ln_10() -> Self;
+
+    /// The difference between `1` and the next representable value of this type.
+    ///
+    /// The largest and smallest finite values are given by the `Bounded` supertrait, i.e.,
+    /// `Self::max_value()` and `Self::min_value()`.
+    ///
+    /// The default implementation halves `1` for as long as adding the result to `1` changes it.
+    /// Implementors should override it with a constant.
+    #[inline]
+    fn epsilon() -> Self {
+        let one = Self::one();
+        let two = one + one;
+        let mut eps = one;
+
+        while one + eps / two != one {
+            eps = eps / two;
+        }
+
+        eps
+    }
+
+    /// The smallest positive normal value of this type.
+    ///
+    /// The default implementation halves `1` for as long as the result stays normal. Implementors
+    /// should override it with a constant.
+    #[inline]
+    fn min_positive_value() -> Self {
+        let two = Self::one() + Self::one();
+        let mut min = Self::one();
+
+        loop {
+            let half = min / two;
+
+            if half.is_zero() || half.is_subnormal() {
+                return min;
+            }
+
+            min = half;
+        }
+    }
+
+    /// The number of significant binary digits of this type, including the implicit leading bit.
+    ///
+    /// The default implementation counts the doublings from `Self::epsilon()` to `1`.
+    #[inline]
+    fn mantissa_digits() -> u32 {
+        let one = Self::one();
+        let two = one + one;
+        let mut eps = Self::epsilon();
+        let mut digits = 1;
+
+        while eps < one {
+            eps = eps * two;
+            digits += 1;
+        }
+
+        digits
+    }
+
+    /// The floating-point category of this number.
+    #[inline]
+    fn classify(&self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.is_infinite() {
+            FpCategory::Infinite
+        } else if self.is_zero() {
+            FpCategory::Zero
+        } else if self.is_subnormal() {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    /// Rounds `self` to the nearest multiple of `step`, using `mode` to resolve which multiple is
+    /// selected.
+    ///
+    /// If `self` is already a grid point (i.e. `self == k * step` for some integer `k`, the product
+    /// being computed with this type's arithmetic), `self` is returned unchanged so that points
+    /// lying exactly on the grid are never perturbed by the rounding error of `self / step`.
+    ///
+    /// Panics if `step` is not strictly positive.
+    #[inline]
+    fn quantize(self, step: Self, mode: RoundingMode) -> Self {
+        assert!(
+            step > Self::zero(),
+            "Quantization step must be strictly positive."
+        );
+
+        let q = self / step;
+
+        if q.round() * step == self {
+            self
+        } else {
+            mode.round(q) * step
+        }
+    }
+
+    /// Snaps `self` to the nearest point of the regular grid `origin + k * step`, `k` being an
+    /// integer. Ties are resolved to the even `k` (banker's rounding).
+    ///
+    /// Points lying exactly on the grid are returned unchanged.
+    ///
+    /// Panics if `step` is not strictly positive.
+    #[inline]
+    fn snap_to_grid(self, origin: Self, step: Self) -> Self {
+        let offset = self - origin;
+        let snapped = offset.quantize(step, RoundingMode::NearestTiesEven);
+
+        if snapped == offset {
+            self
+        } else {
+            origin + snapped
+        }
+    }
+}
+
+/// The strategy used by `RealField::quantize` to select an integer from a real.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest integer, ties being rounded to the even integer (banker's rounding).
+    NearestTiesEven,
+    /// Rounds to the nearest integer, ties being rounded away from zero.
+    NearestTiesAway,
+    /// Rounds toward zero.
+    TowardZero,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward positive infinity.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Rounds `x` to an integer according to this rounding mode.
+    #[inline]
+    pub fn round<N: RealField>(self, x: N) -> N {
+        match self {
+            RoundingMode::NearestTiesEven => {
+                let one = N::one();
+                let two = one + one;
+                let floor = x.floor();
+                let diff = x - floor;
+                let half = one / two;
+
+                if diff < half {
+                    floor
+                } else if diff > half {
+                    floor + one
+                } else if (floor / two).fract().is_zero() {
+                    floor
+                } else {
+                    floor + one
+                }
+            }
+            RoundingMode::NearestTiesAway => x.round(),
+            RoundingMode::TowardZero => x.trunc(),
+            RoundingMode::Floor => x.floor(),
+            RoundingMode::Ceil => x.ceil(),
+        }
+    }
 }
 
 macro_rules! impl_real(
     ($($T:ty, $M:ident, $libm: ident);*) => ($(
+        impl ArchimedeanField for $T {
+            #[inline]
+            fn floor_to_integer(&self) -> Option<i64> {
+                let floor = $libm::floor(*self);
+                // NOTE: -2⁶³ is exactly representable, and NaN fails both comparisons.
+                let min = i64::MIN as $T;
+
+                if min <= floor && floor < -min {
+                    Some(floor as i64)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn from_integer(n: i64) -> Self {
+                n as $T
+            }
+        }
+
         impl RealField for $T {
             #[inline]
             fn is_sign_positive(self) -> bool {
@@ -171,6 +353,26 @@ macro_rules! impl_real(
             fn ln_10() -> Self {
                 $M::consts::LN_10
             }
+
+            #[inline]
+            fn epsilon() -> Self {
+                $M::EPSILON
+            }
+
+            #[inline]
+            fn min_positive_value() -> Self {
+                $M::MIN_POSITIVE
+            }
+
+            #[inline]
+            fn mantissa_digits() -> u32 {
+                $M::MANTISSA_DIGITS
+            }
+
+            #[inline]
+            fn classify(&self) -> FpCategory {
+                $M::classify(*self)
+            }
         }
     )*)
 );
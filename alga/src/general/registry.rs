@@ -0,0 +1,117 @@
+//! Runtime identifiers of the algebraic structures implemented by a type.
+
+/// The runtime identifier of an operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperatorId {
+    /// The additive operator, see `Additive`.
+    Additive,
+    /// The multiplicative operator, see `Multiplicative`.
+    Multiplicative,
+    /// The composition operator, see `Composition`.
+    Composition,
+}
+
+/// The runtime identifier of an algebraic structure trait, together with its operators.
+///
+/// The variants are named after the arguments of the `register_structures` macro, e.g.,
+/// `Group(OperatorId::Additive)` identifies `AbstractGroup<Additive>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StructureId {
+    /// `AbstractMagma<O>`.
+    Magma(OperatorId),
+    /// `AbstractQuasigroup<O>`.
+    Quasigroup(OperatorId),
+    /// `AbstractSemigroup<O>`.
+    Semigroup(OperatorId),
+    /// `AbstractLoop<O>`.
+    Loop(OperatorId),
+    /// `AbstractMonoid<O>`.
+    Monoid(OperatorId),
+    /// `AbstractGroup<O>`.
+    Group(OperatorId),
+    /// `AbstractGroupAbelian<O>`.
+    GroupAbelian(OperatorId),
+    /// `AbstractRing<A, M>`.
+    Ring(OperatorId, OperatorId),
+    /// `AbstractRingCommutative<A, M>`.
+    RingCommutative(OperatorId, OperatorId),
+    /// `AbstractField<A, M>`.
+    Field(OperatorId, OperatorId),
+    /// `EuclideanDomain`.
+    EuclideanDomain,
+    /// `AbstractModule`, with the default operators.
+    Module,
+    /// `Lattice`.
+    Lattice,
+    /// `BoundedLattice`.
+    BoundedLattice,
+    /// `DistributiveLattice`.
+    DistributiveLattice,
+    /// `HeytingAlgebra`.
+    HeytingAlgebra,
+    /// `MetricSpace`.
+    MetricSpace,
+}
+
+impl StructureId {
+    /// The structures this one directly refines, i.e., the supertraits of its trait.
+    pub fn supers(self) -> [Option<StructureId>; 2] {
+        use self::StructureId::*;
+
+        match self {
+            Magma(_) | Lattice | MetricSpace => [None, None],
+            Quasigroup(o) | Semigroup(o) => [Some(Magma(o)), None],
+            Loop(o) => [Some(Quasigroup(o)), None],
+            Monoid(o) => [Some(Semigroup(o)), None],
+            Group(o) => [Some(Loop(o)), Some(Monoid(o))],
+            GroupAbelian(o) => [Some(Group(o)), None],
+            Ring(a, m) => [Some(GroupAbelian(a)), Some(Monoid(m))],
+            RingCommutative(a, m) => [Some(Ring(a, m)), None],
+            Field(a, m) => [Some(RingCommutative(a, m)), Some(GroupAbelian(m))],
+            EuclideanDomain => [
+                Some(RingCommutative(
+                    OperatorId::Additive,
+                    OperatorId::Multiplicative,
+                )),
+                None,
+            ],
+            Module => [Some(GroupAbelian(OperatorId::Additive)), None],
+            BoundedLattice | DistributiveLattice => [Some(Lattice), None],
+            HeytingAlgebra => [Some(BoundedLattice), Some(DistributiveLattice)],
+        }
+    }
+
+    /// Returns `true` if every type with this structure also has the structure `other`, i.e., if
+    /// `other` is this structure or one of its direct or indirect supertraits.
+    pub fn implies(self, other: StructureId) -> bool {
+        self == other || self.supers().iter().flatten().any(|s| s.implies(other))
+    }
+}
+
+/// Types that recorded the algebraic structures they implement, usually with the
+/// `register_structures` macro.
+pub trait Structures {
+    /// The structures declared for this type. Their supertraits are implied and need not be
+    /// listed.
+    const STRUCTURES: &'static [StructureId];
+}
+
+/// The structures declared for `T`, without the implied ones.
+#[inline]
+pub fn structures_of<T: Structures + ?Sized>() -> &'static [StructureId] {
+    T::STRUCTURES
+}
+
+/// Returns `true` if the structure `s` has been declared for `T`, or is implied by one that has.
+#[inline]
+pub fn has_structure<T: Structures + ?Sized>(s: StructureId) -> bool {
+    T::STRUCTURES.iter().any(|t| t.implies(s))
+}
+
+register_structures!(
+    i8, i16, i32, i64, isize => RingCommutative(Additive, Multiplicative), EuclideanDomain, Module,
+        HeytingAlgebra;
+    i128 => RingCommutative(Additive, Multiplicative), EuclideanDomain, HeytingAlgebra;
+    f32, f64 => Field(Additive, Multiplicative), Module, HeytingAlgebra;
+    bool, u8, u16, u32, u64, u128, usize => HeytingAlgebra;
+);
@@ -0,0 +1,257 @@
+//! Algebraic structures and conversions for the arbitrary-precision numbers of the `rug` crate.
+//!
+//! `rug::Float` and `rug::Complex` implement the abstract algebraic structures up to
+//! `AbstractField`, the lattices, and the inclusions of the primitive floats and of
+//! `num_complex::Complex`.
+//!
+//! They do **not** implement `RealField` or `ComplexField` yet. This would first require relaxing
+//! the `Copy` bound of `ComplexFieldCore` to `Clone`, which has not been done. Even then, the
+//! orphan rules prevent this crate from implementing the `num-traits` traits required by
+//! `ComplexField` and `RealField` (e.g. `Num`, `Signed`, `Bounded`) for them, so those impls have to
+//! come from `rug` itself.
+//!
+//! The result of an operation has the largest precision of its operands. The identity elements
+//! have the smallest precision, which still represents them exactly, so that they are neutral for
+//! operands of any precision.
+
+use num_complex::Complex as NumComplex;
+use rug::float;
+use rug::{Complex, Float};
+
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Identity, JoinSemilattice, Lattice, MeetSemilattice, Multiplicative, SubsetOf,
+    TwoSidedInverse,
+};
+
+#[inline]
+fn complex_prec(z: &Complex) -> u32 {
+    let (re, im) = z.prec();
+    re.max(im)
+}
+
+impl AbstractMagma<Additive> for Float {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Float::with_val(self.prec().max(right.prec()), self + right)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Float {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Float::with_val(self.prec().max(right.prec()), self * right)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Float {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Float::with_val(self.prec(), -self)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Float {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Float::with_val(self.prec(), self.recip_ref())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(TwoSidedInverse::<Multiplicative>::two_sided_inverse(self))
+        }
+    }
+}
+
+impl Identity<Additive> for Float {
+    #[inline]
+    fn identity() -> Self {
+        Float::with_val(float::prec_min(), 0)
+    }
+}
+
+impl Identity<Multiplicative> for Float {
+    #[inline]
+    fn identity() -> Self {
+        Float::with_val(float::prec_min(), 1)
+    }
+}
+
+impl AbstractMagma<Additive> for Complex {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Complex::with_val(complex_prec(self).max(complex_prec(right)), self + right)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Complex {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Complex::with_val(complex_prec(self).max(complex_prec(right)), self * right)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Complex {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Complex::with_val(self.prec(), -self)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Complex {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Complex::with_val(self.prec(), self.recip_ref())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.real().is_zero() && self.imag().is_zero() {
+            None
+        } else {
+            Some(TwoSidedInverse::<Multiplicative>::two_sided_inverse(self))
+        }
+    }
+}
+
+impl Identity<Additive> for Complex {
+    #[inline]
+    fn identity() -> Self {
+        Complex::with_val(float::prec_min(), (0, 0))
+    }
+}
+
+impl Identity<Multiplicative> for Complex {
+    #[inline]
+    fn identity() -> Self {
+        Complex::with_val(float::prec_min(), (1, 0))
+    }
+}
+
+macro_rules! impl_rug_field(
+    ($($T: ident),*) => {$(
+        impl AbstractSemigroup<Additive> for $T {}
+        impl AbstractQuasigroup<Additive> for $T {}
+        impl AbstractMonoid<Additive> for $T {}
+        impl AbstractLoop<Additive> for $T {}
+        impl AbstractGroup<Additive> for $T {}
+        impl AbstractGroupAbelian<Additive> for $T {}
+        impl AbstractSemigroup<Multiplicative> for $T {}
+        impl AbstractQuasigroup<Multiplicative> for $T {}
+        impl AbstractMonoid<Multiplicative> for $T {}
+        impl AbstractLoop<Multiplicative> for $T {}
+        impl AbstractGroup<Multiplicative> for $T {}
+        impl AbstractGroupAbelian<Multiplicative> for $T {}
+        impl AbstractRing for $T {}
+        impl AbstractRingCommutative for $T {}
+        impl AbstractField for $T {}
+    )*}
+);
+
+impl_rug_field!(Float, Complex);
+
+impl MeetSemilattice for Float {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        if self <= other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+
+impl JoinSemilattice for Float {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        if self >= other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+
+impl Lattice for Float {}
+
+impl_reflexive_subset!(Float, Complex);
+
+macro_rules! impl_float_subset_of_rug(
+    ($($T: ident, $prec: expr, $to_float: ident);* $(;)*) => {$(
+        impl SubsetOf<Float> for $T {
+            #[inline]
+            fn to_superset(&self) -> Float {
+                Float::with_val($prec, *self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &Float) -> $T {
+                element.$to_float()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &Float) -> bool {
+                element.is_nan() || *element == element.$to_float()
+            }
+        }
+
+        impl SubsetOf<Complex> for $T {
+            #[inline]
+            fn to_superset(&self) -> Complex {
+                Complex::with_val($prec, (*self, 0))
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &Complex) -> $T {
+                element.real().$to_float()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &Complex) -> bool {
+                element.imag().is_zero() && <$T as SubsetOf<Float>>::is_in_subset(element.real())
+            }
+        }
+
+        impl SubsetOf<Complex> for NumComplex<$T> {
+            #[inline]
+            fn to_superset(&self) -> Complex {
+                Complex::with_val($prec, (self.re, self.im))
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &Complex) -> NumComplex<$T> {
+                NumComplex::new(element.real().$to_float(), element.imag().$to_float())
+            }
+
+            #[inline]
+            fn is_in_subset(element: &Complex) -> bool {
+                <$T as SubsetOf<Float>>::is_in_subset(element.real())
+                    && <$T as SubsetOf<Float>>::is_in_subset(element.imag())
+            }
+        }
+    )*}
+);
+
+impl_float_subset_of_rug!(f32, 24, to_f32; f64, 53, to_f64);
+
+impl SubsetOf<Complex> for Float {
+    #[inline]
+    fn to_superset(&self) -> Complex {
+        Complex::with_val(self.prec(), (self, 0))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Complex) -> Float {
+        element.real().clone()
+    }
+
+    #[inline]
+    fn is_in_subset(element: &Complex) -> bool {
+        element.imag().is_zero()
+    }
+}
@@ -0,0 +1,139 @@
+//! Secret algebraic elements, erased from memory when dropped.
+
+use std::fmt;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Identity, Multiplicative, TwoSidedInverse,
+};
+
+/// A secret element of an algebraic structure.
+///
+/// The wrapped value is zeroized when this wrapper is dropped, and is never printed: both `Debug`
+/// and `Display` only output a placeholder. This wrapper has the algebraic structures of the
+/// wrapped type, so that secrets can be handled by generic code without leaking them into logs.
+///
+/// Each intermediate result of an operation is itself a `SecretElement`, and is thus zeroized as
+/// well. This does not prevent the compiler from leaving copies of the values in registers or on
+/// the stack.
+pub struct SecretElement<T: Zeroize>(T);
+
+impl<T: Zeroize> SecretElement<T> {
+    /// Wraps a secret value.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        SecretElement(value)
+    }
+
+    /// A reference to the secret value.
+    #[inline]
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> Drop for SecretElement<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T: Zeroize> Zeroize for SecretElement<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for SecretElement<T> {}
+
+impl<T: Zeroize + Clone> Clone for SecretElement<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SecretElement(self.0.clone())
+    }
+}
+
+impl<T: Zeroize + PartialEq> PartialEq for SecretElement<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Zeroize + Eq> Eq for SecretElement<T> {}
+
+impl<T: Zeroize> fmt::Debug for SecretElement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretElement(<redacted>)")
+    }
+}
+
+impl<T: Zeroize> fmt::Display for SecretElement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/*
+ *
+ * Algebraic structures.
+ *
+ */
+macro_rules! impl_secret_structures(
+    ($($O: ident),* $(,)*) => {$(
+        impl<T: Zeroize + AbstractMagma<$O>> AbstractMagma<$O> for SecretElement<T> {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                SecretElement(self.0.operate(&right.0))
+            }
+
+            #[inline]
+            fn operate_assign(&mut self, right: &Self) {
+                self.0.operate_assign(&right.0)
+            }
+        }
+
+        impl<T: Zeroize + TwoSidedInverse<$O>> TwoSidedInverse<$O> for SecretElement<T> {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                SecretElement(self.0.two_sided_inverse())
+            }
+
+            #[inline]
+            fn try_two_sided_inverse(&self) -> Option<Self> {
+                self.0.try_two_sided_inverse().map(SecretElement)
+            }
+        }
+
+        impl<T: Zeroize + Identity<$O>> Identity<$O> for SecretElement<T> {
+            #[inline]
+            fn identity() -> Self {
+                SecretElement(T::identity())
+            }
+        }
+
+        impl<T: Zeroize + AbstractSemigroup<$O>> AbstractSemigroup<$O> for SecretElement<T> {}
+        impl<T: Zeroize + AbstractQuasigroup<$O>> AbstractQuasigroup<$O> for SecretElement<T> {}
+        impl<T: Zeroize + AbstractMonoid<$O>> AbstractMonoid<$O> for SecretElement<T> {}
+        impl<T: Zeroize + AbstractLoop<$O>> AbstractLoop<$O> for SecretElement<T> {}
+        impl<T: Zeroize + AbstractGroup<$O>> AbstractGroup<$O> for SecretElement<T> {}
+        impl<T: Zeroize + AbstractGroupAbelian<$O>> AbstractGroupAbelian<$O> for SecretElement<T> {}
+    )*}
+);
+
+impl_secret_structures!(Additive, Multiplicative);
+
+impl<T: Zeroize + AbstractRing> AbstractRing for SecretElement<T> {
+    #[inline]
+    fn characteristic() -> u64 {
+        T::characteristic()
+    }
+}
+
+impl<T: Zeroize + AbstractRingCommutative> AbstractRingCommutative for SecretElement<T> {}
+impl<T: Zeroize + AbstractField> AbstractField for SecretElement<T> {}
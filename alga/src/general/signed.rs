@@ -0,0 +1,173 @@
+use approx::{AbsDiffEq, RelativeEq};
+use num::Signed;
+use num_complex::Complex;
+
+use crate::general::{
+    AbstractMagma, Additive, ComplexFieldCore, Identity, Multiplicative, OrderedRing, RealField,
+};
+
+/// Elements with an absolute value and a sign.
+///
+/// The absolute value lives in an ordered ring, the `Magnitude`, which is `Self` for integers and
+/// reals but the reals for complex numbers. It is nonnegative, multiplicative, and satisfies the
+/// triangle inequality:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, 0 ≤ |a|, |a × b| = |a| × |b|, |a + b| ≤ |a| + |b|
+/// ~~~
+///
+/// The sign of a nonzero element is the element of absolute value one in its direction, so that
+/// `a = signum(a) × |a|` whenever the magnitude embeds into `Self`.
+pub trait SignedAlgebra: Sized {
+    /// The ordered ring containing the absolute values.
+    type Magnitude: OrderedRing;
+
+    /// The absolute value of this element.
+    fn abs(&self) -> Self::Magnitude;
+
+    /// The sign of this element.
+    fn signum(&self) -> Self;
+
+    /// Returns `true` if the absolute value of the given argument is nonnegative.
+    fn prop_abs_is_nonnegative(args: (Self,)) -> bool {
+        let (a,) = args;
+        let zero: Self::Magnitude = Identity::<Additive>::identity();
+
+        zero <= a.abs()
+    }
+
+    /// Returns `true` if the absolute value of the product of the given arguments is the product
+    /// of their absolute values.
+    fn prop_abs_is_multiplicative(args: (Self, Self)) -> bool
+    where
+        Self: AbstractMagma<Multiplicative>,
+    {
+        let (a, b) = args;
+        let ab = AbstractMagma::<Multiplicative>::operate(&a, &b);
+
+        ab.abs() == AbstractMagma::<Multiplicative>::operate(&a.abs(), &b.abs())
+    }
+
+    /// Returns `true` if the absolute value of the product of the given arguments is the product
+    /// of their absolute values. Approximate equality is used for verifications.
+    fn prop_abs_is_multiplicative_approx(args: (Self, Self)) -> bool
+    where
+        Self: AbstractMagma<Multiplicative>,
+        Self::Magnitude: RelativeEq,
+    {
+        Self::prop_abs_is_multiplicative_approx_with(
+            args,
+            <Self::Magnitude as AbsDiffEq>::default_epsilon(),
+            <Self::Magnitude as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the absolute value of the product of the given arguments is the product
+    /// of their absolute values. Approximate equality, up to the given `epsilon` and
+    /// `max_relative` tolerances, is used for verifications.
+    fn prop_abs_is_multiplicative_approx_with(
+        args: (Self, Self),
+        epsilon: <Self::Magnitude as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Magnitude as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self: AbstractMagma<Multiplicative>,
+        Self::Magnitude: RelativeEq,
+    {
+        let (a, b) = args;
+        let ab = AbstractMagma::<Multiplicative>::operate(&a, &b);
+
+        relative_eq!(
+            ab.abs(),
+            AbstractMagma::<Multiplicative>::operate(&a.abs(), &b.abs()),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments.
+    fn prop_triangle_inequality(args: (Self, Self)) -> bool
+    where
+        Self: AbstractMagma<Additive>,
+    {
+        let (a, b) = args;
+        let ab = AbstractMagma::<Additive>::operate(&a, &b);
+
+        ab.abs() <= AbstractMagma::<Additive>::operate(&a.abs(), &b.abs())
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+    /// equality is used for verifications.
+    fn prop_triangle_inequality_approx(args: (Self, Self)) -> bool
+    where
+        Self: AbstractMagma<Additive>,
+        Self::Magnitude: RelativeEq,
+    {
+        Self::prop_triangle_inequality_approx_with(
+            args,
+            <Self::Magnitude as AbsDiffEq>::default_epsilon(),
+            <Self::Magnitude as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+    /// equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_triangle_inequality_approx_with(
+        args: (Self, Self),
+        epsilon: <Self::Magnitude as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Magnitude as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self: AbstractMagma<Additive>,
+        Self::Magnitude: RelativeEq,
+    {
+        let (a, b) = args;
+        let lhs = AbstractMagma::<Additive>::operate(&a, &b).abs();
+        let rhs = AbstractMagma::<Additive>::operate(&a.abs(), &b.abs());
+
+        lhs <= rhs || relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+    }
+}
+
+/*
+ *
+ * Implementations.
+ *
+ */
+// NOTE: like for the ring laws, the absolute value of the primitive integers is only correct as
+// long as it does not overflow, i.e., for every value but `MIN`.
+macro_rules! impl_signed_algebra(
+    ($($T:ty),* $(,)*) => {$(
+        impl SignedAlgebra for $T {
+            type Magnitude = $T;
+
+            #[inline]
+            fn abs(&self) -> $T {
+                Signed::abs(self)
+            }
+
+            #[inline]
+            fn signum(&self) -> $T {
+                Signed::signum(self)
+            }
+        }
+    )*}
+);
+
+impl_signed_algebra!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+// NOTE: the absolute value of a complex number is its modulus, and its sign lies on the unit circle.
+impl<N: RealField + OrderedRing> SignedAlgebra for Complex<N> {
+    type Magnitude = N;
+
+    #[inline]
+    fn abs(&self) -> N {
+        self.modulus()
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        ComplexFieldCore::signum(*self)
+    }
+}
@@ -0,0 +1,388 @@
+//! Split-complex (or hyperbolic) numbers.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Identity, Module, Multiplicative, PartialInverse, RealField, SubsetOf, SupersetOf,
+    TwoSidedInverse,
+};
+
+/// A split-complex number `re + im j`, where the unit `j` satisfies `j² = +1`.
+///
+/// Split-complex numbers form a commutative ring, which is not a field: the elements on the
+/// diagonals `re = ±im` have no multiplicative inverse. They play the same role for the Lorentz
+/// boosts of the plane as the complex numbers do for its rotations, with the hyperbolic functions
+/// replacing the circular ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SplitComplex<N> {
+    /// The real part.
+    pub re: N,
+    /// The coefficient of the unit `j`.
+    pub im: N,
+}
+
+impl<N> SplitComplex<N> {
+    /// Creates a split-complex number from its components.
+    #[inline]
+    pub const fn new(re: N, im: N) -> Self {
+        SplitComplex { re, im }
+    }
+}
+
+impl<N: RealField> SplitComplex<N> {
+    /// The split-complex number with real part `re` and a zero coefficient of `j`.
+    #[inline]
+    pub fn from_real(re: N) -> Self {
+        SplitComplex::new(re, N::zero())
+    }
+
+    /// The unit `j`.
+    #[inline]
+    pub fn j() -> Self {
+        SplitComplex::new(N::zero(), N::one())
+    }
+
+    /// The conjugate `re - im j` of this number.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        SplitComplex::new(self.re, -self.im)
+    }
+
+    /// The quadratic form `re² - im²`, i.e., the product of this number with its conjugate.
+    ///
+    /// Unlike the squared modulus of a complex number, it is negative when `|im| > |re|` and zero
+    /// on the diagonals `re = ±im`.
+    #[inline]
+    pub fn modulus_squared(self) -> N {
+        self.re * self.re - self.im * self.im
+    }
+
+    /// The modulus `sqrt(|re² - im²|)` of this number.
+    #[inline]
+    pub fn modulus(self) -> N {
+        self.modulus_squared().abs().sqrt()
+    }
+
+    /// The number `r (cosh φ + j sinh φ)` with modulus `r` and hyperbolic argument `φ`.
+    #[inline]
+    pub fn from_hyperbolic(r: N, phi: N) -> Self {
+        let (sinh, cosh) = phi.sinh_cosh();
+        SplitComplex::new(r * cosh, r * sinh)
+    }
+
+    /// The modulus and the hyperbolic argument `φ` of this number, such that
+    /// `self = r (cosh φ + j sinh φ)`.
+    ///
+    /// Returns `None` unless `re > |im|`, because the other numbers have no such form.
+    #[inline]
+    pub fn to_hyperbolic(self) -> Option<(N, N)> {
+        if self.re > self.im.abs() {
+            Some((self.modulus(), (self.im / self.re).atanh()))
+        } else {
+            None
+        }
+    }
+
+    /// The exponential `e^re (cosh im + j sinh im)` of this number.
+    #[inline]
+    pub fn exp(self) -> Self {
+        SplitComplex::from_hyperbolic(self.re.exp(), self.im)
+    }
+
+    /// The natural logarithm of this number, i.e., the inverse of `exp`.
+    ///
+    /// Returns `None` unless `re > |im|`, because only these numbers are exponentials.
+    #[inline]
+    pub fn ln(self) -> Option<Self> {
+        self.to_hyperbolic()
+            .map(|(r, phi)| SplitComplex::new(r.ln(), phi))
+    }
+
+    /// The hyperbolic cosine `(e^self + e^-self) / 2` of this number.
+    #[inline]
+    pub fn cosh(self) -> Self {
+        let (sinh, cosh) = self.re.sinh_cosh();
+        let (sinh_j, cosh_j) = self.im.sinh_cosh();
+        SplitComplex::new(cosh * cosh_j, sinh * sinh_j)
+    }
+
+    /// The hyperbolic sine `(e^self - e^-self) / 2` of this number.
+    #[inline]
+    pub fn sinh(self) -> Self {
+        let (sinh, cosh) = self.re.sinh_cosh();
+        let (sinh_j, cosh_j) = self.im.sinh_cosh();
+        SplitComplex::new(sinh * cosh_j, cosh * sinh_j)
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for SplitComplex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}j", self.re, self.im)
+    }
+}
+
+impl<N: RealField> Add for SplitComplex<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        SplitComplex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<N: RealField> Sub for SplitComplex<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        SplitComplex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<N: RealField> Mul for SplitComplex<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        SplitComplex::new(
+            self.re * rhs.re + self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<N: RealField> Mul<N> for SplitComplex<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Self {
+        SplitComplex::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl<N: RealField> Neg for SplitComplex<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        SplitComplex::new(-self.re, -self.im)
+    }
+}
+
+impl<N: RealField> AddAssign for SplitComplex<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<N: RealField> SubAssign for SplitComplex<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<N: RealField> MulAssign for SplitComplex<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> MulAssign<N> for SplitComplex<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField> Zero for SplitComplex<N> {
+    #[inline]
+    fn zero() -> Self {
+        SplitComplex::new(N::zero(), N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<N: RealField> One for SplitComplex<N> {
+    #[inline]
+    fn one() -> Self {
+        SplitComplex::from_real(N::one())
+    }
+}
+
+impl<N: RealField> AbstractMagma<Additive> for SplitComplex<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<N: RealField> AbstractMagma<Multiplicative> for SplitComplex<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<N: RealField> TwoSidedInverse<Additive> for SplitComplex<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<N: RealField> Identity<Additive> for SplitComplex<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<N: RealField> Identity<Multiplicative> for SplitComplex<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<N: RealField> AbstractSemigroup<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractQuasigroup<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractMonoid<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractLoop<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractGroup<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractGroupAbelian<Additive> for SplitComplex<N> {}
+impl<N: RealField> AbstractSemigroup<Multiplicative> for SplitComplex<N> {}
+impl<N: RealField> AbstractMonoid<Multiplicative> for SplitComplex<N> {}
+impl<N: RealField> AbstractRing for SplitComplex<N> {}
+impl<N: RealField> AbstractRingCommutative for SplitComplex<N> {}
+
+impl<N: RealField> PartialInverse<Multiplicative> for SplitComplex<N> {
+    /// The inverse `conjugate / (re² - im²)`, or `None` on the diagonals `re = ±im`.
+    #[inline]
+    fn partial_inverse(&self) -> Option<Self> {
+        let q = self.modulus_squared();
+
+        if q.is_zero() {
+            None
+        } else {
+            Some(SplitComplex::new(self.re / q, -self.im / q))
+        }
+    }
+}
+
+impl<N: RealField> AbstractModule for SplitComplex<N> {
+    type AbstractRing = N;
+
+    #[inline]
+    fn multiply_by(&self, r: N) -> Self {
+        *self * r
+    }
+}
+
+impl<N: RealField> Module for SplitComplex<N> {
+    type Ring = N;
+}
+
+impl<N: AbsDiffEq> AbsDiffEq for SplitComplex<N>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon.clone()) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+}
+
+impl<N: RelativeEq> RelativeEq for SplitComplex<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.re
+            .relative_eq(&other.re, epsilon.clone(), max_relative.clone())
+            && self.im.relative_eq(&other.im, epsilon, max_relative)
+    }
+}
+
+impl<N: UlpsEq> UlpsEq for SplitComplex<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon.clone(), max_ulps)
+            && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+    }
+}
+
+impl<N1, N2: SupersetOf<N1>> SubsetOf<SplitComplex<N2>> for SplitComplex<N1> {
+    #[inline]
+    fn to_superset(&self) -> SplitComplex<N2> {
+        SplitComplex::new(N2::from_subset(&self.re), N2::from_subset(&self.im))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &SplitComplex<N2>) -> SplitComplex<N1> {
+        SplitComplex::new(
+            element.re.to_subset_unchecked(),
+            element.im.to_subset_unchecked(),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(c: &SplitComplex<N2>) -> bool {
+        c.re.is_in_subset() && c.im.is_in_subset()
+    }
+}
+
+impl<N: RealField, N2: Zero + SupersetOf<N>> SubsetOf<SplitComplex<N2>> for N {
+    #[inline]
+    fn to_superset(&self) -> SplitComplex<N2> {
+        SplitComplex::new(N2::from_subset(self), N2::zero())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &SplitComplex<N2>) -> N {
+        element.re.to_subset_unchecked()
+    }
+
+    #[inline]
+    fn is_in_subset(c: &SplitComplex<N2>) -> bool {
+        c.re.is_in_subset() && c.im.is_zero()
+    }
+}
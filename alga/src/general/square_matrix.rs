@@ -0,0 +1,141 @@
+//! Square matrices, represented as arrays of rows.
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractSemigroup, Additive, Field, Identity,
+    Multiplicative, PartialInverse, RingCommutative, TwoSidedInverse,
+};
+
+/// Operations specific to the rings of square matrices.
+///
+/// Unlike `linear::SquareMatrix`, this does not require a vector type for the rows and columns,
+/// nor a field of scalars. The arrays `[[T; N]; N]`, seen as arrays of rows, form a ring under the entrywise addition and
+/// the matrix product, which is not commutative as soon as `N > 1`. When `T` is a field, the
+/// invertible matrices are given by `PartialInverse<Multiplicative>`.
+pub trait MatrixRing: AbstractRing {
+    /// The type of the entries.
+    type Scalar;
+
+    /// The transpose of this matrix.
+    fn transpose(&self) -> Self;
+
+    /// The sum of the diagonal entries of this matrix.
+    fn trace(&self) -> Self::Scalar;
+}
+
+#[inline]
+fn from_fn<T, F: FnMut(usize, usize) -> T, const N: usize>(mut f: F) -> [[T; N]; N] {
+    std::array::from_fn(|i| std::array::from_fn(|j| f(i, j)))
+}
+
+impl<T: RingCommutative, const N: usize> MatrixRing for [[T; N]; N] {
+    type Scalar = T;
+
+    #[inline]
+    fn transpose(&self) -> Self {
+        from_fn(|i, j| self[j][i].clone())
+    }
+
+    #[inline]
+    fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self[i][i].clone())
+    }
+}
+
+impl<T: RingCommutative, const N: usize> AbstractMagma<Additive> for [[T; N]; N] {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        from_fn(|i, j| self[i][j].clone() + right[i][j].clone())
+    }
+}
+
+impl<T: RingCommutative, const N: usize> AbstractMagma<Multiplicative> for [[T; N]; N] {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        from_fn(|i, j| {
+            (0..N).fold(T::zero(), |acc, k| {
+                acc + self[i][k].clone() * right[k][j].clone()
+            })
+        })
+    }
+}
+
+impl<T: RingCommutative, const N: usize> TwoSidedInverse<Additive> for [[T; N]; N] {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        from_fn(|i, j| -self[i][j].clone())
+    }
+}
+
+impl<T: RingCommutative, const N: usize> Identity<Additive> for [[T; N]; N] {
+    #[inline]
+    fn identity() -> Self {
+        from_fn(|_, _| T::zero())
+    }
+}
+
+impl<T: RingCommutative, const N: usize> Identity<Multiplicative> for [[T; N]; N] {
+    #[inline]
+    fn identity() -> Self {
+        from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: RingCommutative, const N: usize> AbstractSemigroup<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractQuasigroup<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractMonoid<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractLoop<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractGroup<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractGroupAbelian<Additive> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractSemigroup<Multiplicative> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractMonoid<Multiplicative> for [[T; N]; N] {}
+impl<T: RingCommutative, const N: usize> AbstractRing for [[T; N]; N] {}
+
+impl<T: RingCommutative, const N: usize> AbstractModule for [[T; N]; N] {
+    type AbstractRing = T;
+
+    #[inline]
+    fn multiply_by(&self, r: T) -> Self {
+        from_fn(|i, j| self[i][j].clone() * r.clone())
+    }
+}
+
+// NOTE: the pivot is the first nonzero entry of its column, since a field carries no notion of
+// magnitude. For floating-point entries, this may be less accurate than partial pivoting.
+impl<T: Field, const N: usize> PartialInverse<Multiplicative> for [[T; N]; N] {
+    /// The inverse of this matrix, computed by Gauss–Jordan elimination, or `None` if it is
+    /// singular.
+    fn partial_inverse(&self) -> Option<Self> {
+        let mut m = self.clone();
+        let mut inv: Self = Identity::<Multiplicative>::identity();
+
+        for col in 0..N {
+            let pivot = (col..N).find(|&row| !m[row][col].is_zero())?;
+            m.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let scale = T::one() / m[col][col].clone();
+
+            for j in 0..N {
+                m[col][j] *= scale.clone();
+                inv[col][j] *= scale.clone();
+            }
+
+            for row in 0..N {
+                let factor = m[row][col].clone();
+
+                if row == col || factor.is_zero() {
+                    continue;
+                }
+
+                for j in 0..N {
+                    let (m_col, inv_col) = (m[col][j].clone(), inv[col][j].clone());
+                    m[row][j] -= factor.clone() * m_col;
+                    inv[row][j] -= factor.clone() * inv_col;
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
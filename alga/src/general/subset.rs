@@ -1,7 +1,11 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
-use num::Zero;
+use num::{Float, Zero};
 use num_complex::Complex;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::general::RealField;
 
 /// Nested sets and conversions between them (using an injective mapping). Useful to work with
 /// substructures. In generic code, it is preferable to use `SupersetOf` as trait bound whenever
@@ -84,6 +88,78 @@ pub trait SupersetOf<T>: Sized {
     fn from_subset(element: &T) -> Self;
 }
 
+/// Converts an object from one type to an equivalent or more general one.
+///
+/// See also `::try_convert` for conversion to more specific types.
+#[inline]
+pub fn convert<From, To: SupersetOf<From>>(t: From) -> To {
+    To::from_subset(&t)
+}
+
+/// Attempts to convert an object to a more specific one.
+///
+/// See also `::convert` for conversion to more general types.
+#[inline]
+pub fn try_convert<From: SupersetOf<To>, To>(t: From) -> Option<To> {
+    t.to_subset()
+}
+
+/// Indicates if `::try_convert` will succeed without actually performing the conversion.
+#[inline]
+pub fn is_convertible<From: SupersetOf<To>, To>(t: &From) -> bool {
+    t.is_in_subset()
+}
+
+/// Use with care! Same as `try_convert` but without any property checks.
+///
+/// # Safety
+///
+/// The caller must ensure that `t.is_in_subset()` holds, i.e., that `is_convertible(&t)`.
+#[inline]
+pub unsafe fn convert_unchecked<From: SupersetOf<To>, To>(t: From) -> To {
+    t.to_subset_unchecked()
+}
+
+/// Converts an object from one type to an equivalent or more general one.
+///
+/// Same as `::convert` but takes its argument by reference.
+#[inline]
+pub fn convert_ref<From, To: SupersetOf<From>>(t: &From) -> To {
+    To::from_subset(t)
+}
+
+/// Attempts to convert an object to a more specific one.
+///
+/// Same as `::try_convert` but takes its argument by reference.
+#[inline]
+pub fn try_convert_ref<From: SupersetOf<To>, To>(t: &From) -> Option<To> {
+    t.to_subset()
+}
+
+/// Use with care! Same as `try_convert_ref` but without any property checks.
+///
+/// # Safety
+///
+/// The caller must ensure that `t.is_in_subset()` holds, i.e., that `is_convertible(t)`.
+#[inline]
+pub unsafe fn convert_ref_unchecked<From: SupersetOf<To>, To>(t: &From) -> To {
+    t.to_subset_unchecked()
+}
+
+/// The error returned by the standard `TryFrom` conversions generated by
+/// `std_convert_via_subset!` when an element has no equivalent in the subset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotInSubsetError;
+
+impl fmt::Display for NotInSubsetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "element is not part of the subset")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotInSubsetError {}
+
 impl<SS: SubsetOf<SP>, SP> SupersetOf<SS> for SP {
     #[inline]
     fn to_subset(&self) -> Option<SS> {
@@ -129,7 +205,217 @@ macro_rules! impl_subset(
     }
 );
 
+macro_rules! impl_int_subset_of_int(
+    ($($subset: ty as $( $superset: ty),+ );* $(;)*) => {
+        $($(
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                *element as $subset
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                <$subset>::try_from(*element).is_ok()
+            }
+        }
+        )+)*
+    }
+);
+
+macro_rules! impl_bool_subset_of_int(
+    ($($superset: ty),+) => {
+        $(
+        impl SubsetOf<$superset> for bool {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> bool {
+                *element != 0
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                *element == 0 || *element == 1
+            }
+        }
+        )+
+    }
+);
+
+macro_rules! impl_char_subset_of_int(
+    ($($superset: ty),+) => {
+        $(
+        impl SubsetOf<$superset> for char {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> char {
+                char::from_u32_unchecked(*element as u32)
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                u32::try_from(*element).ok().and_then(char::from_u32).is_some()
+            }
+        }
+        )+
+    }
+);
+
+macro_rules! impl_int_subset_of_float(
+    ($($subset: ty as $( $superset: ty),+ );* $(;)*) => {
+        $($(
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                *element as $subset
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                // NOTE: both bounds are powers of two (or zero) so they are computed exactly.
+                let min = <$subset>::MIN as $superset;
+                let sup = (<$subset>::MAX / 2 + 1) as $superset * 2.0;
+
+                // NOTE: this also rejects NaN and infinities since their fractional part is NaN.
+                Float::fract(*element) == 0.0 && *element >= min && *element < sup
+            }
+        }
+        )+)*
+    }
+);
+
 impl_subset!(
+    bool as bool;
+    char as char;
+
+    f32 as f32, f64;
+    f64 as f32, f64;
+);
+
+impl_int_subset_of_int!(
+    u8    as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u16   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u32   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u64   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u128  as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    usize as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+
+    i8    as i8, i16, i32, i64, i128, isize;
+    i16   as i8, i16, i32, i64, i128, isize;
+    i32   as i8, i16, i32, i64, i128, isize;
+    i64   as i8, i16, i32, i64, i128, isize;
+    i128  as i8, i16, i32, i64, i128, isize;
+    isize as i8, i16, i32, i64, i128, isize;
+);
+
+impl_bool_subset_of_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_char_subset_of_int!(u32, u64, u128, i64, i128);
+
+impl_int_subset_of_float!(
+    u8    as f32, f64;
+    u16   as f32, f64;
+    u32   as f32, f64;
+    u64   as f32, f64;
+    u128  as f32, f64;
+    usize as f32, f64;
+
+    i8    as f32, f64;
+    i16   as f32, f64;
+    i32   as f32, f64;
+    i64   as f32, f64;
+    i128  as f32, f64;
+    isize as f32, f64;
+);
+
+/// A refinement of `SubsetOf` for inclusions that are exact.
+///
+/// Implementing this trait guarantees that `self.to_superset()` does not lose any information,
+/// i.e., `Self::from_superset(&self.to_superset()) == Some(self)` for every element of `Self`.
+/// For example, `u16` is an exact subset of `f32`, but `u64` is not because most `u64` values
+/// cannot be represented by an `f32`.
+pub trait ExactSubsetOf<T>: SubsetOf<T> {}
+
+macro_rules! impl_exact_subset(
+    ($($subset: ty as $( $superset: ty),+ );* $(;)*) => {
+        $($(
+        impl ExactSubsetOf<$superset> for $subset {}
+        )+)*
+    }
+);
+
+// NOTE: `usize` and `isize` are only assumed to hold at least 16 bits.
+impl_exact_subset!(
+    bool  as bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    char  as char, u32, u64, u128, i64, i128;
+
+    u8    as u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64;
+    u16   as u16, u32, u64, u128, usize, i32, i64, i128, f32, f64;
+    u32   as u32, u64, u128, i64, i128, f64;
+    u64   as u64, u128, i128;
+    u128  as u128;
+    usize as usize;
+
+    i8    as i8, i16, i32, i64, i128, isize, f32, f64;
+    i16   as i16, i32, i64, i128, isize, f32, f64;
+    i32   as i32, i64, i128, f64;
+    i64   as i64, i128;
+    i128  as i128;
+    isize as isize;
+
+    f32 as f32, f64;
+    f64 as f64;
+);
+
+/// Conversions between nested sets that never fail, at the cost of precision.
+///
+/// This is an escape hatch for code that does not care about representability: elements of the
+/// superset with no equivalent in `Self` are mapped to some element of `Self` (with the semantics
+/// of `as` casts for primitive types) instead of being rejected.
+pub trait LossySubsetOf<T>: Sized {
+    /// The inclusion map, possibly rounding `self` to the closest element of its superset.
+    fn to_superset_lossy(&self) -> T;
+
+    /// Converts `element` to some element of `Self`, possibly rounding or wrapping it.
+    fn from_superset_lossy(element: &T) -> Self;
+}
+
+macro_rules! impl_lossy_subset(
+    ($($subset: ty as $( $superset: ty),+ );* $(;)*) => {
+        $($(
+        impl LossySubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset_lossy(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            fn from_superset_lossy(element: &$superset) -> $subset {
+                *element as $subset
+            }
+        }
+        )+)*
+    }
+);
+
+impl_lossy_subset!(
     u8    as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
     u16   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
     u32   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
@@ -213,6 +499,41 @@ macro_rules! impl_scalar_subset_of_complex(
     )*}
 );
 
-impl_scalar_subset_of_complex!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_scalar_subset_of_complex!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 #[cfg(feature = "decimal")]
 impl_scalar_subset_of_complex!(d128);
+
+macro_rules! impl_exact_scalar_subset_of_complex(
+    ($($t: ident),*) => {$(
+        impl<N2: Zero + SupersetOf<$t>> ExactSubsetOf<Complex<N2>> for $t
+            where $t: ExactSubsetOf<N2> {}
+    )*}
+);
+
+impl_exact_scalar_subset_of_complex!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+// NOTE: this covers `f32` and `f64` and lets generic code promote any real to the complex field it
+// is working over.
+impl<N: RealField, N2: Zero + SupersetOf<N>> SubsetOf<Complex<N2>> for N {
+    #[inline]
+    fn to_superset(&self) -> Complex<N2> {
+        Complex {
+            re: N2::from_subset(self),
+            im: N2::zero(),
+        }
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Complex<N2>) -> N {
+        element.re.to_subset_unchecked()
+    }
+
+    #[inline]
+    fn is_in_subset(c: &Complex<N2>) -> bool {
+        c.re.is_in_subset() && c.im.is_zero()
+    }
+}
+
+impl<N: RealField + ExactSubsetOf<N2>, N2: Zero + SupersetOf<N>> ExactSubsetOf<Complex<N2>> for N {}
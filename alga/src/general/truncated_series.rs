@@ -0,0 +1,535 @@
+//! Formal power series truncated at a fixed order.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Field, Identity, Module, Multiplicative, PartialInverse, RingCommutative,
+    TwoSidedInverse,
+};
+
+/// A formal power series truncated at the order `N` (excluded):
+///
+/// ~~~notrust
+/// a₀ + a₁ x + a₂ x² + … + aₙ₋₁ xᴺ⁻¹ + O(xᴺ)
+/// ~~~
+///
+/// The truncated series form a commutative ring, where the terms of order `N` and above are
+/// discarded from the products. A series is invertible if and only if its constant term is, and
+/// may be composed with the series without constant term.
+///
+/// A series is also a higher-order automatic differentiation carrier: if `f` is computed by the
+/// ring operations and the functions of this module from `variable(x₀)`, the coefficient of order
+/// `k` of the result is `f⁽ᵏ⁾(x₀) / k!`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TruncatedSeries<R, const N: usize> {
+    coeffs: [R; N],
+}
+
+impl<R, const N: usize> TruncatedSeries<R, N> {
+    /// Creates a series from its coefficients, by increasing order.
+    #[inline]
+    pub const fn new(coeffs: [R; N]) -> Self {
+        TruncatedSeries { coeffs }
+    }
+
+    /// The coefficients of this series, by increasing order.
+    #[inline]
+    pub fn coefficients(&self) -> &[R; N] {
+        &self.coeffs
+    }
+
+    /// Consumes this series and returns its coefficients.
+    #[inline]
+    pub fn into_coefficients(self) -> [R; N] {
+        self.coeffs
+    }
+}
+
+impl<R: RingCommutative, const N: usize> TruncatedSeries<R, N> {
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> R>(f: F) -> Self {
+        TruncatedSeries::new(std::array::from_fn(f))
+    }
+
+    /// The constant series `c`.
+    #[inline]
+    pub fn constant(c: R) -> Self {
+        let mut c = Some(c);
+        Self::from_fn(|k| if k == 0 { c.take().unwrap() } else { R::zero() })
+    }
+
+    /// The series `x₀ + x`, i.e., the variable of a function expanded around `x₀`.
+    #[inline]
+    pub fn variable(x0: R) -> Self {
+        let mut res = Self::constant(x0);
+
+        if N > 1 {
+            res.coeffs[1] = R::one();
+        }
+
+        res
+    }
+
+    /// The coefficient of order `k`, which is zero if `k` is above the truncation order.
+    #[inline]
+    pub fn coefficient(&self, k: usize) -> R {
+        self.coeffs.get(k).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// The formal derivative of this series.
+    ///
+    /// The coefficient of order `N - 1` of the result is unknown, and set to zero.
+    #[inline]
+    pub fn derivative(&self) -> Self {
+        Self::from_fn(|k| {
+            if k + 1 < N {
+                self.coeffs[k + 1].clone() * multiple(k + 1)
+            } else {
+                R::zero()
+            }
+        })
+    }
+
+    /// The composition `self ∘ inner`, or `None` if the constant term of `inner` is not zero.
+    pub fn compose(&self, inner: &Self) -> Option<Self> {
+        if N > 0 && !inner.coeffs[0].is_zero() {
+            return None;
+        }
+
+        // Horner's scheme.
+        let mut res = Self::zero();
+
+        for a in self.coeffs.iter().rev() {
+            res = res * inner.clone() + Self::constant(a.clone());
+        }
+
+        Some(res)
+    }
+}
+
+impl<R: Field, const N: usize> TruncatedSeries<R, N> {
+    // The inverse of `n` in `R`, or `None` if it is zero, i.e., if `R` has a nonzero
+    // characteristic that divides `n`.
+    #[inline]
+    fn inverse_of(n: usize) -> Option<R> {
+        let n: R = multiple(n);
+
+        if n.is_zero() {
+            None
+        } else {
+            Some(R::one() / n)
+        }
+    }
+
+    /// The compositional inverse of this series, i.e., the series `g` such that
+    /// `self ∘ g = g ∘ self = x`.
+    ///
+    /// Returns `None` if the constant term of `self` is not zero, or if its term of order one is.
+    pub fn reversion(&self) -> Option<Self> {
+        if N < 2 {
+            return if N == 1 && self.coeffs[0].is_zero() {
+                Some(self.clone())
+            } else {
+                None
+            };
+        }
+
+        if !self.coeffs[0].is_zero() || self.coeffs[1].is_zero() {
+            return None;
+        }
+
+        let inv_a1 = R::one() / self.coeffs[1].clone();
+        let mut res = Self::zero();
+        res.coeffs[1] = inv_a1.clone();
+
+        // The coefficient of order `n` of `self ∘ res` is `a₁ bₙ` plus terms that only involve the
+        // lower coefficients of `res`.
+        for n in 2..N {
+            let err = self.compose(&res)?.coeffs[n].clone();
+            res.coeffs[n] = -(err * inv_a1.clone());
+        }
+
+        Some(res)
+    }
+
+    /// The exponential of this series.
+    ///
+    /// Returns `None` if the constant term is not zero, since its exponential is not defined in a
+    /// general field. For a constant term `c`, `exp(self) = exp(c) exp(self - c)`.
+    pub fn exp(&self) -> Option<Self> {
+        if N == 0 {
+            return Some(self.clone());
+        }
+
+        if !self.coeffs[0].is_zero() {
+            return None;
+        }
+
+        // b' = a' b, hence n bₙ = Σ k aₖ bₙ₋ₖ.
+        let mut res = Self::zero();
+        res.coeffs[0] = R::one();
+
+        for n in 1..N {
+            let sum = (1..=n).fold(R::zero(), |acc, k| {
+                acc + multiple::<R>(k) * self.coeffs[k].clone() * res.coeffs[n - k].clone()
+            });
+            res.coeffs[n] = sum * Self::inverse_of(n)?;
+        }
+
+        Some(res)
+    }
+
+    /// The natural logarithm of this series.
+    ///
+    /// Returns `None` if the constant term is not one, since its logarithm is not defined in a
+    /// general field. For a constant term `c`, `ln(self) = ln(c) + ln(self / c)`.
+    pub fn ln(&self) -> Option<Self> {
+        if N == 0 {
+            return Some(self.clone());
+        }
+
+        if !self.coeffs[0].is_one() {
+            return None;
+        }
+
+        // a b' = a', hence n bₙ = n aₙ - Σ k bₖ aₙ₋ₖ for 0 < k < n.
+        let mut res = Self::zero();
+
+        for n in 1..N {
+            let sum = (1..n).fold(R::zero(), |acc, k| {
+                acc + multiple::<R>(k) * res.coeffs[k].clone() * self.coeffs[n - k].clone()
+            });
+            res.coeffs[n] = self.coeffs[n].clone() - sum * Self::inverse_of(n)?;
+        }
+
+        Some(res)
+    }
+
+    /// The square root of this series with a constant term equal to one.
+    ///
+    /// Returns `None` if the constant term is not one, since square roots are not defined in a
+    /// general field. For a constant term `c`, `sqrt(self) = sqrt(c) sqrt(self / c)`.
+    pub fn sqrt(&self) -> Option<Self> {
+        if N == 0 {
+            return Some(self.clone());
+        }
+
+        if !self.coeffs[0].is_one() {
+            return None;
+        }
+
+        // b² = a, hence 2 bₙ = aₙ - Σ bₖ bₙ₋ₖ for 0 < k < n.
+        let inv_2 = Self::inverse_of(2)?;
+        let mut res = Self::zero();
+        res.coeffs[0] = R::one();
+
+        for n in 1..N {
+            let sum = (1..n).fold(R::zero(), |acc, k| {
+                acc + res.coeffs[k].clone() * res.coeffs[n - k].clone()
+            });
+            res.coeffs[n] = (self.coeffs[n].clone() - sum) * inv_2.clone();
+        }
+
+        Some(res)
+    }
+}
+
+// The sum of `n` copies of the unit of `R`.
+#[inline]
+fn multiple<R: RingCommutative>(n: usize) -> R {
+    (0..n).fold(R::zero(), |acc, _| acc + R::one())
+}
+
+impl<R: fmt::Display, const N: usize> fmt::Display for TruncatedSeries<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (k, a) in self.coeffs.iter().enumerate() {
+            match k {
+                0 => write!(f, "{}", a)?,
+                1 => write!(f, " + {}x", a)?,
+                _ => write!(f, " + {}x^{}", a, k)?,
+            }
+        }
+
+        if N == 0 {
+            write!(f, "O(1)")
+        } else {
+            write!(f, " + O(x^{})", N)
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Add for TruncatedSeries<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|k| self.coeffs[k].clone() + rhs.coeffs[k].clone())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Sub for TruncatedSeries<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|k| self.coeffs[k].clone() - rhs.coeffs[k].clone())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Neg for TruncatedSeries<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|k| -self.coeffs[k].clone())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Mul for TruncatedSeries<R, N> {
+    type Output = Self;
+
+    /// The product of the series, truncated at the order `N`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_fn(|n| {
+            (0..=n).fold(R::zero(), |acc, k| {
+                acc + self.coeffs[k].clone() * rhs.coeffs[n - k].clone()
+            })
+        })
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Mul<R> for TruncatedSeries<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: R) -> Self {
+        Self::from_fn(|k| self.coeffs[k].clone() * rhs.clone())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AddAssign for TruncatedSeries<R, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a += b;
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> SubAssign for TruncatedSeries<R, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a -= b;
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> MulAssign for TruncatedSeries<R, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<R: RingCommutative, const N: usize> MulAssign<R> for TruncatedSeries<R, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: R) {
+        for a in self.coeffs.iter_mut() {
+            *a *= rhs.clone();
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Zero for TruncatedSeries<R, N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from_fn(|_| R::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|a| a.is_zero())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> One for TruncatedSeries<R, N> {
+    #[inline]
+    fn one() -> Self {
+        Self::constant(R::one())
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AbstractMagma<Additive> for TruncatedSeries<R, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        *self += right.clone()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AbstractMagma<Multiplicative> for TruncatedSeries<R, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() * right.clone()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> TwoSidedInverse<Additive> for TruncatedSeries<R, N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Identity<Additive> for TruncatedSeries<R, N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Identity<Multiplicative> for TruncatedSeries<R, N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AbstractSemigroup<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractQuasigroup<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractMonoid<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractLoop<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractGroup<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractGroupAbelian<Additive> for TruncatedSeries<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractSemigroup<Multiplicative>
+    for TruncatedSeries<R, N>
+{
+}
+impl<R: RingCommutative, const N: usize> AbstractMonoid<Multiplicative> for TruncatedSeries<R, N> {}
+
+impl<R: RingCommutative, const N: usize> AbstractRing for TruncatedSeries<R, N> {
+    #[inline]
+    fn characteristic() -> u64 {
+        if N == 0 {
+            1
+        } else {
+            R::characteristic()
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AbstractRingCommutative for TruncatedSeries<R, N> {}
+
+impl<R: RingCommutative, const N: usize> AbstractModule for TruncatedSeries<R, N> {
+    type AbstractRing = R;
+
+    #[inline]
+    fn multiply_by(&self, r: R) -> Self {
+        self.clone() * r
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Module for TruncatedSeries<R, N> {
+    type Ring = R;
+}
+
+impl<R: Field, const N: usize> PartialInverse<Multiplicative> for TruncatedSeries<R, N> {
+    /// The multiplicative inverse of this series, which exists if and only if its constant term is
+    /// not zero.
+    fn partial_inverse(&self) -> Option<Self> {
+        if N == 0 {
+            return Some(self.clone());
+        }
+
+        if self.coeffs[0].is_zero() {
+            return None;
+        }
+
+        // a b = 1, hence a₀ bₙ = -Σ aₖ bₙ₋ₖ for 0 < k ≤ n.
+        let inv_a0 = R::one() / self.coeffs[0].clone();
+        let mut res = Self::zero();
+        res.coeffs[0] = inv_a0.clone();
+
+        for n in 1..N {
+            let sum = (1..=n).fold(R::zero(), |acc, k| {
+                acc + self.coeffs[k].clone() * res.coeffs[n - k].clone()
+            });
+            res.coeffs[n] = -(sum * inv_a0.clone());
+        }
+
+        Some(res)
+    }
+}
+
+impl<R: AbsDiffEq, const N: usize> AbsDiffEq for TruncatedSeries<R, N>
+where
+    R::Epsilon: Clone,
+{
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        R::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.coeffs
+            .iter()
+            .zip(other.coeffs.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<R: RelativeEq, const N: usize> RelativeEq for TruncatedSeries<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        R::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.coeffs
+            .iter()
+            .zip(other.coeffs.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<R: UlpsEq, const N: usize> UlpsEq for TruncatedSeries<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.coeffs
+            .iter()
+            .zip(other.coeffs.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
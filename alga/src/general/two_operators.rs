@@ -4,9 +4,9 @@ use decimal::d128;
 use num::Num;
 use num_complex::Complex;
 
-use crate::general::wrapper::Wrapper as W;
 use crate::general::{
-    AbstractGroupAbelian, AbstractMonoid, Additive, ClosedNeg, Multiplicative, Operator,
+    AbstractGroupAbelian, AbstractMagma, AbstractMonoid, Additive, ClosedNeg, Identity,
+    Multiplicative, Operator,
 };
 
 /// A **ring** is the combination of an Abelian group and a multiplicative monoid structure.
@@ -30,21 +30,124 @@ use crate::general::{
 pub trait AbstractRing<A: Operator = Additive, M: Operator = Multiplicative>:
     AbstractGroupAbelian<A> + AbstractMonoid<M>
 {
+    /// The characteristic of the ring, i.e., the smallest `n > 0` such that adding the
+    /// multiplicative identity `n` times to itself yields the additive identity, or zero if there is
+    /// no such `n`.
+    ///
+    /// The default implementation returns zero, so rings with a nonzero characteristic must
+    /// override it.
+    #[inline]
+    fn characteristic() -> u64 {
+        0
+    }
+
+    /// Returns `true` if `characteristic` is the additive order of the multiplicative identity.
+    /// Always returns `true` in characteristic zero, which cannot be checked in finite time.
+    fn prop_characteristic_is_additive_order_of_one() -> bool
+    where
+        Self: Eq,
+    {
+        let n = Self::characteristic();
+
+        if n == 0 {
+            return true;
+        }
+
+        let one: Self = Identity::<M>::identity();
+        let zero: Self = Identity::<A>::identity();
+
+        if multiple::<A, _>(&one, n) != zero {
+            return false;
+        }
+
+        // The order is exactly `n` if no `n / p` annihilates the identity, for `p` a prime factor
+        // of `n`.
+        let mut m = n;
+        let mut p = 2;
+
+        while p <= m / p {
+            if m % p == 0 {
+                if multiple::<A, _>(&one, n / p) == zero {
+                    return false;
+                }
+
+                while m % p == 0 {
+                    m /= p;
+                }
+            }
+
+            p += 1;
+        }
+
+        m == 1 || multiple::<A, _>(&one, n / m) != zero
+    }
+
     /// Returns `true` if the multiplication and addition operators are distributive for
     /// the given argument tuple. Approximate equality is used for verifications.
+    // NOTE: this does not forward to `prop_mul_and_add_are_distributive_approx_with`, which would
+    // require `Self::Epsilon: Clone`.
     fn prop_mul_and_add_are_distributive_approx(args: (Self, Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
         let (a, b, c) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
-        let c = || W::<_, A, M>::new(c.clone());
+        let b_plus_c = AbstractMagma::<A>::operate(&b, &c);
+        let mut ab = AbstractMagma::<M>::operate(&a, &b);
+        let mut ac = AbstractMagma::<M>::operate(&a, &c);
+        let mut lhs = AbstractMagma::<M>::operate(&a, &b_plus_c);
+        let mut rhs = AbstractMagma::<A>::operate(&ab, &ac);
+
+        // Left distributivity
+        let left = relative_eq!(lhs, rhs);
+
+        // Right distributivity
+        AbstractMagma::<M>::operate_into(&b_plus_c, &a, &mut lhs);
+        AbstractMagma::<M>::operate_into(&b, &a, &mut ab);
+        AbstractMagma::<M>::operate_into(&c, &a, &mut ac);
+        AbstractMagma::<A>::operate_into(&ab, &ac, &mut rhs);
+
+        left && relative_eq!(lhs, rhs)
+    }
+
+    /// Returns `true` if the multiplication and addition operators are distributive for the given
+    /// argument tuple. Approximate equality, up to the given `epsilon` and `max_relative`
+    /// tolerances, is used for verifications.
+    fn prop_mul_and_add_are_distributive_approx_with(
+        args: (Self, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b, c) = args;
+        let b_plus_c = AbstractMagma::<A>::operate(&b, &c);
+        let mut ab = AbstractMagma::<M>::operate(&a, &b);
+        let mut ac = AbstractMagma::<M>::operate(&a, &c);
+        let mut lhs = AbstractMagma::<M>::operate(&a, &b_plus_c);
+        let mut rhs = AbstractMagma::<A>::operate(&ab, &ac);
 
         // Left distributivity
-        relative_eq!(a() * (b() + c()), a() * b() + a() * c()) &&
+        let left = relative_eq!(
+            lhs,
+            rhs,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        );
+
         // Right distributivity
-        relative_eq!((b() + c()) * a(), b() * a() + c() * a())
+        AbstractMagma::<M>::operate_into(&b_plus_c, &a, &mut lhs);
+        AbstractMagma::<M>::operate_into(&b, &a, &mut ab);
+        AbstractMagma::<M>::operate_into(&c, &a, &mut ac);
+        AbstractMagma::<A>::operate_into(&ab, &ac, &mut rhs);
+
+        left && relative_eq!(
+            lhs,
+            rhs,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
     }
 
     /// Returns `true` if the multiplication and addition operators are distributive for
@@ -54,15 +157,40 @@ pub trait AbstractRing<A: Operator = Additive, M: Operator = Multiplicative>:
         Self: Eq,
     {
         let (a, b, c) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
-        let c = || W::<_, A, M>::new(c.clone());
+        let b_plus_c = AbstractMagma::<A>::operate(&b, &c);
+        let mut ab = AbstractMagma::<M>::operate(&a, &b);
+        let mut ac = AbstractMagma::<M>::operate(&a, &c);
+        let mut lhs = AbstractMagma::<M>::operate(&a, &b_plus_c);
+        let mut rhs = AbstractMagma::<A>::operate(&ab, &ac);
 
         // Left distributivity
-        a() * (b() + c()) == (a() * b()) + (a() * c()) &&
+        let left = lhs == rhs;
+
         // Right distributivity
-        (b() + c()) * a() == (b() * a()) + (c() * a())
+        AbstractMagma::<M>::operate_into(&b_plus_c, &a, &mut lhs);
+        AbstractMagma::<M>::operate_into(&b, &a, &mut ab);
+        AbstractMagma::<M>::operate_into(&c, &a, &mut ac);
+        AbstractMagma::<A>::operate_into(&ab, &ac, &mut rhs);
+
+        left && lhs == rhs
+    }
+}
+
+/// The element `a` operated `k` times with itself, i.e., the identity element if `k` is zero.
+fn multiple<O: Operator, T: AbstractMonoid<O>>(a: &T, mut k: u64) -> T {
+    let mut base = a.clone();
+    let mut result = T::identity();
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result.operate_assign(&base);
+        }
+
+        base = base.operate(&base);
+        k >>= 1;
     }
+
+    result
 }
 
 /// Implements the ring trait for types provided.
@@ -132,14 +260,32 @@ pub trait AbstractRingCommutative<A: Operator = Additive, M: Operator = Multipli
     /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
     /// Approximate equality is used for verifications.
     fn prop_mul_is_commutative_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_mul_is_commutative_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+    /// Approximate equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_mul_is_commutative_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
     where
         Self: RelativeEq,
     {
         let (a, b) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
+        let ab = AbstractMagma::<M>::operate(&a, &b);
+        let ba = AbstractMagma::<M>::operate(&b, &a);
 
-        relative_eq!(a() * b(), b() * a())
+        relative_eq!(ab, ba, epsilon = epsilon, max_relative = max_relative)
     }
 
     /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
@@ -148,10 +294,10 @@ pub trait AbstractRingCommutative<A: Operator = Additive, M: Operator = Multipli
         Self: Eq,
     {
         let (a, b) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
+        let ab = AbstractMagma::<M>::operate(&a, &b);
+        let ba = AbstractMagma::<M>::operate(&b, &a);
 
-        a() * b() == b() * a()
+        ab == ba
     }
 }
 
@@ -284,6 +430,11 @@ impl_field!(<Additive, Multiplicative> for f32; f64);
 #[cfg(feature = "decimal")]
 impl_field!(<Additive, Multiplicative> for d128);
 
-impl<N: Num + Clone + ClosedNeg + AbstractRing> AbstractRing for Complex<N> {}
+impl<N: Num + Clone + ClosedNeg + AbstractRing> AbstractRing for Complex<N> {
+    #[inline]
+    fn characteristic() -> u64 {
+        N::characteristic()
+    }
+}
 impl<N: Num + Clone + ClosedNeg + AbstractRingCommutative> AbstractRingCommutative for Complex<N> {}
 impl<N: Num + Clone + ClosedNeg + AbstractField> AbstractField for Complex<N> {}
@@ -0,0 +1,286 @@
+//! Valuations measuring the size of ring elements.
+
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::general::{
+    AbstractMagma, AbstractMonoid, Additive, Identity, Multiplicative, Operator, SignedAlgebra,
+};
+#[cfg(feature = "alloc")]
+use crate::general::{MultivariatePolynomial, RingCommutative};
+
+/// A valuation of the ring `R`, i.e., a multiplicative measure of the size of its elements.
+///
+/// The sizes of the nonzero elements live in an ordered monoid, the `Value`, and the zero element
+/// is smaller than every other one. The size of a product is the product of the sizes, for the
+/// operator of the monoid:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ R, v(a × b) = v(a) ∘ v(b)
+/// ~~~
+///
+/// The valuations are normalized so that the larger the value, the larger the element:
+///
+/// * the modulus of numbers has values in the multiplicative monoid of the nonnegative reals,
+/// * the degree of polynomials has values in the additive monoid of the natural numbers,
+/// * the p-adic valuation is represented by the logarithm of the p-adic absolute value, i.e., by
+///   `-vₚ(a)`, in the additive group of the integers.
+///
+/// The behavior of the valuation with respect to the addition is selected by implementing one of
+/// the marker traits `ArchimedeanValuation` or `UltrametricValuation`.
+pub trait Valuation<R> {
+    /// The operator of the monoid of values.
+    type Operator: Operator;
+    /// The values of the nonzero elements.
+    type Value: AbstractMonoid<Self::Operator> + PartialOrd;
+
+    /// The value of `element`, or `None` if it is zero.
+    fn value(element: &R) -> Option<Self::Value>;
+
+    /// Returns `true` if the value of the product of the given arguments is the product of their
+    /// values.
+    fn prop_is_multiplicative(args: (R, R)) -> bool
+    where
+        R: AbstractMagma<Multiplicative>,
+    {
+        let (a, b) = args;
+        let ab = Self::value(&AbstractMagma::<Multiplicative>::operate(&a, &b));
+
+        match (Self::value(&a), Self::value(&b)) {
+            (Some(va), Some(vb)) => ab == Some(AbstractMagma::<Self::Operator>::operate(&va, &vb)),
+            _ => ab.is_none(),
+        }
+    }
+
+    /// Returns `true` if the value of the product of the given arguments is the product of their
+    /// values. Approximate equality is used for verifications.
+    fn prop_is_multiplicative_approx(args: (R, R)) -> bool
+    where
+        R: AbstractMagma<Multiplicative>,
+        Self::Value: RelativeEq,
+    {
+        Self::prop_is_multiplicative_approx_with(
+            args,
+            <Self::Value as AbsDiffEq>::default_epsilon(),
+            <Self::Value as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the value of the product of the given arguments is the product of their
+    /// values. Approximate equality, up to the given `epsilon` and `max_relative` tolerances, is
+    /// used for verifications.
+    fn prop_is_multiplicative_approx_with(
+        args: (R, R),
+        epsilon: <Self::Value as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Value as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        R: AbstractMagma<Multiplicative>,
+        Self::Value: RelativeEq,
+    {
+        let (a, b) = args;
+        let ab = Self::value(&AbstractMagma::<Multiplicative>::operate(&a, &b));
+
+        match (Self::value(&a), Self::value(&b), ab) {
+            (Some(va), Some(vb), Some(vab)) => relative_eq!(
+                vab,
+                AbstractMagma::<Self::Operator>::operate(&va, &vb),
+                epsilon = epsilon,
+                max_relative = max_relative
+            ),
+            (Some(_), Some(_), None) => false,
+            (_, _, vab) => vab.is_none(),
+        }
+    }
+}
+
+/// A valuation satisfying the triangle inequality, for the addition of the values.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ R, v(a + b) ≤ v(a) + v(b)
+/// ~~~
+pub trait ArchimedeanValuation<R>: Valuation<R> {
+    /// Returns `true` if the triangle inequality holds for the given arguments.
+    fn prop_triangle_inequality(args: (R, R)) -> bool
+    where
+        R: AbstractMagma<Additive>,
+        Self::Value: AbstractMagma<Additive>,
+    {
+        let (a, b) = args;
+        let lhs = Self::value(&AbstractMagma::<Additive>::operate(&a, &b));
+
+        lhs <= sum_of_values(Self::value(&a), Self::value(&b))
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+    /// equality is used for verifications.
+    fn prop_triangle_inequality_approx(args: (R, R)) -> bool
+    where
+        R: AbstractMagma<Additive>,
+        Self::Value: AbstractMagma<Additive> + RelativeEq,
+    {
+        Self::prop_triangle_inequality_approx_with(
+            args,
+            <Self::Value as AbsDiffEq>::default_epsilon(),
+            <Self::Value as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+    /// equality, up to the given `epsilon` and `max_relative` tolerances, is used for
+    /// verifications.
+    fn prop_triangle_inequality_approx_with(
+        args: (R, R),
+        epsilon: <Self::Value as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Value as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        R: AbstractMagma<Additive>,
+        Self::Value: AbstractMagma<Additive> + RelativeEq,
+    {
+        let (a, b) = args;
+        let lhs = Self::value(&AbstractMagma::<Additive>::operate(&a, &b));
+        let rhs = sum_of_values(Self::value(&a), Self::value(&b));
+
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => {
+                lhs <= rhs || relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+            }
+            (lhs, rhs) => lhs <= rhs,
+        }
+    }
+}
+
+/// A valuation satisfying the ultrametric inequality.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ R, v(a + b) ≤ max(v(a), v(b))
+/// ~~~
+pub trait UltrametricValuation<R>: Valuation<R> {
+    /// Returns `true` if the ultrametric inequality holds for the given arguments.
+    fn prop_ultrametric_inequality(args: (R, R)) -> bool
+    where
+        R: AbstractMagma<Additive>,
+    {
+        let (a, b) = args;
+        let lhs = Self::value(&AbstractMagma::<Additive>::operate(&a, &b));
+        let (va, vb) = (Self::value(&a), Self::value(&b));
+
+        lhs <= va || lhs <= vb
+    }
+}
+
+// NOTE: the value of zero is the identity of the addition of values.
+#[inline]
+fn sum_of_values<V: AbstractMagma<Additive>>(a: Option<V>, b: Option<V>) -> Option<V> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(AbstractMagma::<Additive>::operate(&a, &b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/*
+ *
+ * Implementations.
+ *
+ */
+/// The archimedean valuation given by the absolute value of `SignedAlgebra`, e.g., the modulus of
+/// complex numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Modulus;
+
+impl<R: SignedAlgebra> Valuation<R> for Modulus {
+    type Operator = Multiplicative;
+    type Value = R::Magnitude;
+
+    #[inline]
+    fn value(element: &R) -> Option<R::Magnitude> {
+        let abs = element.abs();
+
+        if abs == Identity::<Additive>::identity() {
+            None
+        } else {
+            Some(abs)
+        }
+    }
+}
+
+impl<R: SignedAlgebra> ArchimedeanValuation<R> for Modulus {}
+
+/// The ultrametric valuation given by the total degree of polynomials.
+///
+/// It is only multiplicative for polynomials with coefficients in an integral domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Degree;
+
+#[cfg(feature = "alloc")]
+impl<R: RingCommutative> Valuation<MultivariatePolynomial<R>> for Degree {
+    type Operator = Additive;
+    type Value = u32;
+
+    #[inline]
+    fn value(element: &MultivariatePolynomial<R>) -> Option<u32> {
+        element.total_degree()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: RingCommutative> UltrametricValuation<MultivariatePolynomial<R>> for Degree {}
+
+/// The ultrametric `P`-adic valuation of the integers.
+///
+/// Its value is the logarithm in base `P` of the `P`-adic absolute value `|a|ₚ = P^-vₚ(a)`, i.e.,
+/// the opposite of the exponent `vₚ(a)` of the largest power of `P` dividing `a`, which is given
+/// by `order`.
+///
+/// `P` must be a prime. Whether it is at least two is checked at compile-time, but its primality
+/// is not checked: the valuation is not multiplicative for a composite `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PAdicValuation<const P: u64>;
+
+impl<const P: u64> PAdicValuation<P> {
+    /// `P`, checked to be at least two.
+    const PRIME: u64 = {
+        assert!(
+            P >= 2,
+            "The prime of a p-adic valuation must be at least two."
+        );
+        P
+    };
+
+    /// The exponent `vₚ(n)` of the largest power of `P` dividing `n`, or `None` if `n` is zero.
+    #[inline]
+    pub fn order(n: u128) -> Option<u32> {
+        let p = Self::PRIME as u128;
+        let (mut n, mut order) = (n, 0);
+
+        if n == 0 {
+            return None;
+        }
+
+        while n % p == 0 {
+            n /= p;
+            order += 1;
+        }
+
+        Some(order)
+    }
+}
+
+macro_rules! impl_padic_valuation(
+    ($($T:ty),* $(,)*) => {$(
+        impl<const P: u64> Valuation<$T> for PAdicValuation<P> {
+            type Operator = Additive;
+            type Value = i32;
+
+            #[inline]
+            fn value(element: &$T) -> Option<i32> {
+                Self::order(element.unsigned_abs() as u128).map(|order| -(order as i32))
+            }
+        }
+
+        impl<const P: u64> UltrametricValuation<$T> for PAdicValuation<P> {}
+    )*}
+);
+
+impl_padic_valuation!(i8, i16, i32, i64, i128, isize);
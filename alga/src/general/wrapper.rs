@@ -2,13 +2,17 @@
 
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt::{Display, Error, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 use crate::general::AbstractMagma;
 use crate::general::AbstractQuasigroup;
+use crate::general::CanonicalHash;
 use crate::general::{Operator, TwoSidedInverse};
 
 /// Wrapper that allows to use operators on algebraic types.
@@ -39,8 +43,21 @@ impl<T: PartialEq, A, M> PartialEq for Wrapper<T, A, M> {
     }
 }
 
+impl<T: Hash, A, M> Hash for Wrapper<T, A, M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.val.hash(state)
+    }
+}
+
+impl<T: CanonicalHash, A, M> CanonicalHash for Wrapper<T, A, M> {
+    #[inline]
+    fn canonical_hash<H: Hasher>(&self, state: &mut H) {
+        self.val.canonical_hash(state)
+    }
+}
+
 impl<T, A, M> Wrapper<T, A, M> {
-    pub fn new(val: T) -> Self {
+    pub const fn new(val: T) -> Self {
         Wrapper {
             val,
             _add: PhantomData,
@@ -49,6 +66,14 @@ impl<T, A, M> Wrapper<T, A, M> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<T: Zeroize, A, M> Zeroize for Wrapper<T, A, M> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.val.zeroize()
+    }
+}
+
 impl<T: Display, A: Operator, M: Operator> Display for Wrapper<T, A, M> {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         self.val.fmt(fmt)
@@ -106,7 +131,7 @@ where
 
     #[inline]
     fn add(self, lhs: Self) -> Self {
-        Wrapper::new(self.val.operate(&lhs.val))
+        Wrapper::new(self.val.operate_owned(lhs.val))
     }
 }
 
@@ -143,7 +168,7 @@ where
 
     #[inline]
     fn mul(self, lhs: Self) -> Self {
-        Wrapper::new(self.val.operate(&lhs.val))
+        Wrapper::new(self.val.operate_owned(lhs.val))
     }
 }
 
@@ -155,6 +180,25 @@ where
     fn two_sided_inverse(&self) -> Self {
         Wrapper::new(self.val.two_sided_inverse())
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        self.val.try_two_sided_inverse().map(Wrapper::new)
+    }
+}
+
+impl<T, A, M: Operator> Wrapper<T, A, M>
+where
+    T: AbstractQuasigroup<M>,
+{
+    /// Divides `self` by `rhs`, returning `None` if `rhs` is not invertible.
+    ///
+    /// The `/` operator does not check the divisor and gives whatever `two_sided_inverse` does,
+    /// e.g., an infinity for floats.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        rhs.try_two_sided_inverse().map(|inv| self * inv)
+    }
 }
 
 impl<T, A, M: Operator> Div<Wrapper<T, A, M>> for Wrapper<T, A, M>
@@ -42,6 +42,12 @@ extern crate num_traits as num;
 
 #[cfg(not(feature = "std"))]
 extern crate core as std;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+
+// NOTE: re-exported for the macros bridging alga and num-traits.
+#[doc(hidden)]
+pub use num_traits as __num;
 
 #[macro_use]
 mod macros;
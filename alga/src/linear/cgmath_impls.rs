@@ -0,0 +1,233 @@
+//! Implementations of the algebraic structures for the types of the `cgmath` crate.
+//!
+//! NOTE: the points of `cgmath` do not implement `Neg`, so they cannot be euclidean spaces, and
+//! the transformation traits (which act on euclidean spaces) cannot be implemented either. The
+//! rotations, matrices and decomposed transforms are given their multiplicative structure
+//! instead, so that they can still be used by code generic over groups and monoids.
+
+use cgmath::{
+    BaseFloat, Decomposed, Matrix2, Matrix3, Matrix4, Point3, Quaternion, Transform, Vector1,
+    Vector2, Vector3, Vector4,
+};
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, Identity, Module,
+    Multiplicative, RealField, SubsetOf, SupersetOf, TwoSidedInverse,
+};
+use crate::linear::vector::orthonormal_subspace_basis_from_canonical;
+use crate::linear::{
+    BanachSpace, ConstDimVectorSpace, FiniteDimInnerSpace, FiniteDimVectorSpace,
+    FiniteDimVectorSpaceMap, HilbertSpace, InnerSpace, LpNormedSpace, NormedSpace, VectorSpace,
+};
+
+macro_rules! impl_cgmath_vector(
+    ($($V:ident, $dim:expr);* $(;)*) => {$(
+        impl<S: RealField + BaseFloat> AbstractMagma<Additive> for $V<S> {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self + *right
+            }
+        }
+
+        impl<S: RealField + BaseFloat> TwoSidedInverse<Additive> for $V<S> {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                -*self
+            }
+        }
+
+        impl<S: RealField + BaseFloat> Identity<Additive> for $V<S> {
+            #[inline]
+            fn identity() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl<S: RealField + BaseFloat> AbstractSemigroup<Additive> for $V<S> {}
+        impl<S: RealField + BaseFloat> AbstractQuasigroup<Additive> for $V<S> {}
+        impl<S: RealField + BaseFloat> AbstractMonoid<Additive> for $V<S> {}
+        impl<S: RealField + BaseFloat> AbstractLoop<Additive> for $V<S> {}
+        impl<S: RealField + BaseFloat> AbstractGroup<Additive> for $V<S> {}
+        impl<S: RealField + BaseFloat> AbstractGroupAbelian<Additive> for $V<S> {}
+
+        impl<S: RealField + BaseFloat> AbstractModule for $V<S> {
+            type AbstractRing = S;
+
+            #[inline]
+            fn multiply_by(&self, r: S) -> Self {
+                *self * r
+            }
+        }
+
+        impl<S: RealField + BaseFloat> Module for $V<S> {
+            type Ring = S;
+        }
+
+        impl<S: RealField + BaseFloat> VectorSpace for $V<S> {
+            type Field = S;
+        }
+
+        impl<S: RealField + BaseFloat> FiniteDimVectorSpace for $V<S> {
+            #[inline]
+            fn dimension() -> usize {
+                $dim
+            }
+
+            #[inline]
+            fn canonical_basis_element(i: usize) -> Self {
+                assert!(i < $dim, "Canonical basis element index out of bounds.");
+                let mut res = Self::zero();
+                res[i] = S::one();
+                res
+            }
+
+            #[inline]
+            fn dot(&self, other: &Self) -> S {
+                cgmath::InnerSpace::dot(*self, *other)
+            }
+
+            #[inline]
+            unsafe fn component_unchecked(&self, i: usize) -> &S {
+                let components: &[S; $dim] = self.as_ref();
+                components.get_unchecked(i)
+            }
+
+            #[inline]
+            unsafe fn component_unchecked_mut(&mut self, i: usize) -> &mut S {
+                let components: &mut [S; $dim] = self.as_mut();
+                components.get_unchecked_mut(i)
+            }
+        }
+
+        impl<S: RealField + BaseFloat> ConstDimVectorSpace for $V<S> {
+            const DIM: usize = $dim;
+        }
+
+        impl<S: RealField + BaseFloat, S2: RealField + BaseFloat> FiniteDimVectorSpaceMap<S2> for $V<S> {
+            type Mapped = $V<S2>;
+
+            #[inline]
+            fn map_coords<F: FnMut(S) -> S2>(&self, f: F) -> $V<S2> {
+                self.map(f)
+            }
+        }
+
+        impl<S: RealField + BaseFloat, S2: RealField + BaseFloat + SupersetOf<S>> SubsetOf<$V<S2>> for $V<S> {
+            #[inline]
+            fn to_superset(&self) -> $V<S2> {
+                self.map(|c| S2::from_subset(&c))
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$V<S2>) -> $V<S> {
+                element.map(|c| <S2 as SupersetOf<S>>::to_subset_unchecked(&c))
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$V<S2>) -> bool {
+                let components: &[S2; $dim] = element.as_ref();
+                components.iter().all(<S2 as SupersetOf<S>>::is_in_subset)
+            }
+        }
+
+        impl<S: RealField + BaseFloat> NormedSpace for $V<S> {
+            type RealField = S;
+            type ComplexField = S;
+
+            #[inline]
+            fn norm_squared(&self) -> S {
+                cgmath::InnerSpace::magnitude2(*self)
+            }
+        }
+
+        impl<S: RealField + BaseFloat> InnerSpace for $V<S> {
+            #[inline]
+            fn inner_product(&self, other: &Self) -> S {
+                cgmath::InnerSpace::dot(*self, *other)
+            }
+        }
+
+        impl<S: RealField + BaseFloat> FiniteDimInnerSpace for $V<S> {
+            #[inline]
+            fn orthonormal_subspace_basis<F: FnMut(&Self) -> bool>(vs: &[Self], f: F) {
+                orthonormal_subspace_basis_from_canonical::<_, _, $dim>(vs, f)
+            }
+        }
+
+        impl<S: RealField + BaseFloat> LpNormedSpace for $V<S> {}
+        impl<S: RealField + BaseFloat> BanachSpace for $V<S> {}
+        impl<S: RealField + BaseFloat> HilbertSpace for $V<S> {}
+    )*}
+);
+
+impl_cgmath_vector!(Vector1, 1; Vector2, 2; Vector3, 3; Vector4, 4);
+
+macro_rules! impl_cgmath_multiplicative_monoid(
+    ($($T:ty),*) => {$(
+        impl<S: RealField + BaseFloat> AbstractMagma<Multiplicative> for $T {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self * *right
+            }
+        }
+
+        impl<S: RealField + BaseFloat> Identity<Multiplicative> for $T {
+            #[inline]
+            fn identity() -> Self {
+                Self::one()
+            }
+        }
+
+        impl<S: RealField + BaseFloat> AbstractSemigroup<Multiplicative> for $T {}
+        impl<S: RealField + BaseFloat> AbstractMonoid<Multiplicative> for $T {}
+    )*}
+);
+
+// NOTE: matrices are not invertible in general, so they only form a monoid.
+impl_cgmath_multiplicative_monoid!(Matrix2<S>, Matrix3<S>, Matrix4<S>, Quaternion<S>);
+
+impl<S: RealField + BaseFloat> TwoSidedInverse<Multiplicative> for Quaternion<S> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.conjugate() / cgmath::InnerSpace::magnitude2(*self)
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        let norm_squared = cgmath::InnerSpace::magnitude2(*self);
+
+        if norm_squared.is_zero() {
+            None
+        } else {
+            Some(self.conjugate() / norm_squared)
+        }
+    }
+}
+
+impl<S: RealField + BaseFloat> AbstractQuasigroup<Multiplicative> for Quaternion<S> {}
+impl<S: RealField + BaseFloat> AbstractLoop<Multiplicative> for Quaternion<S> {}
+impl<S: RealField + BaseFloat> AbstractGroup<Multiplicative> for Quaternion<S> {}
+
+/// The 3-dimensional similarity transformations, decomposed into a uniform scaling, a rotation
+/// and a translation.
+type Decomposed3<S> = Decomposed<Vector3<S>, Quaternion<S>>;
+
+// NOTE: the scaling factor may be zero, so decomposed transforms only form a monoid.
+impl<S: RealField + BaseFloat> AbstractMagma<Multiplicative> for Decomposed3<S> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Transform::<Point3<S>>::concat(self, right)
+    }
+}
+
+impl<S: RealField + BaseFloat> Identity<Multiplicative> for Decomposed3<S> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<S: RealField + BaseFloat> AbstractSemigroup<Multiplicative> for Decomposed3<S> {}
+impl<S: RealField + BaseFloat> AbstractMonoid<Multiplicative> for Decomposed3<S> {}
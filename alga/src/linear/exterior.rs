@@ -0,0 +1,162 @@
+use std::ops::{Add, Neg};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::general::RingCommutative;
+
+/// The exterior (aka. wedge) product, an antisymmetric bilinear product.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, a ∧ b = -(b ∧ a)
+/// ∀ a ∈ Self, a ∧ a = 0
+/// ~~~
+///
+/// The grade of the output is the sum of the grades of the operands, e.g., the wedge product of
+/// two vectors is a bivector.
+pub trait WedgeProduct<Rhs = Self>: Sized {
+    /// The result of the wedge product, with a grade equal to the sum of the grades of `Self` and
+    /// `Rhs`.
+    type Output;
+
+    /// Computes the wedge product `self ∧ rhs`.
+    fn wedge(&self, rhs: &Rhs) -> Self::Output;
+
+    /// Returns `true` if the wedge product is antisymmetric for the given argument tuple.
+    fn prop_wedge_is_antisymmetric(args: (Self, Self)) -> bool
+    where
+        Self: WedgeProduct<Self>,
+        <Self as WedgeProduct<Self>>::Output:
+            Neg<Output = <Self as WedgeProduct<Self>>::Output> + PartialEq,
+    {
+        let (a, b) = args;
+        let wedge = WedgeProduct::<Self>::wedge;
+
+        wedge(&a, &b) == -wedge(&b, &a) && wedge(&a, &a) == -wedge(&a, &a)
+    }
+}
+
+/// A bivector of the three-dimensional exterior algebra, expressed in the basis
+/// `(e₂ ∧ e₃, e₃ ∧ e₁, e₁ ∧ e₂)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bivector3<N> {
+    /// The component along `e₂ ∧ e₃`.
+    pub yz: N,
+    /// The component along `e₃ ∧ e₁`.
+    pub zx: N,
+    /// The component along `e₁ ∧ e₂`.
+    pub xy: N,
+}
+
+impl<N> Bivector3<N> {
+    /// Creates a new bivector from its components.
+    #[inline]
+    pub fn new(yz: N, zx: N, xy: N) -> Self {
+        Bivector3 { yz, zx, xy }
+    }
+}
+
+impl<N: Neg<Output = N>> Neg for Bivector3<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Bivector3::new(-self.yz, -self.zx, -self.xy)
+    }
+}
+
+impl<N: Add<Output = N>> Add for Bivector3<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Bivector3::new(self.yz + rhs.yz, self.zx + rhs.zx, self.xy + rhs.xy)
+    }
+}
+
+impl<N: AbsDiffEq> AbsDiffEq for Bivector3<N>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.yz.abs_diff_eq(&other.yz, epsilon.clone())
+            && self.zx.abs_diff_eq(&other.zx, epsilon.clone())
+            && self.xy.abs_diff_eq(&other.xy, epsilon)
+    }
+}
+
+impl<N: RelativeEq> RelativeEq for Bivector3<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.yz
+            .relative_eq(&other.yz, epsilon.clone(), max_relative.clone())
+            && self
+                .zx
+                .relative_eq(&other.zx, epsilon.clone(), max_relative.clone())
+            && self.xy.relative_eq(&other.xy, epsilon, max_relative)
+    }
+}
+
+impl<N: UlpsEq> UlpsEq for Bivector3<N>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.yz.ulps_eq(&other.yz, epsilon.clone(), max_ulps)
+            && self.zx.ulps_eq(&other.zx, epsilon.clone(), max_ulps)
+            && self.xy.ulps_eq(&other.xy, epsilon, max_ulps)
+    }
+}
+
+// NOTE: in two dimensions, bivectors are pseudo-scalars, i.e., multiples of `e₁ ∧ e₂`.
+impl<N: RingCommutative> WedgeProduct for [N; 2] {
+    type Output = N;
+
+    #[inline]
+    fn wedge(&self, rhs: &Self) -> N {
+        self[0].clone() * rhs[1].clone() - self[1].clone() * rhs[0].clone()
+    }
+}
+
+impl<N: RingCommutative> WedgeProduct for [N; 3] {
+    type Output = Bivector3<N>;
+
+    #[inline]
+    fn wedge(&self, rhs: &Self) -> Bivector3<N> {
+        let [x1, y1, z1] = self.clone();
+        let [x2, y2, z2] = rhs.clone();
+
+        Bivector3::new(
+            y1.clone() * z2.clone() - z1.clone() * y2.clone(),
+            z1 * x2.clone() - x1.clone() * z2,
+            x1 * y2 - y1 * x2,
+        )
+    }
+}
@@ -0,0 +1,229 @@
+use approx::{AbsDiffEq, RelativeEq};
+use num;
+
+use crate::linear::{InnerSpace, NormedSpace, VectorSpace};
+
+/// A map from two vectors to a scalar, linear in each of its arguments.
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ V, ∀ s ∈ V::Field, B(a * s + b, c) = B(a, c) * s + B(b, c)
+/// ∀ a, b, c ∈ V, ∀ s ∈ V::Field, B(c, a * s + b) = B(c, a) * s + B(c, b)
+/// ~~~
+pub trait BilinearForm<V: VectorSpace>: Sized {
+    /// Evaluates this bilinear form at `(a, b)`.
+    fn eval(&self, a: &V, b: &V) -> V::Field;
+
+    /// Returns `true` if this form is approximately linear in each argument for the given
+    /// argument tuple.
+    fn prop_is_bilinear_approx(args: (Self, V, V, V, V::Field)) -> bool
+    where
+        V::Field: RelativeEq,
+        <V::Field as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_is_bilinear_approx_with(
+            args,
+            <V::Field as AbsDiffEq>::default_epsilon(),
+            <V::Field as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this form is approximately linear in each argument for the given argument
+    /// tuple. The approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_is_bilinear_approx_with(
+        args: (Self, V, V, V, V::Field),
+        epsilon: <V::Field as AbsDiffEq>::Epsilon,
+        max_relative: <V::Field as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::Field: RelativeEq,
+        <V::Field as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (f, a, b, c, s) = args;
+        let ab = a.clone() * s.clone() + b.clone();
+
+        relative_eq!(
+            f.eval(&ab, &c),
+            f.eval(&a, &c) * s.clone() + f.eval(&b, &c),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            f.eval(&c, &ab),
+            f.eval(&c, &a) * s + f.eval(&c, &b),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if this form is linear in each argument for the given argument tuple.
+    fn prop_is_bilinear(args: (Self, V, V, V, V::Field)) -> bool
+    where
+        V::Field: Eq,
+    {
+        let (f, a, b, c, s) = args;
+        let ab = a.clone() * s.clone() + b.clone();
+
+        f.eval(&ab, &c) == f.eval(&a, &c) * s.clone() + f.eval(&b, &c)
+            && f.eval(&c, &ab) == f.eval(&c, &a) * s + f.eval(&c, &b)
+    }
+}
+
+/// A bilinear form invariant by permutation of its arguments.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ V, B(a, b) = B(b, a)
+/// ~~~
+pub trait SymmetricBilinearForm<V: VectorSpace>: BilinearForm<V> {
+    /// Returns `true` if this form is approximately symmetric for the given argument tuple.
+    fn prop_is_symmetric_approx(args: (Self, V, V)) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        Self::prop_is_symmetric_approx_with(
+            args,
+            <V::Field as AbsDiffEq>::default_epsilon(),
+            <V::Field as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this form is approximately symmetric for the given argument tuple. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_is_symmetric_approx_with(
+        args: (Self, V, V),
+        epsilon: <V::Field as AbsDiffEq>::Epsilon,
+        max_relative: <V::Field as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        let (f, a, b) = args;
+        relative_eq!(
+            f.eval(&a, &b),
+            f.eval(&b, &a),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if this form is symmetric for the given argument tuple.
+    fn prop_is_symmetric(args: (Self, V, V)) -> bool
+    where
+        V::Field: Eq,
+    {
+        let (f, a, b) = args;
+        f.eval(&a, &b) == f.eval(&b, &a)
+    }
+}
+
+/// A symmetric bilinear form that is strictly positive on every non-zero vector.
+///
+/// ~~~notrust
+/// ∀ a ∈ V, a ≠ 0 ⇒ B(a, a) > 0
+/// ~~~
+pub trait PositiveDefiniteForm<V: VectorSpace + PartialEq>: SymmetricBilinearForm<V>
+where
+    V::Field: PartialOrd,
+{
+    /// Returns `true` if this form is positive definite for the given argument tuple.
+    fn prop_is_positive_definite(args: (Self, V)) -> bool {
+        let (f, a) = args;
+        a == num::zero() || f.eval(&a, &a) > num::zero()
+    }
+}
+
+/// A map `Q` from vectors to scalars, homogeneous of degree two, and whose polar form is bilinear.
+///
+/// ~~~notrust
+/// ∀ a ∈ V, ∀ s ∈ V::Field, Q(a * s) = Q(a) * s * s
+/// ~~~
+pub trait QuadraticForm<V: VectorSpace>: Sized {
+    /// Evaluates this quadratic form at `v`.
+    fn quadratic(&self, v: &V) -> V::Field;
+
+    /// The polar form of this quadratic form, i.e., the unique symmetric bilinear form `B` such
+    /// that `B(v, v) = Q(v)`.
+    #[inline]
+    fn polar(&self, a: &V, b: &V) -> V::Field {
+        let two: V::Field = num::one::<V::Field>() + num::one();
+        (self.quadratic(&(a.clone() + b.clone())) - self.quadratic(a) - self.quadratic(b)) / two
+    }
+
+    /// Returns `true` if this form is approximately homogeneous of degree two for the given
+    /// argument tuple.
+    fn prop_is_homogeneous_approx(args: (Self, V, V::Field)) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        Self::prop_is_homogeneous_approx_with(
+            args,
+            <V::Field as AbsDiffEq>::default_epsilon(),
+            <V::Field as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this form is approximately homogeneous of degree two for the given
+    /// argument tuple. The approximate equalities use the given `epsilon` and `max_relative`
+    /// tolerances.
+    fn prop_is_homogeneous_approx_with(
+        args: (Self, V, V::Field),
+        epsilon: <V::Field as AbsDiffEq>::Epsilon,
+        max_relative: <V::Field as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        let (f, a, s) = args;
+        relative_eq!(
+            f.quadratic(&(a.clone() * s.clone())),
+            f.quadratic(&a) * s.clone() * s,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if this form is homogeneous of degree two for the given argument tuple.
+    fn prop_is_homogeneous(args: (Self, V, V::Field)) -> bool
+    where
+        V::Field: Eq,
+    {
+        let (f, a, s) = args;
+        f.quadratic(&(a.clone() * s.clone())) == f.quadratic(&a) * s.clone() * s
+    }
+}
+
+/// The inner product of an inner space seen as a bilinear form.
+///
+/// Only real inner spaces are supported since complex inner products are sesquilinear instead of
+/// bilinear.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InnerProduct;
+
+impl<V: InnerSpace<ComplexField = <V as NormedSpace>::RealField>> BilinearForm<V> for InnerProduct {
+    #[inline]
+    fn eval(&self, a: &V, b: &V) -> V::Field {
+        a.inner_product(b)
+    }
+}
+
+impl<V: InnerSpace<ComplexField = <V as NormedSpace>::RealField>> SymmetricBilinearForm<V>
+    for InnerProduct
+{
+}
+
+impl<V: InnerSpace<ComplexField = <V as NormedSpace>::RealField> + PartialEq>
+    PositiveDefiniteForm<V> for InnerProduct
+{
+}
+
+impl<V: InnerSpace<ComplexField = <V as NormedSpace>::RealField>> QuadraticForm<V>
+    for InnerProduct
+{
+    #[inline]
+    fn quadratic(&self, v: &V) -> V::Field {
+        v.norm_squared()
+    }
+
+    #[inline]
+    fn polar(&self, a: &V, b: &V) -> V::Field {
+        a.inner_product(b)
+    }
+}
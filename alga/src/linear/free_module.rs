@@ -0,0 +1,455 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::Zero;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, ComplexField, ConstIdentity,
+    Field, Identity, Module, RingCommutative, SubsetOf, SupersetOf, TwoSidedInverse,
+};
+use crate::linear::vector::orthonormal_subspace_basis_from_canonical;
+use crate::linear::{
+    BanachSpace, ConstDimVectorSpace, FiniteDimInnerSpace, FiniteDimVectorSpace,
+    FiniteDimVectorSpaceMap, HilbertSpace, InnerSpace, LpNormedSpace, NormedSpace, TensorProduct,
+    VectorSpace,
+};
+
+/// The free module `Rⁿ` of `N`-tuples of elements of the commutative ring `R`.
+///
+/// All the operations are performed componentwise. This is a vector space whenever `R` is a
+/// field, e.g., `FreeModule<f64, 3>` is the usual 3-dimensional real vector space while
+/// `FreeModule<i64, 3>` is the 3-dimensional integer lattice.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FreeModule<R, const N: usize>(pub [R; N]);
+
+impl<R, const N: usize> FreeModule<R, N> {
+    /// Creates a new element from its components.
+    #[inline]
+    pub fn new(components: [R; N]) -> Self {
+        FreeModule(components)
+    }
+
+    /// Creates a new element where the `i`-th component is given by `f(i)`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> R>(f: F) -> Self {
+        FreeModule(std::array::from_fn(f))
+    }
+
+    /// The components of this element.
+    #[inline]
+    pub fn as_array(&self) -> &[R; N] {
+        &self.0
+    }
+
+    /// Applies `f` to each component of this element.
+    #[inline]
+    pub fn map<R2, F: FnMut(R) -> R2>(self, f: F) -> FreeModule<R2, N> {
+        FreeModule(self.0.map(f))
+    }
+}
+
+impl<R: RingCommutative, const N: usize> FreeModule<R, N> {
+    /// The `i`-th element of the canonical basis, i.e., with all its components set to zero
+    /// except the `i`-th which is set to one.
+    #[inline]
+    pub fn canonical_basis_element(i: usize) -> Self {
+        assert!(i < N, "Index out of bound.");
+        Self::from_fn(|j| if i == j { R::one() } else { R::zero() })
+    }
+}
+
+impl<R, const N: usize> Index<usize> for FreeModule<R, N> {
+    type Output = R;
+
+    #[inline]
+    fn index(&self, i: usize) -> &R {
+        &self.0[i]
+    }
+}
+
+impl<R, const N: usize> IndexMut<usize> for FreeModule<R, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut R {
+        &mut self.0[i]
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<R: quickcheck::Arbitrary, const N: usize> quickcheck::Arbitrary for FreeModule<R, N> {
+    #[inline]
+    fn arbitrary<G: quickcheck::Gen>(g: &mut G) -> Self {
+        FreeModule::from_fn(|_| R::arbitrary(g))
+    }
+
+    // NOTE: shrinks one component at a time.
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let v = self.clone();
+
+        Box::new((0..N).flat_map(move |i| {
+            let v = v.clone();
+
+            v.0[i].shrink().map(move |c| {
+                let mut w = v.clone();
+                w.0[i] = c;
+                w
+            })
+        }))
+    }
+}
+
+impl<R: AbsDiffEq, const N: usize> AbsDiffEq for FreeModule<R, N>
+where
+    R::Epsilon: Clone,
+{
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        R::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<R: RelativeEq, const N: usize> RelativeEq for FreeModule<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        R::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<R: UlpsEq, const N: usize> UlpsEq for FreeModule<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+impl<R: RingCommutative, const N: usize> Add for FreeModule<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative, const N: usize> AddAssign for FreeModule<R, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += b.clone();
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Sub for FreeModule<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative, const N: usize> SubAssign for FreeModule<R, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= b.clone();
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Neg for FreeModule<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(|a| -a)
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Mul<R> for FreeModule<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: R) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<R: RingCommutative, const N: usize> MulAssign<R> for FreeModule<R, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: R) {
+        for a in self.0.iter_mut() {
+            *a *= rhs.clone();
+        }
+    }
+}
+
+impl<R: Field, const N: usize> Div<R> for FreeModule<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, rhs: R) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<R: Field, const N: usize> DivAssign<R> for FreeModule<R, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: R) {
+        for a in self.0.iter_mut() {
+            *a /= rhs.clone();
+        }
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Zero for FreeModule<R, N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from_fn(|_| R::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|a| a.is_zero())
+    }
+}
+
+/*
+ *
+ * Algebraic structures.
+ *
+ */
+impl<R: RingCommutative, const N: usize> AbstractMagma<Additive> for FreeModule<R, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        for (a, b) in self.0.iter_mut().zip(right.0.iter()) {
+            AbstractMagma::<Additive>::operate_assign(a, b)
+        }
+    }
+
+    #[inline]
+    fn operate_owned(self, right: Self) -> Self {
+        self + right
+    }
+}
+
+impl<R: RingCommutative, const N: usize> TwoSidedInverse<Additive> for FreeModule<R, N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Identity<Additive> for FreeModule<R, N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<R, const N: usize> ConstIdentity<Additive> for FreeModule<R, N>
+where
+    R: RingCommutative + ConstIdentity<Additive>,
+{
+    const IDENTITY: Self = FreeModule([R::IDENTITY; N]);
+}
+
+impl<R: RingCommutative, const N: usize> AbstractSemigroup<Additive> for FreeModule<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractQuasigroup<Additive> for FreeModule<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractMonoid<Additive> for FreeModule<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractLoop<Additive> for FreeModule<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractGroup<Additive> for FreeModule<R, N> {}
+impl<R: RingCommutative, const N: usize> AbstractGroupAbelian<Additive> for FreeModule<R, N> {}
+
+impl<R: RingCommutative, const N: usize> AbstractModule for FreeModule<R, N> {
+    type AbstractRing = R;
+
+    #[inline]
+    fn multiply_by(&self, r: R) -> Self {
+        self.clone() * r
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Module for FreeModule<R, N> {
+    type Ring = R;
+}
+
+impl<R: Field, const N: usize> VectorSpace for FreeModule<R, N> {
+    type Field = R;
+}
+
+impl<R: Field, const N: usize> FiniteDimVectorSpace for FreeModule<R, N> {
+    #[inline]
+    fn dimension() -> usize {
+        N
+    }
+
+    #[inline]
+    fn canonical_basis_element(i: usize) -> Self {
+        FreeModule::canonical_basis_element(i)
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> R {
+        let mut res = R::zero();
+
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            res += a.clone() * b.clone();
+        }
+
+        res
+    }
+
+    #[inline]
+    unsafe fn component_unchecked(&self, i: usize) -> &R {
+        self.0.get_unchecked(i)
+    }
+
+    #[inline]
+    unsafe fn component_unchecked_mut(&mut self, i: usize) -> &mut R {
+        self.0.get_unchecked_mut(i)
+    }
+}
+
+impl<R: Field, const N: usize> ConstDimVectorSpace for FreeModule<R, N> {
+    const DIM: usize = N;
+}
+
+impl<R: Field, R2: Field, const N: usize> FiniteDimVectorSpaceMap<R2> for FreeModule<R, N> {
+    type Mapped = FreeModule<R2, N>;
+
+    #[inline]
+    fn map_coords<F: FnMut(R) -> R2>(&self, f: F) -> FreeModule<R2, N> {
+        self.clone().map(f)
+    }
+}
+
+impl<R1, R2: SupersetOf<R1>, const N: usize> SubsetOf<FreeModule<R2, N>> for FreeModule<R1, N> {
+    #[inline]
+    fn to_superset(&self) -> FreeModule<R2, N> {
+        FreeModule::from_fn(|i| R2::from_subset(&self.0[i]))
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &FreeModule<R2, N>) -> FreeModule<R1, N> {
+        FreeModule::from_fn(|i| element.0[i].to_subset_unchecked())
+    }
+
+    #[inline]
+    fn is_in_subset(element: &FreeModule<R2, N>) -> bool {
+        element.0.iter().all(|c| c.is_in_subset())
+    }
+}
+
+impl<R: ComplexField, const N: usize> NormedSpace for FreeModule<R, N> {
+    type RealField = R::RealField;
+    type ComplexField = R;
+
+    #[inline]
+    fn norm_squared(&self) -> R::RealField {
+        let mut res = R::RealField::zero();
+
+        for a in self.0.iter() {
+            res += a.modulus_squared();
+        }
+
+        res
+    }
+}
+
+impl<R: ComplexField, const N: usize> InnerSpace for FreeModule<R, N> {
+    #[inline]
+    fn inner_product(&self, other: &Self) -> R {
+        let mut res = R::zero();
+
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            res += *a * b.conjugate();
+        }
+
+        res
+    }
+}
+
+impl<R: ComplexField, const N: usize> FiniteDimInnerSpace for FreeModule<R, N> {
+    #[inline]
+    fn orthonormal_subspace_basis<F: FnMut(&Self) -> bool>(vs: &[Self], f: F) {
+        orthonormal_subspace_basis_from_canonical::<_, _, N>(vs, f)
+    }
+}
+
+impl<R: ComplexField, const N: usize> LpNormedSpace for FreeModule<R, N> {}
+impl<R: ComplexField, const N: usize> BanachSpace for FreeModule<R, N> {}
+impl<R: ComplexField, const N: usize> HilbertSpace for FreeModule<R, N> {}
+
+// NOTE: the dimension of the tensor product can't be computed from generic const parameters
+// yet, so it is only implemented for small dimensions.
+macro_rules! impl_tensor_product(
+    ($($dim: literal),*) => {
+        impl_tensor_product!(@lhs [$($dim),*] [$($dim),*]);
+    };
+    (@lhs [$($m: literal),*] $rhs: tt) => {$(
+        impl_tensor_product!(@rhs $m; $rhs);
+    )*};
+    (@rhs $m: literal; [$($k: literal),*]) => {$(
+        impl<R: Field> TensorProduct<FreeModule<R, $k>> for FreeModule<R, $m> {
+            type Tensor = FreeModule<R, { $m * $k }>;
+        }
+    )*};
+);
+
+impl_tensor_product!(1, 2, 3, 4);
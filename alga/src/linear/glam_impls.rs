@@ -0,0 +1,124 @@
+//! Implementations of the algebraic structures for the types of the `glam` crate.
+//!
+//! NOTE: the `VectorSpace` and `Transformation` hierarchies require `num::Zero` and `num::One`
+//! which, because of the orphan rules, can only be implemented for `glam` types by `glam` itself
+//! or by `num-traits`. Only the structures that do not depend on those traits are implemented
+//! here: the additive groups of the vectors, the multiplicative groups of the rotations and
+//! affine transformations, and their metric and convex structures.
+
+use glam::{Affine3A, Quat, Vec2, Vec3, Vec4};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractSemigroup, Additive, Identity, MetricSpace, Multiplicative,
+    MultiplicativeComposition, TwoSidedInverse,
+};
+use crate::linear::ConvexSpace;
+
+macro_rules! impl_glam_vector(
+    ($($T:ident),*) => {$(
+        impl AbstractMagma<Additive> for $T {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self + *right
+            }
+        }
+
+        impl TwoSidedInverse<Additive> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                -*self
+            }
+        }
+
+        impl Identity<Additive> for $T {
+            #[inline]
+            fn identity() -> Self {
+                $T::ZERO
+            }
+        }
+
+        impl AbstractSemigroup<Additive> for $T {}
+        impl AbstractQuasigroup<Additive> for $T {}
+        impl AbstractMonoid<Additive> for $T {}
+        impl AbstractLoop<Additive> for $T {}
+        impl AbstractGroup<Additive> for $T {}
+        impl AbstractGroupAbelian<Additive> for $T {}
+
+        impl MetricSpace for $T {
+            type Distance = f32;
+
+            #[inline]
+            fn distance(&self, other: &Self) -> f32 {
+                $T::distance(*self, *other)
+            }
+        }
+
+        impl ConvexSpace for $T {
+            type Weight = f32;
+
+            #[inline]
+            fn convex_combination(&self, other: &Self, t: f32) -> Self {
+                self.lerp(*other, t)
+            }
+        }
+    )*}
+);
+
+impl_glam_vector!(Vec2, Vec3, Vec4);
+
+macro_rules! impl_glam_transformation(
+    ($($T:ident),*) => {$(
+        impl AbstractMagma<Multiplicative> for $T {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self * *right
+            }
+        }
+
+        // NOTE: the inverse of a non-invertible affine transformation is unspecified.
+        impl TwoSidedInverse<Multiplicative> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                self.inverse()
+            }
+        }
+
+        impl Identity<Multiplicative> for $T {
+            #[inline]
+            fn identity() -> Self {
+                $T::IDENTITY
+            }
+        }
+
+        impl AbstractSemigroup<Multiplicative> for $T {}
+        impl AbstractQuasigroup<Multiplicative> for $T {}
+        impl AbstractMonoid<Multiplicative> for $T {}
+        impl AbstractLoop<Multiplicative> for $T {}
+        impl AbstractGroup<Multiplicative> for $T {}
+
+        impl MultiplicativeComposition for $T {}
+    )*}
+);
+
+impl_glam_transformation!(Quat, Affine3A);
+
+// NOTE: the geodesic distance on the rotation group, i.e., the angle of the rotation between two
+// unit quaternions.
+impl MetricSpace for Quat {
+    type Distance = f32;
+
+    #[inline]
+    fn distance(&self, other: &Self) -> f32 {
+        self.angle_between(*other)
+    }
+}
+
+impl ConvexSpace for Quat {
+    type Weight = f32;
+
+    #[inline]
+    fn convex_combination(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
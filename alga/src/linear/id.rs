@@ -1,15 +1,17 @@
 use num;
 
-use crate::general::{Id, Identity};
+use crate::general::{Id, Identity, MultiplicativeComposition};
 use crate::linear::{
-    AffineTransformation, DirectIsometry, EuclideanSpace, InnerSpace, Isometry,
+    AffineTransformation, DirectIsometry, EuclideanSpace, InnerSpace, Isometry, LieRotation,
     OrthogonalTransformation, ProjectiveTransformation, Rotation, Scaling, Similarity,
-    Transformation, Translation,
+    SpecialOrthogonalGroup, Transformation, Translation,
 };
 
 /*
  * Implementation of linear algebra structures for the ubiquitous identity element.
  */
+impl MultiplicativeComposition for Id {}
+
 impl<E: EuclideanSpace> Transformation<E> for Id {
     #[inline]
     fn transform_point(&self, pt: &E) -> E {
@@ -20,6 +22,12 @@ impl<E: EuclideanSpace> Transformation<E> for Id {
     fn transform_vector(&self, v: &E::Coordinates) -> E::Coordinates {
         v.clone()
     }
+
+    #[inline]
+    fn transform_points(&self, _: &mut [E]) {}
+
+    #[inline]
+    fn transform_vectors(&self, _: &mut [E::Coordinates]) {}
 }
 
 impl<E: EuclideanSpace> ProjectiveTransformation<E> for Id {
@@ -97,7 +105,12 @@ impl<E: EuclideanSpace> Similarity<E> for Id {
 impl<E: EuclideanSpace> Scaling<E> for Id {}
 impl<E: EuclideanSpace> Isometry<E> for Id {}
 impl<E: EuclideanSpace> DirectIsometry<E> for Id {}
-impl<E: EuclideanSpace> OrthogonalTransformation<E> for Id {}
+impl<E: EuclideanSpace> OrthogonalTransformation<E> for Id {
+    #[inline]
+    fn det_is_positive(&self) -> bool {
+        true
+    }
+}
 
 impl<E: EuclideanSpace> Rotation<E> for Id {
     #[inline]
@@ -124,6 +137,22 @@ impl<E: EuclideanSpace> Rotation<E> for Id {
     }
 }
 
+impl<E: EuclideanSpace> SpecialOrthogonalGroup<E> for Id {}
+
+impl<E: EuclideanSpace> LieRotation<E> for Id {
+    type Generator = Id;
+
+    #[inline]
+    fn exp(_: &Id) -> Self {
+        Id::new()
+    }
+
+    #[inline]
+    fn log(&self) -> Id {
+        Id::new()
+    }
+}
+
 impl<E: EuclideanSpace> Translation<E> for Id {
     #[inline]
     fn to_vector(&self) -> E::Coordinates {
@@ -0,0 +1,120 @@
+//! Conversions between the interchange types of the `mint` crate and the types implementing the
+//! `linear` traits.
+//!
+//! Every `mint` type is a subset of all the spaces with the same dimension, so that any two crates
+//! implementing the `linear` traits can exchange data through `mint` with `convert`. The
+//! conversions to a space with a different dimension panic, and `is_in_subset` returns `false`
+//! for them.
+
+use mint::{
+    ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3, RowMatrix2, RowMatrix3,
+    RowMatrix4, Vector2, Vector3, Vector4,
+};
+use num::Zero;
+
+use crate::general::SubsetOf;
+use crate::linear::{EuclideanSpace, FiniteDimVectorSpace, MatrixMut};
+
+macro_rules! impl_mint_vector(
+    ($($T:ident, $dim:expr);* $(;)*) => {$(
+        impl<N: Clone, V: FiniteDimVectorSpace<Field = N>> SubsetOf<V> for $T<N> {
+            #[inline]
+            fn to_superset(&self) -> V {
+                assert_eq!(V::dimension(), $dim, "Cannot convert to a space of a different dimension.");
+                let components: &[N; $dim] = self.as_ref();
+                V::from_fn(|i| components[i].clone())
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &V) -> Self {
+                let components: [N; $dim] = std::array::from_fn(|i| element[i].clone());
+                components.into()
+            }
+
+            #[inline]
+            fn is_in_subset(_: &V) -> bool {
+                V::dimension() == $dim
+            }
+        }
+    )*}
+);
+
+impl_mint_vector!(Vector2, 2; Vector3, 3; Vector4, 4);
+
+macro_rules! impl_mint_point(
+    ($($T:ident, $dim:expr);* $(;)*) => {$(
+        impl<E: EuclideanSpace> SubsetOf<E> for $T<E::RealField> {
+            #[inline]
+            fn to_superset(&self) -> E {
+                let dim = E::Coordinates::dimension();
+                assert_eq!(dim, $dim, "Cannot convert to a space of a different dimension.");
+                let components: &[E::RealField; $dim] = self.as_ref();
+                E::from_coordinates(E::Coordinates::from_fn(|i| components[i]))
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &E) -> Self {
+                let coords = element.coordinates();
+                let components: [E::RealField; $dim] = std::array::from_fn(|i| coords[i]);
+                components.into()
+            }
+
+            #[inline]
+            fn is_in_subset(_: &E) -> bool {
+                E::Coordinates::dimension() == $dim
+            }
+        }
+    )*}
+);
+
+impl_mint_point!(Point2, 2; Point3, 3);
+
+macro_rules! impl_mint_matrix(
+    ($($T:ident, $dim:expr, $column_major:expr);* $(;)*) => {$(
+        impl<N: Clone, M: MatrixMut<Field = N> + Zero> SubsetOf<M> for $T<N> {
+            #[inline]
+            fn to_superset(&self) -> M {
+                let mut res = M::zero();
+                assert!(
+                    res.nrows() == $dim && res.ncolumns() == $dim,
+                    "Cannot convert to a matrix of different dimensions."
+                );
+                let entries: [[N; $dim]; $dim] = self.clone().into();
+
+                for (a, line) in entries.iter().enumerate() {
+                    for (b, e) in line.iter().enumerate() {
+                        let (i, j) = if $column_major { (b, a) } else { (a, b) };
+                        res.set(i, j, e.clone());
+                    }
+                }
+
+                res
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &M) -> Self {
+                let entries: [[N; $dim]; $dim] = std::array::from_fn(|a| {
+                    std::array::from_fn(|b| {
+                        let (i, j) = if $column_major { (b, a) } else { (a, b) };
+                        element.get(i, j)
+                    })
+                });
+                entries.into()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &M) -> bool {
+                element.nrows() == $dim && element.ncolumns() == $dim
+            }
+        }
+    )*}
+);
+
+impl_mint_matrix!(
+    ColumnMatrix2, 2, true;
+    ColumnMatrix3, 3, true;
+    ColumnMatrix4, 4, true;
+    RowMatrix2, 2, false;
+    RowMatrix3, 3, false;
+    RowMatrix4, 4, false;
+);
@@ -1,16 +1,36 @@
 //! Traits dedicated to linear algebra.
 
+pub use self::exterior::{Bivector3, WedgeProduct};
+pub use self::form::{
+    BilinearForm, InnerProduct, PositiveDefiniteForm, QuadraticForm, SymmetricBilinearForm,
+};
+pub use self::free_module::FreeModule;
 pub use self::matrix::{InversibleSquareMatrix, Matrix, MatrixMut, SquareMatrix, SquareMatrixMut};
+pub use self::operator::{AdjointOperator, Composed, IdentityOperator, LinearOperator};
+pub use self::point::Point;
 pub use self::transformation::{
-    AffineTransformation, DirectIsometry, Isometry, OrthogonalTransformation,
-    ProjectiveTransformation, Rotation, Scaling, Similarity, Transformation, Translation,
+    AffineTransformation, DirectIsometry, Isometry, LieRotation, OrthogonalTransformation,
+    ProjectiveTransformation, Reflection, Rotation, Scaling, Similarity, SpecialOrthogonalGroup,
+    Transformation, Translation, Twist,
 };
 pub use self::vector::{
-    AffineSpace, EuclideanSpace, FiniteDimInnerSpace, FiniteDimVectorSpace, InnerSpace,
-    NormedSpace, VectorSpace,
+    AffineSpace, BanachSpace, ConstDimVectorSpace, ConvexSpace, DualSpace, EuclideanSpace,
+    FiniteDimInnerSpace, FiniteDimVectorSpace, FiniteDimVectorSpaceMap, HilbertSpace, InnerSpace,
+    LinearFunctional, LpNormedSpace, NormedSpace, ProjectiveSpace, TensorProduct, VectorSpace,
 };
 
+#[cfg(feature = "cgmath")]
+mod cgmath_impls;
+mod exterior;
+mod form;
+mod free_module;
+#[cfg(feature = "glam")]
+mod glam_impls;
 mod id;
 mod matrix;
+#[cfg(feature = "mint")]
+mod mint_impls;
+mod operator;
+mod point;
 mod transformation;
 mod vector;
@@ -0,0 +1,216 @@
+use approx::{AbsDiffEq, RelativeEq};
+use std::marker::PhantomData;
+
+use crate::linear::{InnerSpace, VectorSpace};
+
+/// A linear map from the vector space `V` to the vector space `W`, both over the same field.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ V, ∀ s ∈ V::Field, f(a * s + b) = f(a) * s + f(b)
+/// ~~~
+///
+/// This does not require the map to be represented by a matrix so that, e.g., iterative solvers
+/// can be generic over anything that acts linearly.
+pub trait LinearOperator<V: VectorSpace, W: VectorSpace<Field = V::Field>>: Sized {
+    /// Applies this linear operator to `v`.
+    fn apply(&self, v: &V) -> W;
+
+    /// The composition `self ∘ inner`, i.e., the operator that applies `inner` first and then
+    /// `self`.
+    #[inline]
+    fn compose<U, G>(self, inner: G) -> Composed<Self, G, V>
+    where
+        U: VectorSpace<Field = V::Field>,
+        G: LinearOperator<U, V>,
+    {
+        Composed::new(self, inner)
+    }
+
+    /// Returns `true` if this operator is approximately linear for the given argument tuple.
+    fn prop_is_linear_approx(args: (Self, V, V, V::Field)) -> bool
+    where
+        W: RelativeEq,
+    {
+        Self::prop_is_linear_approx_with(
+            args,
+            <W as AbsDiffEq>::default_epsilon(),
+            <W as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this operator is approximately linear for the given argument tuple. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_is_linear_approx_with(
+        args: (Self, V, V, V::Field),
+        epsilon: <W as AbsDiffEq>::Epsilon,
+        max_relative: <W as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        W: RelativeEq,
+    {
+        let (f, a, b, s) = args;
+        let lhs = f.apply(&(a.clone() * s.clone() + b.clone()));
+        let rhs = f.apply(&a) * s + f.apply(&b);
+
+        relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+    }
+
+    /// Returns `true` if this operator is linear for the given argument tuple.
+    fn prop_is_linear(args: (Self, V, V, V::Field)) -> bool
+    where
+        W: Eq,
+    {
+        let (f, a, b, s) = args;
+        f.apply(&(a.clone() * s.clone() + b.clone())) == f.apply(&a) * s + f.apply(&b)
+    }
+}
+
+/// A linear operator on an inner space that has an adjoint.
+///
+/// ~~~notrust
+/// ∀ v, w ∈ V, ⟨A(v), w⟩ = ⟨v, A*(w)⟩
+/// ~~~
+pub trait AdjointOperator<V: InnerSpace>: LinearOperator<V, V> {
+    /// The type of the adjoint of this operator.
+    type Adjoint: LinearOperator<V, V>;
+
+    /// The adjoint `A*` of this operator.
+    fn adjoint(&self) -> Self::Adjoint;
+
+    /// Returns `true` if `adjoint` approximately satisfies its defining identity for the given
+    /// argument tuple.
+    fn prop_adjoint_is_adjoint_approx(args: (Self, V, V)) -> bool
+    where
+        V::ComplexField: RelativeEq,
+    {
+        Self::prop_adjoint_is_adjoint_approx_with(
+            args,
+            <V::ComplexField as AbsDiffEq>::default_epsilon(),
+            <V::ComplexField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if `adjoint` approximately satisfies its defining identity for the given
+    /// argument tuple. The approximate equalities use the given `epsilon` and `max_relative`
+    /// tolerances.
+    fn prop_adjoint_is_adjoint_approx_with(
+        args: (Self, V, V),
+        epsilon: <V::ComplexField as AbsDiffEq>::Epsilon,
+        max_relative: <V::ComplexField as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::ComplexField: RelativeEq,
+    {
+        let (a, v, w) = args;
+        relative_eq!(
+            a.apply(&v).inner_product(&w),
+            v.inner_product(&a.adjoint().apply(&w)),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if this operator is approximately self-adjoint, i.e., `A = A*`, for the
+    /// given argument tuple.
+    fn prop_is_self_adjoint_approx(args: (Self, V, V)) -> bool
+    where
+        V::ComplexField: RelativeEq,
+    {
+        Self::prop_is_self_adjoint_approx_with(
+            args,
+            <V::ComplexField as AbsDiffEq>::default_epsilon(),
+            <V::ComplexField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this operator is approximately self-adjoint, i.e., `A = A*`, for the given
+    /// argument tuple. The approximate equalities use the given `epsilon` and `max_relative`
+    /// tolerances.
+    fn prop_is_self_adjoint_approx_with(
+        args: (Self, V, V),
+        epsilon: <V::ComplexField as AbsDiffEq>::Epsilon,
+        max_relative: <V::ComplexField as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::ComplexField: RelativeEq,
+    {
+        let (a, v, w) = args;
+        relative_eq!(
+            a.apply(&v).inner_product(&w),
+            v.inner_product(&a.apply(&w)),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+}
+
+/// The identity operator on any vector space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IdentityOperator;
+
+impl<V: VectorSpace> LinearOperator<V, V> for IdentityOperator {
+    #[inline]
+    fn apply(&self, v: &V) -> V {
+        v.clone()
+    }
+}
+
+impl<V: InnerSpace> AdjointOperator<V> for IdentityOperator {
+    type Adjoint = IdentityOperator;
+
+    #[inline]
+    fn adjoint(&self) -> IdentityOperator {
+        IdentityOperator
+    }
+}
+
+/// The composition `outer ∘ inner` of two linear operators, where `U` is the space between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Composed<F, G, U> {
+    /// The operator applied last.
+    pub outer: F,
+    /// The operator applied first.
+    pub inner: G,
+    _phantom: PhantomData<U>,
+}
+
+impl<F, G, U> Composed<F, G, U> {
+    /// Creates the composition `outer ∘ inner`.
+    #[inline]
+    pub fn new(outer: F, inner: G) -> Self {
+        Composed {
+            outer,
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<V, U, W, F, G> LinearOperator<V, W> for Composed<F, G, U>
+where
+    V: VectorSpace,
+    U: VectorSpace<Field = V::Field>,
+    W: VectorSpace<Field = V::Field>,
+    F: LinearOperator<U, W>,
+    G: LinearOperator<V, U>,
+{
+    #[inline]
+    fn apply(&self, v: &V) -> W {
+        self.outer.apply(&self.inner.apply(v))
+    }
+}
+
+// NOTE: (A ∘ B)* = B* ∘ A*
+impl<V, F, G> AdjointOperator<V> for Composed<F, G, V>
+where
+    V: InnerSpace,
+    F: AdjointOperator<V>,
+    G: AdjointOperator<V>,
+{
+    type Adjoint = Composed<G::Adjoint, F::Adjoint, V>;
+
+    #[inline]
+    fn adjoint(&self) -> Self::Adjoint {
+        Composed::new(self.inner.adjoint(), self.outer.adjoint())
+    }
+}
@@ -0,0 +1,210 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::RealField;
+use crate::linear::{AffineSpace, EuclideanSpace, FreeModule, ProjectiveSpace};
+
+/// A point of an euclidean space, with coordinates of type `C`.
+///
+/// This distinguishes points from the vectors between them: `Point<[N; D]>` is the
+/// `D`-dimensional euclidean space whose translations are the vectors `FreeModule<N, D>`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point<C>(pub C);
+
+impl<N, const D: usize> Point<[N; D]> {
+    /// Creates a point from its coordinates.
+    #[inline]
+    pub fn new(coords: [N; D]) -> Self {
+        Point(coords)
+    }
+}
+
+impl<N: AbsDiffEq, const D: usize> AbsDiffEq for Point<[N; D]>
+where
+    N::Epsilon: Clone,
+{
+    type Epsilon = N::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        N::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<N: RelativeEq, const D: usize> RelativeEq for Point<[N; D]>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        N::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<N: UlpsEq, const D: usize> UlpsEq for Point<[N; D]>
+where
+    N::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+/*
+ *
+ * Operators.
+ *
+ */
+impl<N: RealField, const D: usize> Sub for Point<[N; D]> {
+    type Output = FreeModule<N, D>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> FreeModule<N, D> {
+        FreeModule(self.0) - FreeModule(rhs.0)
+    }
+}
+
+impl<N: RealField, const D: usize> Add<FreeModule<N, D>> for Point<[N; D]> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: FreeModule<N, D>) -> Self {
+        Point((FreeModule(self.0) + rhs).0)
+    }
+}
+
+impl<N: RealField, const D: usize> AddAssign<FreeModule<N, D>> for Point<[N; D]> {
+    #[inline]
+    fn add_assign(&mut self, rhs: FreeModule<N, D>) {
+        *self = *self + rhs
+    }
+}
+
+impl<N: RealField, const D: usize> Sub<FreeModule<N, D>> for Point<[N; D]> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: FreeModule<N, D>) -> Self {
+        Point((FreeModule(self.0) - rhs).0)
+    }
+}
+
+impl<N: RealField, const D: usize> SubAssign<FreeModule<N, D>> for Point<[N; D]> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: FreeModule<N, D>) {
+        *self = *self - rhs
+    }
+}
+
+impl<N: RealField, const D: usize> Mul<N> for Point<[N; D]> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Self {
+        Point((FreeModule(self.0) * rhs).0)
+    }
+}
+
+impl<N: RealField, const D: usize> MulAssign<N> for Point<[N; D]> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        *self = *self * rhs
+    }
+}
+
+impl<N: RealField, const D: usize> Div<N> for Point<[N; D]> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: N) -> Self {
+        Point(self.0.map(|x| x / rhs))
+    }
+}
+
+impl<N: RealField, const D: usize> DivAssign<N> for Point<[N; D]> {
+    #[inline]
+    fn div_assign(&mut self, rhs: N) {
+        *self = *self / rhs
+    }
+}
+
+impl<N: RealField, const D: usize> Neg for Point<[N; D]> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Point((-FreeModule(self.0)).0)
+    }
+}
+
+/*
+ *
+ * Euclidean space.
+ *
+ */
+impl<N: RealField, const D: usize> AffineSpace for Point<[N; D]> {
+    type Translation = FreeModule<N, D>;
+}
+
+impl<N: RealField, const D: usize> EuclideanSpace for Point<[N; D]> {
+    type Coordinates = FreeModule<N, D>;
+    type RealField = N;
+
+    #[inline]
+    fn origin() -> Self {
+        Point([N::zero(); D])
+    }
+
+    #[inline]
+    fn coordinates(&self) -> FreeModule<N, D> {
+        FreeModule(self.0)
+    }
+
+    #[inline]
+    fn from_coordinates(coords: FreeModule<N, D>) -> Self {
+        Point(coords.0)
+    }
+}
+
+// NOTE: `{ D + 1 }` is not allowed as a const argument for a generic `D`, hence one impl per
+// dimension.
+macro_rules! impl_projective_space(
+    ($($dim: literal),*) => {$(
+        impl<N: RealField> ProjectiveSpace for Point<[N; $dim]> {
+            type Homogeneous = FreeModule<N, { $dim + 1 }>;
+        }
+    )*}
+);
+
+impl_projective_space!(1, 2, 3);
@@ -1,8 +1,14 @@
+use approx::{AbsDiffEq, RelativeEq};
+use std::ops::Mul;
+
 use crate::general::{
-    ClosedDiv, ClosedMul, ClosedNeg, ComplexField, Id, MultiplicativeGroup, MultiplicativeMonoid,
-    RealField, SubsetOf, TwoSidedInverse,
+    ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ComplexFieldCore, Id, Identity, Multiplicative,
+    MultiplicativeComposition, MultiplicativeGroup, MultiplicativeMonoid, RealField, SubsetOf,
+    TwoSidedInverse,
+};
+use crate::linear::{
+    EuclideanSpace, FiniteDimVectorSpace, InnerSpace, NormedSpace, ProjectiveSpace,
 };
-use crate::linear::{EuclideanSpace, NormedSpace};
 
 // NOTE: A subgroup trait inherit from its parent groups.
 
@@ -16,6 +22,28 @@ pub trait Transformation<E: EuclideanSpace>: MultiplicativeMonoid {
     /// If `v` is a vector and `a, b` two point such that `v = a - b`, the action `∘` on a vector
     /// is defined as `self ∘ v = (self × a) - (self × b)`.
     fn transform_vector(&self, v: &E::Coordinates) -> E::Coordinates;
+
+    /// Applies this group's action on each point of the slice, in-place.
+    ///
+    /// The default implementation calls `transform_point` on each point. Implementors may
+    /// override it with a vectorized or cache-blocked version, which must give the same result.
+    #[inline]
+    fn transform_points(&self, pts: &mut [E]) {
+        for pt in pts.iter_mut() {
+            *pt = self.transform_point(pt);
+        }
+    }
+
+    /// Applies this group's action on each vector of the slice, in-place.
+    ///
+    /// The default implementation calls `transform_vector` on each vector. Implementors may
+    /// override it with a vectorized or cache-blocked version, which must give the same result.
+    #[inline]
+    fn transform_vectors(&self, vs: &mut [E::Coordinates]) {
+        for v in vs.iter_mut() {
+            *v = self.transform_vector(v);
+        }
+    }
 }
 
 /// The most general form of invertible transformations on an euclidean space.
@@ -30,6 +58,74 @@ pub trait ProjectiveTransformation<E: EuclideanSpace>:
     /// If `v` is a vector and `a, b` two point such that `v = a - b`, the action `∘` on a vector
     /// is defined as `self ∘ v = (self × a) - (self × b)`.
     fn inverse_transform_vector(&self, v: &E::Coordinates) -> E::Coordinates;
+
+    /// Applies this group's action on a point expressed in homogeneous coordinates, possibly at
+    /// infinity.
+    ///
+    /// Points at infinity are transformed as the direction they represent.
+    #[inline]
+    fn transform_homogeneous(&self, h: &E::Homogeneous) -> E::Homogeneous
+    where
+        E: ProjectiveSpace,
+    {
+        match E::from_homogeneous(h) {
+            Some(pt) => self.transform_point(&pt).to_homogeneous(),
+            None => {
+                let dim = E::Coordinates::dimension();
+                let mut v: E::Coordinates = num::zero();
+
+                for i in 0..dim {
+                    v[i] = h[i];
+                }
+
+                E::direction_to_homogeneous(&self.transform_vector(&v))
+            }
+        }
+    }
+
+    /// Returns `true` if the inverse action of this transformation on the given point and vector
+    /// is approximately the action of its inverse.
+    fn prop_inverse_transform_is_inverse_action_approx(args: (Self, E, E::Coordinates)) -> bool
+    where
+        E: RelativeEq,
+        E::Coordinates: RelativeEq<Epsilon = <E as AbsDiffEq>::Epsilon>,
+        <E as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_inverse_transform_is_inverse_action_approx_with(
+            args,
+            <E as AbsDiffEq>::default_epsilon(),
+            <E as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the inverse action of this transformation on the given point and vector
+    /// is approximately the action of its inverse. The approximate equalities use the given
+    /// `epsilon` and `max_relative` tolerances.
+    fn prop_inverse_transform_is_inverse_action_approx_with(
+        args: (Self, E, E::Coordinates),
+        epsilon: <E as AbsDiffEq>::Epsilon,
+        max_relative: <E as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        E: RelativeEq,
+        E::Coordinates: RelativeEq<Epsilon = <E as AbsDiffEq>::Epsilon>,
+        <E as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (m, pt, v) = args;
+        let inv = m.two_sided_inverse();
+
+        relative_eq!(
+            m.inverse_transform_point(&pt),
+            inv.transform_point(&pt),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            m.inverse_transform_vector(&v),
+            inv.transform_vector(&v),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
 }
 
 /// The group of affine transformations. They are decomposable into a rotation, a non-uniform
@@ -44,6 +140,10 @@ pub trait AffineTransformation<E: EuclideanSpace>: ProjectiveTransformation<E> {
 
     /// Decomposes this affine transformation into a rotation followed by a non-uniform scaling,
     /// followed by a rotation, followed by a translation.
+    ///
+    /// The returned tuple `(t, r1, s, r2)` is such that `r1` is applied first, i.e., a point `p` is
+    /// mapped to `t(r2(s(r1(p))))`. The product of transformations being their composition, the
+    /// linear part of `self` is `r2 * s * r1`.
     fn decompose(
         &self,
     ) -> (
@@ -54,6 +154,98 @@ pub trait AffineTransformation<E: EuclideanSpace>: ProjectiveTransformation<E> {
     );
     // FIXME: add a `recompose` method?
 
+    /*
+     * Polar decomposition.
+     */
+    /// The translational part of this affine transformation.
+    #[inline]
+    fn translation_part(&self) -> Self::Translation {
+        self.decompose().0
+    }
+
+    /// The rotational part of the polar decomposition of this affine transformation.
+    ///
+    /// The linear part of `self` is the product of this rotation and of the symmetric stretch
+    /// described by `self.scaling_part()` and `self.shear_part()`.
+    #[inline]
+    fn rotation_part(&self) -> Self::Rotation {
+        let (_, r1, _, r2) = self.decompose();
+        r2 * r1
+    }
+
+    /// The scaling factors of the stretch of this affine transformation, along its principal
+    /// axes.
+    #[inline]
+    fn scaling_part(&self) -> Self::NonUniformScaling {
+        self.decompose().2
+    }
+
+    /// The shear of this affine transformation, expressed as the rotation that aligns the
+    /// principal axes of its stretch with the canonical basis.
+    ///
+    /// The symmetric stretch of `self` is `shear⁻¹ * scaling * shear`.
+    #[inline]
+    fn shear_part(&self) -> Self::Rotation {
+        self.decompose().1
+    }
+
+    /// Builds an affine transformation from the parts of its polar decomposition. This is the
+    /// inverse of `translation_part`, `rotation_part`, `scaling_part`, and `shear_part`.
+    #[inline]
+    fn from_parts(
+        translation: &Self::Translation,
+        rotation: &Self::Rotation,
+        scaling: &Self::NonUniformScaling,
+        shear: &Self::Rotation,
+    ) -> Self {
+        <Self as Identity<Multiplicative>>::identity()
+            .append_rotation(shear)
+            .append_scaling(scaling)
+            .append_rotation(&shear.two_sided_inverse())
+            .append_rotation(rotation)
+            .append_translation(translation)
+    }
+
+    /// Returns `true` if recomposing this affine transformation from its parts gives back a
+    /// transformation approximately equal to it.
+    fn prop_from_parts_round_trip_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_from_parts_round_trip_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if recomposing this affine transformation from its parts gives back a
+    /// transformation approximately equal to it. The approximate equalities use the given `epsilon`
+    /// and `max_relative` tolerances.
+    fn prop_from_parts_round_trip_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (m,) = args;
+        let recomposed = Self::from_parts(
+            &m.translation_part(),
+            &m.rotation_part(),
+            &m.scaling_part(),
+            &m.shear_part(),
+        );
+
+        relative_eq!(
+            recomposed,
+            m,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
     /*
      * Composition with components.
      */
@@ -116,6 +308,170 @@ pub trait Similarity<E: EuclideanSpace>:
     /// The pure scaling component of this similarity transformation.
     fn scaling(&self) -> Self::Scaling;
 
+    /// The isometric part of this similarity transformation, i.e., `self` without its scaling
+    /// component.
+    #[inline]
+    fn isometry_part(&self) -> Self {
+        <Self as Identity<Multiplicative>>::identity()
+            .append_rotation(&self.rotation())
+            .append_translation(&self.translation())
+    }
+
+    /// Builds the similarity transformation that applies the uniform scaling `scaling` followed
+    /// by `isometry`. This is the inverse of `isometry_part` and `scaling`.
+    #[inline]
+    fn from_isometry_and_scaling(isometry: &Self, scaling: &Self::Scaling) -> Self {
+        isometry.prepend_scaling(scaling)
+    }
+
+    /// Interpolates between `self` and `other`.
+    ///
+    /// The translations are interpolated linearly, the rotations spherically, and the scaling
+    /// factors geometrically. Returns `None` if an intermediate component cannot be represented
+    /// by its type.
+    #[inline]
+    fn interpolate(&self, other: &Self, t: E::RealField) -> Option<Self> {
+        let ta = self.translation().to_vector();
+        let tb = other.translation().to_vector();
+        let sa = self.scaling();
+        let ds = sa.two_sided_inverse() * other.scaling();
+
+        let translation = Self::Translation::from_vector(ta.clone() + (tb - ta) * t)?;
+        let rotation = self.rotation().slerp(&other.rotation(), t)?;
+        let scaling = sa * Scaling::powf(&ds, t)?;
+        let isometry = <Self as Identity<Multiplicative>>::identity()
+            .append_rotation(&rotation)
+            .append_translation(&translation);
+
+        Some(Self::from_isometry_and_scaling(&isometry, &scaling))
+    }
+
+    /// Returns `true` if recomposing this similarity from its isometric part and its scaling
+    /// gives back a transformation approximately equal to it.
+    fn prop_isometry_and_scaling_round_trip_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_isometry_and_scaling_round_trip_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if recomposing this similarity from its isometric part and its scaling gives
+    /// back a transformation approximately equal to it. The approximate equalities use the given
+    /// `epsilon` and `max_relative` tolerances.
+    fn prop_isometry_and_scaling_round_trip_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (m,) = args;
+        relative_eq!(
+            Self::from_isometry_and_scaling(&m.isometry_part(), &m.scaling()),
+            m,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if the scaling of the composition `a * b` is approximately the product of
+    /// the scalings of `a` and `b`, and if `a * b` is approximately recomposed from its parts.
+    fn prop_parts_of_composition_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+        Self::Scaling: RelativeEq<Epsilon = Self::Epsilon>,
+    {
+        Self::prop_parts_of_composition_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the scaling of the composition `a * b` is approximately the product of the
+    /// scalings of `a` and `b`, and if `a * b` is approximately recomposed from its parts. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_parts_of_composition_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+        Self::Scaling: RelativeEq<Epsilon = Self::Epsilon>,
+    {
+        let (a, b) = args;
+        let ab = a.clone() * b.clone();
+
+        relative_eq!(
+            ab.scaling(),
+            a.scaling() * b.scaling(),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            Self::from_isometry_and_scaling(&ab.isometry_part(), &ab.scaling()),
+            ab,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if interpolating between two similarities approximately gives back the
+    /// endpoints for `t = 0` and `t = 1`.
+    fn prop_interpolate_endpoints_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_interpolate_endpoints_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if interpolating between two similarities approximately gives back the
+    /// endpoints for `t = 0` and `t = 1`. The approximate equalities use the given `epsilon` and
+    /// `max_relative` tolerances.
+    fn prop_interpolate_endpoints_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b) = args;
+
+        match (
+            a.interpolate(&b, num::zero()),
+            a.interpolate(&b, num::one()),
+        ) {
+            (Some(start), Some(end)) => {
+                relative_eq!(
+                    start,
+                    a,
+                    epsilon = epsilon.clone(),
+                    max_relative = max_relative.clone()
+                ) && relative_eq!(
+                    end,
+                    b,
+                    epsilon = epsilon.clone(),
+                    max_relative = max_relative.clone()
+                )
+            }
+            _ => true,
+        }
+    }
+
     /*
      * Transformations.
      */
@@ -181,6 +537,70 @@ pub trait Similarity<E: EuclideanSpace>:
     fn inverse_scale_vector(&self, pt: &E::Coordinates) -> E::Coordinates {
         self.scaling().inverse_transform_vector(pt)
     }
+
+    /// Applies the inverse of this transformation to a point, part by part.
+    ///
+    /// This undoes the translation, then the rotation, then the scaling, without computing the
+    /// inverse of `self`. For an isometry, this is the conjugate rotation applied to the point
+    /// translated by the opposite translation. Implementations of `inverse_transform_point` can
+    /// forward to this method when inverting `self` is more expensive than inverting its parts.
+    #[inline]
+    fn inverse_transform_point_by_parts(&self, pt: &E) -> E {
+        self.inverse_scale_point(&self.inverse_rotate_point(&self.inverse_translate_point(pt)))
+    }
+
+    /// Applies the inverse of this transformation to a vector, part by part.
+    ///
+    /// This undoes the rotation, then the scaling, without computing the inverse of `self`. The
+    /// translation does not act on vectors.
+    #[inline]
+    fn inverse_transform_vector_by_parts(&self, v: &E::Coordinates) -> E::Coordinates {
+        self.inverse_scale_vector(&self.inverse_rotate_vector(v))
+    }
+
+    /// Returns `true` if applying the inverse of this similarity part by part to the given point
+    /// and vector is approximately the action of its inverse.
+    fn prop_inverse_transform_by_parts_approx(args: (Self, E, E::Coordinates)) -> bool
+    where
+        E: RelativeEq,
+        E::Coordinates: RelativeEq<Epsilon = <E as AbsDiffEq>::Epsilon>,
+        <E as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_inverse_transform_by_parts_approx_with(
+            args,
+            <E as AbsDiffEq>::default_epsilon(),
+            <E as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if applying the inverse of this similarity part by part to the given point
+    /// and vector is approximately the action of its inverse. The approximate equalities use the
+    /// given `epsilon` and `max_relative` tolerances.
+    fn prop_inverse_transform_by_parts_approx_with(
+        args: (Self, E, E::Coordinates),
+        epsilon: <E as AbsDiffEq>::Epsilon,
+        max_relative: <E as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        E: RelativeEq,
+        E::Coordinates: RelativeEq<Epsilon = <E as AbsDiffEq>::Epsilon>,
+        <E as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (m, pt, v) = args;
+        let inv = m.two_sided_inverse();
+
+        relative_eq!(
+            m.inverse_transform_point_by_parts(&pt),
+            inv.transform_point(&pt),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            m.inverse_transform_vector_by_parts(&v),
+            inv.transform_vector(&v),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
 }
 
 /// Subgroups of the isometry group `E(n)`, i.e., rotations, reflexions, and translations.
@@ -190,7 +610,34 @@ pub trait Isometry<E: EuclideanSpace>: Similarity<E, Scaling = Id> {}
 pub trait DirectIsometry<E: EuclideanSpace>: Isometry<E> {}
 
 /// Subgroups of the n-dimensional rotations and scaling `O(n)`.
-pub trait OrthogonalTransformation<E: EuclideanSpace>: Isometry<E, Translation = Id> {}
+pub trait OrthogonalTransformation<E: EuclideanSpace>: Isometry<E, Translation = Id> {
+    /// Returns `true` if the determinant of this orthogonal transformation is `1`, and `false` if
+    /// it is `-1`, i.e., if `self` contains a reflection.
+    fn det_is_positive(&self) -> bool;
+
+    /// Returns `true` if this transformation preserves the orientation of the space, i.e., maps
+    /// right-handed bases to right-handed bases. Same as `self.det_is_positive()`.
+    #[inline]
+    fn is_orientation_preserving(&self) -> bool {
+        self.det_is_positive()
+    }
+
+    /// Returns `true` if the sign of the determinant is multiplicative for the given argument
+    /// tuple, i.e., if composing two transformations preserves the orientation iff both or none
+    /// of them preserves it.
+    fn prop_det_sign_is_multiplicative(args: (Self, Self)) -> bool {
+        let (a, b) = args;
+        let ab = a.clone() * b.clone();
+
+        ab.det_is_positive() == (a.det_is_positive() == b.det_is_positive())
+    }
+
+    /// Returns `true` if the inverse of the given transformation has the same orientation.
+    fn prop_inverse_preserves_det_sign(args: (Self,)) -> bool {
+        let (a,) = args;
+        a.two_sided_inverse().det_is_positive() == a.det_is_positive()
+    }
+}
 
 /// Subgroups of the (signed) uniform scaling group.
 pub trait Scaling<E: EuclideanSpace>:
@@ -233,6 +680,10 @@ pub trait Translation<E: EuclideanSpace>:
     // structure of Self uses the multiplication for composition, while E::Coordinates uses addition.
     // Having a trait that says "remap this operator to this other one" does not seem to be
     // possible without higher kinded traits.
+    // For the same reason, the additive group structure of E::Coordinates cannot be transported to
+    // Self with a blanket `AbstractGroupAbelian<Additive>` impl: the space `E` would not be
+    // constrained by the impl. The `prop_composition_is_vector_addition_approx` law checks instead
+    // that `to_vector` is a group isomorphism.
     /// Converts this translation to a vector.
     fn to_vector(&self) -> E::Coordinates;
 
@@ -252,6 +703,82 @@ pub trait Translation<E: EuclideanSpace>:
     fn translation_between(a: &E, b: &E) -> Option<Self> {
         Self::from_vector(b.clone() - a.clone())
     }
+
+    /// Returns `true` if converting the given translation to a vector and back approximately
+    /// gives back the same translation.
+    fn prop_vector_round_trip_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_vector_round_trip_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if converting the given translation to a vector and back approximately gives
+    /// back the same translation. The approximate equalities use the given `epsilon` and
+    /// `max_relative` tolerances.
+    fn prop_vector_round_trip_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (t,) = args;
+
+        match Self::from_vector(t.to_vector()) {
+            Some(back) => relative_eq!(back, t, epsilon = epsilon, max_relative = max_relative),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the composition of translations approximately matches the addition of
+    /// their vectors, and if the inverse of a translation matches the opposite of its vector,
+    /// for the given argument tuple.
+    fn prop_composition_is_vector_addition_approx(args: (Self, Self)) -> bool
+    where
+        E::Coordinates: RelativeEq,
+        <E::Coordinates as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_composition_is_vector_addition_approx_with(
+            args,
+            <E::Coordinates as AbsDiffEq>::default_epsilon(),
+            <E::Coordinates as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the composition of translations approximately matches the addition of
+    /// their vectors, and if the inverse of a translation matches the opposite of its vector, for
+    /// the given argument tuple. The approximate equalities use the given `epsilon` and
+    /// `max_relative` tolerances.
+    fn prop_composition_is_vector_addition_approx_with(
+        args: (Self, Self),
+        epsilon: <E::Coordinates as AbsDiffEq>::Epsilon,
+        max_relative: <E::Coordinates as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        E::Coordinates: RelativeEq,
+        <E::Coordinates as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (a, b) = args;
+        let sum = a.to_vector() + b.to_vector();
+
+        relative_eq!(
+            (a.clone() * b).to_vector(),
+            sum,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a.two_sided_inverse().to_vector(),
+            -a.to_vector(),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
 }
 
 /// Subgroups of the n-dimensional rotation group `SO(n)`.
@@ -281,6 +808,350 @@ pub trait Rotation<E: EuclideanSpace>:
 
     // FIXME: add a function that computes the rotation with the axis orthogonal to Span(a, b) and
     // with angle equal to `n`?
+
+    /// Spherical linear interpolation between `self` and `other`, i.e., the rotation
+    /// `self * (self⁻¹ * other)^t`.
+    ///
+    /// Returns `None` if the intermediate rotation cannot be represented by `Self`.
+    #[inline]
+    fn slerp(&self, other: &Self, t: E::RealField) -> Option<Self> {
+        let delta = self.two_sided_inverse() * other.clone();
+        delta.powf(t).map(|d| self.clone() * d)
+    }
+}
+
+/// Subgroups of the special orthogonal group `SO(n)` that are guaranteed to never contain
+/// reflections.
+///
+/// This is a marker trait for generic code where orientation preservation matters, e.g., when
+/// transforming normals.
+///
+/// ~~~notrust
+/// ∀ r ∈ Self, det(r) = 1
+/// ~~~
+pub trait SpecialOrthogonalGroup<E: EuclideanSpace>: Rotation<E> {
+    /// Returns `true` if the given rotation preserves the orientation.
+    fn prop_is_orientation_preserving(args: (Self,)) -> bool {
+        let (r,) = args;
+        r.det_is_positive()
+    }
+}
+
+/// Rotations that can be obtained from, and mapped back to, their infinitesimal generators
+/// through the exponential and logarithm maps.
+///
+/// The generator is, e.g., the rotation angle in 2D or the scaled rotation axis in 3D.
+///
+/// ~~~notrust
+/// ∀ r ∈ Self, exp(log(r)) = r
+/// ∀ r ∈ Self, ∀ t ∈ E::RealField, r^t = exp(log(r) * t)
+/// ~~~
+pub trait LieRotation<E: EuclideanSpace>: Rotation<E> {
+    /// The type of the infinitesimal generators of this rotation group.
+    type Generator: Clone;
+
+    /// The exponential map: the rotation generated by `generator`.
+    fn exp(generator: &Self::Generator) -> Self;
+
+    /// The logarithm map: the smallest generator of this rotation.
+    fn log(&self) -> Self::Generator;
+
+    /// Returns `true` if `exp` is approximately a left inverse of `log` for the given argument.
+    fn prop_exp_log_is_identity_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_exp_log_is_identity_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if `exp` is approximately a left inverse of `log` for the given argument. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_exp_log_is_identity_approx_with(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (r,) = args;
+        relative_eq!(
+            Self::exp(&r.log()),
+            r,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if `powf` approximately agrees with the scaling of the generator for the
+    /// given argument tuple.
+    fn prop_powf_is_scaled_log_approx(args: (Self, E::RealField)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Generator: Mul<E::RealField, Output = Self::Generator>,
+    {
+        Self::prop_powf_is_scaled_log_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if `powf` approximately agrees with the scaling of the generator for the
+    /// given argument tuple. The approximate equalities use the given `epsilon` and `max_relative`
+    /// tolerances.
+    fn prop_powf_is_scaled_log_approx_with(
+        args: (Self, E::RealField),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Generator: Mul<E::RealField, Output = Self::Generator>,
+    {
+        let (r, t) = args;
+
+        match r.powf(t) {
+            Some(rt) => relative_eq!(
+                rt,
+                Self::exp(&(r.log() * t)),
+                epsilon = epsilon,
+                max_relative = max_relative
+            ),
+            None => true,
+        }
+    }
+}
+
+/// Reflections with respect to a hyperplane, i.e., involutive isometries that fix every point of
+/// that hyperplane.
+///
+/// The hyperplane is the set of points `p` such that `⟨p - origin, axis⟩ = bias`, where `axis`
+/// is a unit vector. A reflection with a zero bias is linear: it is then an orthogonal
+/// transformation with a determinant equal to `-1`, and thus an element of the groups
+/// implementing `OrthogonalTransformation` that are not rotations.
+///
+/// Reflections do not form a group on their own, so this trait is not part of the group
+/// hierarchy.
+///
+/// ~~~notrust
+/// ∀ r ∈ Self, r ∘ r = id
+/// ~~~
+pub trait Reflection<E: EuclideanSpace>: Sized {
+    /// The unit normal of the reflection hyperplane.
+    fn axis(&self) -> E::Coordinates;
+
+    /// The signed distance from the origin to the reflection hyperplane, along `self.axis()`.
+    fn bias(&self) -> E::RealField;
+
+    /// Reflects the vector `v` with respect to the hyperplane parallel to the reflection
+    /// hyperplane and passing through the origin.
+    #[inline]
+    fn reflect_vector(&self, v: &E::Coordinates) -> E::Coordinates {
+        let axis = self.axis();
+        let two = num::one::<E::RealField>() + num::one();
+        let dot = v.inner_product(&axis);
+
+        v.clone() - axis * (dot * two)
+    }
+
+    /// Reflects the point `pt` with respect to the reflection hyperplane.
+    #[inline]
+    fn reflect_point(&self, pt: &E) -> E {
+        let axis = self.axis();
+        let two = num::one::<E::RealField>() + num::one();
+        let dist = pt.coordinates().inner_product(&axis) - self.bias();
+
+        pt.translate_by(&(axis * (-dist * two)))
+    }
+
+    /// Returns `true` if this reflection is approximately involutive for the given argument tuple.
+    fn prop_is_involutive_approx(args: (Self, E)) -> bool
+    where
+        E: RelativeEq,
+    {
+        Self::prop_is_involutive_approx_with(
+            args,
+            <E as AbsDiffEq>::default_epsilon(),
+            <E as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this reflection is approximately involutive for the given argument tuple.
+    /// The approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_is_involutive_approx_with(
+        args: (Self, E),
+        epsilon: <E as AbsDiffEq>::Epsilon,
+        max_relative: <E as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        E: RelativeEq,
+    {
+        let (r, pt) = args;
+        relative_eq!(
+            r.reflect_point(&r.reflect_point(&pt)),
+            pt,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if this reflection approximately fixes the projection of the given point on
+    /// the reflection hyperplane, and preserves the norm of the given vector.
+    fn prop_fixes_hyperplane_approx(args: (Self, E, E::Coordinates)) -> bool
+    where
+        E::RealField: RelativeEq,
+        E: RelativeEq<Epsilon = E::RealField>,
+    {
+        Self::prop_fixes_hyperplane_approx_with(
+            args,
+            <E::RealField as AbsDiffEq>::default_epsilon(),
+            <E::RealField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this reflection approximately fixes the projection of the given point on
+    /// the reflection hyperplane, and preserves the norm of the given vector. The approximate
+    /// equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_fixes_hyperplane_approx_with(
+        args: (Self, E, E::Coordinates),
+        epsilon: E::RealField,
+        max_relative: E::RealField,
+    ) -> bool
+    where
+        E: RelativeEq,
+        E::RealField: RelativeEq,
+        E: RelativeEq<Epsilon = E::RealField>,
+    {
+        let (r, pt, v) = args;
+        let axis = r.axis();
+        let dist = pt.coordinates().inner_product(&axis) - r.bias();
+        let proj = pt.translate_by(&(axis * -dist));
+
+        relative_eq!(
+            r.reflect_point(&proj),
+            proj,
+            epsilon = epsilon,
+            max_relative = max_relative
+        ) && relative_eq!(
+            r.reflect_vector(&v).norm(),
+            v.norm(),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+}
+
+/// A twist, i.e., an element of the Lie algebra of direct isometries: an angular velocity
+/// together with a linear velocity.
+///
+/// Integrating a constant twist during a given time yields a screw motion, i.e., a rotation about
+/// an axis combined with a translation along that same axis.
+///
+/// ~~~notrust
+/// ∀ m ∈ Self::Isometry, exp(log(m)) = m
+/// ∀ ξ ∈ Self, ∀ s, t ∈ E::RealField, exp(ξ * (s + t)) = exp(ξ * s) * exp(ξ * t)
+/// ~~~
+pub trait Twist<E: EuclideanSpace>: Sized + Clone + ClosedAdd + ClosedMul<E::RealField> {
+    /// The type of the angular velocities, i.e., the generators of the rotational part.
+    type AngularVelocity;
+    /// The type of the rigid-body motions generated by this twist.
+    type Isometry: DirectIsometry<E>;
+
+    /// The angular part of this twist.
+    fn angular_velocity(&self) -> Self::AngularVelocity;
+
+    /// The linear part of this twist.
+    fn linear_velocity(&self) -> E::Coordinates;
+
+    /// The exponential map: the rigid-body motion obtained by following this twist during a unit
+    /// of time.
+    fn exp(&self) -> Self::Isometry;
+
+    /// The logarithm map: the smallest twist generating the given rigid-body motion.
+    fn log(motion: &Self::Isometry) -> Self;
+
+    /// The rigid-body motion obtained by following this twist during the time `t`.
+    #[inline]
+    fn screw_motion(&self, t: E::RealField) -> Self::Isometry {
+        (self.clone() * t).exp()
+    }
+
+    /// The composition of two velocities expressed in the same frame.
+    #[inline]
+    fn compose_velocity(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
+
+    /// Returns `true` if `exp` is approximately a left inverse of `log` for the given argument.
+    fn prop_exp_log_is_identity_approx(args: (Self::Isometry,)) -> bool
+    where
+        Self::Isometry: RelativeEq,
+    {
+        Self::prop_exp_log_is_identity_approx_with(
+            args,
+            <Self::Isometry as AbsDiffEq>::default_epsilon(),
+            <Self::Isometry as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if `exp` is approximately a left inverse of `log` for the given argument. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_exp_log_is_identity_approx_with(
+        args: (Self::Isometry,),
+        epsilon: <Self::Isometry as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Isometry as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Isometry: RelativeEq,
+    {
+        let (m,) = args;
+        relative_eq!(
+            Self::log(&m).exp(),
+            m,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+
+    /// Returns `true` if the screw motions generated by this twist approximately form a
+    /// one-parameter subgroup for the given argument tuple.
+    fn prop_screw_motion_is_one_parameter_subgroup_approx(
+        args: (Self, E::RealField, E::RealField),
+    ) -> bool
+    where
+        Self::Isometry: RelativeEq,
+    {
+        Self::prop_screw_motion_is_one_parameter_subgroup_approx_with(
+            args,
+            <Self::Isometry as AbsDiffEq>::default_epsilon(),
+            <Self::Isometry as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the screw motions generated by this twist approximately form a
+    /// one-parameter subgroup for the given argument tuple. The approximate equalities use the
+    /// given `epsilon` and `max_relative` tolerances.
+    fn prop_screw_motion_is_one_parameter_subgroup_approx_with(
+        args: (Self, E::RealField, E::RealField),
+        epsilon: <Self::Isometry as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Isometry as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Isometry: RelativeEq,
+    {
+        let (xi, s, t) = args;
+        relative_eq!(
+            xi.screw_motion(s + t),
+            xi.screw_motion(s) * xi.screw_motion(t),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
 }
 
 /*
@@ -289,6 +1160,8 @@ pub trait Rotation<E: EuclideanSpace>:
  *
  */
 
+impl<R: RealField> MultiplicativeComposition for R {}
+
 impl<R, E> Transformation<E> for R
 where
     R: RealField,
@@ -389,7 +1262,7 @@ where
 
     #[inline]
     fn powf(&self, n: E::RealField) -> Option<Self> {
-        Some(n.powf(n))
+        Some(ComplexFieldCore::powf(*self, n))
     }
 
     #[inline]
@@ -1,11 +1,15 @@
-use num;
+use approx::{AbsDiffEq, RelativeEq};
+use num::{self, Zero};
 use num_complex::Complex;
 
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
-use crate::general::{ClosedAdd, ClosedDiv, ClosedMul, ComplexField, Field, Module, RealField};
+use crate::general::{
+    ClosedAdd, ClosedDiv, ClosedMul, ComplexField, ComplexFieldCore, Field, MetricSpace, Module,
+    RealField, SubsetOf, SupersetOf,
+};
 
 /// A vector space has a module structure over a field instead of a ring.
 pub trait VectorSpace: Module<Ring = <Self as VectorSpace>::Field>
@@ -14,6 +18,30 @@ ClosedDiv<<Self as VectorSpace>::Field> */
 {
     /// The underlying scalar field.
     type Field: Field;
+
+    /// Adds `a * x` to this vector, in-place.
+    ///
+    /// Implementors may override it with a fused version, which must give the same result.
+    #[inline]
+    fn axpy(&mut self, a: &Self::Field, x: &Self) {
+        *self += x.clone() * a.clone();
+    }
+
+    /// The linear combination `a₁ * x₁ + a₂ * x₂ + …` of the given pairs `(aᵢ, xᵢ)`, which is zero
+    /// if there is none.
+    ///
+    /// The default implementation accumulates the terms with `axpy`. Implementors may override it
+    /// with a fused version, which must give the same result.
+    #[inline]
+    fn linear_combination(pairs: &[(Self::Field, &Self)]) -> Self {
+        let mut res: Self = num::zero();
+
+        for (a, x) in pairs {
+            res.axpy(a, x);
+        }
+
+        res
+    }
 }
 
 /// A normed vector space.
@@ -27,21 +55,66 @@ pub trait NormedSpace: VectorSpace<Field = <Self as NormedSpace>::ComplexField>
     fn norm_squared(&self) -> Self::RealField;
 
     /// The norm of this vector.
-    fn norm(&self) -> Self::RealField;
+    #[inline]
+    fn norm(&self) -> Self::RealField {
+        self.norm_squared().sqrt()
+    }
 
     /// Returns a normalized version of this vector.
-    fn normalize(&self) -> Self;
+    ///
+    /// The result is unspecified (usually made of NaNs for floating-point fields) if this vector
+    /// is zero. Use `try_normalize` if this vector may be zero.
+    #[inline]
+    fn normalize(&self) -> Self {
+        let inv_norm = num::one::<Self::RealField>() / self.norm();
+        self.clone() * Self::ComplexField::from_real(inv_norm)
+    }
 
     /// Normalizes this vector in-place and returns its norm.
-    fn normalize_mut(&mut self) -> Self::RealField;
+    ///
+    /// The result is unspecified (usually made of NaNs for floating-point fields) if this vector
+    /// is zero. Use `try_normalize_mut` if this vector may be zero.
+    #[inline]
+    fn normalize_mut(&mut self) -> Self::RealField {
+        let norm = self.norm();
+        *self *= Self::ComplexField::from_real(num::one::<Self::RealField>() / norm);
+        norm
+    }
 
     /// Returns a normalized version of this vector unless its norm as smaller or equal to `eps`.
-    fn try_normalize(&self, eps: Self::RealField) -> Option<Self>;
+    ///
+    /// The tolerance `eps` must be non-negative. In particular, this returns `None` for the zero
+    /// vector. The comparison is performed on the squared norm so no square root is computed when
+    /// this returns `None`.
+    #[inline]
+    fn try_normalize(&self, eps: Self::RealField) -> Option<Self> {
+        let sq_norm = self.norm_squared();
+
+        if sq_norm > eps * eps {
+            let inv_norm = num::one::<Self::RealField>() / sq_norm.sqrt();
+            Some(self.clone() * Self::ComplexField::from_real(inv_norm))
+        } else {
+            None
+        }
+    }
 
     /// Normalizes this vector in-place or does nothing if its norm is smaller or equal to `eps`.
     ///
-    /// If the normalization succeeded, returns the old normal of this vector.
-    fn try_normalize_mut(&mut self, eps: Self::RealField) -> Option<Self::RealField>;
+    /// If the normalization succeeded, returns the old normal of this vector. The tolerance `eps`
+    /// must be non-negative. In particular, the zero vector is left unchanged and `None` is
+    /// returned.
+    #[inline]
+    fn try_normalize_mut(&mut self, eps: Self::RealField) -> Option<Self::RealField> {
+        let sq_norm = self.norm_squared();
+
+        if sq_norm > eps * eps {
+            let norm = sq_norm.sqrt();
+            *self *= Self::ComplexField::from_real(num::one::<Self::RealField>() / norm);
+            Some(norm)
+        } else {
+            None
+        }
+    }
 }
 
 /// A vector space equipped with an inner product.
@@ -73,6 +146,108 @@ pub trait InnerSpace: NormedSpace {
             }
         }
     }
+
+    /// The cosine of the angle between `self` and `other`, i.e., the real part of their inner
+    /// product divided by the product of their norms, clamped to `[-1, 1]`.
+    ///
+    /// Returns `None` if either vector is zero.
+    #[inline]
+    fn cosine_similarity(&self, other: &Self) -> Option<Self::RealField> {
+        let n1 = self.norm();
+        let n2 = other.norm();
+
+        if n1.is_zero() || n2.is_zero() {
+            None
+        } else {
+            let cos = self.inner_product(other).real() / (n1 * n2);
+            Some(cos.max(-num::one::<Self::RealField>()).min(num::one()))
+        }
+    }
+
+    /// The angle between `self` and `other`, in `[0, π]`.
+    ///
+    /// Returns zero if either vector is zero.
+    #[inline]
+    fn angle_between(&self, other: &Self) -> Self::RealField {
+        match self.cosine_similarity(other) {
+            Some(cos) => cos.acos(),
+            None => num::zero(),
+        }
+    }
+
+    /// The orthogonal projection of `self` onto the line spanned by `other`.
+    ///
+    /// Returns the zero vector if `other` is zero.
+    #[inline]
+    fn project_onto(&self, other: &Self) -> Self {
+        let sq_norm = other.norm_squared();
+
+        if sq_norm.is_zero() {
+            num::zero()
+        } else {
+            let factor = self.inner_product(other) / Self::ComplexField::from_real(sq_norm);
+            other.clone() * factor
+        }
+    }
+
+    /// The component of `self` orthogonal to `other`, i.e., `self - self.project_onto(other)`.
+    ///
+    /// Returns `self` if `other` is zero.
+    #[inline]
+    fn reject_from(&self, other: &Self) -> Self {
+        self.clone() - self.project_onto(other)
+    }
+}
+
+/// A complete normed vector space, i.e., where every Cauchy sequence converges.
+///
+/// This is a marker trait: completeness cannot be checked by a computer. Note that every
+/// finite-dimensional normed space over the reals or the complex numbers is complete.
+pub trait BanachSpace: NormedSpace {}
+
+/// A complete inner product space, i.e., a Banach space whose norm is induced by its inner
+/// product.
+///
+/// ~~~notrust
+/// ∀ v ∈ Self, ‖v‖² = ⟨v, v⟩
+/// ~~~
+pub trait HilbertSpace: InnerSpace + BanachSpace {
+    /// Returns `true` if the norm approximately agrees with the inner product for the given
+    /// argument.
+    fn prop_norm_agrees_with_inner_product_approx(args: (Self,)) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        Self::prop_norm_agrees_with_inner_product_approx_with(
+            args,
+            <Self::RealField as AbsDiffEq>::default_epsilon(),
+            <Self::RealField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the norm approximately agrees with the inner product for the given
+    /// argument. The approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_norm_agrees_with_inner_product_approx_with(
+        args: (Self,),
+        epsilon: Self::RealField,
+        max_relative: Self::RealField,
+    ) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        let (v,) = args;
+        relative_eq!(
+            v.norm_squared(),
+            v.inner_product(&v).real(),
+            epsilon = epsilon,
+            max_relative = max_relative
+        ) && relative_eq!(
+            v.norm() * v.norm(),
+            v.norm_squared(),
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
 }
 
 /// A finite-dimensional vector space.
@@ -84,6 +259,19 @@ pub trait FiniteDimVectorSpace:
     /// The vector space dimension.
     fn dimension() -> usize;
 
+    /// Builds a vector from its components in the canonical basis, where the `i`-th component is
+    /// given by `f(i)`.
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> Self::Field>(mut f: F) -> Self {
+        let mut res: Self = num::zero();
+
+        for i in 0..Self::dimension() {
+            res[i] = f(i);
+        }
+
+        res
+    }
+
     /// Applies the given closule to each element of this vector space's canonical basis. Stops if
     /// `f` returns `false`.
     // XXX: return an iterator instead when `-> impl Iterator` will be supported by Rust.
@@ -108,15 +296,468 @@ pub trait FiniteDimVectorSpace:
     unsafe fn component_unchecked_mut(&mut self, i: usize) -> &mut Self::Field;
 }
 
+/// A finite-dimensional vector space whose dimension is known at compile-time.
+///
+/// The dimension can then be used in constant expressions, e.g., to check that two spaces have
+/// the same dimension in a `const` assertion.
+pub trait ConstDimVectorSpace: FiniteDimVectorSpace {
+    /// The vector space dimension. Same as `Self::dimension()`.
+    const DIM: usize;
+}
+
+/// A finite-dimensional vector space whose components can be mapped to another field, yielding a
+/// vector of the same vector space over that field.
+///
+/// This is useful to, e.g., convert a whole vector from one floating-point precision to another.
+/// The components are promoted with the `SubsetOf`/`SupersetOf` inclusion maps, and the vector
+/// types of this crate also use them to implement `SubsetOf<Self::Mapped>`, so that a whole vector
+/// can be promoted the same way as a scalar.
+pub trait FiniteDimVectorSpaceMap<N2: Field>: FiniteDimVectorSpace {
+    /// The vector space with the same dimension and canonical basis as `Self`, but over `N2`.
+    type Mapped: FiniteDimVectorSpace<Field = N2>;
+
+    /// Applies `f` to each component of this vector, expressed in the canonical basis.
+    #[inline]
+    fn map_coords<F: FnMut(Self::Field) -> N2>(&self, mut f: F) -> Self::Mapped {
+        let mut res: Self::Mapped = num::zero();
+
+        for i in 0..Self::dimension() {
+            res[i] = f(self[i].clone());
+        }
+
+        res
+    }
+
+    /// Converts each component of this vector to its superset `N2`.
+    #[inline]
+    fn convert_coords(&self) -> Self::Mapped
+    where
+        Self::Field: SubsetOf<N2>,
+    {
+        self.map_coords(|x| x.to_superset())
+    }
+
+    /// Attempts to convert each component of this vector to its subset `N2`. Returns `None` if any
+    /// component has no equivalent in `N2`.
+    #[inline]
+    fn try_convert_coords(&self) -> Option<Self::Mapped>
+    where
+        Self::Field: SupersetOf<N2>,
+    {
+        let mut res: Self::Mapped = num::zero();
+
+        for i in 0..Self::dimension() {
+            res[i] = self[i].to_subset()?;
+        }
+
+        Some(res)
+    }
+}
+
+/// A linear map from a vector space to its scalar field, aka. a covector.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ V, ∀ s ∈ V::Field, f(a * s + b) = f(a) * s + f(b)
+/// ~~~
+pub trait LinearFunctional<V: VectorSpace>: Sized {
+    /// Evaluates this linear functional at `v`.
+    fn apply(&self, v: &V) -> V::Field;
+
+    /// Returns `true` if this functional is approximately linear for the given argument tuple.
+    fn prop_is_linear_approx(args: (Self, V, V, V::Field)) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        Self::prop_is_linear_approx_with(
+            args,
+            <V::Field as AbsDiffEq>::default_epsilon(),
+            <V::Field as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if this functional is approximately linear for the given argument tuple. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_is_linear_approx_with(
+        args: (Self, V, V, V::Field),
+        epsilon: <V::Field as AbsDiffEq>::Epsilon,
+        max_relative: <V::Field as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        let (f, a, b, s) = args;
+        let lhs = f.apply(&(a.clone() * s.clone() + b.clone()));
+        let rhs = f.apply(&a) * s + f.apply(&b);
+
+        relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+    }
+
+    /// Returns `true` if this functional is linear for the given argument tuple.
+    fn prop_is_linear(args: (Self, V, V, V::Field)) -> bool
+    where
+        V::Field: Eq,
+    {
+        let (f, a, b, s) = args;
+        f.apply(&(a.clone() * s.clone() + b.clone())) == f.apply(&a) * s + f.apply(&b)
+    }
+}
+
+/// A finite-dimensional vector space together with its dual, i.e., the space of linear
+/// functionals on `Self`.
+///
+/// The dual basis is the canonical basis of `Self::Dual`: the `i`-th dual basis element maps a
+/// vector to its `i`-th component.
+pub trait DualSpace: FiniteDimVectorSpace {
+    /// The space of linear functionals on `Self`.
+    type Dual: FiniteDimVectorSpace<Field = Self::Field> + LinearFunctional<Self>;
+
+    /// The canonical pairing `⟨covector, v⟩` between a covector and a vector.
+    #[inline]
+    fn pairing(covector: &Self::Dual, v: &Self) -> Self::Field {
+        let mut res: Self::Field = num::zero();
+
+        for i in 0..Self::dimension() {
+            res += covector[i].clone() * v[i].clone();
+        }
+
+        res
+    }
+
+    /// The covector with the same components as `self` in the dual basis.
+    ///
+    /// This is the isomorphism induced by the canonical basis so, in general, it is not
+    /// preserved by a change of basis.
+    #[inline]
+    fn to_dual(&self) -> Self::Dual {
+        let mut res: Self::Dual = num::zero();
+
+        for i in 0..Self::dimension() {
+            res[i] = self[i].clone();
+        }
+
+        res
+    }
+
+    /// The vector with the same components as `covector` in the canonical basis.
+    #[inline]
+    fn from_dual(covector: &Self::Dual) -> Self {
+        let mut res: Self = num::zero();
+
+        for i in 0..Self::dimension() {
+            res[i] = covector[i].clone();
+        }
+
+        res
+    }
+}
+
+/// The tensor product of two finite-dimensional vector spaces over the same field.
+///
+/// The canonical basis of `Self::Tensor` is made of the tensor products `eᵢ ⊗ fⱼ` of the
+/// canonical basis elements of `Self` and `W`, in row-major order, i.e., `eᵢ ⊗ fⱼ` is the
+/// `(i * W::dimension() + j)`-th basis element of `Self::Tensor`.
+pub trait TensorProduct<W: FiniteDimVectorSpace<Field = Self::Field>>:
+    FiniteDimVectorSpace
+{
+    /// The tensor product space, with dimension `Self::dimension() * W::dimension()`.
+    type Tensor: FiniteDimVectorSpace<Field = Self::Field>;
+
+    /// The tensor product `self ⊗ w`.
+    #[inline]
+    fn tensor(&self, w: &W) -> Self::Tensor {
+        let mut res: Self::Tensor = num::zero();
+        let dim = W::dimension();
+
+        for i in 0..Self::dimension() {
+            for j in 0..dim {
+                res[i * dim + j] = self[i].clone() * w[j].clone();
+            }
+        }
+
+        res
+    }
+
+    /// Returns `true` if the dimension of the tensor product is the product of the dimensions.
+    fn prop_dimension_is_product() -> bool {
+        Self::Tensor::dimension() == Self::dimension() * W::dimension()
+    }
+
+    /// Returns `true` if the tensor product of the `i`-th and `j`-th canonical basis elements is
+    /// the corresponding canonical basis element of the tensor product. The indices are taken
+    /// modulo the dimensions.
+    fn prop_tensor_of_bases_is_basis(args: (usize, usize)) -> bool
+    where
+        Self::Tensor: PartialEq,
+    {
+        let (i, j) = args;
+        let (i, j) = (i % Self::dimension(), j % W::dimension());
+        let e = Self::canonical_basis_element(i);
+        let f = W::canonical_basis_element(j);
+
+        e.tensor(&f) == Self::Tensor::canonical_basis_element(i * W::dimension() + j)
+    }
+
+    /// Returns `true` if the tensor product is approximately linear in each argument for the
+    /// given argument tuple.
+    fn prop_tensor_is_bilinear_approx(args: (Self, Self, W, W, Self::Field)) -> bool
+    where
+        Self::Tensor: RelativeEq,
+        <Self::Tensor as AbsDiffEq>::Epsilon: Clone,
+    {
+        Self::prop_tensor_is_bilinear_approx_with(
+            args,
+            <Self::Tensor as AbsDiffEq>::default_epsilon(),
+            <Self::Tensor as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the tensor product is approximately linear in each argument for the given
+    /// argument tuple. The approximate equalities use the given `epsilon` and `max_relative`
+    /// tolerances.
+    fn prop_tensor_is_bilinear_approx_with(
+        args: (Self, Self, W, W, Self::Field),
+        epsilon: <Self::Tensor as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Tensor as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Tensor: RelativeEq,
+        <Self::Tensor as AbsDiffEq>::Epsilon: Clone,
+    {
+        let (a, b, v, w, s) = args;
+        let ab = a.clone() * s.clone() + b.clone();
+        let vw = v.clone() * s.clone() + w.clone();
+
+        relative_eq!(
+            ab.tensor(&v),
+            a.tensor(&v) * s.clone() + b.tensor(&v),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a.tensor(&vw),
+            a.tensor(&v) * s + a.tensor(&w),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if the tensor product is linear in each argument for the given argument
+    /// tuple.
+    fn prop_tensor_is_bilinear(args: (Self, Self, W, W, Self::Field)) -> bool
+    where
+        Self::Tensor: Eq,
+    {
+        let (a, b, v, w, s) = args;
+        let ab = a.clone() * s.clone() + b.clone();
+        let vw = v.clone() * s.clone() + w.clone();
+
+        ab.tensor(&v) == a.tensor(&v) * s.clone() + b.tensor(&v)
+            && a.tensor(&vw) == a.tensor(&v) * s + a.tensor(&w)
+    }
+}
+
+/// A finite-dimensional vector space equipped with the family of `p`-norms, for `p ≥ 1`.
+///
+/// ~~~notrust
+/// ‖v‖ₚ = (∑ᵢ |vᵢ|ᵖ)^(1/p)
+/// ‖v‖∞ = maxᵢ |vᵢ|
+/// ~~~
+///
+/// The components are those in the canonical basis. All the methods have default
+/// implementations that may be overridden for efficiency.
+pub trait LpNormedSpace:
+    NormedSpace + FiniteDimVectorSpace<Field = <Self as NormedSpace>::ComplexField>
+{
+    /// The 1-norm of this vector, i.e., the sum of the moduli of its components.
+    #[inline]
+    fn norm_l1(&self) -> Self::RealField {
+        let mut res: Self::RealField = num::zero();
+
+        for i in 0..Self::dimension() {
+            res += self[i].modulus();
+        }
+
+        res
+    }
+
+    /// The 2-norm of this vector, i.e., the square root of the sum of the squared moduli of its
+    /// components.
+    #[inline]
+    fn norm_l2(&self) -> Self::RealField {
+        let mut res: Self::RealField = num::zero();
+
+        for i in 0..Self::dimension() {
+            res += self[i].modulus_squared();
+        }
+
+        res.sqrt()
+    }
+
+    /// The ∞-norm of this vector, i.e., the largest modulus of its components.
+    #[inline]
+    fn norm_linf(&self) -> Self::RealField {
+        let mut res: Self::RealField = num::zero();
+
+        for i in 0..Self::dimension() {
+            res = res.max(self[i].modulus());
+        }
+
+        res
+    }
+
+    /// The `p`-norm of this vector. An infinite `p` yields the ∞-norm.
+    ///
+    /// Panics if `p < 1`.
+    #[inline]
+    fn norm_p(&self, p: Self::RealField) -> Self::RealField {
+        assert!(p >= num::one(), "The p-norm is only defined for p ≥ 1.");
+
+        if !p.is_finite() {
+            self.norm_linf()
+        } else if p == num::one() {
+            self.norm_l1()
+        } else if p == num::one::<Self::RealField>() + num::one() {
+            self.norm_l2()
+        } else {
+            let mut res: Self::RealField = num::zero();
+
+            for i in 0..Self::dimension() {
+                res += self[i].modulus().powf(p);
+            }
+
+            res.powf(num::one::<Self::RealField>() / p)
+        }
+    }
+
+    /// Returns `true` if the `p`-norm, with `p = 1 + |q|`, satisfies the triangle inequality up
+    /// to rounding errors for the given argument tuple.
+    fn prop_triangle_inequality_approx(args: (Self, Self, Self::RealField)) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        Self::prop_triangle_inequality_approx_with(
+            args,
+            <Self::RealField as AbsDiffEq>::default_epsilon(),
+            <Self::RealField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the `p`-norm, with `p = 1 + |q|`, satisfies the triangle inequality up to
+    /// rounding errors for the given argument tuple. The approximate equalities use the given
+    /// `epsilon` and `max_relative` tolerances.
+    fn prop_triangle_inequality_approx_with(
+        args: (Self, Self, Self::RealField),
+        epsilon: Self::RealField,
+        max_relative: Self::RealField,
+    ) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        let (a, b, q) = args;
+        let p = num::one::<Self::RealField>() + q.abs();
+        let lhs = (a.clone() + b.clone()).norm_p(p);
+        let rhs = a.norm_p(p) + b.norm_p(p);
+
+        lhs <= rhs || relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+    }
+
+    /// Returns `true` if the 1-, 2-, and ∞-norms satisfy the triangle inequality up to rounding
+    /// errors for the given argument tuple.
+    fn prop_triangle_inequality_l1_l2_linf_approx(args: (Self, Self)) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        Self::prop_triangle_inequality_l1_l2_linf_approx_with(
+            args,
+            <Self::RealField as AbsDiffEq>::default_epsilon(),
+            <Self::RealField as RelativeEq>::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the 1-, 2-, and ∞-norms satisfy the triangle inequality up to rounding
+    /// errors for the given argument tuple. The approximate equalities use the given `epsilon` and
+    /// `max_relative` tolerances.
+    fn prop_triangle_inequality_l1_l2_linf_approx_with(
+        args: (Self, Self),
+        epsilon: Self::RealField,
+        max_relative: Self::RealField,
+    ) -> bool
+    where
+        Self::RealField: RelativeEq,
+    {
+        let (a, b) = args;
+        let ab = a.clone() + b.clone();
+        let check = |lhs: Self::RealField, rhs: Self::RealField| {
+            lhs <= rhs || relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+        };
+
+        check(ab.norm_l1(), a.norm_l1() + b.norm_l1())
+            && check(ab.norm_l2(), a.norm_l2() + b.norm_l2())
+            && check(ab.norm_linf(), a.norm_linf() + b.norm_linf())
+    }
+}
+
 /// A finite-dimensional vector space equipped with an inner product that must coincide
 /// with the dot product.
 pub trait FiniteDimInnerSpace:
     InnerSpace + FiniteDimVectorSpace<Field = <Self as NormedSpace>::ComplexField>
 {
     /// Orthonormalizes the given family of vectors. The largest free family of vectors is moved at
-    /// the beginning of the array and its size is returned. Vectors at an indices larger or equal to
+    /// the beginning of the array and its size is returned. Vectors at indices larger or equal to
     /// this length can be modified to an arbitrary value.
-    fn orthonormalize(vs: &mut [Self]) -> usize;
+    ///
+    /// Defaults to the modified Gram-Schmidt process with the default epsilon of the underlying
+    /// reals as tolerance.
+    #[inline]
+    fn orthonormalize(vs: &mut [Self]) -> usize {
+        Self::modified_gram_schmidt(vs, Self::RealField::default_epsilon())
+    }
+
+    /// Orthonormalizes the given family of vectors using the modified Gram-Schmidt process.
+    ///
+    /// Unlike the classical Gram-Schmidt process, each vector is orthogonalized against the
+    /// previous ones one at a time, which is numerically much more stable. Vectors that have a norm
+    /// smaller or equal to `eps` once orthogonalized are considered linearly dependent on the
+    /// previous ones. The largest free family of vectors is moved at the beginning of the array
+    /// and its size is returned. Vectors at indices larger or equal to this length can be
+    /// modified to an arbitrary value.
+    #[inline]
+    fn modified_gram_schmidt(vs: &mut [Self], eps: Self::RealField) -> usize {
+        let mut rank = 0;
+
+        for i in 0..vs.len() {
+            let mut v = vs[i].clone();
+
+            for q in &vs[..rank] {
+                let proj = v.inner_product(q);
+                v -= q.clone() * proj;
+            }
+
+            if v.try_normalize_mut(eps).is_some() {
+                vs[rank] = v;
+                rank += 1;
+            }
+        }
+
+        rank
+    }
+
+    /// Applies the given closure to each element of an orthonormal basis of the whole space that
+    /// starts with an orthonormalization of `vs`. Stops if `f` returns `false`.
+    ///
+    /// The vectors of `vs` are orthonormalized in-place using `Self::orthonormalize`. The
+    /// remaining basis elements are those given by `Self::orthonormal_subspace_basis`.
+    #[inline]
+    fn complete_basis<F: FnMut(&Self) -> bool>(vs: &mut [Self], mut f: F) {
+        let rank = Self::orthonormalize(vs);
+
+        for v in &vs[..rank] {
+            if !f(v) {
+                return;
+            }
+        }
+
+        Self::orthonormal_subspace_basis(&vs[..rank], f)
+    }
 
     /// Applies the given closure to each element of the orthonormal basis of the subspace
     /// orthogonal to free family of vectors `vs`. If `vs` is not a free family, the result is
@@ -125,6 +766,168 @@ pub trait FiniteDimInnerSpace:
     fn orthonormal_subspace_basis<F: FnMut(&Self) -> bool>(vs: &[Self], f: F);
 }
 
+/// Applies `f` to each element of an orthonormal basis of the subspace orthogonal to the free
+/// family `vs`, obtained by orthogonalizing the canonical basis of the `D`-dimensional space `V`.
+///
+/// This implements `FiniteDimInnerSpace::orthonormal_subspace_basis` for copyable vectors with a
+/// dimension known at compile-time.
+pub(crate) fn orthonormal_subspace_basis_from_canonical<V, F, const D: usize>(vs: &[V], mut f: F)
+where
+    V: FiniteDimInnerSpace + Copy,
+    F: FnMut(&V) -> bool,
+{
+    // NOTE: the residuals of the canonical basis elements have squared norms summing up to the
+    // dimension of the orthogonal subspace, so a fairly large threshold is safe here.
+    let eps = V::RealField::default_epsilon().sqrt();
+    let mut basis = [V::zero(); D];
+    let mut rank = vs.len();
+
+    basis[..rank].copy_from_slice(vs);
+
+    for i in 0..D {
+        if rank == D {
+            break;
+        }
+
+        let mut v = V::canonical_basis_element(i);
+
+        for q in &basis[..rank] {
+            let proj = v.inner_product(q);
+            v -= *q * proj;
+        }
+
+        if v.try_normalize_mut(eps).is_some() {
+            if !f(&v) {
+                return;
+            }
+
+            basis[rank] = v;
+            rank += 1;
+        }
+    }
+}
+
+/// A set closed under convex combinations, i.e., weighted averages of two elements with a weight
+/// in `[0, 1]`.
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, a ⊕₀ b = a and a ⊕₁ b = b
+/// ∀ a, b ∈ Self, ∀ t ∈ [0, 1], a ⊕ₜ b = b ⊕₁₋ₜ a
+/// ∀ a ∈ Self, ∀ t ∈ [0, 1], a ⊕ₜ a = a
+/// ~~~
+///
+/// This is weaker than an affine space: every affine space over the reals is a convex space,
+/// but so are, e.g., probabilities or intervals which are not closed under arbitrary affine
+/// combinations.
+pub trait ConvexSpace: Sized + Clone {
+    /// The type of the weights.
+    type Weight: RealField;
+
+    /// The convex combination `(1 - t) * self + t * other`, aka. linear interpolation.
+    ///
+    /// The result is unspecified if `t` is not in `[0, 1]`.
+    fn convex_combination(&self, other: &Self, t: Self::Weight) -> Self;
+
+    /// Returns `true` if the convex combinations with weight zero and one approximately give
+    /// back the endpoints for the given argument tuple.
+    fn prop_convex_combination_endpoints_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_convex_combination_endpoints_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the convex combinations with weight zero and one approximately give back
+    /// the endpoints for the given argument tuple. The approximate equalities use the given
+    /// `epsilon` and `max_relative` tolerances.
+    fn prop_convex_combination_endpoints_approx_with(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            a.convex_combination(&b, num::zero()),
+            a,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a.convex_combination(&b, num::one()),
+            b,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+
+    /// Returns `true` if the convex combinations are approximately symmetric and idempotent for
+    /// the given argument tuple. The weight is taken modulo one.
+    fn prop_convex_combination_is_symmetric_approx(args: (Self, Self, Self::Weight)) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        Self::prop_convex_combination_is_symmetric_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if the convex combinations are approximately symmetric and idempotent for the
+    /// given argument tuple. The weight is taken modulo one. The approximate equalities use the
+    /// given `epsilon` and `max_relative` tolerances.
+    fn prop_convex_combination_is_symmetric_approx_with(
+        args: (Self, Self, Self::Weight),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        let (a, b, t) = args;
+        let t = t.abs().fract();
+        let s = num::one::<Self::Weight>() - t;
+
+        relative_eq!(
+            a.convex_combination(&b, t),
+            b.convex_combination(&a, s),
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        ) && relative_eq!(
+            a.convex_combination(&a, t),
+            a,
+            epsilon = epsilon.clone(),
+            max_relative = max_relative.clone()
+        )
+    }
+}
+
+// NOTE: probabilities are closed under convex combinations, as are the reals.
+macro_rules! impl_convex_space(
+    ($($T:ty),*) => {$(
+        impl ConvexSpace for $T {
+            type Weight = $T;
+
+            #[inline]
+            fn convex_combination(&self, other: &Self, t: Self::Weight) -> Self {
+                *self + (*other - *self) * t
+            }
+        }
+    )*}
+);
+
+impl_convex_space!(f32, f64);
+
 /// A set points associated with a vector space and a transitive and free additive group action
 /// (the translation).
 pub trait AffineSpace:
@@ -150,6 +953,122 @@ pub trait AffineSpace:
     fn subtract(&self, right: &Self) -> Self::Translation {
         self.clone() - right.clone()
     }
+
+    /// The affine combination `∑ᵢ weights[i] * points[i]` of the given points.
+    ///
+    /// The weights must sum to one for the result to be independent from the choice of origin.
+    /// Panics if `points` is empty or if `points` and `weights` do not have the same length.
+    #[inline]
+    fn affine_combination(
+        points: &[Self],
+        weights: &[<Self::Translation as VectorSpace>::Field],
+    ) -> Self {
+        assert!(
+            !points.is_empty() && points.len() == weights.len(),
+            "An affine combination requires as many weights as points, and at least one point."
+        );
+
+        let reference = &points[0];
+        let mut offset: Self::Translation = num::zero();
+
+        for (p, w) in points.iter().zip(weights.iter()).skip(1) {
+            offset += p.subtract(reference) * w.clone();
+        }
+
+        reference.translate_by(&offset)
+    }
+
+    /// The barycenter of the given points, i.e., their affine combination with the given weights
+    /// divided by their sum.
+    ///
+    /// Panics if `points` is empty or if `points` and `weights` do not have the same length. The
+    /// result is unspecified if the weights sum to zero.
+    #[inline]
+    fn barycenter(points: &[Self], weights: &[<Self::Translation as VectorSpace>::Field]) -> Self {
+        assert!(
+            !points.is_empty() && points.len() == weights.len(),
+            "A barycenter requires as many weights as points, and at least one point."
+        );
+
+        let reference = &points[0];
+        let mut total = weights[0].clone();
+        let mut offset: Self::Translation = num::zero();
+
+        for (p, w) in points.iter().zip(weights.iter()).skip(1) {
+            offset += p.subtract(reference) * w.clone();
+            total += w.clone();
+        }
+
+        reference.translate_by(
+            &(offset * (num::one::<<Self::Translation as VectorSpace>::Field>() / total)),
+        )
+    }
+
+    /// Returns `true` if an affine combination with weights summing to one does not depend on the
+    /// point used as reference for the given argument tuple.
+    fn prop_affine_combination_is_origin_independent_approx(
+        args: (
+            Self,
+            Self,
+            Self,
+            <Self::Translation as VectorSpace>::Field,
+            <Self::Translation as VectorSpace>::Field,
+        ),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_affine_combination_is_origin_independent_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if an affine combination with weights summing to one does not depend on the
+    /// point used as reference for the given argument tuple. The approximate equalities use the
+    /// given `epsilon` and `max_relative` tolerances.
+    fn prop_affine_combination_is_origin_independent_approx_with(
+        args: (
+            Self,
+            Self,
+            Self,
+            <Self::Translation as VectorSpace>::Field,
+            <Self::Translation as VectorSpace>::Field,
+        ),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (a, b, c, wa, wb) = args;
+        let wc = num::one::<<Self::Translation as VectorSpace>::Field>() - wa.clone() - wb.clone();
+        let combination = Self::affine_combination(
+            &[a.clone(), b.clone(), c.clone()],
+            &[wa.clone(), wb.clone(), wc.clone()],
+        );
+        let shifted = Self::affine_combination(&[c, a, b], &[wc, wa, wb]);
+
+        relative_eq!(
+            combination,
+            shifted,
+            epsilon = epsilon,
+            max_relative = max_relative
+        )
+    }
+}
+
+impl<P: AffineSpace> ConvexSpace for P
+where
+    <P::Translation as VectorSpace>::Field: RealField,
+{
+    type Weight = <P::Translation as VectorSpace>::Field;
+
+    #[inline]
+    fn convex_combination(&self, other: &Self, t: Self::Weight) -> Self {
+        self.translate_by(&(other.subtract(self) * t))
+    }
 }
 
 /// The finite-dimensional affine space based on the field of reals.
@@ -223,6 +1142,114 @@ pub trait EuclideanSpace: AffineSpace<Translation = <Self as EuclideanSpace>::Co
     }
 }
 
+impl<E: EuclideanSpace> MetricSpace for E {
+    type Distance = E::RealField;
+
+    #[inline]
+    fn distance(&self, other: &Self) -> E::RealField {
+        EuclideanSpace::distance(self, other)
+    }
+}
+
+/// An euclidean space together with its projective completion, i.e., where points are
+/// represented by their homogeneous coordinates.
+///
+/// The homogeneous coordinates of a point are its coordinates followed by an extra component set
+/// to one. Any non-zero multiple of those represents the same point. Homogeneous coordinates with
+/// a zero last component represent points at infinity, i.e., directions.
+pub trait ProjectiveSpace: EuclideanSpace {
+    /// The homogeneous coordinates, with a dimension equal to the dimension of `Self` plus one.
+    type Homogeneous: FiniteDimVectorSpace<Field = Self::RealField>;
+
+    /// The homogeneous coordinates of this point.
+    #[inline]
+    fn to_homogeneous(&self) -> Self::Homogeneous {
+        let coords = self.coordinates();
+        let dim = Self::Coordinates::dimension();
+        let mut res: Self::Homogeneous = num::zero();
+
+        for i in 0..dim {
+            res[i] = coords[i];
+        }
+
+        res[dim] = num::one();
+        res
+    }
+
+    /// The homogeneous coordinates of the point at infinity in the direction `v`.
+    #[inline]
+    fn direction_to_homogeneous(v: &Self::Coordinates) -> Self::Homogeneous {
+        let mut res: Self::Homogeneous = num::zero();
+
+        for i in 0..Self::Coordinates::dimension() {
+            res[i] = v[i];
+        }
+
+        res
+    }
+
+    /// Returns `true` if `h` represents a point at infinity.
+    #[inline]
+    fn is_at_infinity(h: &Self::Homogeneous) -> bool {
+        h[Self::Coordinates::dimension()].is_zero()
+    }
+
+    /// The point represented by the homogeneous coordinates `h`. Returns `None` if `h` represents
+    /// a point at infinity.
+    #[inline]
+    fn from_homogeneous(h: &Self::Homogeneous) -> Option<Self> {
+        if Self::is_at_infinity(h) {
+            None
+        } else {
+            let dim = Self::Coordinates::dimension();
+            let w = h[dim];
+            let mut coords: Self::Coordinates = num::zero();
+
+            for i in 0..dim {
+                coords[i] = h[i] / w;
+            }
+
+            Some(Self::from_coordinates(coords))
+        }
+    }
+
+    /// Returns `true` if converting the given point to homogeneous coordinates, scaling them by a
+    /// non-zero factor, and converting them back, approximately gives back that point.
+    fn prop_homogeneous_round_trip_approx(args: (Self, Self::RealField)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        Self::prop_homogeneous_round_trip_approx_with(
+            args,
+            Self::default_epsilon(),
+            Self::default_max_relative(),
+        )
+    }
+
+    /// Returns `true` if converting the given point to homogeneous coordinates, scaling them by a
+    /// non-zero factor, and converting them back, approximately gives back that point. The
+    /// approximate equalities use the given `epsilon` and `max_relative` tolerances.
+    fn prop_homogeneous_round_trip_approx_with(
+        args: (Self, Self::RealField),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        let (pt, s) = args;
+
+        if s.is_zero() {
+            return true;
+        }
+
+        match Self::from_homogeneous(&(pt.to_homogeneous() * s)) {
+            Some(back) => relative_eq!(back, pt, epsilon = epsilon, max_relative = max_relative),
+            None => false,
+        }
+    }
+}
+
 macro_rules! impl_vec_space(
     ($($T:ty),*) => {
         $(
@@ -284,6 +1311,22 @@ macro_rules! impl_vec_space(
 );
 
 impl_vec_space!(f32, f64);
+impl BanachSpace for f32 {}
+impl BanachSpace for f64 {}
+
+macro_rules! impl_linear_functional(
+    ($($T:ty),*) => {$(
+        // NOTE: the linear forms on a field are the multiplications by its elements.
+        impl LinearFunctional<$T> for $T {
+            #[inline]
+            fn apply(&self, v: &$T) -> $T {
+                *self * *v
+            }
+        }
+    )*}
+);
+
+impl_linear_functional!(f32, f64);
 
 impl<N: Field + num::NumAssign> VectorSpace for Complex<N> {
     type Field = N;
@@ -338,5 +1381,7 @@ impl<N: RealField> NormedSpace for Complex<N> {
     }
 }
 
+impl<N: RealField> BanachSpace for Complex<N> {}
+
 // Note: we can't implement FiniteDimVectorSpace for Complex because
 // the `Complex` type does not implement Index.
@@ -140,20 +140,486 @@ macro_rules! impl_ident {
     }
 }
 
-macro_rules! impl_approx_eq {
-    ($V:expr; $($T:ty),* $(,)*) => {
-        $(impl ApproxEq for $T {
-            type Eps = $T;
-            #[inline]
-            fn default_epsilon() -> Self::Eps { $V }
-            #[inline]
-            fn approx_eq_eps(&self, b: &$T, epsilon: &$T) -> bool {
-                if self < b {
-                    *b - *self <= *epsilon
-                } else {
-                    *self - *b <= *epsilon
-                }
-            }
-        })+
+macro_rules! impl_const_ident {
+    ($M:ty; $V:expr; $($T:ty),* $(,)*) => {
+        $(impl ConstIdentity<$M> for $T { const IDENTITY: $T = $V; })+
+        impl_ident!($M; $V; $($T),*);
     }
 }
+
+/// Implements the standard `From` and `TryFrom` conversions for pairs of types already related by
+/// `SubsetOf`.
+///
+/// For each `Subset => Superset` pair, `From<Subset> for Superset` is implemented with
+/// `SubsetOf::to_superset`, and `TryFrom<Superset> for Subset` is implemented with
+/// `SubsetOf::from_superset`. The usual orphan rules apply, so at least one of the two types must
+/// be defined by the crate invoking this macro.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # use alga::general::SubsetOf;
+/// # use std::convert::TryFrom;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Even(i64);
+///
+/// impl SubsetOf<i64> for Even {
+///     fn to_superset(&self) -> i64 {
+///         self.0
+///     }
+///
+///     unsafe fn from_superset_unchecked(element: &i64) -> Even {
+///         Even(*element)
+///     }
+///
+///     fn is_in_subset(element: &i64) -> bool {
+///         *element % 2 == 0
+///     }
+/// }
+///
+/// std_convert_via_subset!(Even => i64);
+///
+/// # fn main() {
+/// assert_eq!(i64::from(Even(4)), 4);
+/// assert_eq!(Even::try_from(4), Ok(Even(4)));
+/// assert!(Even::try_from(3).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! std_convert_via_subset(
+    ($($subset: ty => $superset: ty),* $(,)*) => {$(
+        impl ::core::convert::From<$subset> for $superset {
+            #[inline]
+            fn from(element: $subset) -> $superset {
+                $crate::general::SubsetOf::<$superset>::to_superset(&element)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$superset> for $subset {
+            type Error = $crate::general::NotInSubsetError;
+
+            #[inline]
+            fn try_from(element: $superset) -> ::core::result::Result<$subset, Self::Error> {
+                <$subset as $crate::general::SubsetOf<$superset>>::from_superset(&element)
+                    .ok_or($crate::general::NotInSubsetError)
+            }
+        }
+    )*}
+);
+
+/// Implements `SubsetOf` for pairs of types already related by the standard `From` and
+/// `TryFrom` conversions.
+///
+/// For each `Subset as Superset` pair, `Superset` must implement `From<Subset>` and `Subset` must
+/// implement `TryFrom<Superset>`. Both types must be `Clone` because the standard conversions
+/// consume their argument. The usual orphan rules apply, so at least one of the two types must be
+/// defined by the crate invoking this macro.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # use alga::general::{SubsetOf, SupersetOf};
+/// # use std::convert::TryFrom;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Digit(u8);
+///
+/// impl From<Digit> for u32 {
+///     fn from(d: Digit) -> u32 {
+///         d.0 as u32
+///     }
+/// }
+///
+/// impl TryFrom<u32> for Digit {
+///     type Error = ();
+///
+///     fn try_from(n: u32) -> Result<Digit, ()> {
+///         if n < 10 { Ok(Digit(n as u8)) } else { Err(()) }
+///     }
+/// }
+///
+/// subset_via_tryfrom!(Digit as u32);
+///
+/// # fn main() {
+/// assert_eq!(Digit(7).to_superset(), 7u32);
+/// assert_eq!(9u32.to_subset(), Some(Digit(9)));
+/// assert_eq!(SupersetOf::<Digit>::to_subset(&10u32), None);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! subset_via_tryfrom(
+    ($($subset: ty as $superset: ty),* $(,)*) => {$(
+        impl $crate::general::SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                <$superset as ::core::convert::From<$subset>>::from(self.clone())
+            }
+
+            #[inline]
+            fn from_superset(element: &$superset) -> ::core::option::Option<$subset> {
+                <$subset as ::core::convert::TryFrom<$superset>>::try_from(element.clone()).ok()
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                match <$subset as ::core::convert::TryFrom<$superset>>::try_from(element.clone()) {
+                    ::core::result::Result::Ok(e) => e,
+                    ::core::result::Result::Err(_) => panic!("Conversion to subset failed."),
+                }
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                <$subset as ::core::convert::TryFrom<$superset>>::try_from(element.clone()).is_ok()
+            }
+        }
+    )*}
+);
+
+/// Implements the reflexive inclusions `SubsetOf<Self>` and `ExactSubsetOf<Self>` for each of the
+/// given types.
+///
+/// Every set is a subset of itself, and `ComplexField` requires this inclusion explicitly. It
+/// cannot be provided by a blanket `impl<T: Clone> SubsetOf<T> for T` because it would overlap
+/// with the generic inclusions of `Id`, `Complex`, and of the reals into the complex numbers, even
+/// with specialization. The types must be `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # use alga::general::{SubsetOf, SupersetOf};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Scalar(f64);
+///
+/// impl_reflexive_subset!(Scalar);
+///
+/// # fn main() {
+/// assert_eq!(SubsetOf::<Scalar>::to_superset(&Scalar(1.0)), Scalar(1.0));
+/// assert_eq!(SupersetOf::<Scalar>::to_subset(&Scalar(2.0)), Some(Scalar(2.0)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_reflexive_subset(
+    ($($T: ty),* $(,)*) => {$(
+        impl $crate::general::SubsetOf<$T> for $T {
+            #[inline]
+            fn to_superset(&self) -> $T {
+                self.clone()
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$T) -> $T {
+                element.clone()
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$T) -> bool {
+                true
+            }
+        }
+
+        impl $crate::general::ExactSubsetOf<$T> for $T {}
+    )*}
+);
+
+/// Implements the `num-traits` identity traits `Zero` and `One` for types already equipped with
+/// alga's `Identity<Additive>` and `Identity<Multiplicative>`.
+///
+/// The types must also implement `PartialEq`, `Add`, and `Mul`. The usual orphan rules apply, so
+/// the types must be defined by the crate invoking this macro.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # extern crate num_traits;
+/// # use alga::general::{Additive, Identity, Multiplicative};
+/// # use std::ops::{Add, Mul};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Boolean(bool);
+///
+/// impl Add for Boolean {
+///     type Output = Boolean;
+///     fn add(self, rhs: Boolean) -> Boolean { Boolean(self.0 ^ rhs.0) }
+/// }
+///
+/// impl Mul for Boolean {
+///     type Output = Boolean;
+///     fn mul(self, rhs: Boolean) -> Boolean { Boolean(self.0 & rhs.0) }
+/// }
+///
+/// impl Identity<Additive> for Boolean {
+///     fn identity() -> Boolean { Boolean(false) }
+/// }
+///
+/// impl Identity<Multiplicative> for Boolean {
+///     fn identity() -> Boolean { Boolean(true) }
+/// }
+///
+/// num_from_alga!(Boolean);
+///
+/// # fn main() {
+/// assert_eq!(<Boolean as num_traits::Zero>::zero(), Boolean(false));
+/// assert_eq!(<Boolean as num_traits::One>::one(), Boolean(true));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! num_from_alga(
+    ($($T: ty),* $(,)*) => {$(
+        impl $crate::__num::Zero for $T {
+            #[inline]
+            fn zero() -> $T {
+                <$T as $crate::general::Identity<$crate::general::Additive>>::identity()
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == <$T as $crate::__num::Zero>::zero()
+            }
+        }
+
+        impl $crate::__num::One for $T {
+            #[inline]
+            fn one() -> $T {
+                <$T as $crate::general::Identity<$crate::general::Multiplicative>>::identity()
+            }
+        }
+    )*}
+);
+
+/// Implements alga's algebraic structures for types implementing the `num-traits` arithmetic
+/// traits, e.g., `Num + NumAssign`.
+///
+/// By default, the ring structures up to `AbstractRingCommutative` are implemented. Prefixing the
+/// list of types with `field:` implements the structures up to `AbstractField` instead, with the
+/// multiplicative inverse given by the division. The types must implement `Clone`, `PartialEq`,
+/// `Zero`, `One`, `Add`, `Sub`, and `Mul` (and `Div` for fields), and must actually satisfy the
+/// corresponding laws. The usual orphan rules apply, so the types must be defined by the crate
+/// invoking this macro.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # extern crate num_traits;
+/// # use alga::general::AbstractRingCommutative;
+/// # use num_traits::{One, Zero};
+/// # use std::ops::{Add, Mul, Sub};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Int(i64);
+///
+/// impl Add for Int {
+///     type Output = Int;
+///     fn add(self, rhs: Int) -> Int { Int(self.0 + rhs.0) }
+/// }
+///
+/// impl Sub for Int {
+///     type Output = Int;
+///     fn sub(self, rhs: Int) -> Int { Int(self.0 - rhs.0) }
+/// }
+///
+/// impl Mul for Int {
+///     type Output = Int;
+///     fn mul(self, rhs: Int) -> Int { Int(self.0 * rhs.0) }
+/// }
+///
+/// impl Zero for Int {
+///     fn zero() -> Int { Int(0) }
+///     fn is_zero(&self) -> bool { self.0 == 0 }
+/// }
+///
+/// impl One for Int {
+///     fn one() -> Int { Int(1) }
+/// }
+///
+/// impl_alga_from_num!(Int);
+///
+/// fn is_ring<T: AbstractRingCommutative>() {}
+///
+/// # fn main() {
+/// is_ring::<Int>();
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_alga_from_num(
+    (field: $($T: ty),* $(,)*) => {$(
+        impl_alga_from_num!($T);
+
+        impl $crate::general::TwoSidedInverse<$crate::general::Multiplicative> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> $T {
+                <$T as $crate::__num::One>::one() / self.clone()
+            }
+        }
+
+        impl $crate::general::AbstractQuasigroup<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractLoop<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractGroup<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractGroupAbelian<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractField for $T {}
+    )*};
+    ($($T: ty),* $(,)*) => {$(
+        impl $crate::general::AbstractMagma<$crate::general::Additive> for $T {
+            #[inline]
+            fn operate(&self, right: &$T) -> $T {
+                self.clone() + right.clone()
+            }
+        }
+
+        impl $crate::general::AbstractMagma<$crate::general::Multiplicative> for $T {
+            #[inline]
+            fn operate(&self, right: &$T) -> $T {
+                self.clone() * right.clone()
+            }
+        }
+
+        impl $crate::general::TwoSidedInverse<$crate::general::Additive> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> $T {
+                <$T as $crate::__num::Zero>::zero() - self.clone()
+            }
+        }
+
+        impl $crate::general::Identity<$crate::general::Additive> for $T {
+            #[inline]
+            fn identity() -> $T {
+                <$T as $crate::__num::Zero>::zero()
+            }
+        }
+
+        impl $crate::general::Identity<$crate::general::Multiplicative> for $T {
+            #[inline]
+            fn identity() -> $T {
+                <$T as $crate::__num::One>::one()
+            }
+        }
+
+        impl $crate::general::AbstractSemigroup<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractQuasigroup<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractMonoid<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractLoop<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractGroup<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractGroupAbelian<$crate::general::Additive> for $T {}
+        impl $crate::general::AbstractSemigroup<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractMonoid<$crate::general::Multiplicative> for $T {}
+        impl $crate::general::AbstractRing for $T {}
+        impl $crate::general::AbstractRingCommutative for $T {}
+    )*}
+);
+
+/// Records the algebraic structures implemented by each of the given types, so that they can be
+/// queried at runtime with `structures_of` and `has_structure`.
+///
+/// Each structure is given by the name of a variant of `StructureId`, followed by its operators
+/// if any, just like in the `alga_traits` attribute of `alga_derive`. The supertraits of a
+/// structure are implied and need not be listed. The macro fails to compile if a type does not
+/// actually implement one of its structures.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # use alga::general::{
+/// #     has_structure, structures_of, AbstractMagma, AbstractMonoid, AbstractSemigroup, Additive,
+/// #     Identity, OperatorId, StructureId,
+/// # };
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Length(u64);
+///
+/// impl AbstractMagma<Additive> for Length {
+///     fn operate(&self, right: &Self) -> Self {
+///         Length(self.0 + right.0)
+///     }
+/// }
+///
+/// impl Identity<Additive> for Length {
+///     fn identity() -> Self {
+///         Length(0)
+///     }
+/// }
+///
+/// impl AbstractSemigroup<Additive> for Length {}
+/// impl AbstractMonoid<Additive> for Length {}
+///
+/// register_structures!(Length => Monoid(Additive));
+///
+/// # fn main() {
+/// assert_eq!(
+///     structures_of::<Length>(),
+///     &[StructureId::Monoid(OperatorId::Additive)]
+/// );
+/// assert!(has_structure::<Length>(StructureId::Semigroup(OperatorId::Additive)));
+/// assert!(!has_structure::<Length>(StructureId::Group(OperatorId::Additive)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! register_structures(
+    ($($($T: ty),+ => $($S: ident $(($($O: ident),+))*),+);* $(;)*) => {$(
+        register_structures!(@types [$($T),+] [$($S $(($($O),+))*),+]);
+    )*};
+    (@types [$($T: ty),+] $structures: tt) => {$(
+        register_structures!(@impl $T, $structures);
+    )+};
+    (@impl $T: ty, [$($S: ident $(($($O: ident),+))*),+]) => {
+        impl $crate::general::Structures for $T {
+            const STRUCTURES: &'static [$crate::general::StructureId] = &[$(
+                $crate::general::StructureId::$S $(($($crate::general::OperatorId::$O),+))*
+            ),+];
+        }
+
+        $(register_structures!(@check $T, $S $(($($O),+))*);)+
+    };
+    (@check $T: ty, Magma($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractMagma<$crate::general::$O>);
+    };
+    (@check $T: ty, Quasigroup($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractQuasigroup<$crate::general::$O>);
+    };
+    (@check $T: ty, Semigroup($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractSemigroup<$crate::general::$O>);
+    };
+    (@check $T: ty, Loop($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractLoop<$crate::general::$O>);
+    };
+    (@check $T: ty, Monoid($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractMonoid<$crate::general::$O>);
+    };
+    (@check $T: ty, Group($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractGroup<$crate::general::$O>);
+    };
+    (@check $T: ty, GroupAbelian($O: ident)) => {
+        register_structures!(@assert $T: $crate::general::AbstractGroupAbelian<$crate::general::$O>);
+    };
+    (@check $T: ty, Ring($A: ident, $M: ident)) => {
+        register_structures!(@assert $T:
+            $crate::general::AbstractRing<$crate::general::$A, $crate::general::$M>);
+    };
+    (@check $T: ty, RingCommutative($A: ident, $M: ident)) => {
+        register_structures!(@assert $T:
+            $crate::general::AbstractRingCommutative<$crate::general::$A, $crate::general::$M>);
+    };
+    (@check $T: ty, Field($A: ident, $M: ident)) => {
+        register_structures!(@assert $T:
+            $crate::general::AbstractField<$crate::general::$A, $crate::general::$M>);
+    };
+    (@check $T: ty, Module) => {
+        register_structures!(@assert $T: $crate::general::AbstractModule);
+    };
+    (@check $T: ty, $S: ident) => {
+        register_structures!(@assert $T: $crate::general::$S);
+    };
+    (@assert $T: ty: $Trait: path) => {
+        const _: fn() = || {
+            fn implements<T: ?Sized + $Trait>() {}
+            implements::<$T>();
+        };
+    };
+);
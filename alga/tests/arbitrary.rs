@@ -0,0 +1,52 @@
+#![cfg(feature = "quickcheck")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::wrapper::Wrapper;
+use alga::general::{
+    AbstractGroupAbelian, AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractSemigroup,
+    Additive, Bits, DistributiveLattice, Id, Multiplicative, Zn, ZnUnit,
+};
+use alga::linear::FreeModule;
+use quickcheck::Arbitrary;
+
+quickcheck! {
+    fn prop_wrapper_add_is_commutative(args: (Wrapper<i32, Additive, Multiplicative>, Wrapper<i32, Additive, Multiplicative>)) -> bool {
+        let (a, b) = args;
+        a + b == b + a
+    }
+
+    fn prop_id_is_noop(args: (Id<Additive>,)) -> bool {
+        AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop(args)
+    }
+
+    fn prop_bits_meet_distributes_over_join(args: (Bits<u16>, Bits<u16>, Bits<u16>)) -> bool {
+        DistributiveLattice::prop_meet_distributes_over_join(args)
+    }
+
+    fn prop_zn_units_are_group(args: (ZnUnit<15>, ZnUnit<15>, ZnUnit<15>)) -> bool {
+        AbstractSemigroup::<Multiplicative>::prop_is_associative(args)
+            && AbstractQuasigroup::<Multiplicative>::prop_inv_is_latin_square((args.0, args.1))
+    }
+
+    fn prop_zn_ring_is_distributive(args: (Zn<12>, Zn<12>, Zn<12>)) -> bool {
+        AbstractRing::prop_mul_and_add_are_distributive(args)
+    }
+
+    fn prop_free_module_add_is_commutative(args: (FreeModule<i32, 3>, FreeModule<i32, 3>)) -> bool {
+        AbstractGroupAbelian::<Additive>::prop_is_commutative(args)
+    }
+}
+
+#[test]
+fn free_module_shrinks_componentwise() {
+    let v = FreeModule::new([0i32, 4, 0]);
+
+    for w in v.shrink() {
+        assert_eq!(w[0], 0);
+        assert_eq!(w[2], 0);
+        assert!(w[1].abs() < 4);
+    }
+}
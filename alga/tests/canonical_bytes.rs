@@ -0,0 +1,57 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{FromCanonicalBytes, MontgomeryFp, ToCanonicalBytes, Zn, ZnUnit};
+
+type P = Zn<1_000_003>;
+type M = MontgomeryFp<1_000_003>;
+
+quickcheck!(
+    fn prop_round_trip(args: (u64,)) -> bool {
+        FromCanonicalBytes::prop_canonical_bytes_round_trip((P::new(args.0),))
+            && FromCanonicalBytes::prop_canonical_bytes_round_trip((M::new(args.0),))
+            && ZnUnit::new(Zn::<1_000_002>::new(args.0))
+                .is_none_or(|u| FromCanonicalBytes::prop_canonical_bytes_round_trip((u,)))
+    }
+
+    fn prop_encodings_are_unique(args: (u64, bool)) -> bool {
+        // NOTE: half of the encodings are close to the modulus.
+        let value = if args.1 { args.0 % 2_000_006 } else { args.0 };
+        let bytes = (value.to_le_bytes(),);
+
+        P::prop_canonical_bytes_are_unique(bytes)
+            && M::prop_canonical_bytes_are_unique(bytes)
+            && ZnUnit::<1_000_002>::prop_canonical_bytes_are_unique(bytes)
+    }
+
+    fn prop_representations_have_the_same_encoding(args: (u64,)) -> bool {
+        P::new(args.0).to_canonical_bytes() == M::new(args.0).to_canonical_bytes()
+    }
+);
+
+#[test]
+fn encoding_is_little_endian() {
+    assert_eq!(
+        P::new(0x0102).to_canonical_bytes(),
+        [2, 1, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        P::from_canonical_bytes(&[2, 1, 0, 0, 0, 0, 0, 0]),
+        Some(P::new(0x0102))
+    );
+}
+
+#[test]
+fn non_canonical_encodings_are_rejected() {
+    let modulus = 1_000_003u64.to_le_bytes();
+
+    assert_eq!(P::from_canonical_bytes(&modulus), None);
+    assert_eq!(M::from_canonical_bytes(&modulus), None);
+    assert_eq!(P::from_canonical_bytes(&[0xff; 8]), None);
+    assert_eq!(
+        ZnUnit::<10>::from_canonical_bytes(&2u64.to_le_bytes()),
+        None
+    );
+    assert!(ZnUnit::<10>::from_canonical_bytes(&3u64.to_le_bytes()).is_some());
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "cgmath")]
+
+extern crate alga;
+extern crate cgmath;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractMagma, Additive, Identity, Multiplicative, SubsetOf, TwoSidedInverse};
+use alga::linear::{
+    ConstDimVectorSpace, FiniteDimInnerSpace, FiniteDimVectorSpace, FiniteDimVectorSpaceMap,
+    HilbertSpace, InnerSpace, LpNormedSpace, NormedSpace,
+};
+use cgmath::{Decomposed, Matrix3, Quaternion, Rad, Rotation3, Vector2, Vector3};
+
+// NOTE: `cgmath` implements another version of the `approx` traits, so the law checks can't be
+// used directly and the laws are checked with exact arithmetic on small integers instead.
+quickcheck! {
+    fn prop_vectors_are_vector_spaces(args: ((i8, i8, i8), (i8, i8, i8), i8)) -> bool {
+        let v = |(x, y, z): (i8, i8, i8)| Vector3::new(x as f64, y as f64, z as f64);
+        let (a, b, s) = (v(args.0), v(args.1), args.2 as f64);
+        let add = |x: &Vector3<f64>, y: &Vector3<f64>| AbstractMagma::<Additive>::operate(x, y);
+
+        add(&a, &b) == add(&b, &a)
+            && add(&a, &<Vector3<f64> as Identity<Additive>>::identity()) == a
+            && add(&a, &TwoSidedInverse::<Additive>::two_sided_inverse(&a)) == Vector3::new(0.0, 0.0, 0.0)
+            && (a + b) * s == a * s + b * s
+            && a.dot(&b) == a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    fn prop_vectors_are_hilbert_spaces(args: (i16, i16, i16)) -> bool {
+        let (x, y, z) = args;
+        let v = Vector3::new(x as f64 / 8.0, y as f64 / 8.0, z as f64 / 8.0);
+
+        HilbertSpace::prop_norm_agrees_with_inner_product_approx((v,))
+            && HilbertSpace::prop_norm_agrees_with_inner_product_approx((Vector2::new(v.x, v.y),))
+    }
+}
+
+#[test]
+fn vector_components_and_norms() {
+    let v = Vector3::new(2.0f64, -3.0, 6.0);
+
+    assert_eq!(<Vector3<f64> as FiniteDimVectorSpace>::dimension(), 3);
+    assert_eq!(<Vector2<f32> as ConstDimVectorSpace>::DIM, 2);
+    assert_eq!(
+        <Vector3<f64> as FiniteDimVectorSpace>::canonical_basis_element(1),
+        Vector3::unit_y()
+    );
+    assert_eq!(v[2], 6.0);
+    assert_eq!(v.norm(), 7.0);
+    assert_eq!(v.norm_l1(), 11.0);
+    assert_eq!(v.norm_linf(), 6.0);
+    assert_eq!(v.inner_product(&Vector3::unit_x()), 2.0);
+
+    let mut basis = Vec::new();
+    FiniteDimInnerSpace::complete_basis(&mut [Vector3::new(0.0f64, 0.0, 3.0)], |e| {
+        basis.push(*e);
+        true
+    });
+
+    assert_eq!(basis.len(), 3);
+    assert_eq!(basis[0], Vector3::unit_z());
+}
+
+#[test]
+fn vector_change_of_precision() {
+    let v = Vector3::new(1.5f32, -2.0, 0.25);
+    let w: Vector3<f64> = v.convert_coords();
+
+    assert_eq!(w, Vector3::new(1.5, -2.0, 0.25));
+    assert_eq!(v.to_superset(), w);
+    assert_eq!(
+        FiniteDimVectorSpaceMap::<f64>::map_coords(&v, |c| c as f64 * 2.0),
+        Vector3::new(3.0, -4.0, 0.5)
+    );
+    assert_eq!(w.try_convert_coords(), Some(v));
+    assert_eq!(
+        Vector2::<f32>::from_superset(&Vector2::new(1.0, 2.0)),
+        Some(Vector2::new(1.0, 2.0))
+    );
+}
+
+#[test]
+fn multiplicative_structures() {
+    let q = Quaternion::from_angle_z(Rad(0.5f64));
+    let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&q);
+    let one = AbstractMagma::<Multiplicative>::operate(&q, &inv);
+
+    assert!(
+        (one.s - 1.0).abs() < 1.0e-12 && one.v.x.abs() + one.v.y.abs() + one.v.z.abs() < 1.0e-12
+    );
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Quaternion::new(
+            0.0f64, 0.0, 0.0, 0.0
+        )),
+        None
+    );
+
+    let m = Matrix3::new(1.0f64, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 3.0);
+    let id = <Matrix3<f64> as Identity<Multiplicative>>::identity();
+    assert_eq!(AbstractMagma::<Multiplicative>::operate(&m, &id), m);
+
+    let d = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::from_angle_x(Rad(0.0)),
+        disp: Vector3::new(1.0, 0.0, 0.0),
+    };
+    let dd = AbstractMagma::<Multiplicative>::operate(&d, &d);
+    assert_eq!(dd.scale, 4.0);
+    assert_eq!(dd.disp, Vector3::new(3.0, 0.0, 0.0));
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::operate(&d, &Identity::<Multiplicative>::identity()).disp,
+        d.disp
+    );
+}
@@ -0,0 +1,210 @@
+extern crate alga;
+extern crate num_complex;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use alga::general::wrapper::Wrapper;
+use alga::general::{
+    Additive, CanonicalHash, ComplexField, ComplexFieldCore, Id, Multiplicative, SignedAlgebra,
+};
+use num_complex::Complex;
+
+fn hash_of<N: CanonicalHash>(x: N) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.canonical_hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn canonical_hash_normalizes_zero_and_nan() {
+    assert_eq!(hash_of(0.0f64), hash_of(-0.0f64));
+    assert_eq!(hash_of(0.0f32), hash_of(-0.0f32));
+    assert_eq!(hash_of(f64::NAN), hash_of(-f64::NAN));
+    assert_eq!(
+        hash_of(f64::NAN),
+        hash_of(f64::from_bits(0x7ff8_0000_0000_0001))
+    );
+    assert_ne!(hash_of(1.0f64), hash_of(-1.0f64));
+}
+
+#[test]
+fn canonical_hash_of_complex() {
+    assert_eq!(
+        hash_of(Complex::new(-0.0f64, 1.0)),
+        hash_of(Complex::new(0.0f64, 1.0))
+    );
+    assert_ne!(
+        hash_of(Complex::new(1.0f64, 2.0)),
+        hash_of(Complex::new(2.0f64, 1.0))
+    );
+}
+
+#[test]
+fn canonical_hash_of_generic_scalars() {
+    fn hash_of_complex_field<N: ComplexField>(x: N) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.canonical_hash(&mut hasher);
+        hasher.finish()
+    }
+
+    assert_eq!(hash_of_complex_field(-0.0f32), hash_of(0.0f32));
+    assert_eq!(hash_of_complex_field(f64::NAN), hash_of(-f64::NAN));
+    assert_eq!(
+        hash_of_complex_field(Complex::new(1.0f64, -0.0)),
+        hash_of(Complex::new(1.0f64, 0.0))
+    );
+}
+
+#[test]
+fn canonical_hash_of_wrappers() {
+    type W = Wrapper<f64, Additive, Multiplicative>;
+
+    assert_eq!(hash_of(W::new(-0.0)), hash_of(W::new(0.0)));
+    assert_eq!(hash_of(W::new(f64::NAN)), hash_of(f64::NAN));
+    assert_ne!(hash_of(W::new(1.0)), hash_of(W::new(2.0)));
+    assert_eq!(
+        hash_of(Id::<Multiplicative>::new()),
+        hash_of(Id::<Multiplicative>::new())
+    );
+}
+
+// Only relies on the bounds of `ComplexFieldCore`, i.e., neither on `Num` nor on `NumAssign`.
+fn unit_and_modulus<N: ComplexFieldCore>(x: N) -> (N, N::RealField) {
+    let (modulus, unit) = x.to_exp();
+    (unit * N::from_real(modulus.sqrt()), modulus)
+}
+
+fn core_of<N: ComplexField>(x: N) -> (N, N::RealField) {
+    unit_and_modulus(x)
+}
+
+#[test]
+fn complex_field_core() {
+    assert_eq!(core_of(-4.0f64), (-2.0, 4.0));
+    assert_eq!(
+        core_of(Complex::new(0.0f32, 9.0)),
+        (Complex::new(0.0, 3.0), 9.0)
+    );
+    assert_eq!(unit_and_modulus(0.0f64), (0.0, 0.0));
+}
+
+#[cfg(feature = "scalar-fmt")]
+#[test]
+fn scalars_are_formattable() {
+    fn format<N: ComplexField>(x: N) -> String {
+        format!("{} {:?}", x, x)
+    }
+
+    assert_eq!(format(1.5f64), "1.5 1.5");
+    assert_eq!(
+        format(Complex::new(1.0f32, -2.0)),
+        "1-2i Complex { re: 1.0, im: -2.0 }"
+    );
+}
+
+#[cfg(feature = "scalar-send-sync")]
+#[test]
+fn scalars_can_be_sent_across_threads() {
+    fn sum_in_thread<N: ComplexField>(values: Vec<N>) -> N {
+        std::thread::spawn(move || values.into_iter().fold(N::zero(), |acc, x| acc + x))
+            .join()
+            .unwrap()
+    }
+
+    assert_eq!(sum_in_thread(vec![1.0f64, 2.0, 3.5]), 6.5);
+    assert_eq!(
+        sum_in_thread(vec![Complex::new(1.0f32, 1.0), Complex::new(2.0, -3.0)]),
+        Complex::new(3.0, -2.0)
+    );
+}
+
+#[test]
+fn complex_from_parts() {
+    // Splits interleaved numbers into planar storage and back.
+    fn to_planar<N: ComplexField>(zs: &[N]) -> (Vec<N::RealField>, Vec<N::RealField>) {
+        zs.iter().map(|z| z.into_re_im()).unzip()
+    }
+
+    fn from_planar<N: ComplexField>(re: &[N::RealField], im: &[N::RealField]) -> Vec<N> {
+        re.iter()
+            .zip(im.iter())
+            .map(|(re, im)| N::from_re_im(*re, *im))
+            .collect()
+    }
+
+    let zs = [Complex::new(1.0f64, 2.0), Complex::new(-3.0, 0.5)];
+    let (re, im) = to_planar(&zs);
+    assert_eq!(re, [1.0, -3.0]);
+    assert_eq!(im, [2.0, 0.5]);
+    assert_eq!(from_planar::<Complex<f64>>(&re, &im), zs);
+
+    assert_eq!(to_planar(&[4.0f32]), (vec![4.0], vec![0.0]));
+    assert_eq!(from_planar::<f32>(&[4.0], &[1.0]), [4.0]);
+}
+
+// The number of non-finite, NaN, infinite, and subnormal values among `xs`.
+fn census<N: ComplexFieldCore>(xs: &[N]) -> [usize; 4] {
+    [
+        xs.iter().filter(|x| !x.is_finite()).count(),
+        xs.iter().filter(|x| x.is_nan()).count(),
+        xs.iter().filter(|x| x.is_infinite()).count(),
+        xs.iter().filter(|x| x.is_subnormal()).count(),
+    ]
+}
+
+#[test]
+fn classification_of_reals() {
+    let xs = [
+        1.0f64,
+        0.0,
+        f64::NAN,
+        f64::INFINITY,
+        -f64::INFINITY,
+        f64::MIN_POSITIVE / 2.0,
+    ];
+
+    assert_eq!(census(&xs), [3, 1, 2, 1]);
+    assert_eq!(census(&[f32::MIN_POSITIVE, 1.0e-40f32]), [0, 0, 0, 1]);
+}
+
+#[test]
+fn classification_of_complex_is_component_wise() {
+    let (nan, inf, tiny) = (f64::NAN, f64::INFINITY, 1.0e-310f64);
+    let xs = [
+        Complex::new(1.0, 2.0),
+        Complex::new(nan, 2.0),
+        Complex::new(1.0, inf),
+        Complex::new(inf, nan),
+        Complex::new(1.0e300, tiny),
+    ];
+
+    assert_eq!(census(&xs), [3, 2, 2, 1]);
+}
+
+#[test]
+fn approximate_equality_of_complex_numbers() {
+    let z = Complex::new(1.0f64, 2.0);
+    let w = Complex::new(1.0 + 3.0e-9, 2.0 - 4.0e-9);
+
+    assert!(z.approx_eq_modulus(&w, 5.1e-9));
+    assert!(!z.approx_eq_modulus(&w, 4.9e-9));
+    assert!(z.approx_eq_components(&w, 4.1e-9));
+    assert!(!z.approx_eq_components(&w, 3.9e-9));
+    assert!(1.0f32.approx_eq_modulus(&1.5, 0.5));
+    assert!(!Complex::new(f64::NAN, 0.0).approx_eq_components(&z, 1.0e300));
+}
+
+#[test]
+fn signed_algebra_of_complex_numbers() {
+    let z = Complex::new(3.0f64, -4.0);
+
+    assert_eq!(SignedAlgebra::abs(&z), 5.0);
+    assert_eq!(SignedAlgebra::signum(&z), Complex::new(0.6, -0.8));
+    assert_eq!(
+        SignedAlgebra::signum(&Complex::new(0.0f64, 0.0)),
+        Complex::new(1.0, 0.0)
+    );
+    assert_eq!(SignedAlgebra::abs(&-7i32), 7);
+    assert_eq!(SignedAlgebra::signum(&-2.5f64), -1.0);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "subtle")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+extern crate subtle;
+
+use alga::general::{AbstractMagma, ConstantTimeOps, Identity, MontgomeryFp, Multiplicative};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+type F = MontgomeryFp<998_244_353>;
+
+// Raises `x` to the power `k` without branching on the bits of `k`.
+fn ct_pow<T: ConstantTimeOps + AbstractMagma<Multiplicative> + Identity<Multiplicative>>(
+    x: &T,
+    k: u64,
+) -> T {
+    let mut result = T::identity();
+
+    for i in (0..64).rev() {
+        result = result.operate(&result);
+        let product = result.operate(x);
+        result = T::conditional_select(&result, &product, Choice::from(((k >> i) & 1) as u8));
+    }
+
+    result
+}
+
+quickcheck!(
+    fn prop_ct_eq_is_eq(args: (u64, u64)) -> bool {
+        let (a, b) = (F::new(args.0), F::new(args.1));
+
+        bool::from(a.ct_eq(&b)) == (a == b) && bool::from(a.ct_eq(&a))
+    }
+
+    fn prop_conditional_select_selects(args: (u64, u64)) -> bool {
+        let (a, b) = (F::new(args.0), F::new(args.1));
+
+        F::conditional_select(&a, &b, Choice::from(0)) == a
+            && F::conditional_select(&a, &b, Choice::from(1)) == b
+    }
+
+    fn prop_ct_pow_agrees_with_naive_pow(args: (u64, u8)) -> bool {
+        let x = F::new(args.0);
+        let naive = (0..args.1).fold(F::new(1), |acc, _| acc * x);
+
+        ct_pow(&x, args.1 as u64) == naive
+    }
+);
@@ -0,0 +1,200 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, FiniteField, FiniteGroup, Identity, Multiplicative, TwoSidedInverse, Zn,
+};
+use quickcheck::{Arbitrary, Gen};
+
+/// The field with four elements `a₁w + a₀`, where `w² = w + 1`, stored as the bits `a₁a₀`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Gf4(u8);
+
+impl AbstractMagma<Additive> for Gf4 {
+    fn operate(&self, right: &Self) -> Self {
+        Gf4(self.0 ^ right.0)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Gf4 {
+    fn operate(&self, right: &Self) -> Self {
+        let (a1, a0) = (self.0 >> 1, self.0 & 1);
+        let (b1, b0) = (right.0 >> 1, right.0 & 1);
+        let w = (a1 & b1) ^ (a1 & b0) ^ (a0 & b1);
+        let c = (a1 & b1) ^ (a0 & b0);
+
+        Gf4((w << 1) | c)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Gf4 {
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Gf4 {
+    fn two_sided_inverse(&self) -> Self {
+        match self.0 {
+            0 => panic!("Zero has no multiplicative inverse."),
+            1 => Gf4(1),
+            2 => Gf4(3),
+            _ => Gf4(2),
+        }
+    }
+}
+
+impl Identity<Additive> for Gf4 {
+    fn identity() -> Self {
+        Gf4(0)
+    }
+}
+
+impl Identity<Multiplicative> for Gf4 {
+    fn identity() -> Self {
+        Gf4(1)
+    }
+}
+
+impl AbstractSemigroup<Additive> for Gf4 {}
+impl AbstractQuasigroup<Additive> for Gf4 {}
+impl AbstractMonoid<Additive> for Gf4 {}
+impl AbstractLoop<Additive> for Gf4 {}
+impl AbstractGroup<Additive> for Gf4 {}
+impl AbstractGroupAbelian<Additive> for Gf4 {}
+impl AbstractSemigroup<Multiplicative> for Gf4 {}
+impl AbstractQuasigroup<Multiplicative> for Gf4 {}
+impl AbstractMonoid<Multiplicative> for Gf4 {}
+impl AbstractLoop<Multiplicative> for Gf4 {}
+impl AbstractGroup<Multiplicative> for Gf4 {}
+impl AbstractGroupAbelian<Multiplicative> for Gf4 {}
+impl AbstractRingCommutative for Gf4 {}
+impl AbstractField for Gf4 {}
+
+impl AbstractRing for Gf4 {
+    fn characteristic() -> u64 {
+        2
+    }
+}
+
+impl FiniteGroup<Additive> for Gf4 {
+    type Elements = std::vec::IntoIter<Gf4>;
+
+    fn cardinality() -> u64 {
+        4
+    }
+
+    fn elements() -> Self::Elements {
+        vec![Gf4(0), Gf4(1), Gf4(2), Gf4(3)].into_iter()
+    }
+}
+
+impl FiniteField for Gf4 {}
+
+impl Arbitrary for Gf4 {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Gf4(u8::arbitrary(g) & 3)
+    }
+}
+
+quickcheck!(
+    fn prop_gf4_frobenius_is_ring_homomorphism(args: (Gf4, Gf4)) -> bool {
+        FiniteField::prop_frobenius_is_ring_homomorphism(args)
+    }
+
+    fn prop_gf4_frobenius_has_order_degree(args: (Gf4,)) -> bool {
+        FiniteField::prop_frobenius_has_order_degree(args)
+    }
+
+    fn prop_zn_frobenius_is_ring_homomorphism(args: (u64, u64)) -> bool {
+        let (a, b) = (Zn::<101>::new(args.0), Zn::<101>::new(args.1));
+        FiniteField::prop_frobenius_is_ring_homomorphism((a, b))
+    }
+
+    fn prop_gf4_sqrt_is_square_root(args: (Gf4,)) -> bool {
+        FiniteField::prop_sqrt_is_square_root(args)
+    }
+
+    fn prop_zn_legendre_symbol_is_multiplicative(args: (u64, u64)) -> bool {
+        let (a, b) = (Zn::<1_000_003>::new(args.0), Zn::<1_000_003>::new(args.1));
+        FiniteField::prop_legendre_symbol_is_multiplicative((a, b))
+    }
+
+    fn prop_zn_sqrt_is_square_root(args: (u64,)) -> bool {
+        // NOTE: 998244353 = 119 × 2²³ + 1, so Tonelli–Shanks runs many iterations.
+        let a = Zn::<998_244_353>::new(args.0);
+        let square = a * a;
+
+        FiniteField::prop_sqrt_is_square_root((a,))
+            && square.sqrt().is_some_and(|r| r == a || r == -a)
+    }
+
+    fn prop_zn_frobenius_is_identity(args: (u64,)) -> bool {
+        let a = Zn::<7>::new(args.0);
+        a.frobenius() == a && FiniteField::prop_frobenius_has_order_degree((a,))
+    }
+);
+
+#[test]
+fn gf4_frobenius() {
+    assert!(<Gf4 as AbstractRing>::prop_characteristic_is_additive_order_of_one());
+    assert_eq!(<Gf4 as FiniteField>::degree(), 2);
+    assert_eq!(<Zn<7> as FiniteField>::degree(), 1);
+
+    // The Frobenius endomorphism swaps the two roots of `x² + x + 1`.
+    assert_eq!(Gf4(2).frobenius(), Gf4(3));
+    assert_eq!(Gf4(3).frobenius(), Gf4(2));
+    assert_eq!(Gf4(1).frobenius(), Gf4(1));
+    assert_eq!(Gf4(2).frobenius_pow(2), Gf4(2));
+    assert_eq!(Gf4(2).frobenius_pow(5), Gf4(3));
+}
+
+mod quadratic_residues {
+    macro_rules! check {
+        ($($name:ident: $p:expr),* $(,)*) => {
+            $(#[test]
+            fn $name() {
+                use alga::general::{FiniteField, FiniteGroup, Additive, Zn};
+
+                type F = Zn<$p>;
+
+                let squares: Vec<F> = <F as FiniteGroup<Additive>>::elements().map(|a| a * a).collect();
+                let mut residues = 0;
+
+                for a in <F as FiniteGroup<Additive>>::elements() {
+                    let is_square = squares.contains(&a);
+
+                    assert_eq!(a.is_quadratic_residue(), is_square);
+                    assert!(FiniteField::prop_sqrt_is_square_root((a,)));
+
+                    for b in <F as FiniteGroup<Additive>>::elements() {
+                        assert!(FiniteField::prop_legendre_symbol_is_multiplicative((a, b)));
+                    }
+
+                    if is_square {
+                        residues += 1;
+                    }
+                }
+
+                // Zero and half of the nonzero elements are squares, in odd characteristic.
+                assert_eq!(residues, if $p == 2 { 2 } else { ($p + 1) / 2 });
+            })*
+        }
+    }
+
+    check!(f2: 2, f3: 3, f5: 5, f7: 7, f11: 11, f13: 13, f17: 17, f41: 41, f97: 97, f257: 257);
+}
+
+#[test]
+fn legendre_symbol() {
+    // 2 is a square modulo p iff p = ±1 mod 8.
+    assert_eq!(Zn::<7>::new(2).legendre_symbol(), 1);
+    assert_eq!(Zn::<11>::new(2).legendre_symbol(), -1);
+    assert_eq!(Zn::<11>::new(0).legendre_symbol(), 0);
+    assert_eq!(Zn::<13>::new(10).sqrt().map(|r| r * r), Some(Zn::new(10)));
+    assert_eq!(Zn::<13>::new(5).sqrt(), None);
+    assert_eq!(Gf4(2).sqrt(), Some(Gf4(3)));
+}
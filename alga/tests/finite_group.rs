@@ -0,0 +1,323 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    crt, discrete_log, discrete_log_bounded, AbstractGroup, AbstractGroupAbelian, AbstractLoop,
+    AbstractMagma, AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, Crt,
+    CyclicGroup, FiniteGroup, Id, Identity, Multiplicative, TwoSidedInverse, Zn, ZnUnit,
+};
+use quickcheck::{Arbitrary, Gen};
+
+/// The Klein four-group, as pairs of bits under componentwise exclusive or.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Klein(bool, bool);
+
+impl AbstractMagma<Additive> for Klein {
+    fn operate(&self, right: &Self) -> Self {
+        Klein(self.0 ^ right.0, self.1 ^ right.1)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Klein {
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl Identity<Additive> for Klein {
+    fn identity() -> Self {
+        Klein(false, false)
+    }
+}
+
+impl AbstractSemigroup<Additive> for Klein {}
+impl AbstractQuasigroup<Additive> for Klein {}
+impl AbstractMonoid<Additive> for Klein {}
+impl AbstractLoop<Additive> for Klein {}
+impl AbstractGroup<Additive> for Klein {}
+impl AbstractGroupAbelian<Additive> for Klein {}
+
+impl FiniteGroup<Additive> for Klein {
+    type Elements = std::vec::IntoIter<Klein>;
+
+    fn cardinality() -> u64 {
+        4
+    }
+
+    fn elements() -> Self::Elements {
+        vec![
+            Klein(false, false),
+            Klein(true, false),
+            Klein(false, true),
+            Klein(true, true),
+        ]
+        .into_iter()
+    }
+}
+
+impl Arbitrary for Klein {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Klein(bool::arbitrary(g), bool::arbitrary(g))
+    }
+}
+
+type U = ZnUnit<1_000_003>;
+
+quickcheck!(
+    fn prop_discrete_log_is_index_of(args: (u64, u64)) -> bool {
+        let target = <U as CyclicGroup<Multiplicative>>::generator_pow(args.0);
+        let k = discrete_log::<Multiplicative, _>(&U::generator(), &target);
+
+        k == Some(args.0 % U::cardinality())
+    }
+
+    fn prop_discrete_log_is_smallest_exponent(args: (u64, u64, u8)) -> bool {
+        let unit = |value| ZnUnit::<101>::new(Zn::new(value));
+
+        match (unit(args.0), unit(args.1)) {
+            (Some(base), Some(target)) => {
+                let mut acc: ZnUnit<101> = Identity::<Multiplicative>::identity();
+                let naive = (0..100).find(|_| {
+                    let found = acc == target;
+                    acc = AbstractMagma::<Multiplicative>::operate(&acc, &base);
+                    found
+                });
+                let bound = args.2 as usize % 16 + 1;
+
+                discrete_log::<Multiplicative, _>(&base, &target) == naive
+                    && discrete_log_bounded::<Multiplicative, _>(&base, &target, bound) == naive
+            }
+            _ => true,
+        }
+    }
+
+    fn prop_crt_is_ring_isomorphism(args: (u64, u64)) -> bool {
+        let (x, y) = (Zn::new(args.0), Zn::new(args.1));
+
+        Crt::<8, 15, 120>::prop_is_ring_isomorphism((x, y))
+            && Crt::<1_000_003, 998_244_353, 998_247_347_733_059>::prop_is_ring_isomorphism((
+                Zn::new(args.0),
+                Zn::new(args.1),
+            ))
+    }
+
+    fn prop_klein_element_order_divides_cardinality(args: (Klein,)) -> bool {
+        FiniteGroup::<Additive>::prop_element_order_divides_cardinality(args)
+    }
+);
+
+#[test]
+fn klein_group() {
+    assert!(<Klein as FiniteGroup<Additive>>::prop_elements_are_exhaustive());
+    assert!(<Klein as FiniteGroup<Additive>>::prop_lagrange_holds_exhaustively());
+    assert_eq!(
+        FiniteGroup::<Additive>::element_order(&Klein(false, false)),
+        1
+    );
+    assert_eq!(
+        FiniteGroup::<Additive>::element_order(&Klein(true, false)),
+        2
+    );
+    assert_eq!(
+        FiniteGroup::<Additive>::element_order(&Klein(true, true)),
+        2
+    );
+}
+
+#[test]
+fn klein_discrete_log() {
+    let a = Klein(true, false);
+
+    assert_eq!(
+        discrete_log::<Additive, _>(&a, &Klein(false, false)),
+        Some(0)
+    );
+    assert_eq!(discrete_log::<Additive, _>(&a, &a), Some(1));
+    assert_eq!(discrete_log::<Additive, _>(&a, &Klein(false, true)), None);
+}
+
+#[test]
+fn trivial_group() {
+    assert_eq!(
+        <Id<Multiplicative> as FiniteGroup<Multiplicative>>::cardinality(),
+        1
+    );
+    assert!(<Id<Multiplicative> as FiniteGroup<Multiplicative>>::prop_elements_are_exhaustive());
+    assert!(<Id<Additive> as FiniteGroup<Additive>>::prop_lagrange_holds_exhaustively());
+    assert_eq!(Id::<Additive>::new().element_order(), 1);
+}
+
+mod zn_check {
+    macro_rules! check {
+        ($($name:ident: $N:expr),* $(,)*) => {
+            $(mod $name {
+                use alga::general::{
+                    AbstractGroupAbelian, AbstractMonoid, AbstractRing,
+                    AbstractRingCommutative, AbstractSemigroup, Additive, CyclicGroup,
+                    FiniteGroup, Multiplicative, PartialInverse, Zn, ZnUnit,
+                };
+
+                type Z = Zn<$N>;
+                type U = ZnUnit<$N>;
+
+                quickcheck!(
+                    fn prop_add_is_abelian_group(args: (u64, u64, u64)) -> bool {
+                        let (a, b, c) = (Z::new(args.0), Z::new(args.1), Z::new(args.2));
+
+                        AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+                            && AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop(
+                                (a,),
+                            )
+                            && AbstractSemigroup::<Additive>::prop_is_associative((a, b, c))
+                    }
+
+                    fn prop_is_commutative_ring(args: (u64, u64, u64)) -> bool {
+                        let (a, b, c) = (Z::new(args.0), Z::new(args.1), Z::new(args.2));
+
+                        AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+                            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+                    }
+
+                    fn prop_partial_inverse_is_inverse(args: (u64,)) -> bool {
+                        let a = Z::new(args.0);
+                        PartialInverse::<Multiplicative>::prop_partial_inverse_is_inverse((a,))
+                    }
+
+                    fn prop_index_of_is_inverse_of_generator_pow(args: (u64,)) -> bool {
+                        let a = Z::new(args.0);
+                        CyclicGroup::<Additive>::prop_index_of_is_inverse_of_generator_pow((a,))
+                    }
+
+                    fn prop_generator_pow_is_homomorphism(args: (u64, u64)) -> bool {
+                        <Z as CyclicGroup<Additive>>::prop_generator_pow_is_homomorphism(args)
+                    }
+
+                    fn prop_units_index_of_is_inverse_of_generator_pow(args: (u64,)) -> bool {
+                        match U::new(Z::new(args.0)) {
+                            Some(u) => CyclicGroup::<Multiplicative>::
+                                prop_index_of_is_inverse_of_generator_pow((u,)),
+                            None => true,
+                        }
+                    }
+
+                    fn prop_units_generator_pow_is_homomorphism(args: (u64, u64)) -> bool {
+                        <U as CyclicGroup<Multiplicative>>::prop_generator_pow_is_homomorphism(args)
+                    }
+                );
+
+                #[test]
+                fn groups_are_finite_and_cyclic() {
+                    assert!(<Z as FiniteGroup<Additive>>::prop_elements_are_exhaustive());
+                    assert!(<Z as FiniteGroup<Additive>>::prop_lagrange_holds_exhaustively());
+                    assert!(<Z as CyclicGroup<Additive>>::prop_generator_order_is_cardinality());
+                    assert!(<U as FiniteGroup<Multiplicative>>::prop_elements_are_exhaustive());
+                    assert!(<U as FiniteGroup<Multiplicative>>::prop_lagrange_holds_exhaustively());
+                    assert!(
+                        <U as CyclicGroup<Multiplicative>>::prop_generator_order_is_cardinality()
+                    );
+                }
+
+                #[test]
+                fn characteristic_is_modulus() {
+                    assert_eq!(<Z as AbstractRing>::characteristic(), $N);
+                    assert!(<Z as AbstractRing>::prop_characteristic_is_additive_order_of_one());
+                }
+            })*
+        }
+    }
+
+    check!(z2: 2, z7: 7, z9: 9, z10: 10, z101: 101);
+}
+
+#[test]
+fn zn_arithmetic() {
+    use alga::general::{Multiplicative, TwoSidedInverse, Zn, ZnUnit};
+
+    let a = Zn::<12>::new(15);
+    assert_eq!(a.value(), 3);
+    assert_eq!((a + Zn::new(10)).value(), 1);
+    assert_eq!((-a).value(), 9);
+    assert_eq!((a * Zn::new(5)).value(), 3);
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&a),
+        None
+    );
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Zn::<12>::new(5)),
+        Some(Zn::new(5))
+    );
+    assert_eq!(ZnUnit::new(a), None);
+    assert_eq!(
+        FiniteGroup::<Multiplicative>::element_order(&ZnUnit::new(Zn::<7>::new(2)).unwrap()),
+        3
+    );
+    assert_eq!(
+        <ZnUnit<12> as FiniteGroup<Multiplicative>>::cardinality(),
+        4
+    );
+    assert_eq!(
+        <ZnUnit<7> as CyclicGroup<Multiplicative>>::generator()
+            .residue()
+            .value(),
+        3
+    );
+
+    // Operations must not overflow for large moduli.
+    let b = Zn::<{ u64::MAX }>::new(u64::MAX - 1);
+    assert_eq!((b + b).value(), u64::MAX - 2);
+    assert_eq!((b * b).value(), 1);
+}
+
+#[test]
+fn prime_moduli_are_fields() {
+    use alga::general::{AbstractField, FiniteField, Multiplicative, TwoSidedInverse, Zn};
+
+    fn is_field<F: AbstractField>() {}
+
+    is_field::<Zn<2>>();
+    is_field::<Zn<101>>();
+
+    // NOTE: the largest 64-bit prime.
+    type Z = Zn<{ u64::MAX - 58 }>;
+    let a = Z::new(3);
+    assert_eq!(
+        a * TwoSidedInverse::<Multiplicative>::two_sided_inverse(&a),
+        Z::new(1)
+    );
+    assert_eq!(<Zn<1_000_000_007> as FiniteField>::degree(), 1);
+}
+
+#[test]
+fn ring_characteristic() {
+    use alga::general::{AbstractRing, Zn};
+    use num_complex::Complex;
+
+    assert_eq!(<i32 as AbstractRing>::characteristic(), 0);
+    assert_eq!(<f64 as AbstractRing>::characteristic(), 0);
+    assert_eq!(<Complex<f32> as AbstractRing>::characteristic(), 0);
+    assert_eq!(<Zn<1> as AbstractRing>::characteristic(), 1);
+    assert!(<Zn<1> as AbstractRing>::prop_characteristic_is_additive_order_of_one());
+    assert!(<Zn<{ u64::MAX }> as AbstractRing>::prop_characteristic_is_additive_order_of_one());
+    assert!(<i64 as AbstractRing>::prop_characteristic_is_additive_order_of_one());
+}
+
+#[test]
+#[should_panic]
+fn units_modulo_8_are_not_cyclic() {
+    let _ = <alga::general::ZnUnit<8> as CyclicGroup<Multiplicative>>::generator();
+}
+
+#[test]
+fn chinese_remainders() {
+    // x = 2 mod 3 and x = 3 mod 5 iff x = 8 mod 15.
+    let x: Zn<15> = crt(Zn::<3>::new(2), Zn::<5>::new(3));
+    assert_eq!(x, Zn::new(8));
+    assert_eq!(Crt::<3, 5, 15>::split(x), (Zn::new(2), Zn::new(3)));
+
+    for c in 0..120 {
+        let (a, b) = Crt::<8, 15, 120>::split(Zn::new(c));
+        assert_eq!(Crt::<8, 15, 120>::combine(a, b).value(), c);
+    }
+}
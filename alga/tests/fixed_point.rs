@@ -0,0 +1,51 @@
+#![cfg(feature = "fixed")]
+
+extern crate alga;
+extern crate fixed;
+
+use alga::general::{convert, is_convertible, try_convert};
+use fixed::types::{I4F4, I8F8, U8F8};
+
+#[test]
+fn out_of_range_floats() {
+    assert_eq!(try_convert::<f64, I4F4>(1.5), Some(I4F4::from_num(1.5)));
+    assert_eq!(try_convert::<f64, I4F4>(7.9375), Some(I4F4::MAX));
+    assert_eq!(try_convert::<f64, I4F4>(8.0), None);
+    assert_eq!(try_convert::<f64, I4F4>(-8.0625), None);
+    assert_eq!(try_convert::<f32, U8F8>(-1.0), None);
+    assert_eq!(try_convert::<f64, U8F8>(1.0e300), None);
+}
+
+#[test]
+fn fractional_bits_lost_between_widths() {
+    let fine = I8F8::from_bits(0x0101); // 1 + 1/256
+    let coarse = I8F8::from_num(1.5);
+
+    assert!(!is_convertible::<I8F8, I4F4>(&fine));
+    assert_eq!(try_convert::<I8F8, I4F4>(fine), None);
+    assert_eq!(try_convert::<I8F8, I4F4>(coarse), Some(I4F4::from_num(1.5)));
+    assert_eq!(try_convert::<I8F8, I4F4>(I8F8::from_num(16)), None);
+    assert_eq!(try_convert::<I8F8, U8F8>(I8F8::from_num(-1)), None);
+
+    let widened: I8F8 = convert(I4F4::from_num(-2.25));
+    assert_eq!(widened, I8F8::from_num(-2.25));
+    assert_eq!(try_convert::<f64, I4F4>(1.0 + 1.0 / 256.0), None);
+}
+
+#[test]
+fn non_finite_floats() {
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(!is_convertible::<f64, I4F4>(&x));
+        assert!(!is_convertible::<f64, I8F8>(&x));
+        assert_eq!(try_convert::<f64, U8F8>(x), None);
+    }
+
+    assert!(!is_convertible::<f32, I8F8>(&f32::NAN));
+}
+
+#[test]
+fn fixed_into_float() {
+    let x: f64 = convert(I8F8::from_num(-3.75));
+    assert_eq!(x, -3.75);
+    assert_eq!(try_convert::<I4F4, f32>(I4F4::MIN), Some(-8.0));
+}
@@ -0,0 +1,108 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroup, AbstractMagma, AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive,
+    FreeGroup, Identity, Letter, Multiplicative, TwoSidedInverse, Zn, ZnUnit,
+};
+
+type F2 = FreeGroup<bool>;
+
+// A word as `(generator, inverted)` pairs.
+type Letters = Vec<(bool, bool)>;
+
+fn word(letters: Letters) -> F2 {
+    FreeGroup::from_letters(letters.into_iter().map(|(g, inverse)| {
+        if inverse {
+            Letter::Inverse(g)
+        } else {
+            Letter::Generator(g)
+        }
+    }))
+}
+
+fn is_reduced(w: &F2) -> bool {
+    w.letters().windows(2).all(|l| !l[0].cancels(&l[1]))
+}
+
+fn mul(a: &F2, b: &F2) -> F2 {
+    AbstractMagma::<Multiplicative>::operate(a, b)
+}
+
+fn inv(a: &F2) -> F2 {
+    TwoSidedInverse::<Multiplicative>::two_sided_inverse(a)
+}
+
+quickcheck!(
+    fn prop_words_are_reduced(args: (Letters, Letters)) -> bool {
+        let (a, b) = (word(args.0), word(args.1));
+        is_reduced(&a) && is_reduced(&b) && is_reduced(&mul(&a, &b)) && is_reduced(&inv(&a))
+    }
+
+    fn prop_is_group(args: (Letters, Letters, Letters)) -> bool {
+        let (a, b, c) = (word(args.0), word(args.1), word(args.2));
+
+        AbstractSemigroup::<Multiplicative>::prop_is_associative((a.clone(), b.clone(), c))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((
+                a.clone(),
+            ))
+            && AbstractQuasigroup::<Multiplicative>::prop_inv_is_latin_square((a, b))
+    }
+
+    fn prop_in_place_operations_match(args: (Letters, Letters)) -> bool {
+        let (a, b) = (word(args.0), word(args.1));
+        let ab = mul(&a, &b);
+        let mut c = a.clone();
+        AbstractMagma::<Multiplicative>::operate_assign(&mut c, &b);
+        let mut a_inv = a.clone();
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse_mut(&mut a_inv);
+
+        c == ab
+            && AbstractMagma::<Multiplicative>::operate_owned(a.clone(), b) == ab
+            && a_inv == inv(&a)
+    }
+
+    fn prop_evaluate_is_homomorphism(args: (Letters, Letters)) -> bool {
+        let (a, b) = (word(args.0), word(args.1));
+        let images = |g: &bool| ZnUnit::new(Zn::<11>::new(if *g { 2 } else { 7 })).unwrap();
+        let eval = |w: &F2| w.evaluate::<Multiplicative, _, _>(images);
+
+        eval(&mul(&a, &b)) == AbstractMagma::<Multiplicative>::operate(&eval(&a), &eval(&b))
+    }
+);
+
+#[test]
+fn reduction() {
+    let w = word(vec![
+        (true, false),
+        (false, false),
+        (false, true),
+        (true, true),
+    ]);
+    assert!(w.is_empty());
+    assert_eq!(w, Identity::<Multiplicative>::identity());
+    assert_eq!(word(vec![(true, false), (true, false)]).len(), 2);
+    assert_ne!(
+        word(vec![(true, false), (false, false)]),
+        word(vec![(false, false), (true, false)])
+    );
+}
+
+#[test]
+fn commutator_vanishes_in_abelian_groups() {
+    let x = FreeGroup::generator(true);
+    let y = FreeGroup::generator(false);
+    let commutator = mul(&mul(&x, &y), &mul(&inv(&x), &inv(&y)));
+
+    assert_eq!(commutator.len(), 4);
+    let z = commutator.evaluate::<Additive, _, _>(|g| Zn::<12>::new(if *g { 5 } else { 3 }));
+    assert_eq!(z, Zn::new(0));
+}
+
+fn is_group<G: AbstractGroup<Multiplicative>>() {}
+
+#[test]
+fn free_group_is_a_group() {
+    is_group::<F2>();
+}
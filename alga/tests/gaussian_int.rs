@@ -0,0 +1,111 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractMonoid, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, EuclideanDomain, GaussianInt, Multiplicative, PartialInverse, SubsetOf,
+};
+use num_complex::Complex;
+
+type G = GaussianInt<i64>;
+
+// Small parts, so that no product overflows.
+fn gauss(parts: (i16, i16)) -> G {
+    GaussianInt::new(parts.0 as i64, parts.1 as i64)
+}
+
+quickcheck!(
+    fn prop_is_commutative_ring(args: ((i16, i16), (i16, i16), (i16, i16))) -> bool {
+        let (a, b, c) = (gauss(args.0), gauss(args.1), gauss(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((a,))
+    }
+
+    fn prop_is_euclidean_domain(args: ((i16, i16), (i16, i16))) -> bool {
+        let (a, b) = (gauss(args.0), gauss(args.1));
+
+        EuclideanDomain::prop_div_rem_is_euclidean((a, b))
+            && EuclideanDomain::prop_norm_is_monotone((a, b))
+            && EuclideanDomain::prop_gcd_divides((a, b))
+            && EuclideanDomain::prop_extended_gcd_is_bezout((a, b))
+    }
+
+    fn prop_remainder_is_at_most_half_the_divisor(args: ((i16, i16), (i16, i16))) -> bool {
+        let (a, b) = (gauss(args.0), gauss(args.1));
+
+        b == G::new(0, 0) || 2 * a.div_rem(&b).1.norm() <= b.norm()
+    }
+
+    fn prop_integers_are_euclidean_domain(args: (i32, i32)) -> bool {
+        let (a, b) = (args.0 as i64, args.1 as i64);
+
+        EuclideanDomain::prop_div_rem_is_euclidean((a, b))
+            && EuclideanDomain::prop_norm_is_monotone((a, b))
+            && EuclideanDomain::prop_gcd_divides((a, b))
+            && EuclideanDomain::prop_extended_gcd_is_bezout((a, b))
+    }
+
+    fn prop_norm_is_multiplicative(args: ((i16, i16), (i16, i16))) -> bool {
+        let (a, b) = (gauss(args.0), gauss(args.1));
+
+        (a * b).norm() == a.norm() * b.norm() && a * a.conjugate() == G::new(a.norm(), 0)
+    }
+);
+
+#[test]
+fn gcd() {
+    // 5 = (2 + i)(2 - i) and 13 = (3 + 2i)(3 - 2i) do not share any factor.
+    let g = G::new(5, 0).gcd(&G::new(13, 0));
+    assert!(PartialInverse::<Multiplicative>::partial_inverse(&g).is_some());
+
+    // (2 + i)(3 + 2i) and (2 + i)(3 - 2i) share the factor 2 + i, up to a unit.
+    let p = G::new(2, 1);
+    let g = (p * G::new(3, 2)).gcd(&(p * G::new(3, -2)));
+    assert_eq!(g.norm(), 5);
+    assert_eq!(g.div_rem(&p).1, G::new(0, 0));
+
+    assert_eq!(12i64.gcd(&-18), 6);
+    assert_eq!((-7i64).div_rem(&2), (-4, 1));
+    assert_eq!(240i64.extended_gcd(&46), (2, -9, 47));
+}
+
+#[test]
+fn division_of_the_smallest_integer() {
+    assert_eq!(i32::MIN.div_rem(&-1), (i32::MIN, 0));
+    assert_eq!(i32::MIN.div_rem(&1), (i32::MIN, 0));
+    assert_eq!(i32::MIN.div_rem(&-2), (1 << 30, 0));
+    assert_eq!(7i32.div_rem(&-1), (-7, 0));
+    assert_eq!(i32::MIN.gcd(&-1), -1);
+    assert_eq!(i8::MIN.div_rem(&-1), (i8::MIN, 0));
+}
+
+#[test]
+fn units() {
+    let units = [G::new(1, 0), G::new(-1, 0), G::i(), -G::i()];
+
+    for u in &units {
+        let inv = PartialInverse::<Multiplicative>::partial_inverse(u).unwrap();
+        assert_eq!(*u * inv, G::new(1, 0));
+    }
+
+    assert_eq!(
+        PartialInverse::<Multiplicative>::partial_inverse(&G::new(1, 1)),
+        None
+    );
+}
+
+#[test]
+fn subset_of_complex() {
+    let a = G::new(3, -4);
+    let c: Complex<f64> = a.to_superset();
+
+    assert_eq!(c, Complex::new(3.0, -4.0));
+    assert_eq!(G::from_superset(&c), Some(a));
+    assert_eq!(G::from_superset(&Complex::new(0.5, 1.0)), None);
+}
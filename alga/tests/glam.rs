@@ -0,0 +1,84 @@
+#![cfg(feature = "glam")]
+
+extern crate alga;
+extern crate glam;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractMagma, Additive, Identity, MetricSpace, Multiplicative, TwoSidedInverse,
+};
+use alga::linear::ConvexSpace;
+use glam::{Affine3A, Quat, Vec2, Vec3, Vec4};
+use std::f32::consts::FRAC_PI_2;
+
+type Args = (i8, i8, i8);
+
+// NOTE: small integers keep the floating-point arithmetic exact.
+fn vec3((x, y, z): Args) -> Vec3 {
+    Vec3::new(x as f32, y as f32, z as f32)
+}
+
+// NOTE: the law checks require `Eq` or the `approx` traits, which `glam` types don't implement.
+quickcheck! {
+    fn prop_vectors_are_abelian_groups(args: (Args, Args, Args)) -> bool {
+        let (a, b, c) = (vec3(args.0), vec3(args.1), vec3(args.2));
+        let add = |x: &Vec3, y: &Vec3| AbstractMagma::<Additive>::operate(x, y);
+        let zero = <Vec3 as Identity<Additive>>::identity();
+        let neg = |x: &Vec3| TwoSidedInverse::<Additive>::two_sided_inverse(x);
+
+        add(&add(&a, &b), &c) == add(&a, &add(&b, &c))
+            && add(&a, &b) == add(&b, &a)
+            && add(&a, &zero) == a
+            && add(&a, &neg(&a)) == zero
+    }
+}
+
+#[test]
+fn vector_metric_and_convex_structures() {
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(4.0, 6.0);
+
+    assert_eq!(MetricSpace::distance(&a, &b), 5.0);
+    assert_eq!(a.convex_combination(&b, 0.5), Vec2::new(2.5, 4.0));
+    assert_eq!(<Vec4 as Identity<Additive>>::identity(), Vec4::ZERO);
+    assert_eq!(
+        TwoSidedInverse::<Additive>::two_sided_inverse(&Vec3::X),
+        -Vec3::X
+    );
+}
+
+#[test]
+fn rotation_group() {
+    let r = Quat::from_rotation_z(FRAC_PI_2);
+    let s = Quat::from_rotation_z(-0.5);
+    let id = <Quat as Identity<Multiplicative>>::identity();
+    let mul = |x: &Quat, y: &Quat| AbstractMagma::<Multiplicative>::operate(x, y);
+    let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&r);
+
+    assert_eq!(id, Quat::IDENTITY);
+    assert!(mul(&r, &inv).abs_diff_eq(id, 1.0e-6));
+    assert!(mul(&r, &s).abs_diff_eq(Quat::from_rotation_z(FRAC_PI_2 - 0.5), 1.0e-6));
+    assert!((MetricSpace::distance(&r, &id) - FRAC_PI_2).abs() < 1.0e-6);
+    assert!(id
+        .convex_combination(&r, 0.5)
+        .abs_diff_eq(Quat::from_rotation_z(FRAC_PI_2 / 2.0), 1.0e-6));
+}
+
+#[test]
+fn affine_group() {
+    let m = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::from_rotation_x(0.3),
+        Vec3::new(1.0, -2.0, 3.0),
+    );
+    let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&m);
+
+    assert!(
+        AbstractMagma::<Multiplicative>::operate(&m, &inv).abs_diff_eq(Affine3A::IDENTITY, 1.0e-5)
+    );
+    assert_eq!(
+        <Affine3A as Identity<Multiplicative>>::identity(),
+        Affine3A::IDENTITY
+    );
+}
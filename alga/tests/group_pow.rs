@@ -0,0 +1,73 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractMagma, Additive, FreeGroup, GroupPow, Identity, Letter, Multiplicative, Zn, ZnUnit,
+};
+
+type U = ZnUnit<101>;
+
+fn unit(value: u64) -> U {
+    ZnUnit::new(Zn::new(value)).unwrap_or_else(Identity::<Multiplicative>::identity)
+}
+
+quickcheck!(
+    fn prop_pow_is_homomorphism(args: (u64, i32, i32)) -> bool {
+        GroupPow::<Multiplicative>::prop_pow_is_homomorphism((unit(args.0), args.1, args.2))
+    }
+
+    fn prop_additive_pow_is_multiplication(args: (i32, i32)) -> bool {
+        let (g, n) = (args.0 as i64, args.1);
+
+        GroupPow::<Additive>::pow(&g, &n) == g * n as i64
+    }
+
+    fn prop_exponent_types_agree(args: (u64, i8)) -> bool {
+        let (g, n) = (unit(args.0), args.1);
+        let pow = GroupPow::<Multiplicative>::pow(&g, &n);
+
+        GroupPow::<Multiplicative>::pow(&g, &(n as i128)) == pow
+            && GroupPow::<Multiplicative>::pow(&g, &(n as isize)) == pow
+            && (n < 0 || GroupPow::<Multiplicative>::pow(&g, &(n as u16)) == pow)
+    }
+);
+
+#[test]
+fn huge_exponents() {
+    let g = unit(3);
+    let one: U = Identity::<Multiplicative>::identity();
+
+    // Fermat's little theorem: g¹⁰⁰ = 1 modulo 101.
+    assert_eq!(GroupPow::<Multiplicative>::pow(&g, &100u8), one);
+    assert_eq!(
+        GroupPow::<Multiplicative>::pow(&g, &(100 * (u128::MAX / 100) + 7)),
+        GroupPow::<Multiplicative>::pow(&g, &7)
+    );
+    assert_eq!(
+        GroupPow::<Multiplicative>::pow(&g, &i128::MIN),
+        GroupPow::<Multiplicative>::pow(&g, &-28)
+    );
+}
+
+#[test]
+fn non_abelian_powers() {
+    let x = FreeGroup::generator(0u8);
+    let y = FreeGroup::generator(1u8);
+    let xy = AbstractMagma::<Multiplicative>::operate(&x, &y);
+    let pow = GroupPow::<Multiplicative>::pow(&xy, &-2);
+
+    assert_eq!(
+        pow.letters(),
+        &[
+            Letter::Inverse(1),
+            Letter::Inverse(0),
+            Letter::Inverse(1),
+            Letter::Inverse(0)
+        ]
+    );
+    assert_eq!(
+        GroupPow::<Multiplicative>::pow(&xy, &0u64),
+        Identity::<Multiplicative>::identity()
+    );
+}
@@ -0,0 +1,34 @@
+//! Run with `cargo test --no-default-features --features alloc --test inclusion_alloc`.
+#![cfg(all(feature = "alloc", not(feature = "std")))]
+
+extern crate alga;
+extern crate alloc;
+
+use alga::general::{
+    DistributiveLattice, Inclusion, JoinSemilattice, Lattice, LowerBoundedLattice, MeetSemilattice,
+};
+use alloc::collections::BTreeSet;
+use core::cmp::Ordering;
+
+fn set(elements: &[u8]) -> Inclusion<BTreeSet<u8>> {
+    Inclusion(elements.iter().cloned().collect())
+}
+
+#[test]
+fn btree_sets_ordered_by_inclusion() {
+    let (a, b, c) = (set(&[1, 2, 3]), set(&[2, 3, 4]), set(&[3, 5]));
+
+    assert_eq!(a.meet(&b), set(&[2, 3]));
+    assert_eq!(a.join(&b), set(&[1, 2, 3, 4]));
+    assert_eq!(a.partial_cmp(&b), None);
+    assert_eq!(set(&[2]).partial_cmp(&a), Some(Ordering::Less));
+    assert_eq!(a.join(&Inclusion::bottom()), a);
+
+    assert!(Lattice::prop_meet_and_join_are_absorptive((
+        a.clone(),
+        b.clone()
+    )));
+    assert!(DistributiveLattice::prop_meet_distributes_over_join((
+        a, b, c
+    )));
+}
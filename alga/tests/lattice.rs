@@ -0,0 +1,281 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    Bits, BoundedLattice, CompleteLattice, DistributiveLattice, GaloisConnection, HeytingAlgebra,
+    Inclusion, Interval, JoinSemilattice, Lattice, LowerBoundedLattice, MeetSemilattice,
+};
+use quickcheck::Arbitrary;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+mod lattice_check {
+    macro_rules! check {
+        ($($T:ident),* $(,)*) => {
+            $(mod $T {
+                use alga::general::{BoundedLattice, DistributiveLattice, HeytingAlgebra};
+
+                quickcheck!(
+                    fn prop_top_and_bottom_are_identities(args: ($T,)) -> bool {
+                        BoundedLattice::prop_top_and_bottom_are_identities(args)
+                    }
+
+                    fn prop_meet_distributes_over_join(args: ($T, $T, $T)) -> bool {
+                        DistributiveLattice::prop_meet_distributes_over_join(args)
+                    }
+
+                    fn prop_join_distributes_over_meet(args: ($T, $T, $T)) -> bool {
+                        DistributiveLattice::prop_join_distributes_over_meet(args)
+                    }
+
+                    fn prop_implies_is_residual(args: ($T, $T, $T)) -> bool {
+                        HeytingAlgebra::prop_implies_is_residual(args)
+                    }
+
+                    fn prop_implies_is_modus_ponens(args: ($T, $T)) -> bool {
+                        HeytingAlgebra::prop_implies_is_modus_ponens(args)
+                    }
+                );
+            })+
+        }
+    }
+
+    check!(bool, u8, u32, i64, i128);
+}
+
+#[test]
+fn float_bounds_are_infinite() {
+    assert_eq!(<f64 as BoundedLattice>::top(), f64::INFINITY);
+    assert_eq!(
+        <f32 as LowerBoundedLattice>::bottom(),
+        f32::NEG_INFINITY
+    );
+    assert!(f64::prop_meet_distributes_over_join((1.0, -2.0, 3.5)));
+}
+
+#[test]
+fn complete_lattice_folds() {
+    assert_eq!(i32::meet_all(vec![3, -1, 7]), -1);
+    assert_eq!(i32::join_all(vec![3, -1, 7]), 7);
+    assert_eq!(u8::meet_all(Vec::new()), u8::MAX);
+    assert_eq!(f64::join_all(Vec::new()), f64::NEG_INFINITY);
+    assert!(bool::join_all(vec![false, true, false]));
+}
+
+#[test]
+fn boolean_implication() {
+    assert!(false.implies(&false));
+    assert!(false.implies(&true));
+    assert!(!true.implies(&false));
+    assert!(true.implies(&true));
+    assert!(false.pseudo_complement());
+    assert_eq!(5i32.implies(&3), 3);
+    assert_eq!(3i32.implies(&5), i32::MAX);
+}
+
+quickcheck! {
+    fn prop_btree_set_is_distributive(args: (BTreeSet<u8>, BTreeSet<u8>, BTreeSet<u8>)) -> bool {
+        let (a, b, c) = args;
+        DistributiveLattice::prop_meet_distributes_over_join((Inclusion(a.clone()), Inclusion(b.clone()), Inclusion(c.clone())))
+            && DistributiveLattice::prop_join_distributes_over_meet((Inclusion(a), Inclusion(b), Inclusion(c)))
+    }
+}
+
+#[cfg(feature = "std")]
+quickcheck! {
+    fn prop_hash_set_bottom_is_join_identity(args: (HashSet<u8>,)) -> bool {
+        LowerBoundedLattice::prop_bottom_is_join_identity((Inclusion(args.0),))
+    }
+}
+
+#[test]
+fn sets_ordered_by_inclusion() {
+    let a: BTreeSet<_> = [1, 2].iter().cloned().collect();
+    let b: BTreeSet<_> = [2, 3].iter().cloned().collect();
+
+    assert_eq!(a.meet(&b), [2].iter().cloned().collect());
+    assert_eq!(a.join(&b), [1, 2, 3].iter().cloned().collect());
+    assert_eq!(
+        Inclusion(a.clone()).partial_cmp(&Inclusion(b.clone())),
+        None
+    );
+    assert!(Inclusion(a.meet(&b)) < Inclusion(a.clone()));
+    assert!(Inclusion(BTreeSet::<i32>::new()) == Inclusion::bottom());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_sets_ordered_by_inclusion() {
+    let a: HashSet<_> = [1, 2].iter().cloned().collect();
+    let b: HashSet<_> = [2, 3].iter().cloned().collect();
+
+    assert_eq!(a.meet(&b), [2].iter().cloned().collect());
+    assert_eq!(a.join(&b), [1, 2, 3].iter().cloned().collect());
+}
+
+quickcheck! {
+    fn prop_bits_are_distributive(args: (u8, u8, u8)) -> bool {
+        let (a, b, c) = args;
+        DistributiveLattice::prop_meet_distributes_over_join((Bits(a), Bits(b), Bits(c)))
+            && DistributiveLattice::prop_join_distributes_over_meet((Bits(a), Bits(b), Bits(c)))
+    }
+
+    fn prop_bits_implies_is_residual(args: (u8, u8, u8)) -> bool {
+        let (a, b, c) = args;
+        HeytingAlgebra::prop_implies_is_residual((Bits(a), Bits(b), Bits(c)))
+    }
+
+    fn prop_bits_top_and_bottom_are_identities(args: (u64,)) -> bool {
+        BoundedLattice::prop_top_and_bottom_are_identities((Bits(args.0),))
+    }
+}
+
+#[test]
+fn bits_ordered_by_inclusion() {
+    assert_eq!(Bits(0b1100u8).meet(&Bits(0b1010)), Bits(0b1000));
+    assert_eq!(Bits(0b1100u8).join(&Bits(0b1010)), Bits(0b1110));
+    assert_eq!(Bits(0b1100u8).partial_cmp(&Bits(0b1010)), None);
+    assert!(Bits(0b1000u8) < Bits(0b1100));
+    assert_eq!(Bits(0b1100u8).pseudo_complement(), Bits(0b1111_0011));
+    assert_eq!(0b1100u8.meet(&0b1010), 0b1010);
+}
+
+quickcheck! {
+    fn prop_option_is_heyting_algebra(args: (Option<u8>, Option<u8>, Option<u8>)) -> bool {
+        let (a, b, c) = args;
+        DistributiveLattice::prop_meet_distributes_over_join((a, b, c))
+            && HeytingAlgebra::prop_implies_is_residual((a, b, c))
+            && HeytingAlgebra::prop_implies_is_modus_ponens((a, b))
+            && BoundedLattice::prop_top_and_bottom_are_identities((a,))
+    }
+}
+
+#[test]
+fn option_adjoins_bottom() {
+    assert_eq!(Some(3).meet(&None), None);
+    assert_eq!(None.join(&Some(3)), Some(3));
+    assert_eq!(Some(3).join(&Some(5)), Some(5));
+    assert_eq!(<Option<i32> as LowerBoundedLattice>::bottom(), None);
+    assert_eq!(<Option<u8> as BoundedLattice>::top(), Some(u8::MAX));
+    assert_eq!(Some(Bits(0b01u8)).meet(&Some(Bits(0b10))), Some(Bits(0)));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Parity {
+    Bottom,
+    Even,
+    Odd,
+    Top,
+}
+
+impl PartialOrd for Parity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (*self, *other) {
+            (a, b) if a == b => Some(Ordering::Equal),
+            (Parity::Bottom, _) | (_, Parity::Top) => Some(Ordering::Less),
+            (_, Parity::Bottom) | (Parity::Top, _) => Some(Ordering::Greater),
+            _ => None,
+        }
+    }
+}
+
+impl Arbitrary for Parity {
+    fn arbitrary<G: quickcheck::Gen>(g: &mut G) -> Self {
+        match u8::arbitrary(g) % 4 {
+            0 => Parity::Bottom,
+            1 => Parity::Even,
+            2 => Parity::Odd,
+            _ => Parity::Top,
+        }
+    }
+}
+
+impl GaloisConnection<Parity> for Inclusion<BTreeSet<u8>> {
+    fn abstraction(&self) -> Parity {
+        let has_even = self.0.iter().any(|e| e % 2 == 0);
+        let has_odd = self.0.iter().any(|e| e % 2 == 1);
+
+        match (has_even, has_odd) {
+            (false, false) => Parity::Bottom,
+            (true, false) => Parity::Even,
+            (false, true) => Parity::Odd,
+            (true, true) => Parity::Top,
+        }
+    }
+
+    fn concretization(element: &Parity) -> Self {
+        let all = 0..=u8::MAX;
+
+        Inclusion(match *element {
+            Parity::Bottom => BTreeSet::new(),
+            Parity::Even => all.filter(|e| e % 2 == 0).collect(),
+            Parity::Odd => all.filter(|e| e % 2 == 1).collect(),
+            Parity::Top => all.collect(),
+        })
+    }
+}
+
+quickcheck! {
+    fn prop_parity_is_galois_connection(args: (BTreeSet<u8>, Parity)) -> bool {
+        let (c, a) = args;
+        type C = Inclusion<BTreeSet<u8>>;
+
+        C::prop_is_adjunction((Inclusion(c.clone()), a))
+            && C::prop_abstraction_is_monotone((Inclusion(c.clone()), Inclusion(c).join(&C::concretization(&a))))
+            && C::prop_concretization_is_monotone((a, Parity::Top))
+    }
+}
+
+quickcheck! {
+    fn prop_intervals_form_a_lattice(args: ((i8, i8), (i8, i8), (i8, i8))) -> bool {
+        let interval = |(a, b): (i8, i8)| Interval::new(a as i32, b as i32);
+        let (a, b, c) = (interval(args.0), interval(args.1), interval(args.2));
+
+        Lattice::prop_meet_and_join_are_commutative((a, b))
+            && Lattice::prop_meet_and_join_are_associative((a, b, c))
+            && Lattice::prop_meet_and_join_are_absorptive((a, b))
+            && Lattice::prop_order_agrees_with_meet_and_join((a, b))
+            && BoundedLattice::prop_top_and_bottom_are_identities((a,))
+    }
+
+    fn prop_interval_meet_is_intersection(args: ((i8, i8), (i8, i8), i8)) -> bool {
+        let ((a, b), (c, d), x) = args;
+        let (ab, cd) = (Interval::new(a, b), Interval::new(c, d));
+
+        ab.meet(&cd).contains(&x) == (ab.contains(&x) && cd.contains(&x))
+    }
+}
+
+#[test]
+fn intervals_ordered_by_inclusion() {
+    let a = Interval::new(1, 3);
+    let b = Interval::new(5, 8);
+
+    assert_eq!(a.meet(&b), Interval::empty());
+    assert_eq!(a.join(&b), Interval::new(1, 8));
+    assert_eq!(a.partial_cmp(&b), None);
+    assert!(a < a.join(&b));
+    assert!(a.join(&b).contains(&4) && !a.contains(&4) && !b.contains(&4));
+    assert_eq!(
+        Interval::new(2, 6).meet(&Interval::new(4, 9)),
+        Interval::new(4, 6)
+    );
+    assert_eq!(a.join(&Interval::bottom()), a);
+    assert_eq!(Interval::point(2).inf(), Some(&2));
+    assert_eq!(
+        <Interval<u8> as BoundedLattice>::top(),
+        Interval::new(0, u8::MAX)
+    );
+}
+
+#[test]
+fn reversed_or_nan_intervals_are_empty() {
+    assert!(Interval::new(3, 1).is_empty());
+    assert!(Interval::new(f64::NAN, 1.0).is_empty());
+    assert!(Interval::new(1.0, f64::NAN).sup().is_none());
+    assert!(!Interval::new(1.0, 1.0).is_empty());
+    assert!(Interval::<f64>::empty() < Interval::point(0.0));
+}
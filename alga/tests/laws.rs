@@ -0,0 +1,294 @@
+#![cfg(feature = "alloc")]
+
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::laws::{self, Law, LawViolation};
+use alga::general::{
+    AbstractMagma, AbstractSemigroup, Additive, Bits, Identity, Multiplicative, OrderedRing,
+    SignedAlgebra, TwoSidedInverse, Zn,
+};
+use num_complex::Complex;
+use quickcheck::{Arbitrary, Gen};
+use std::cmp::Ordering;
+
+// Checks every law of the catalog on the first elements of `args`.
+fn all_hold<T>(laws: &[Law<T>], args: &[T]) -> bool {
+    laws.iter().all(|law| law.check(&args[..law.arity]))
+}
+
+// The integers with the subtraction, wrongly declared associative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Sub(i64);
+
+impl AbstractMagma<Additive> for Sub {
+    fn operate(&self, right: &Self) -> Self {
+        Sub(self.0 - right.0)
+    }
+}
+
+impl AbstractSemigroup<Additive> for Sub {}
+
+// The integers with the maximum as multiplication, which is not distributive over the addition.
+#[derive(Alga, Clone, Copy, Debug, PartialEq, Eq)]
+#[alga_traits(RingCommutative(Additive, Multiplicative))]
+struct Max(i64);
+
+impl AbstractMagma<Additive> for Max {
+    fn operate(&self, right: &Self) -> Self {
+        Max(self.0 + right.0)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Max {
+    fn operate(&self, right: &Self) -> Self {
+        Max(self.0.max(right.0))
+    }
+}
+
+impl TwoSidedInverse<Additive> for Max {
+    fn two_sided_inverse(&self) -> Self {
+        Max(-self.0)
+    }
+}
+
+impl Identity<Additive> for Max {
+    fn identity() -> Self {
+        Max(0)
+    }
+}
+
+impl Identity<Multiplicative> for Max {
+    fn identity() -> Self {
+        Max(i64::MIN)
+    }
+}
+
+impl Arbitrary for Max {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Max(i64::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Max))
+    }
+}
+
+// The integers with the reversed order, which is not compatible with the multiplication.
+#[derive(Alga, Clone, Copy, Debug, PartialEq, Eq)]
+#[alga_traits(RingCommutative(Additive, Multiplicative))]
+struct Reversed(i64);
+
+impl PartialOrd for Reversed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl AbstractMagma<Additive> for Reversed {
+    fn operate(&self, right: &Self) -> Self {
+        Reversed(self.0 + right.0)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Reversed {
+    fn operate(&self, right: &Self) -> Self {
+        Reversed(self.0 * right.0)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Reversed {
+    fn two_sided_inverse(&self) -> Self {
+        Reversed(-self.0)
+    }
+}
+
+impl Identity<Additive> for Reversed {
+    fn identity() -> Self {
+        Reversed(0)
+    }
+}
+
+impl Identity<Multiplicative> for Reversed {
+    fn identity() -> Self {
+        Reversed(1)
+    }
+}
+
+impl OrderedRing for Reversed {}
+
+// Cycles through the given values.
+fn cycle<T: Clone>(values: Vec<T>) -> impl FnMut() -> T {
+    let mut i = 0;
+
+    move || {
+        i += 1;
+        values[(i - 1) % values.len()].clone()
+    }
+}
+
+quickcheck!(
+    fn prop_integer_laws_hold(args: (i16, i16, i16)) -> bool {
+        let args = [args.0 as i64, args.1 as i64, args.2 as i64];
+
+        all_hold(&laws::abstract_group_abelian::<Additive, i64>(), &args)
+            && all_hold(&laws::abstract_ring_commutative::<i64>(), &args)
+            && all_hold(&laws::euclidean_domain::<i64>(), &args)
+            && all_hold(&laws::ordered_ring::<i64>(), &args)
+            && all_hold(&laws::signed_algebra::<i64>(), &args)
+    }
+
+    fn prop_float_laws_hold(args: (i8, i8, i8)) -> bool {
+        let args = [args.0 as f64, args.1 as f64, args.2 as f64];
+
+        all_hold(&laws::abstract_field_approx::<f64>(), &args)
+            && all_hold(&laws::ordered_field_approx::<f64>(), &args)
+            && all_hold(&laws::signed_algebra::<f64>(), &args)
+    }
+
+    fn prop_complex_modulus_laws_hold(args: (i8, i8, i8, i8)) -> bool {
+        let a = Complex::new(args.0 as f64, args.1 as f64);
+        let b = Complex::new(args.2 as f64, args.3 as f64);
+
+        // NOTE: the modulus is rounded twice, so the default tolerance of one epsilon is too tight.
+        SignedAlgebra::prop_abs_is_nonnegative((a,))
+            && SignedAlgebra::prop_abs_is_multiplicative_approx_with((a, b), 1.0e-12, 1.0e-12)
+            && SignedAlgebra::prop_triangle_inequality_approx_with((a, b), 1.0e-12, 1.0e-12)
+    }
+
+    fn prop_finite_field_laws_hold(args: (u64, u64, u64)) -> bool {
+        let args = [Zn::<7>::new(args.0), Zn::new(args.1), Zn::new(args.2)];
+
+        all_hold(&laws::abstract_field::<Zn<7>>(), &args)
+    }
+
+    fn prop_lattice_laws_hold(args: (u8, u8, u8)) -> bool {
+        let args = [Bits(args.0), Bits(args.1), Bits(args.2)];
+
+        all_hold(&laws::heyting_algebra::<Bits<u8>>(), &args)
+    }
+);
+
+#[test]
+fn catalogs_include_inherited_laws() {
+    let names = |laws: Vec<Law<i64>>| laws.iter().map(|law| law.name).collect::<Vec<_>>();
+
+    assert_eq!(
+        names(laws::abstract_group_abelian::<Additive, i64>()),
+        ["latin square", "associativity", "identity", "commutativity"]
+    );
+    assert!(names(laws::euclidean_domain::<i64>()).contains(&"distributivity"));
+    assert!(names(laws::abstract_ring::<i64>()).contains(&"characteristic"));
+    assert_eq!(
+        laws::abstract_ring_approx::<f64>().len() + 1,
+        laws::abstract_ring::<i64>().len()
+    );
+
+    let lattice = names(laws::heyting_algebra::<i64>());
+    assert_eq!(
+        lattice.iter().filter(|&&name| name == "absorption").count(),
+        1
+    );
+    assert!(lattice.contains(&"order consistency"));
+}
+
+#[test]
+fn violations_are_detected() {
+    let args = [Sub(1), Sub(2), Sub(3)];
+
+    let associativity = &laws::abstract_semigroup::<Additive, Sub>()[0];
+    assert_eq!(associativity.name, "associativity");
+    assert!(!associativity.check(&args));
+}
+
+#[test]
+fn incompatible_orders_are_detected() {
+    let laws = laws::ordered_ring::<Reversed>();
+    let violated = |args: &[Reversed]| {
+        laws.iter()
+            .filter(|law| !law.check(&args[..law.arity]))
+            .map(|law| law.name)
+            .collect::<Vec<_>>()
+    };
+
+    // -1 ≥ 0 in the reversed order, but (-1) × (-1) = 1 ≱ 0.
+    assert_eq!(
+        violated(&[Reversed(-1), Reversed(-1), Reversed(0)]),
+        ["nonnegative products"]
+    );
+    assert!(violated(&[Reversed(2), Reversed(-3), Reversed(5)]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn arity_is_checked() {
+    let _ = laws::abstract_semigroup::<Additive, i64>()[0].check(&[1, 2]);
+}
+
+#[test]
+fn verifiers_accept_lawful_structures() {
+    assert_eq!(
+        laws::check_ring_with(cycle(vec![-3i64, 0, 1, 7, 12]), 50),
+        Ok(())
+    );
+    assert_eq!(
+        laws::check_field_with(cycle((0..7).map(Zn::<7>::new).collect()), 100),
+        Ok(())
+    );
+}
+
+#[test]
+fn verifiers_report_counterexamples() {
+    let violation = laws::check_ring_commutative_with(cycle(vec![Max(3), Max(1), Max(2)]), 10);
+
+    // max(3, 1 + 2) != max(3, 1) + max(3, 2)
+    assert_eq!(
+        violation,
+        Err(LawViolation {
+            law: "distributivity",
+            args: vec![Max(3), Max(1), Max(2)],
+        })
+    );
+    assert_eq!(
+        violation.unwrap_err().to_string(),
+        "the law `distributivity` does not hold for [Max(3), Max(1), Max(2)]"
+    );
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn verifiers_with_random_arguments() {
+    use alga::general::FreeGroup;
+
+    assert_eq!(laws::check_ring_commutative::<i64>(), Ok(()));
+    assert_eq!(laws::check_field::<Zn<13>>(), Ok(()));
+
+    let violation = laws::check_laws(&laws::abstract_ring::<Max>()).unwrap_err();
+    assert_eq!(violation.law, "distributivity");
+    assert!(violation.args.iter().all(|m| m.0.abs() <= 1));
+
+    // Counterexamples in free groups are shrunk to the shortest words.
+    let commutativity = Law::new("commutativity", 2, |a: &[FreeGroup<u8>]| {
+        let mul = |x: &FreeGroup<u8>, y| AbstractMagma::<Multiplicative>::operate(x, y);
+        mul(&a[0], &a[1]) == mul(&a[1], &a[0])
+    });
+    let violation = laws::check_laws(&[commutativity]).unwrap_err();
+    assert!(violation.args.iter().all(|w| w.len() == 1));
+}
+
+#[test]
+fn counterexamples_are_shrunk() {
+    let distributivity = laws::abstract_ring::<Max>()
+        .into_iter()
+        .find(|law| law.name == "distributivity")
+        .unwrap();
+    let args = vec![Max(300), Max(-100), Max(200)];
+    let shrunk = laws::shrink_counterexample(&distributivity, args, Max::shrink);
+
+    // max(0, -1 + 1) != max(0, -1) + max(0, 1)
+    assert_eq!(shrunk, [Max(0), Max(-1), Max(1)]);
+}
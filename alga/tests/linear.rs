@@ -0,0 +1,671 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, Id,
+    Interval, MetricSpace, SubsetOf, SupersetOf,
+};
+use alga::linear::{
+    AffineSpace, BilinearForm, Bivector3, ConstDimVectorSpace, ConvexSpace, EuclideanSpace,
+    FiniteDimInnerSpace, FiniteDimVectorSpace, FiniteDimVectorSpaceMap, FreeModule, HilbertSpace,
+    IdentityOperator, InnerSpace, LinearFunctional, LinearOperator, LpNormedSpace, NormedSpace,
+    ProjectiveSpace, ProjectiveTransformation, QuadraticForm, Similarity, SymmetricBilinearForm,
+    TensorProduct, Transformation, VectorSpace, WedgeProduct,
+};
+use approx::{AbsDiffEq, RelativeEq};
+use num_complex::Complex;
+use std::ops::{Add, AddAssign, Sub};
+
+/// The scaling of the real line by a constant factor.
+#[derive(Clone, Copy, Debug)]
+struct Scale(f64);
+
+impl LinearOperator<f64, f64> for Scale {
+    fn apply(&self, v: &f64) -> f64 {
+        self.0 * *v
+    }
+}
+
+/// A point on the real line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point(f64);
+
+impl Add<f64> for Point {
+    type Output = Point;
+
+    fn add(self, t: f64) -> Point {
+        Point(self.0 + t)
+    }
+}
+
+impl AddAssign<f64> for Point {
+    fn add_assign(&mut self, t: f64) {
+        self.0 += t
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = f64;
+
+    fn sub(self, other: Point) -> f64 {
+        self.0 - other.0
+    }
+}
+
+impl AffineSpace for Point {
+    type Translation = f64;
+}
+
+impl AbsDiffEq for Point {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        1.0e-9
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Point {
+    fn default_max_relative() -> f64 {
+        1.0e-9
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+/// The form `(a, b) ↦ w * a * b` on the reals.
+#[derive(Clone, Copy, Debug)]
+struct Weighted(f64);
+
+impl BilinearForm<f64> for Weighted {
+    fn eval(&self, a: &f64, b: &f64) -> f64 {
+        self.0 * *a * *b
+    }
+}
+
+impl SymmetricBilinearForm<f64> for Weighted {}
+
+impl QuadraticForm<f64> for Weighted {
+    fn quadratic(&self, v: &f64) -> f64 {
+        self.0 * *v * *v
+    }
+}
+
+type Pair<T> = (T, T);
+type Triple<T> = (T, T, T);
+
+quickcheck! {
+    fn prop_projection_and_rejection_are_orthogonal(args: ((i8, i8, i8), (i8, i8, i8))) -> bool {
+        let v = |(x, y, z): (i8, i8, i8)| FreeModule::new([x as f64, y as f64, z as f64]);
+        let (a, b) = (v(args.0), v(args.1));
+        let proj = a.project_onto(&b);
+        let rej = a.reject_from(&b);
+
+        relative_eq!(proj + rej, a, epsilon = 1.0e-9)
+            && relative_eq!(rej.inner_product(&b), 0.0, epsilon = 1.0e-9)
+    }
+
+    fn prop_free_module_is_hilbert_space(args: Triple<i16>) -> bool {
+        let (x, y, z) = args;
+        let v = FreeModule::new([x as f64 / 8.0, y as f64 / 8.0, z as f64 / 8.0]);
+
+        HilbertSpace::prop_norm_agrees_with_inner_product_approx((v,))
+    }
+
+    fn prop_free_module_is_abelian_group(args: (Triple<i16>, Triple<i16>, Triple<i16>)) -> bool {
+        let v = |(x, y, z): Triple<i16>| FreeModule::new([x as i64, y as i64, z as i64]);
+        let (a, b, c) = (v(args.0), v(args.1), v(args.2));
+
+        AbstractSemigroup::<Additive>::prop_is_associative((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+            && AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop((a,))
+            && AbstractQuasigroup::<Additive>::prop_inv_is_latin_square((a, b))
+    }
+
+    fn prop_composed_operator_is_linear(args: (i8, i8, i8, i8, i8)) -> bool {
+        let (f, g, a, b, s) = args;
+        let op = Scale(f as f64).compose(Scale(g as f64));
+
+        LinearOperator::prop_is_linear_approx((op, a as f64, b as f64, s as f64))
+    }
+
+    fn prop_wedge_is_antisymmetric(args: (i16, i16, i16, i16, i16, i16)) -> bool {
+        let (x1, y1, z1, x2, y2, z2) = args;
+        let (a, b) = ([x1 as i64, y1 as i64, z1 as i64], [x2 as i64, y2 as i64, z2 as i64]);
+
+        WedgeProduct::prop_wedge_is_antisymmetric(([a[0], a[1]], [b[0], b[1]]))
+            && WedgeProduct::prop_wedge_is_antisymmetric((a, b))
+    }
+
+    fn prop_point_convex_combination(args: (i8, i8, f64)) -> bool {
+        let (a, b, t) = args;
+        let (a, b) = (Point(a as f64), Point(b as f64));
+
+        ConvexSpace::prop_convex_combination_endpoints_approx((a, b))
+            && ConvexSpace::prop_convex_combination_is_symmetric_approx((a, b, t))
+    }
+
+    fn prop_interval_convex_combination(args: ((i8, i8), (i8, i8), f64)) -> bool {
+        let interval = |(a, b): (i8, i8)| Interval::new(a.min(b) as f64, a.max(b) as f64);
+        let (a, b, t) = (interval(args.0), interval(args.1), args.2);
+
+        // NOTE: the endpoints are interpolated with a rounding error of a few ulps.
+        ConvexSpace::prop_convex_combination_endpoints_approx((a, b))
+            && ConvexSpace::prop_convex_combination_is_symmetric_approx_with(
+                (a, b, t),
+                1.0e-12,
+                1.0e-12,
+            )
+    }
+
+    fn prop_tensor_product(args: (Pair<i8>, Pair<i8>, Triple<i8>, Triple<i8>, i8)) -> bool {
+        type V2 = FreeModule<f64, 2>;
+        type V3 = FreeModule<f64, 3>;
+
+        let (a, b, v, w, s) = args;
+        let (a, b) = (V2::new([a.0 as f64, a.1 as f64]), V2::new([b.0 as f64, b.1 as f64]));
+        let v = V3::new([v.0 as f64, v.1 as f64, v.2 as f64]);
+        let w = V3::new([w.0 as f64, w.1 as f64, w.2 as f64]);
+        let (i, j) = (s as u8 as usize, s as u8 as usize / 3);
+
+        <V2 as TensorProduct<V3>>::prop_dimension_is_product()
+            && <V2 as TensorProduct<V3>>::prop_tensor_of_bases_is_basis((i, j))
+            && TensorProduct::prop_tensor_is_bilinear_approx((a, b, v, w, s as f64))
+    }
+
+    fn prop_homogeneous_round_trip(args: ((i8, i8, i8), i8)) -> bool {
+        let ((x, y, z), s) = args;
+        let pt = alga::linear::Point::new([x as f64, y as f64, z as f64]);
+
+        ProjectiveSpace::prop_homogeneous_round_trip_approx((pt, s as f64))
+    }
+
+    fn prop_affine_combination_is_origin_independent(args: (i8, i8, i8, i8, i8)) -> bool {
+        let (a, b, c, wa, wb) = args;
+        let p = |x: i8| Point(x as f64);
+
+        AffineSpace::prop_affine_combination_is_origin_independent_approx(
+            (p(a), p(b), p(c), wa as f64 / 8.0, wb as f64 / 8.0),
+        )
+    }
+
+    // NOTE: small integers keep the floating-point arithmetic exact.
+    fn prop_scalar_functional_is_linear(args: (i8, i8, i8, i8)) -> bool {
+        let (f, a, b, s) = args;
+
+        LinearFunctional::prop_is_linear_approx((f as f64, a as f64, b as f64, s as f64))
+    }
+
+    fn prop_weighted_form_is_symmetric_bilinear(args: (i8, i8, i8, i8, i8)) -> bool {
+        let (w, a, b, c, s) = args;
+        let w = Weighted(w as f64);
+
+        BilinearForm::prop_is_bilinear_approx((w, a as f64, b as f64, c as f64, s as f64))
+            && SymmetricBilinearForm::prop_is_symmetric_approx((w, a as f64, b as f64))
+            && QuadraticForm::prop_is_homogeneous_approx((w, a as f64, s as f64))
+    }
+
+    fn prop_uniform_scaling_inverse_transform(args: (i8, (i8, i8, i8), (i8, i8, i8))) -> bool {
+        let (s, (px, py, pz), (vx, vy, vz)) = args;
+
+        if s == 0 {
+            return true;
+        }
+
+        let s = s as f64 / 4.0;
+        let pt = alga::linear::Point::new([px as f64, py as f64, pz as f64]);
+        let v = FreeModule::new([vx as f64, vy as f64, vz as f64]);
+
+        ProjectiveTransformation::prop_inverse_transform_is_inverse_action_approx((s, pt, v))
+            && Similarity::prop_inverse_transform_by_parts_approx((s, pt, v))
+    }
+
+    fn prop_uniform_scaling_parts(args: (i8, i8)) -> bool {
+        type E3 = alga::linear::Point<[f64; 3]>;
+        let (s, t) = (args.0 as f64 / 4.0, args.1 as f64 / 4.0);
+
+        Similarity::<E3>::prop_isometry_and_scaling_round_trip_approx((s,))
+            && Similarity::<E3>::prop_parts_of_composition_approx((s, t))
+    }
+}
+
+#[test]
+fn scalar_functional_is_multiplication() {
+    assert_eq!(LinearFunctional::<f32>::apply(&2.0f32, &3.5), 7.0);
+}
+
+#[test]
+fn quadratic_form_polarization() {
+    let w = Weighted(-2.0);
+    assert_eq!(w.polar(&3.0, &5.0), w.eval(&3.0, &5.0));
+}
+
+#[test]
+fn barycenter_normalizes_weights() {
+    let points = [Point(1.0), Point(3.0), Point(8.0)];
+
+    assert_eq!(Point::barycenter(&points, &[2.0, 2.0, 0.0]), Point(2.0));
+    assert_eq!(
+        Point::affine_combination(&points, &[0.5, 0.5, 0.0]),
+        Point(2.0)
+    );
+    assert_eq!(Point::barycenter(&points[2..], &[4.0]), Point(8.0));
+}
+
+#[test]
+fn convex_combination_interpolates() {
+    assert_eq!(Point(2.0).convex_combination(&Point(6.0), 0.25), Point(3.0));
+    assert_eq!(0.2f64.convex_combination(&0.6, 0.5), 0.4);
+    assert_eq!(
+        Interval::new(0.0, 2.0).convex_combination(&Interval::new(4.0, 10.0), 0.5),
+        Interval::new(2.0, 6.0)
+    );
+    assert_eq!(
+        Interval::new(0.0, 2.0).convex_combination(&Interval::empty(), 0.0),
+        Interval::empty()
+    );
+}
+
+#[test]
+fn wedge_of_basis_vectors() {
+    assert_eq!([1.0, 0.0].wedge(&[0.0, 1.0]), 1.0);
+    assert_eq!([1, 0, 0].wedge(&[0, 1, 0]), Bivector3::new(0, 0, 1));
+    assert_eq!([0, 1, 0].wedge(&[0, 0, 1]), Bivector3::new(1, 0, 0));
+}
+
+#[test]
+fn approximate_wedge() {
+    let b = [0.1, 0.2, 0.3].wedge(&[0.3, 0.1, 0.2]);
+    let expected = Bivector3::new(0.01, 0.07, -0.05);
+
+    assert!(b.relative_eq(&expected, 1.0e-12, 1.0e-12));
+    assert!(b.abs_diff_eq(&expected, 1.0e-12));
+    assert!(!b.abs_diff_eq(&Bivector3::new(0.01, 0.07, -0.06), 1.0e-12));
+}
+
+#[test]
+fn operator_composition() {
+    let op = Scale(2.0).compose(IdentityOperator).compose(Scale(-3.0));
+    assert_eq!(op.apply(&1.5), -9.0);
+}
+
+#[test]
+fn try_normalize_rejects_zero() {
+    assert_eq!(0.0f64.try_normalize(0.0), None);
+    assert_eq!((-3.0f64).try_normalize(1.0e-6), Some(-1.0));
+
+    let mut v = 0.0f32;
+    assert_eq!(v.try_normalize_mut(0.0), None);
+    assert_eq!(v, 0.0);
+}
+
+#[test]
+fn free_module_components() {
+    let e1 = FreeModule::<f64, 3>::canonical_basis_element(1);
+    let v = FreeModule::new([1.0, 2.0, 3.0]);
+
+    assert_eq!(e1, FreeModule::new([0.0, 1.0, 0.0]));
+    assert_eq!(v.dot(&e1), 2.0);
+    assert_eq!(FreeModule::<f64, 3>::dimension(), 3);
+    assert_eq!(v * 2.0 - v, v);
+    assert_eq!(
+        FreeModule::<i32, 2>::new([1, -2]) * 3,
+        FreeModule::new([3, -6])
+    );
+}
+
+#[test]
+fn degenerate_geometric_queries() {
+    let zero = FreeModule::new([0.0, 0.0]);
+    let x = FreeModule::new([2.0, 0.0]);
+    let y = FreeModule::new([0.0, 3.0]);
+
+    assert_eq!(x.cosine_similarity(&zero), None);
+    assert_eq!(x.angle_between(&zero), 0.0);
+    assert_eq!(x.project_onto(&zero), zero);
+    assert_eq!(x.reject_from(&zero), x);
+    assert_eq!(x.cosine_similarity(&y), Some(0.0));
+    assert_relative_eq!(x.angle_between(&(-x)), std::f64::consts::PI);
+}
+
+#[test]
+fn array_points() {
+    let a = alga::linear::Point::new([1.0, 2.0, 2.0]);
+    let b = alga::linear::Point::new([1.0, 0.0, 0.0]);
+
+    assert_eq!(a - b, FreeModule::new([0.0, 2.0, 2.0]));
+    assert_eq!(b + (a - b), a);
+    assert_eq!(
+        EuclideanSpace::distance(&a, &alga::linear::Point::origin()),
+        3.0
+    );
+    assert_eq!(MetricSpace::distance(&a, &b), 8.0f64.sqrt());
+    assert_eq!(a.coordinates(), FreeModule::new([1.0, 2.0, 2.0]));
+}
+
+#[test]
+fn free_module_basis_completion() {
+    let mut vs = [FreeModule::new([1.0, 1.0, 0.0])];
+    let mut basis = Vec::new();
+
+    FiniteDimInnerSpace::complete_basis(&mut vs, |v| {
+        basis.push(*v);
+        true
+    });
+
+    assert_eq!(basis.len(), 3);
+
+    for (i, a) in basis.iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(a.inner_product(b), expected, epsilon = 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn complex_free_module() {
+    let i = Complex::new(0.0, 1.0);
+    let v = FreeModule::new([Complex::new(1.0, 0.0), i]);
+
+    assert_eq!(v.norm_squared(), 2.0);
+    assert_eq!(v.inner_product(&v), Complex::new(2.0, 0.0));
+    assert_eq!(v.inner_product(&(v * i)), Complex::new(0.0, -2.0));
+}
+
+#[test]
+fn free_module_change_of_field() {
+    let v = FreeModule::new([1.5f32, -2.0, 0.25]);
+    let w: FreeModule<f64, 3> = v.convert_coords();
+
+    assert_eq!(w, FreeModule::new([1.5, -2.0, 0.25]));
+    assert_eq!(v.to_superset(), w);
+    assert_eq!(
+        FiniteDimVectorSpaceMap::<f64>::map_coords(&v, |c| c as f64 * 2.0),
+        FreeModule::new([3.0, -4.0, 0.5])
+    );
+    assert_eq!(w.try_convert_coords(), Some(v));
+
+    let real = FreeModule::new([Complex::new(1.0, 0.0), Complex::new(-3.0, 0.0)]);
+    let complex = FreeModule::new([Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+    assert_eq!(
+        FiniteDimVectorSpaceMap::<f64>::try_convert_coords(&real),
+        Some(FreeModule::new([1.0, -3.0]))
+    );
+    assert_eq!(
+        FiniteDimVectorSpaceMap::<f64>::try_convert_coords(&complex),
+        None
+    );
+    assert!(!<FreeModule<f64, 2> as SubsetOf<_>>::is_in_subset(&complex));
+    assert_eq!(
+        FreeModule::<f64, 2>::from_superset(&real),
+        Some(FreeModule::new([1.0, -3.0]))
+    );
+}
+
+#[test]
+fn inverse_transform_by_parts() {
+    let s = 2.0f64;
+    let pt = alga::linear::Point::new([1.0, 2.0, 4.0]);
+    let v = FreeModule::new([2.0, 0.0, 8.0]);
+
+    assert_eq!(
+        s.inverse_transform_point_by_parts(&pt),
+        alga::linear::Point::new([0.5, 1.0, 2.0])
+    );
+    assert_eq!(
+        Similarity::<alga::linear::Point<[f64; 3]>>::inverse_transform_vector_by_parts(&s, &v),
+        FreeModule::new([1.0, 0.0, 4.0])
+    );
+    assert!(Similarity::prop_inverse_transform_by_parts_approx_with(
+        (s, pt, v),
+        1.0e-12,
+        1.0e-12
+    ));
+}
+
+#[test]
+fn batched_transforms() {
+    let s = 2.0f64;
+    let mut pts = [
+        alga::linear::Point::new([1.0, 2.0]),
+        alga::linear::Point::new([-3.0, 0.5]),
+    ];
+    let mut vs = [FreeModule::new([0.0, 1.0]), FreeModule::new([4.0, -1.0])];
+    let expected_pts = [s.transform_point(&pts[0]), s.transform_point(&pts[1])];
+    let expected_vs: [FreeModule<f64, 2>; 2] = [
+        Transformation::<alga::linear::Point<[f64; 2]>>::transform_vector(&s, &vs[0]),
+        Transformation::<alga::linear::Point<[f64; 2]>>::transform_vector(&s, &vs[1]),
+    ];
+
+    s.transform_points(&mut pts);
+    Transformation::<alga::linear::Point<[f64; 2]>>::transform_vectors(&s, &mut vs);
+    assert_eq!(pts, expected_pts);
+    assert_eq!(vs, expected_vs);
+
+    Id::new().transform_points(&mut pts);
+    Transformation::<alga::linear::Point<[f64; 2]>>::transform_vectors(&Id::new(), &mut vs);
+    assert_eq!(pts, expected_pts);
+    assert_eq!(vs, expected_vs);
+}
+
+#[test]
+fn axpy_and_linear_combination() {
+    let x = FreeModule::new([1.0, 2.0, -1.0]);
+    let y = FreeModule::new([0.0, 1.0, 4.0]);
+    let mut z = y;
+
+    z.axpy(&2.0, &x);
+    assert_eq!(z, y + x * 2.0);
+    assert_eq!(
+        VectorSpace::linear_combination(&[(2.0, &x), (-3.0, &y)]),
+        x * 2.0 - y * 3.0
+    );
+    assert_eq!(
+        <FreeModule<f64, 3> as VectorSpace>::linear_combination(&[]),
+        FreeModule::new([0.0; 3])
+    );
+    assert_eq!(
+        VectorSpace::linear_combination(&[(0.5, &3.0), (2.0, &1.0)]),
+        3.5
+    );
+}
+
+#[test]
+fn dimension_and_from_fn() {
+    const DIM: usize = <FreeModule<f64, 3> as ConstDimVectorSpace>::DIM;
+    let components: [f64; DIM] = [0.0; DIM];
+
+    assert_eq!(components.len(), 3);
+    assert_eq!(
+        <FreeModule<f64, 3> as FiniteDimVectorSpace>::dimension(),
+        DIM
+    );
+    assert_eq!(<FreeModule<f64, 0> as ConstDimVectorSpace>::DIM, 0);
+
+    let v: FreeModule<f64, 3> = FiniteDimVectorSpace::from_fn(|i| i as f64 * 2.0);
+    assert_eq!(v, FreeModule::new([0.0, 2.0, 4.0]));
+
+    let pt =
+        alga::linear::Point::<[f64; 2]>::from_coordinates(FiniteDimVectorSpace::from_fn(|i| {
+            i as f64 + 1.0
+        }));
+    assert_eq!(pt, alga::linear::Point::new([1.0, 2.0]));
+}
+
+#[test]
+fn lp_norms() {
+    let v = FreeModule::new([3.0f64, -4.0, 12.0]);
+
+    assert_eq!(v.norm_l1(), 19.0);
+    assert_eq!(v.norm_l2(), 13.0);
+    assert_eq!(v.norm_linf(), 12.0);
+    assert_eq!(v.norm_p(1.0), 19.0);
+    assert_eq!(v.norm_p(2.0), 13.0);
+    assert_eq!(v.norm_p(f64::INFINITY), 12.0);
+    assert_relative_eq!(v.norm_p(3.0), 1819.0f64.cbrt(), epsilon = 1.0e-12);
+
+    let c = FreeModule::new([Complex::new(3.0f64, 4.0), Complex::new(0.0, -1.0)]);
+    assert_eq!(c.norm_l1(), 6.0);
+    assert_eq!(c.norm_linf(), 5.0);
+    assert_relative_eq!(c.norm_l2(), 26.0f64.sqrt());
+}
+
+#[test]
+fn modified_gram_schmidt_detects_rank() {
+    let mut vs = [
+        FreeModule::new([1.0f64, 1.0, 0.0]),
+        FreeModule::new([2.0, 2.0, 0.0]),
+        FreeModule::new([1.0, 0.0, 0.0]),
+        FreeModule::new([3.0, 1.0, 0.0]),
+    ];
+
+    let rank = FreeModule::modified_gram_schmidt(&mut vs, 1.0e-10);
+    assert_eq!(rank, 2);
+
+    let s = 0.5f64.sqrt();
+    assert_relative_eq!(vs[0], FreeModule::new([s, s, 0.0]), epsilon = 1.0e-12);
+    assert_relative_eq!(vs[1], FreeModule::new([s, -s, 0.0]), epsilon = 1.0e-12);
+
+    let mut zeros = [FreeModule::<f64, 3>::new([0.0; 3]); 2];
+    assert_eq!(FreeModule::orthonormalize(&mut zeros), 0);
+}
+
+#[test]
+fn orthonormal_subspace_basis_completes_the_family() {
+    let s = 0.5f64.sqrt();
+    let vs = [FreeModule::new([s, 0.0, s])];
+    let mut complement = Vec::new();
+
+    FreeModule::orthonormal_subspace_basis(&vs, |v| {
+        complement.push(*v);
+        true
+    });
+
+    assert_eq!(complement.len(), 2);
+
+    let basis: Vec<_> = vs.iter().chain(complement.iter()).collect();
+    for (i, a) in basis.iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(a.inner_product(b), expected, epsilon = 1.0e-12);
+        }
+    }
+
+    let mut count = 0;
+    FreeModule::orthonormal_subspace_basis(&vs, |_| {
+        count += 1;
+        false
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn complete_basis_of_a_dependent_family() {
+    let mut vs = [
+        FreeModule::new([0.0f64, 2.0, 0.0]),
+        FreeModule::new([0.0, -1.0, 0.0]),
+    ];
+    let mut basis = Vec::new();
+
+    FiniteDimInnerSpace::complete_basis(&mut vs, |v| {
+        basis.push(*v);
+        true
+    });
+
+    assert_eq!(basis.len(), 3);
+    assert_eq!(basis[0], FreeModule::new([0.0, 1.0, 0.0]));
+
+    for (i, a) in basis.iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(a.inner_product(b), expected, epsilon = 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn tensor_product_components() {
+    let a = FreeModule::new([1.0, 2.0]);
+    let b = FreeModule::new([3.0, 4.0, 5.0]);
+
+    assert_eq!(
+        a.tensor(&b),
+        FreeModule::new([3.0, 4.0, 5.0, 6.0, 8.0, 10.0])
+    );
+    assert_eq!(
+        b.tensor(&a),
+        FreeModule::new([3.0, 6.0, 4.0, 8.0, 5.0, 10.0])
+    );
+    assert_eq!(
+        FreeModule::new([2.0]).tensor(&FreeModule::new([1.0, -1.0, 0.5, 3.0])),
+        FreeModule::new([2.0, -2.0, 1.0, 6.0])
+    );
+}
+
+#[test]
+fn similarity_interpolation_and_recomposition() {
+    type E = alga::linear::Point<[f64; 3]>;
+
+    let (a, b) = (2.0f64, 8.0f64);
+
+    assert_eq!(Similarity::<E>::interpolate(&a, &b, 0.0), Some(a));
+    assert_eq!(Similarity::<E>::interpolate(&a, &b, 1.0), Some(b));
+    assert_relative_eq!(
+        Similarity::<E>::interpolate(&a, &b, 0.5).unwrap(),
+        4.0,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        Similarity::<E>::interpolate(&a, &b, 1.5).unwrap(),
+        16.0,
+        epsilon = 1.0e-12
+    );
+
+    let isometry = Similarity::<E>::isometry_part(&a);
+    assert_eq!(isometry, 1.0);
+    assert_eq!(Similarity::<E>::from_isometry_and_scaling(&isometry, &a), a);
+    assert!(Similarity::<E>::prop_isometry_and_scaling_round_trip_approx((b,)));
+    assert!(Similarity::<E>::prop_interpolate_endpoints_approx((a, b)));
+}
+
+#[test]
+fn homogeneous_coordinates() {
+    type E3 = alga::linear::Point<[f64; 3]>;
+
+    let pt = E3::new([1.0, -2.0, 4.0]);
+    let h = pt.to_homogeneous();
+
+    assert_eq!(h, FreeModule::new([1.0, -2.0, 4.0, 1.0]));
+    assert_eq!(E3::from_homogeneous(&(h * 4.0)), Some(pt));
+    assert!(!E3::is_at_infinity(&h));
+
+    let dir = E3::direction_to_homogeneous(&FreeModule::new([0.0, 1.0, 0.0]));
+    assert_eq!(dir, FreeModule::new([0.0, 1.0, 0.0, 0.0]));
+    assert!(E3::is_at_infinity(&dir));
+    assert_eq!(E3::from_homogeneous(&dir), None);
+
+    let s = 2.0f64;
+    assert_eq!(
+        ProjectiveTransformation::<E3>::transform_homogeneous(&s, &h),
+        FreeModule::new([2.0, -4.0, 8.0, 1.0])
+    );
+    assert_eq!(
+        ProjectiveTransformation::<E3>::transform_homogeneous(&s, &dir),
+        FreeModule::new([0.0, 2.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        alga::linear::Point::new([3.0]).to_homogeneous(),
+        FreeModule::new([3.0, 1.0])
+    );
+}
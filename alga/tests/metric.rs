@@ -0,0 +1,38 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{Bits, Discrete, MetricSpace};
+
+quickcheck! {
+    fn prop_hamming_distance_is_symmetric(args: (u32, u32)) -> bool {
+        MetricSpace::prop_distance_is_symmetric((Bits(args.0), Bits(args.1)))
+    }
+    fn prop_hamming_triangle_inequality(args: (u64, u64, u64)) -> bool {
+        MetricSpace::prop_triangle_inequality((Bits(args.0), Bits(args.1), Bits(args.2)))
+    }
+    fn prop_discrete_distance_is_symmetric(args: (i8, i8)) -> bool {
+        MetricSpace::prop_distance_is_symmetric((Discrete(args.0), Discrete(args.1)))
+    }
+    fn prop_discrete_triangle_inequality(args: (u8, u8, u8)) -> bool {
+        let (a, b, c) = args;
+        // Small alphabet so that collisions actually occur.
+        MetricSpace::prop_triangle_inequality((Discrete(a % 3), Discrete(b % 3), Discrete(c % 3)))
+    }
+}
+
+#[test]
+fn hamming_distance() {
+    assert_eq!(Bits(0b1011u8).distance(&Bits(0b0110)), 3);
+    assert_eq!(Bits(!0u128).distance(&Bits(0)), 128);
+    assert_eq!(Discrete("a").distance(&Discrete("b")), 1);
+    assert_eq!(Discrete('a').distance(&Discrete('a')), 0);
+}
+
+#[test]
+fn discrete_approximate_equality() {
+    use approx::AbsDiffEq;
+
+    assert!(Discrete(1.0f64).abs_diff_eq(&Discrete(1.0 + 1.0e-12), 1.0e-9));
+    assert_eq!(Discrete(1.0f64).distance(&Discrete(1.0 + 1.0e-12)), 1);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "mint")]
+
+extern crate alga;
+extern crate mint;
+
+use alga::general::{convert, try_convert};
+use alga::linear::{FreeModule, Point};
+
+#[test]
+fn vector_round_trip() {
+    let v = mint::Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let w: FreeModule<f64, 3> = convert(v);
+
+    assert_eq!(w, FreeModule::new([1.0, 2.0, 3.0]));
+    assert_eq!(try_convert(w), Some(v));
+    assert_eq!(try_convert::<_, mint::Vector2<f64>>(w), None);
+}
+
+#[test]
+fn point_round_trip() {
+    let p = mint::Point2 { x: 1.0f32, y: -1.0 };
+    let q: Point<[f32; 2]> = convert(p);
+
+    assert_eq!(q, Point::new([1.0, -1.0]));
+    assert_eq!(try_convert(q), Some(p));
+}
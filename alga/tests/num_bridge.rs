@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractField, AbstractGroupAbelian, AbstractRing, Multiplicative};
+use num_traits::{One, Zero};
+use quickcheck::{Arbitrary, Gen};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The integers modulo 5.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Z5(u8);
+
+impl Add for Z5 {
+    type Output = Z5;
+
+    fn add(self, rhs: Z5) -> Z5 {
+        Z5((self.0 + rhs.0) % 5)
+    }
+}
+
+impl Sub for Z5 {
+    type Output = Z5;
+
+    fn sub(self, rhs: Z5) -> Z5 {
+        Z5((self.0 + 5 - rhs.0) % 5)
+    }
+}
+
+impl Mul for Z5 {
+    type Output = Z5;
+
+    fn mul(self, rhs: Z5) -> Z5 {
+        Z5((self.0 * rhs.0) % 5)
+    }
+}
+
+impl Div for Z5 {
+    type Output = Z5;
+
+    fn div(self, rhs: Z5) -> Z5 {
+        // The inverses of 1, 2, 3, 4 are 1, 3, 2, 4.
+        let inv = [0, 1, 3, 2, 4][rhs.0 as usize];
+        Z5((self.0 * inv) % 5)
+    }
+}
+
+impl Zero for Z5 {
+    fn zero() -> Z5 {
+        Z5(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Z5 {
+    fn one() -> Z5 {
+        Z5(1)
+    }
+}
+
+impl Arbitrary for Z5 {
+    fn arbitrary<G: Gen>(g: &mut G) -> Z5 {
+        Z5(u8::arbitrary(g) % 5)
+    }
+}
+
+impl_alga_from_num!(field: Z5);
+
+fn is_field<T: AbstractField>() {}
+
+quickcheck! {
+    fn prop_mul_and_add_are_distributive(args: (Z5, Z5, Z5)) -> bool {
+        AbstractRing::prop_mul_and_add_are_distributive(args)
+    }
+
+    fn prop_mul_is_commutative(args: (Z5, Z5)) -> bool {
+        AbstractGroupAbelian::<Multiplicative>::prop_is_commutative(args)
+    }
+
+    fn prop_nonzero_elements_are_invertible(args: (Z5,)) -> bool {
+        use alga::general::TwoSidedInverse;
+        let (a,) = args;
+        a.is_zero() || a * TwoSidedInverse::<Multiplicative>::two_sided_inverse(&a) == Z5::one()
+    }
+}
+
+#[test]
+fn z5_is_a_field() {
+    is_field::<Z5>();
+}
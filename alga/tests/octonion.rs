@@ -0,0 +1,160 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    convert, try_convert, AbstractAlternativeMagma, AbstractGroupAbelian, AbstractLoop, Additive,
+    AlternativeAlgebra, Multiplicative, NormedDivisionAlgebra, Octonion, Quaternion,
+    TwoSidedInverse,
+};
+
+type O = Octonion<f64>;
+
+type Args = (i8, i8, i8, i8, i8, i8, i8, i8);
+type QuatArgs = (i8, i8, i8, i8);
+
+// Small integers keep the arithmetic exact.
+fn oct(args: Args) -> O {
+    Octonion::from_components([
+        args.0 as f64,
+        args.1 as f64,
+        args.2 as f64,
+        args.3 as f64,
+        args.4 as f64,
+        args.5 as f64,
+        args.6 as f64,
+        args.7 as f64,
+    ])
+}
+
+fn basis(i: usize) -> O {
+    let mut c = [0.0; 8];
+    c[i] = 1.0;
+    Octonion::from_components(c)
+}
+
+fn dot(u: [f64; 7], v: [f64; 7]) -> f64 {
+    u.iter().zip(v.iter()).map(|(a, b)| a * b).sum()
+}
+
+quickcheck!(
+    fn prop_is_alternative_algebra(args: (Args, Args, Args, i8)) -> bool {
+        let (a, b, c) = (oct(args.0), oct(args.1), oct(args.2));
+        let r = args.3 as f64;
+
+        AbstractAlternativeMagma::<Multiplicative>::prop_is_alternative_approx((a, b))
+            && AbstractAlternativeMagma::<Multiplicative>::prop_is_flexible_approx((a, b))
+            && AlternativeAlgebra::prop_mul_and_add_are_distributive_approx((a, b, c))
+            && AlternativeAlgebra::prop_mul_is_compatible_with_scalars_approx((a, b, r))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative_approx((a, b))
+    }
+
+    fn prop_is_normed_division_algebra(args: (Args, Args)) -> bool {
+        let (a, b) = (oct(args.0), oct(args.1));
+
+        NormedDivisionAlgebra::prop_norm_is_multiplicative_approx((a, b))
+            && NormedDivisionAlgebra::prop_conjugate_product_is_norm_squared_approx((a,))
+    }
+
+    fn prop_tenths_form_a_normed_division_algebra(args: (Args, Args, Args)) -> bool {
+        let tenths = |x: Args| oct(x) * 0.1;
+        let (a, b, c) = (tenths(args.0), tenths(args.1), tenths(args.2));
+        let (eps, rel) = (1.0e-9, 1.0e-12);
+
+        AbstractAlternativeMagma::<Multiplicative>::prop_is_alternative_approx_with(
+            (a, b),
+            eps,
+            rel,
+        ) && AlternativeAlgebra::prop_mul_and_add_are_distributive_approx_with((a, b, c), eps, rel)
+            && NormedDivisionAlgebra::prop_norm_is_multiplicative_approx_with((a, b), eps, rel)
+            && NormedDivisionAlgebra::prop_conjugate_product_is_norm_squared_approx_with(
+                (a,),
+                eps,
+                rel,
+            )
+    }
+
+    fn prop_nonzero_octonions_form_a_loop(args: (Args, Args)) -> bool {
+        let (a, b) = (oct(args.0), oct(args.1));
+
+        if b == Octonion::from_real(0.0) {
+            return true;
+        }
+
+        let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&b);
+
+        relative_eq!(a * inv * b, a, epsilon = 1.0e-12)
+            && relative_eq!(a * b * inv, a, epsilon = 1.0e-12)
+    }
+
+    fn prop_cross_product_is_orthogonal(args: (Args, Args)) -> bool {
+        let (u, v) = (oct(args.0).imag(), oct(args.1).imag());
+        let w = O::cross_product(u, v);
+
+        dot(u, w) == 0.0 && dot(v, w) == 0.0 && O::cross_product(v, u) == w.map(|x| -x)
+    }
+
+    fn prop_quaternions_are_alternative(args: (QuatArgs, QuatArgs)) -> bool {
+        let q = |x: QuatArgs| Quaternion::new(x.0 as f64, x.1 as f64, x.2 as f64, x.3 as f64);
+        let (a, b) = (q(args.0), q(args.1));
+
+        AbstractAlternativeMagma::<Multiplicative>::prop_is_alternative_approx((a, b))
+            && AlternativeAlgebra::prop_mul_and_add_are_distributive_approx((a, b, a))
+    }
+);
+
+#[test]
+fn octonions_are_not_associative() {
+    let (e1, e2, e4) = (basis(1), basis(2), basis(4));
+
+    assert_ne!((e1 * e2) * e4, e1 * (e2 * e4));
+    assert_eq!((e1 * e2) * e4, -(e1 * (e2 * e4)));
+}
+
+#[test]
+fn imaginary_units_square_to_minus_one() {
+    let minus_one = Octonion::from_real(-1.0);
+
+    for i in 1..8 {
+        assert_eq!(basis(i) * basis(i), minus_one);
+
+        for j in 1..8 {
+            if i != j {
+                assert_eq!(basis(i) * basis(j), -(basis(j) * basis(i)));
+            }
+        }
+    }
+}
+
+#[test]
+fn octonions_form_a_loop() {
+    fn is_loop<T: AbstractLoop<Multiplicative>>() {}
+    is_loop::<O>();
+}
+
+#[test]
+fn inverse() {
+    let a = Octonion::from_components([1.0, 2.0, -1.0, 0.5, 3.0, 0.0, -2.0, 1.0]);
+    let one = Octonion::from_real(1.0);
+
+    assert_relative_eq!(a / a, one, epsilon = 1.0e-12);
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Octonion::from_real(0.0f64)),
+        None
+    );
+}
+
+#[test]
+fn subsets() {
+    let o: O = convert(2.0f64);
+    assert_eq!(o, Octonion::from_real(2.0));
+
+    let q = Quaternion::new(1.0f64, 2.0, 3.0, 4.0);
+    let o: O = convert(q);
+    assert_eq!(o.components(), [1.0, 2.0, 3.0, 4.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(try_convert::<O, Quaternion<f64>>(o), Some(q));
+    assert_eq!(try_convert::<O, Quaternion<f64>>(basis(5)), None);
+    assert_eq!(try_convert::<O, f64>(o), None);
+}
@@ -1,4 +1,5 @@
 extern crate alga;
+extern crate num_complex;
 #[macro_use]
 extern crate quickcheck;
 
@@ -50,3 +51,344 @@ mod int_check {
 
     check!(/*u8, u16,*/ u32, u64, u128, /*i8, i16,*/ i32, i64, i128);
 }
+
+mod composition_check {
+    use alga::general::{AbstractMonoid, AbstractSemigroup, Composition};
+
+    // NOTE: products of three small integers are exact.
+    quickcheck!(
+        fn prop_identity_is_noop(args: (i8,)) -> bool {
+            AbstractMonoid::<Composition>::prop_operating_identity_element_is_noop_approx((
+                args.0 as f64,
+            ))
+        }
+
+        fn prop_is_associative(args: (i8, i8, i8)) -> bool {
+            let (a, b, c) = args;
+            AbstractSemigroup::<Composition>::prop_is_associative_approx((
+                a as f64, b as f64, c as f64,
+            ))
+        }
+    );
+
+    #[test]
+    fn identity_is_a_group() {
+        use alga::general::{AbstractGroup, Composition, Id, Multiplicative, TwoSidedInverse};
+
+        fn inverse_is_identity<T: AbstractGroup<Composition> + PartialEq>(t: T) -> bool {
+            t.operate(&TwoSidedInverse::<Composition>::two_sided_inverse(&t)) == T::identity()
+        }
+
+        // Both the multiplicative identity and the identity for the composition itself.
+        assert!(inverse_is_identity(Id::<Multiplicative>::new()));
+        assert!(inverse_is_identity(Id::<Composition>::new()));
+    }
+}
+
+mod const_identity_check {
+    use alga::general::{Additive, Composition, ConstIdentity, Id, Identity, Multiplicative};
+    use alga::linear::FreeModule;
+    use num_complex::Complex;
+
+    const ZERO: Complex<f64> = ConstIdentity::<Additive>::IDENTITY;
+    const ONE: Complex<f64> = ConstIdentity::<Multiplicative>::IDENTITY;
+    static ORIGIN: FreeModule<i32, 3> = ConstIdentity::<Additive>::IDENTITY;
+    static ID: Id = Id::new();
+
+    macro_rules! check {
+        ($($T:ident),* $(,)*) => {
+            $(#[test]
+            fn $T() {
+                assert_eq!(
+                    <$T as ConstIdentity<Additive>>::IDENTITY,
+                    <$T as Identity<Additive>>::identity()
+                );
+                assert_eq!(
+                    <$T as ConstIdentity<Multiplicative>>::IDENTITY,
+                    <$T as Identity<Multiplicative>>::identity()
+                );
+            })+
+        }
+    }
+
+    check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+    #[test]
+    fn const_identities_for_composition() {
+        assert_eq!(
+            <f64 as ConstIdentity<Composition>>::IDENTITY,
+            <f64 as Identity<Composition>>::identity()
+        );
+        assert_eq!(
+            <Id as ConstIdentity<Composition>>::IDENTITY,
+            <Id as Identity<Composition>>::identity()
+        );
+    }
+
+    #[test]
+    fn const_identities_match_identity() {
+        assert_eq!(ZERO, Identity::<Additive>::identity());
+        assert_eq!(ONE, Identity::<Multiplicative>::identity());
+        assert_eq!(ORIGIN, Identity::<Additive>::identity());
+        assert_eq!(ID, Identity::<Multiplicative>::identity());
+    }
+}
+
+mod operate_into_check {
+    use alga::general::{AbstractMagma, AbstractSemigroup, Additive, Multiplicative};
+    use alga::linear::FreeModule;
+    use num_complex::Complex;
+
+    /// A permutation of `0..n`, composed in place.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Perm(Vec<usize>);
+
+    impl AbstractMagma<Multiplicative> for Perm {
+        fn operate(&self, right: &Self) -> Self {
+            Perm(right.0.iter().map(|&i| self.0[i]).collect())
+        }
+
+        fn operate_into(&self, right: &Self, out: &mut Self) {
+            out.0.clear();
+            out.0.extend(right.0.iter().map(|&i| self.0[i]));
+        }
+    }
+
+    impl AbstractSemigroup<Multiplicative> for Perm {}
+
+    #[test]
+    fn operate_into_matches_operate() {
+        let a = Perm(vec![1, 2, 0, 3]);
+        let b = Perm(vec![3, 0, 2, 1]);
+        let mut out = Perm(vec![]);
+        AbstractMagma::<Multiplicative>::operate_into(&a, &b, &mut out);
+        assert_eq!(out, AbstractMagma::<Multiplicative>::operate(&a, &b));
+
+        let mut out = 0i32;
+        AbstractMagma::<Multiplicative>::operate_into(&6i32, &7, &mut out);
+        assert_eq!(out, 42);
+    }
+
+    #[test]
+    fn operate_assign_matches_operate() {
+        let mut x = 6i32;
+        AbstractMagma::<Multiplicative>::operate_assign(&mut x, &7);
+        assert_eq!(x, 42);
+        AbstractMagma::<Additive>::operate_assign(&mut x, &-2);
+        assert_eq!(x, 40);
+
+        let mut z = Complex::new(1.0f64, 2.0);
+        AbstractMagma::<Additive>::operate_assign(&mut z, &Complex::new(0.5, -1.0));
+        assert_eq!(z, Complex::new(1.5, 1.0));
+
+        let mut v = FreeModule([1, 2, 3]);
+        AbstractMagma::<Additive>::operate_assign(&mut v, &FreeModule([3, 2, 1]));
+        assert_eq!(v, FreeModule([4, 4, 4]));
+
+        // The default implementation for types that only override `operate`.
+        let mut p = Perm(vec![0, 1, 2, 3]);
+        for _ in 0..3 {
+            AbstractMagma::<Multiplicative>::operate_assign(&mut p, &Perm(vec![1, 2, 0, 3]));
+        }
+        assert_eq!(p, Perm(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn operate_owned_matches_operate() {
+        let a = Perm(vec![1, 2, 0, 3]);
+        let b = Perm(vec![3, 0, 2, 1]);
+        let ab = AbstractMagma::<Multiplicative>::operate(&a, &b);
+        assert_eq!(AbstractMagma::<Multiplicative>::operate_owned(a, b), ab);
+
+        let u = FreeModule([1, 2, 3]);
+        let v = FreeModule([3, 2, 1]);
+        assert_eq!(u.operate_owned(v), FreeModule([4, 4, 4]));
+
+        let z = Complex::new(1.0f64, 2.0);
+        let w = Complex::new(0.5, -1.0);
+        assert_eq!(
+            AbstractMagma::<Multiplicative>::operate_owned(z, w),
+            AbstractMagma::<Multiplicative>::operate(&z, &w)
+        );
+    }
+
+    #[test]
+    fn perm_is_associative() {
+        let a = Perm(vec![1, 2, 0, 3]);
+        let b = Perm(vec![3, 0, 2, 1]);
+        let c = Perm(vec![0, 3, 1, 2]);
+        assert!(AbstractSemigroup::<Multiplicative>::prop_is_associative((
+            a, b, c
+        )));
+    }
+}
+
+mod try_inverse_check {
+    use alga::general::wrapper::Wrapper;
+    use alga::general::{Additive, Composition, Multiplicative, TwoSidedInverse};
+    use num_complex::Complex;
+
+    type W = Wrapper<f64, Additive, Multiplicative>;
+
+    #[test]
+    fn zero_has_no_multiplicative_inverse() {
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&0.0f64),
+            None
+        );
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&4.0f32),
+            Some(0.25)
+        );
+        assert_eq!(
+            TwoSidedInverse::<Composition>::try_two_sided_inverse(&0.0f32),
+            None
+        );
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Complex::new(0.0f64, 0.0)),
+            None
+        );
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Complex::new(0.0f64, 2.0)),
+            Some(Complex::new(0.0, -0.5))
+        );
+    }
+
+    #[test]
+    fn additive_inverse_always_exists() {
+        assert_eq!(
+            TwoSidedInverse::<Additive>::try_two_sided_inverse(&0.0f64),
+            Some(-0.0)
+        );
+    }
+
+    #[test]
+    fn wrapper_checked_div() {
+        assert_eq!(W::new(3.0).checked_div(W::new(2.0)), Some(W::new(1.5)));
+        assert_eq!(W::new(3.0).checked_div(W::new(0.0)), None);
+        assert_eq!(W::new(3.0) / W::new(2.0), W::new(1.5));
+    }
+
+    #[test]
+    fn wrapper_div_by_zero_is_infinite() {
+        assert_eq!(W::new(1.0) / W::new(0.0), W::new(f64::INFINITY));
+    }
+}
+
+mod partial_inverse_check {
+    use alga::general::{Additive, Multiplicative, PartialInverse};
+
+    macro_rules! check {
+        ($($T:ident),* $(,)*) => {
+            $(mod $T {
+                use alga::general::{Additive, Multiplicative, PartialInverse};
+
+                quickcheck!(
+                    fn prop_additive_partial_inverse_is_inverse(args: ($T,)) -> bool {
+                        PartialInverse::<Additive>::prop_partial_inverse_is_inverse(args)
+                    }
+
+                    fn prop_multiplicative_partial_inverse_is_inverse(args: ($T,)) -> bool {
+                        PartialInverse::<Multiplicative>::prop_partial_inverse_is_inverse(args)
+                    }
+                );
+
+                #[test]
+                fn identity_is_invertible() {
+                    assert!(<$T as PartialInverse<Additive>>::prop_identity_is_invertible());
+                    assert!(<$T as PartialInverse<Multiplicative>>::prop_identity_is_invertible());
+                }
+            })+
+        }
+    }
+
+    check!(u8, u32, u64, i8, i32, i64);
+
+    quickcheck!(
+        fn prop_f64_partial_inverse_is_inverse(args: (f64,)) -> bool {
+            let (a,) = args;
+            // Avoid overflows of the inverse of subnormal numbers.
+            !a.is_normal()
+                || PartialInverse::<Multiplicative>::prop_partial_inverse_is_inverse_approx(args)
+        }
+    );
+
+    #[test]
+    fn invertible_elements() {
+        assert!(PartialInverse::<Multiplicative>::is_invertible(&-1i32));
+        assert!(!PartialInverse::<Multiplicative>::is_invertible(&2i32));
+        assert!(!PartialInverse::<Multiplicative>::is_invertible(&0.0f64));
+        assert_eq!(
+            PartialInverse::<Multiplicative>::partial_inverse(&4.0f64),
+            Some(0.25)
+        );
+        assert_eq!(PartialInverse::<Additive>::partial_inverse(&3u32), None);
+        assert_eq!(PartialInverse::<Additive>::partial_inverse(&3i32), Some(-3));
+    }
+}
+
+mod closed_ops_check {
+    use alga::general::ops::{ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub};
+    use num_complex::Complex;
+
+    fn eval<T: Copy + ClosedAdd + ClosedSub + ClosedMul + ClosedDiv + ClosedNeg>(a: T, b: T) -> T {
+        let mut c = -(a + b) * (a - b);
+        c /= b;
+        c
+    }
+
+    fn is_same_trait<T: alga::general::ClosedAdd>() {}
+
+    fn aliases_are_reexports<T: ClosedAdd>() {
+        is_same_trait::<T>()
+    }
+
+    #[test]
+    fn closed_operators() {
+        assert_eq!(eval(3i32, 1), -8);
+        assert_eq!(eval(3.0f64, 2.0), -2.5);
+        assert_eq!(
+            eval(Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)),
+            Complex::new(2.0, 0.0)
+        );
+        aliases_are_reexports::<u8>();
+    }
+}
+
+mod explicit_tolerance_check {
+    use alga::general::{AbstractGroupAbelian, AbstractQuasigroup, AbstractSemigroup, Additive};
+
+    #[test]
+    fn associativity_with_explicit_tolerances() {
+        // (0.1 + 0.2) + 0.3 and 0.1 + (0.2 + 0.3) differ by one ulp.
+        let args = (0.1f64, 0.2, 0.3);
+
+        assert!(AbstractSemigroup::<Additive>::prop_is_associative_approx(
+            args
+        ));
+        assert!(!AbstractSemigroup::<Additive>::prop_is_associative_approx_with(args, 0.0, 0.0));
+        assert!(AbstractSemigroup::<Additive>::prop_is_associative_approx_with(args, 0.0, 1.0e-15));
+        assert!(AbstractSemigroup::<Additive>::prop_is_associative_approx_with(args, 1.0e-15, 0.0));
+    }
+
+    #[test]
+    fn loose_tolerances_accept_more() {
+        let args = (1.0e20f64, 1.0);
+
+        assert!(
+            !AbstractQuasigroup::<Additive>::prop_inv_is_latin_square_approx_with(
+                (1.0, 1.0e20),
+                0.0,
+                0.0
+            )
+        );
+        assert!(
+            AbstractQuasigroup::<Additive>::prop_inv_is_latin_square_approx_with(
+                (1.0, 1.0e20),
+                2.0,
+                0.0
+            )
+        );
+        assert!(AbstractGroupAbelian::<Additive>::prop_is_commutative_approx_with(args, 0.0, 0.0));
+    }
+}
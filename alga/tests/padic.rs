@@ -0,0 +1,103 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractRing, Identity, Multiplicative, PAdicValuation, Padic, TwoSidedInverse, Valuation,
+};
+
+mod padic_check {
+    macro_rules! check {
+        ($($name:ident: $P:expr),* $(,)*) => {
+            $(mod $name {
+                use alga::general::{
+                    AbstractGroupAbelian, AbstractMonoid, AbstractRing, AbstractRingCommutative,
+                    Additive, Identity, Multiplicative, PAdicValuation, Padic, TwoSidedInverse,
+                    UltrametricValuation, Valuation,
+                };
+
+                type Z = Padic<$P>;
+                type V = PAdicValuation<$P>;
+
+                quickcheck!(
+                    fn prop_is_commutative_ring(args: (u64, u64, u64)) -> bool {
+                        let (a, b, c) = (Z::new(args.0), Z::new(args.1), Z::new(args.2));
+
+                        AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+                            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((a,))
+                            && AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+                            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+                    }
+
+                    fn prop_units_are_invertible(args: (u64,)) -> bool {
+                        let a = Z::new(args.0);
+                        let one: Z = Identity::<Multiplicative>::identity();
+
+                        match TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&a) {
+                            Some(inv) => a.is_unit() && a * inv == one,
+                            None => !a.is_unit(),
+                        }
+                    }
+
+                    fn prop_valuation_is_ultrametric(args: (u64, u64, u8, u8)) -> bool {
+                        // NOTE: the valuations are kept small so that the products are not
+                        // truncated to zero.
+                        let p = |k: u8| (0..k % 8).fold(Z::new(1), |acc, _| acc * Z::new($P));
+                        let (a, b) = (Z::new(args.0) * p(args.2), Z::new(args.1) * p(args.3));
+
+                        V::prop_ultrametric_inequality((a, b))
+                            && (a.order().unwrap_or(0) + b.order().unwrap_or(0) >= Z::precision()
+                                || V::prop_is_multiplicative((a, b)))
+                    }
+                );
+
+                #[test]
+                fn precision_fills_a_u64() {
+                    assert!(Z::modulus().checked_mul($P).is_none());
+                    assert_eq!(Z::characteristic(), Z::modulus());
+                    assert_eq!(V::value(&Z::new(0)), None);
+                }
+            })*
+        }
+    }
+
+    check!(p2: 2, p3: 3, p7: 7, p_large: 4_294_967_311);
+}
+
+#[test]
+fn digits_of_negative_integers() {
+    let minus_one = -Padic::<5>::new(1);
+
+    assert_eq!(Padic::<5>::precision(), 27);
+    assert!(minus_one.digits().all(|d| d == 4));
+    assert_eq!(
+        Padic::<5>::new(38).digits().take(4).collect::<Vec<_>>(),
+        [3, 2, 1, 0]
+    );
+    assert_eq!(Padic::<2>::new(1).digits().count(), 63);
+}
+
+#[test]
+fn inverses_of_units() {
+    let two = Padic::<3>::new(2);
+    let half = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&two);
+
+    // 1/2 = (1 + 3 + 3² + …) in ℤ₃, so its digits are all 1 but the first one, which is 2.
+    assert_eq!(half * two, Identity::<Multiplicative>::identity());
+    assert!(half.digits().skip(1).all(|d| d == 1));
+    assert_eq!(half.digits().next(), Some(2));
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Padic::<3>::new(6)),
+        None
+    );
+}
+
+#[test]
+fn padic_valuation() {
+    assert_eq!(Padic::<2>::new(96).order(), Some(5));
+    assert_eq!(PAdicValuation::<2>::value(&Padic::new(96)), Some(-5));
+    assert_eq!(PAdicValuation::<3>::value(&-Padic::new(9)), Some(-2));
+    assert_eq!(Padic::<7>::new(0).order(), None);
+    assert_eq!(Padic::<7>::new(3).to_string(), "3 + O(7^22)");
+    assert!(<Padic<7> as AbstractRing>::characteristic() > 0);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractModule, Additive, Identity, Pointwise, TwoSidedInverse};
+use alga::linear::{FreeModule, VectorSpace};
+use num_traits::Zero;
+
+type F = Pointwise<f64, f64>;
+type Coeffs = (i8, i8, i8);
+
+// A polynomial function with small integer coefficients, so that the arithmetic is exact.
+fn poly(coeffs: Coeffs) -> F {
+    let (c0, c1, c2) = (coeffs.0 as f64, coeffs.1 as f64, coeffs.2 as f64);
+    Pointwise::new(move |x: f64| c0 + c1 * x + c2 * x * x)
+}
+
+// A linear combination, written for any vector space.
+fn combination<V: VectorSpace>(terms: Vec<(V::Field, V)>) -> V {
+    terms.into_iter().fold(V::zero(), |acc, (c, v)| acc + v * c)
+}
+
+quickcheck!(
+    fn prop_is_vector_space(args: (Coeffs, Coeffs, i8, i8, i8)) -> bool {
+        let (f, g) = (poly(args.0), poly(args.1));
+        let (r, s) = (args.2 as f64, args.3 as f64);
+        let x = args.4 as f64;
+
+        (f.clone() + g.clone()).eval(x) == (g.clone() + f.clone()).eval(x)
+            && (f.clone() - f.clone()).eval(x) == 0.0
+            && (f.clone() + TwoSidedInverse::<Additive>::two_sided_inverse(&f)).eval(x) == 0.0
+            && f.multiply_by(r * s).eval(x) == f.multiply_by(r).multiply_by(s).eval(x)
+            && (f.clone() + g.clone()).multiply_by(r).eval(x)
+                == (f.multiply_by(r) + g.multiply_by(r)).eval(x)
+            && f.multiply_by(r + s).eval(x) == (f.multiply_by(r) + f.multiply_by(s)).eval(x)
+    }
+
+    fn prop_zero_is_identity(args: (Coeffs, i8)) -> bool {
+        let f = poly(args.0);
+        let zero: F = Identity::<Additive>::identity();
+
+        f.clone() + zero.clone() == f
+            && zero.clone() + f.clone() == f
+            && zero.eval(args.1 as f64) == 0.0
+    }
+);
+
+#[test]
+fn linear_combination_of_basis_functions() {
+    let basis: Vec<F> = (0..4)
+        .map(|k| Pointwise::new(move |x: f64| x.powi(k)))
+        .collect();
+    let p = combination(vec![
+        (1.0, basis[0].clone()),
+        (-2.0, basis[1].clone()),
+        (0.5, basis[3].clone()),
+    ]);
+
+    assert_eq!(p.eval(2.0), 1.0 - 4.0 + 4.0);
+    assert_eq!(p.eval(0.0), 1.0);
+}
+
+#[test]
+fn functions_into_vector_spaces() {
+    let curve = Pointwise::new(|t: f64| FreeModule::new([t.cos(), t.sin()]));
+    let scaled = curve.clone() * 2.0 + Pointwise::constant(FreeModule::new([1.0, 0.0]));
+
+    assert_eq!(scaled.eval(0.0), FreeModule::new([3.0, 0.0]));
+}
+
+#[test]
+fn equality_is_identity_of_the_closures() {
+    let f = poly((1, 2, 3));
+    let g = poly((1, 2, 3));
+
+    assert_eq!(f.clone(), f);
+    assert_ne!(f, g);
+    assert!(F::zero().is_zero());
+    assert!(!(f.clone() - f).is_zero());
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "alloc")]
+
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractModule, AbstractMonoid, AbstractRing, AbstractRingCommutative,
+    AbstractSemigroup, Additive, Monomial, Multiplicative, MultivariatePolynomial,
+};
+use num_traits::{One, Zero};
+
+type P = MultivariatePolynomial<i64>;
+
+// A polynomial in x0 and x1 of degree at most two, with small coefficients.
+fn poly(c: (i8, i8, i8, i8, i8, i8)) -> P {
+    let term = |e: Vec<u32>, c: i8| (Monomial::new(e), c as i64);
+
+    MultivariatePolynomial::from_terms(vec![
+        term(vec![], c.0),
+        term(vec![1], c.1),
+        term(vec![0, 1], c.2),
+        term(vec![2], c.3),
+        term(vec![1, 1], c.4),
+        term(vec![0, 2], c.5),
+    ])
+}
+
+fn x(i: usize) -> P {
+    MultivariatePolynomial::variable(i)
+}
+
+type Args = (i8, i8, i8, i8, i8, i8);
+
+quickcheck!(
+    fn prop_is_commutative_ring(args: (Args, Args, Args)) -> bool {
+        let (a, b, c) = (poly(args.0), poly(args.1), poly(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive((a.clone(), b.clone(), c.clone()))
+            && AbstractRingCommutative::prop_mul_is_commutative((a.clone(), b.clone()))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative((
+                a.clone(),
+                b.clone(),
+                c.clone(),
+            ))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative((a.clone(), b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((a,))
+    }
+
+    fn prop_is_module(args: (Args, Args, i8, i8)) -> bool {
+        let (a, b) = (poly(args.0), poly(args.1));
+        let (r, s) = (args.2 as i64, args.3 as i64);
+
+        a.multiply_by(r * s) == a.multiply_by(r).multiply_by(s)
+            && (a.clone() + b.clone()).multiply_by(r) == a.multiply_by(r) + b.multiply_by(r)
+            && a.multiply_by(r + s) == a.multiply_by(r) + a.multiply_by(s)
+    }
+
+    fn prop_evaluation_is_ring_morphism(args: (Args, Args, i8, i8)) -> bool {
+        let (a, b) = (poly(args.0), poly(args.1));
+        let point = [args.2 as i64, args.3 as i64];
+
+        (a.clone() + b.clone()).evaluate(&point) == a.evaluate(&point) + b.evaluate(&point)
+            && (a.clone() * b.clone()).evaluate(&point) == a.evaluate(&point) * b.evaluate(&point)
+            && P::one().evaluate(&point) == 1
+    }
+
+    fn prop_substitution_commutes_with_evaluation(args: (Args, Args, Args, i8, i8)) -> bool {
+        let (a, b, c) = (poly(args.0), poly(args.1), poly(args.2));
+        let point = [args.3 as i64, args.4 as i64];
+        let substituted = a.evaluate(&[b.clone(), c.clone()]);
+
+        substituted.evaluate(&point) == a.evaluate(&[b.evaluate(&point), c.evaluate(&point)])
+    }
+
+    fn prop_total_degree_is_additive(args: (Args, Args)) -> bool {
+        let (a, b) = (poly(args.0), poly(args.1));
+
+        match (a.total_degree(), b.total_degree()) {
+            (Some(d), Some(e)) => (a * b).total_degree() == Some(d + e),
+            _ => (a * b).is_zero(),
+        }
+    }
+
+    fn prop_is_sum_of_homogeneous_components(args: Args) -> bool {
+        let a = poly(args);
+        let components: Vec<P> = (0..3).map(|d| a.homogeneous_component(d)).collect();
+
+        components.iter().all(|c| c.is_homogeneous())
+            && components.into_iter().fold(P::zero(), |acc, c| acc + c) == a
+    }
+);
+
+#[test]
+fn canonical_representation() {
+    let a = x(0) * x(1) + x(1) * x(0) - x(0) * x(1) * 2;
+
+    assert!(a.is_zero());
+    assert_eq!(a, P::zero());
+    assert_eq!(a.total_degree(), None);
+    assert_eq!(Monomial::new(vec![1, 0, 0]), Monomial::variable(0));
+}
+
+#[test]
+fn evaluation_and_substitution() {
+    // p = x0² x1 + 3 x1 - 2
+    let p = x(0) * x(0) * x(1) + x(1) * 3 - P::constant(2);
+
+    assert_eq!(p.to_string(), "-2 + 3*x1 + 1*x0^2*x1");
+    assert_eq!(p.evaluate(&[2i64, 5]), 20 + 15 - 2);
+    assert_eq!(p.coefficient(&Monomial::new(vec![2, 1])), 1);
+    assert_eq!(p.total_degree(), Some(3));
+    assert!(!p.is_homogeneous());
+
+    // Substituting x0 ↦ x0 + x1 and x1 ↦ x0 x1.
+    let q = p.evaluate(&[x(0) + x(1), x(0) * x(1)]);
+    let expected = (x(0) + x(1)) * (x(0) + x(1)) * x(0) * x(1) + x(0) * x(1) * 3 - P::constant(2);
+
+    assert_eq!(q, expected);
+    assert_eq!(q.homogeneous_component(4).total_degree(), Some(4));
+    assert_eq!(q.homogeneous_component(3), P::zero());
+}
+
+#[test]
+#[should_panic]
+fn evaluation_needs_all_variables() {
+    let _ = (x(0) * x(2)).evaluate(&[1i64, 2]);
+}
+
+#[test]
+fn characteristic() {
+    assert_eq!(P::characteristic(), 0);
+}
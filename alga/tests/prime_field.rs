@@ -0,0 +1,129 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractField, AbstractMagma, AbstractRing, Additive, ConstIdentity, FiniteField, FiniteGroup,
+    Identity, MontgomeryFp, Multiplicative, PrimeField, Zn,
+};
+
+mod montgomery_check {
+    macro_rules! check {
+        ($($name:ident: $P:expr),* $(,)*) => {
+            $(mod $name {
+                use alga::general::{
+                    AbstractRing, AbstractRingCommutative, AbstractSemigroup, FiniteField,
+                    MontgomeryFp, Multiplicative, PrimeField, Zn,
+                };
+
+                type F = MontgomeryFp<$P>;
+                type Z = Zn<$P>;
+
+                quickcheck!(
+                    fn prop_agrees_with_zn(args: (u64, u64)) -> bool {
+                        let (a, b) = (F::new(args.0), F::new(args.1));
+                        let (x, y) = (Z::new(args.0), Z::new(args.1));
+                        let same = |a: F, x: Z| a.to_zn::<$P>() == x;
+
+                        same(a, x)
+                            && same(a + b, x + y)
+                            && same(a - b, x - y)
+                            && same(a * b, x * y)
+                            && same(-a, -x)
+                            && a.inverse().map(|i| i.to_zn::<$P>()) == x.inverse()
+                            && a.legendre_symbol() == x.legendre_symbol()
+                    }
+
+                    fn prop_is_prime_field(args: (u64, u64, u64)) -> bool {
+                        let (a, b, c) = (F::new(args.0), F::new(args.1), F::new(args.2));
+
+                        PrimeField::prop_canonical_is_isomorphism((a, b))
+                            && PrimeField::prop_inverse_is_inverse((a,))
+                            && AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+                            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+                            && AbstractSemigroup::<Multiplicative>::prop_is_associative((a, b, c))
+                    }
+
+                    fn prop_zn_is_prime_field(args: (u64, u64)) -> bool {
+                        let (a, b) = (Z::new(args.0), Z::new(args.1));
+
+                        PrimeField::prop_canonical_is_isomorphism((a, b))
+                            && PrimeField::prop_inverse_is_inverse((a,))
+                    }
+
+                    fn prop_sqrt_is_square_root(args: (u64,)) -> bool {
+                        FiniteField::prop_sqrt_is_square_root((F::new(args.0),))
+                    }
+                );
+            })*
+        }
+    }
+
+    check!(
+        f3: 3,
+        f7: 7,
+        f1000003: 1_000_003,
+        f998244353: 998_244_353,
+        mersenne61: 2_305_843_009_213_693_951,
+        largest: 9_223_372_036_854_775_783,
+    );
+}
+
+#[test]
+fn montgomery_field() {
+    type F = MontgomeryFp<101>;
+
+    assert_eq!(<F as PrimeField>::modulus(), 101);
+    assert_eq!(<F as AbstractRing>::characteristic(), 101);
+    assert_eq!(<F as FiniteField>::degree(), 1);
+    assert_eq!(<F as FiniteGroup<Additive>>::elements().count(), 101);
+    assert_eq!(F::new(205).value(), 3);
+    assert_eq!(F::new(205), F::from_canonical(3));
+    assert_eq!(
+        <F as ConstIdentity<Multiplicative>>::IDENTITY,
+        Identity::<Multiplicative>::identity()
+    );
+    assert_eq!(F::new(1), Identity::<Multiplicative>::identity());
+    assert_eq!(F::new(3).inverse(), Some(F::new(34)));
+    assert_eq!(F::new(0).inverse(), None);
+    assert_eq!(format!("{:?}", F::new(7)), "MontgomeryFp(7)");
+    assert_eq!(format!("{}", F::new(7)), "7 mod 101");
+}
+
+#[test]
+fn montgomery_reduces_values_above_the_modulus() {
+    type F = MontgomeryFp<101>;
+    type L = MontgomeryFp<9_223_372_036_854_775_783>;
+
+    assert_eq!(F::new(101), F::new(0));
+    assert_eq!(F::from_canonical(102).value(), 1);
+    assert_eq!(F::new(u64::MAX).value(), u64::MAX % 101);
+    assert_eq!(L::new(9_223_372_036_854_775_783).value(), 0);
+    assert_eq!(
+        L::new(u64::MAX).value(),
+        u64::MAX % 9_223_372_036_854_775_783
+    );
+    assert_eq!(
+        L::new(u64::MAX).to_zn::<9_223_372_036_854_775_783>(),
+        Zn::new(u64::MAX)
+    );
+}
+
+#[test]
+fn generic_code_runs_on_both_representations() {
+    // The sum of the inverses of 1, ..., n.
+    fn harmonic<F: PrimeField>(n: u64) -> F {
+        (1..=n).fold(Identity::<Additive>::identity(), |acc: F, k| {
+            let inv = F::from_canonical(k).inverse().unwrap();
+            AbstractMagma::<Additive>::operate(&acc, &inv)
+        })
+    }
+
+    fn is_field<F: AbstractField>() {}
+    is_field::<MontgomeryFp<1_000_003>>();
+
+    assert_eq!(
+        harmonic::<MontgomeryFp<1_000_003>>(1000).to_canonical(),
+        harmonic::<Zn<1_000_003>>(1000).to_canonical()
+    );
+}
@@ -0,0 +1,146 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use num_complex::Complex;
+
+use alga::general::{
+    convert, try_convert, AbstractGroup, AbstractGroupAbelian, AbstractModule, AbstractMonoid,
+    AbstractRing, AbstractSemigroup, Additive, Multiplicative, NormedDivisionAlgebra, Quaternion,
+    TwoSidedInverse,
+};
+use alga::linear::{FreeModule, Transformation};
+
+type Q = Quaternion<f64>;
+type Args = (i8, i8, i8, i8);
+
+// Small integers keep the arithmetic exact.
+fn quat(args: Args) -> Q {
+    Quaternion::new(args.0 as f64, args.1 as f64, args.2 as f64, args.3 as f64)
+}
+
+quickcheck!(
+    fn prop_is_ring(args: (Args, Args, Args)) -> bool {
+        let (a, b, c) = (quat(args.0), quat(args.1), quat(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive_approx((a, b, c))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative_approx((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative_approx((a, b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop_approx(
+                (a,),
+            )
+    }
+
+    fn prop_is_module(args: (Args, Args, i8, i8)) -> bool {
+        let (a, b) = (quat(args.0), quat(args.1));
+        let (r, s) = (args.2 as f64, args.3 as f64);
+
+        a.multiply_by(r * s) == a.multiply_by(r).multiply_by(s)
+            && (a + b).multiply_by(r) == a.multiply_by(r) + b.multiply_by(r)
+            && a.multiply_by(r + s) == a.multiply_by(r) + a.multiply_by(s)
+    }
+
+    fn prop_norm_is_multiplicative(args: (Args, Args)) -> bool {
+        let (a, b) = (quat(args.0), quat(args.1));
+
+        NormedDivisionAlgebra::prop_norm_is_multiplicative_approx((a, b))
+            && NormedDivisionAlgebra::prop_conjugate_product_is_norm_squared_approx((a,))
+    }
+
+    fn prop_inverse_is_two_sided(args: (i8, i8, i8, i8)) -> bool {
+        let a = quat(args);
+
+        if a == Quaternion::new(0.0, 0.0, 0.0, 0.0) {
+            return TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&a).is_none();
+        }
+
+        let inv = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&a);
+        let one: Q = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+
+        relative_eq!(a * inv, one, epsilon = 1.0e-12)
+            && relative_eq!(inv * a, one, epsilon = 1.0e-12)
+            && relative_eq!(a / a, one, epsilon = 1.0e-12)
+    }
+
+    fn prop_reals_and_complexes_are_division_algebras(args: (i8, i8, i8, i8)) -> bool {
+        let (a, b) = (args.0 as f64, args.1 as f64);
+        let (z, w) = (
+            Complex::new(args.0 as f64, args.1 as f64),
+            Complex::new(args.2 as f64, args.3 as f64),
+        );
+
+        NormedDivisionAlgebra::prop_norm_is_multiplicative_approx((a, b))
+            && NormedDivisionAlgebra::prop_conjugate_product_is_norm_squared_approx((a,))
+            && NormedDivisionAlgebra::prop_norm_is_multiplicative_approx((z, w))
+            && z * z.conjugate() == Complex::new(z.norm_squared(), 0.0)
+    }
+);
+
+#[test]
+fn hamilton_product() {
+    let i = Quaternion::from_imag([1.0, 0.0, 0.0]);
+    let j = Quaternion::from_imag([0.0, 1.0, 0.0]);
+    let k = Quaternion::from_imag([0.0, 0.0, 1.0]);
+    let minus_one = Quaternion::from_real(-1.0);
+
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+    assert_eq!(j * k, i);
+    assert_eq!(k * i, j);
+    assert_eq!(i * i, minus_one);
+    assert_eq!(i * j * k, minus_one);
+}
+
+#[test]
+fn quaternions_form_a_non_abelian_group() {
+    fn is_group<T: AbstractGroup<Multiplicative>>() {}
+    is_group::<Q>();
+}
+
+#[test]
+fn norm() {
+    let q = Quaternion::new(1.0, 2.0, 2.0, 4.0);
+
+    assert_eq!(q.norm_squared(), 25.0);
+    assert_eq!(q.norm(), 5.0);
+    assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, -2.0, -4.0));
+    assert_eq!(NormedDivisionAlgebra::norm(&-3.0f64), 3.0);
+    assert_eq!(NormedDivisionAlgebra::norm(&Complex::new(3.0f64, 4.0)), 5.0);
+}
+
+#[test]
+fn unit_quaternions_are_rotations() {
+    let quarter_turn = ::std::f64::consts::FRAC_PI_2;
+    let q: Q = Quaternion::from_axis_angle([0.0, 0.0, 1.0], quarter_turn);
+    let v = FreeModule::new([1.0, 0.0, 0.0]);
+    let rotated = q.transform_vector(&v);
+
+    assert_relative_eq!(q.norm(), 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(rotated, FreeModule::new([0.0, 1.0, 0.0]), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        FreeModule::new(q.rotate_vector([0.0, 0.0, 2.0])),
+        FreeModule::new([0.0, 0.0, 2.0]),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn subsets() {
+    let q: Q = convert(2.0f64);
+    assert_eq!(q, Quaternion::from_real(2.0));
+
+    let q: Q = convert(Complex::new(1.0f64, 2.0));
+    assert_eq!(q, Quaternion::new(1.0, 2.0, 0.0, 0.0));
+    assert_eq!(
+        try_convert::<Q, Complex<f64>>(q),
+        Some(Complex::new(1.0, 2.0))
+    );
+    assert_eq!(
+        try_convert::<Q, Complex<f64>>(Quaternion::new(1.0, 2.0, 3.0, 0.0)),
+        None
+    );
+    assert_eq!(try_convert::<Q, f64>(q), None);
+}
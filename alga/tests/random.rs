@@ -0,0 +1,47 @@
+#![cfg(feature = "rand")]
+
+extern crate alga;
+extern crate num_complex;
+extern crate rand;
+
+use alga::general::{Additive, Bits, Id, RandomElement, RandomElements, UnitModulus, Zn, ZnUnit};
+use num_complex::Complex;
+use rand::rngs::mock::StepRng;
+use rand::Rng;
+
+#[test]
+fn unit_modulus_complex_numbers() {
+    let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..100 {
+        let z: Complex<f64> = rng.sample(UnitModulus);
+        assert!((z.norm_sqr().sqrt() - 1.0).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn random_lattice_elements() {
+    let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+    let mut seen = Bits(0u8);
+
+    for _ in 0..100 {
+        let b: Bits<u8> = rng.sample(RandomElements);
+        seen.0 |= b.0;
+    }
+
+    // Every bit is set at least once.
+    assert_eq!(seen, Bits(!0));
+    assert!(Id::<Additive>::random_element(&mut rng) == Id::new());
+}
+
+#[test]
+fn random_residues() {
+    let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..100 {
+        let a: Zn<10> = rng.sample(RandomElements);
+        let u: ZnUnit<10> = rng.sample(RandomElements);
+        assert!(a.value() < 10);
+        assert!(u.residue().is_unit());
+    }
+}
@@ -0,0 +1,95 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{ArchimedeanField, RealField, RoundingMode};
+
+#[test]
+fn quantize_ties_to_even() {
+    assert_eq!(0.5f64.quantize(1.0, RoundingMode::NearestTiesEven), 0.0);
+    assert_eq!(1.5f64.quantize(1.0, RoundingMode::NearestTiesEven), 2.0);
+    assert_eq!(2.5f64.quantize(1.0, RoundingMode::NearestTiesEven), 2.0);
+    assert_eq!((-2.5f64).quantize(1.0, RoundingMode::NearestTiesEven), -2.0);
+    assert_eq!(0.75f32.quantize(0.5, RoundingMode::NearestTiesEven), 1.0);
+}
+
+#[test]
+fn quantize_directed_modes() {
+    assert_eq!(2.5f64.quantize(1.0, RoundingMode::NearestTiesAway), 3.0);
+    assert_eq!((-1.7f64).quantize(1.0, RoundingMode::TowardZero), -1.0);
+    assert_eq!((-1.2f64).quantize(1.0, RoundingMode::Floor), -2.0);
+    assert_eq!(1.2f64.quantize(1.0, RoundingMode::Ceil), 2.0);
+}
+
+#[test]
+fn quantize_keeps_grid_points() {
+    let step = 0.1f64;
+    for k in -100..100 {
+        let x = k as f64 * step;
+        assert_eq!(x.quantize(step, RoundingMode::Floor), x);
+        assert_eq!(x.quantize(step, RoundingMode::NearestTiesEven), x);
+    }
+}
+
+#[test]
+fn snap_to_grid_uses_origin() {
+    assert_eq!(1.26f64.snap_to_grid(0.25, 0.5), 1.25);
+    assert_eq!(1.0f64.snap_to_grid(0.5, 1.0), 0.5);
+    assert_eq!(0.75f64.snap_to_grid(0.75, 2.0), 0.75);
+}
+
+#[test]
+fn machine_limits() {
+    // A tolerance picked generically, without hard-coding the precision of `N`.
+    fn tolerance<N: RealField>() -> N {
+        N::epsilon() * N::from_subset(&64.0)
+    }
+
+    assert_eq!(<f64 as RealField>::epsilon(), f64::EPSILON);
+    assert_eq!(<f32 as RealField>::epsilon(), f32::EPSILON);
+    assert_eq!(f64::min_positive_value(), f64::MIN_POSITIVE);
+    assert_eq!(f32::mantissa_digits(), 24);
+    assert_eq!(f64::mantissa_digits(), 53);
+    assert!(1.0f32 + <f32 as RealField>::epsilon() > 1.0);
+    assert!(tolerance::<f32>() > tolerance::<f64>() as f32);
+}
+
+#[test]
+fn classify_floats() {
+    use std::num::FpCategory;
+
+    fn classify<N: RealField>(x: N) -> FpCategory {
+        RealField::classify(&x)
+    }
+
+    assert_eq!(classify(1.0f64), FpCategory::Normal);
+    assert_eq!(classify(-0.0f32), FpCategory::Zero);
+    assert_eq!(classify(f64::MIN_POSITIVE / 2.0), FpCategory::Subnormal);
+    assert_eq!(classify(f32::NEG_INFINITY), FpCategory::Infinite);
+    assert_eq!(classify(f64::NAN), FpCategory::Nan);
+}
+
+quickcheck! {
+    fn prop_floor_is_integer_part(args: (f64, f32)) -> bool {
+        ArchimedeanField::prop_floor_is_integer_part((args.0,))
+            && ArchimedeanField::prop_floor_is_integer_part((args.1,))
+            && ArchimedeanField::prop_floor_is_integer_part((args.0 * 1.0e-3,))
+    }
+
+    fn prop_floor_of_integer_is_identity(args: (i64,)) -> bool {
+        <f64 as ArchimedeanField>::prop_floor_of_integer_is_identity(args)
+            && <f32 as ArchimedeanField>::prop_floor_of_integer_is_identity(args)
+    }
+}
+
+#[test]
+fn integer_parts() {
+    assert_eq!(2.5f64.floor_to_integer(), Some(2));
+    assert_eq!((-2.5f64).floor_to_integer(), Some(-3));
+    assert_eq!((-0.0f32).floor_to_integer(), Some(0));
+    assert_eq!(f64::from_integer(-7), -7.0);
+    assert_eq!((i64::MIN as f64).floor_to_integer(), Some(i64::MIN));
+    assert_eq!((-(i64::MIN as f64)).floor_to_integer(), None);
+    assert_eq!(f64::NAN.floor_to_integer(), None);
+    assert_eq!(f32::NEG_INFINITY.floor_to_integer(), None);
+}
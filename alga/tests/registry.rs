@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate alga;
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use alga::general::{
+    has_structure, structures_of, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Identity, Multiplicative, OperatorId,
+    StructureId, Structures, TwoSidedInverse,
+};
+
+use OperatorId::{Additive, Multiplicative as Mul};
+
+// The signs `±1`, under multiplication.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sign(bool);
+
+impl AbstractMagma<Multiplicative> for Sign {
+    fn operate(&self, right: &Self) -> Self {
+        Sign(self.0 == right.0)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Sign {
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl Identity<Multiplicative> for Sign {
+    fn identity() -> Self {
+        Sign(true)
+    }
+}
+
+impl AbstractSemigroup<Multiplicative> for Sign {}
+impl AbstractQuasigroup<Multiplicative> for Sign {}
+impl AbstractMonoid<Multiplicative> for Sign {}
+impl AbstractLoop<Multiplicative> for Sign {}
+impl AbstractGroup<Multiplicative> for Sign {}
+impl AbstractGroupAbelian<Multiplicative> for Sign {}
+
+register_structures!(Sign => GroupAbelian(Multiplicative));
+
+// What an interpreter could record for each type of value it embeds.
+fn registry() -> HashMap<TypeId, &'static [StructureId]> {
+    fn entry<T: Structures + Any>() -> (TypeId, &'static [StructureId]) {
+        (TypeId::of::<T>(), structures_of::<T>())
+    }
+
+    vec![entry::<i64>(), entry::<f64>(), entry::<Sign>()]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn builtin_structures() {
+    assert!(has_structure::<i32>(StructureId::EuclideanDomain));
+    assert!(has_structure::<i32>(StructureId::Monoid(Mul)));
+    assert!(!has_structure::<i32>(StructureId::Field(Additive, Mul)));
+    assert!(has_structure::<f64>(StructureId::Field(Additive, Mul)));
+    assert!(has_structure::<f64>(StructureId::GroupAbelian(Mul)));
+    assert!(has_structure::<bool>(StructureId::Lattice));
+    assert!(!has_structure::<u8>(StructureId::Magma(Additive)));
+}
+
+#[test]
+fn registered_structures() {
+    assert_eq!(structures_of::<Sign>(), &[StructureId::GroupAbelian(Mul)]);
+    assert!(has_structure::<Sign>(StructureId::Loop(Mul)));
+    assert!(!has_structure::<Sign>(StructureId::Group(Additive)));
+}
+
+#[test]
+fn dynamic_queries() {
+    let registry = registry();
+    let values: Vec<Box<dyn Any>> = vec![Box::new(3i64), Box::new(0.5f64), Box::new(Sign(false))];
+
+    let fields: Vec<bool> = values
+        .iter()
+        .map(|v| {
+            registry[&(**v).type_id()]
+                .iter()
+                .any(|s| s.implies(StructureId::Field(Additive, Mul)))
+        })
+        .collect();
+
+    assert_eq!(fields, [false, true, false]);
+}
+
+#[test]
+fn implications() {
+    let field = StructureId::Field(Additive, Mul);
+
+    assert!(field.implies(StructureId::Ring(Additive, Mul)));
+    assert!(field.implies(StructureId::Quasigroup(Mul)));
+    assert!(!field.implies(StructureId::EuclideanDomain));
+    assert!(StructureId::HeytingAlgebra.implies(StructureId::DistributiveLattice));
+    assert!(!StructureId::Lattice.implies(StructureId::BoundedLattice));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "rug")]
+
+extern crate alga;
+extern crate num_complex;
+extern crate rug;
+
+use alga::general::{
+    convert, try_convert, AbstractField, AbstractMagma, Additive, Identity, Lattice,
+    MeetSemilattice, Multiplicative, TwoSidedInverse,
+};
+use rug::{Complex, Float};
+
+fn is_field<T: AbstractField>() {}
+fn is_lattice<T: Lattice>() {}
+
+#[test]
+fn rug_structures() {
+    is_field::<Float>();
+    is_field::<Complex>();
+    is_lattice::<Float>();
+}
+
+#[test]
+fn identities_are_neutral_at_any_precision() {
+    let third = Float::with_val(200, 1) / 3u32;
+    let zero: Float = Identity::<Additive>::identity();
+    let one: Float = Identity::<Multiplicative>::identity();
+
+    let sum = AbstractMagma::<Additive>::operate(&zero, &third);
+    let product = AbstractMagma::<Multiplicative>::operate(&one, &third);
+    assert_eq!(sum.prec(), 200);
+    assert_eq!(sum, third);
+    assert_eq!(product, third);
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::operate(&third, &one),
+        third
+    );
+}
+
+#[test]
+fn inverses() {
+    let two = Float::with_val(64, 2);
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse(&two),
+        0.5
+    );
+    assert_eq!(TwoSidedInverse::<Additive>::two_sided_inverse(&two), -2);
+    assert!(TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Float::new(64)).is_none());
+
+    let i = Complex::with_val(64, (0, 1));
+    let inv_i = TwoSidedInverse::<Multiplicative>::two_sided_inverse(&i);
+    assert_eq!(inv_i, Complex::with_val(64, (0, -1)));
+}
+
+#[test]
+fn lattice() {
+    let a = Float::with_val(64, 1.5);
+    let b = Float::with_val(64, -3);
+    assert_eq!(a.meet(&b), b);
+}
+
+#[test]
+fn conversions() {
+    let x: Float = convert(0.1f64);
+    assert_eq!(x.prec(), 53);
+    assert_eq!(try_convert::<_, f64>(x), Some(0.1));
+    assert_eq!(try_convert::<_, f64>(Float::with_val(200, 1) / 3u32), None);
+    assert_eq!(try_convert::<_, f32>(convert::<_, Float>(0.1f64)), None);
+
+    let z: Complex = convert(num_complex::Complex::new(1.0f64, -2.0));
+    assert_eq!(z, Complex::with_val(53, (1, -2)));
+    assert_eq!(
+        try_convert::<_, num_complex::Complex<f64>>(z.clone()),
+        Some(num_complex::Complex::new(1.0, -2.0))
+    );
+    assert_eq!(try_convert::<_, Float>(z), None);
+    assert_eq!(
+        try_convert::<_, Float>(Complex::with_val(53, (4, 0))),
+        Some(Float::with_val(53, 4))
+    );
+}
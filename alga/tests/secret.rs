@@ -0,0 +1,79 @@
+#![cfg(feature = "zeroize")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+extern crate zeroize;
+
+use alga::general::wrapper::Wrapper;
+use alga::general::{
+    AbstractField, AbstractMagma, AbstractRing, AbstractRingCommutative, Additive, Identity,
+    MontgomeryFp, Multiplicative, SecretElement, TwoSidedInverse, Zn,
+};
+use zeroize::Zeroize;
+
+type F = MontgomeryFp<1_000_003>;
+type S = SecretElement<F>;
+
+// A dot product, written for any field.
+fn dot<T: AbstractField>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(Identity::<Additive>::identity(), |acc: T, (x, y)| {
+            let xy = AbstractMagma::<Multiplicative>::operate(x, y);
+            AbstractMagma::<Additive>::operate(&acc, &xy)
+        })
+}
+
+quickcheck!(
+    fn prop_secrets_are_a_field(args: (u64, u64, u64)) -> bool {
+        let s = |v| S::new(F::new(v));
+        let (a, b, c) = (s(args.0), s(args.1), s(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive((a.clone(), b.clone(), c))
+            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+    }
+
+    fn prop_secrets_compute_like_their_values(args: (u64, u64, u64, u64)) -> bool {
+        let values = [F::new(args.0), F::new(args.1)];
+        let others = [F::new(args.2), F::new(args.3)];
+        let secrets: Vec<S> = values.iter().cloned().map(S::new).collect();
+        let other_secrets: Vec<S> = others.iter().cloned().map(S::new).collect();
+
+        dot(&secrets, &other_secrets).expose_secret() == &dot(&values, &others)
+    }
+);
+
+#[test]
+fn secrets_are_not_printed() {
+    let secret = S::new(F::new(123_456));
+
+    assert_eq!(format!("{:?}", secret), "SecretElement(<redacted>)");
+    assert_eq!(format!("{}", secret), "<redacted>");
+    assert_eq!(secret.expose_secret(), &F::new(123_456));
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse(&secret)
+            .expose_secret()
+            .value(),
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse(&F::new(123_456)).value()
+    );
+}
+
+#[test]
+fn elements_are_zeroized() {
+    let mut a = Zn::<101>::new(42);
+    a.zeroize();
+    assert_eq!(a, Zn::new(0));
+
+    let mut b = F::new(42);
+    b.zeroize();
+    assert_eq!(b, F::new(0));
+
+    let mut w = Wrapper::<Zn<101>, Additive, Multiplicative>::new(Zn::new(7));
+    w.zeroize();
+    assert_eq!(w.val, Zn::new(0));
+
+    let mut s = S::new(F::new(42));
+    s.zeroize();
+    assert_eq!(s.expose_secret(), &F::new(0));
+}
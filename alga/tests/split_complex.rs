@@ -0,0 +1,124 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    convert, try_convert, AbstractGroupAbelian, AbstractModule, AbstractMonoid, AbstractRing,
+    AbstractRingCommutative, AbstractSemigroup, Additive, Multiplicative, PartialInverse,
+    RingCommutative, SplitComplex,
+};
+use num_traits::One;
+
+type S = SplitComplex<f64>;
+
+// Small integers keep the arithmetic exact.
+fn split(args: (i8, i8)) -> S {
+    SplitComplex::new(args.0 as f64, args.1 as f64)
+}
+
+quickcheck!(
+    fn prop_is_commutative_ring(args: ((i8, i8), (i8, i8), (i8, i8))) -> bool {
+        let (a, b, c) = (split(args.0), split(args.1), split(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive_approx((a, b, c))
+            && AbstractRingCommutative::prop_mul_is_commutative_approx((a, b))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative_approx((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative_approx((a, b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop_approx(
+                (a,),
+            )
+    }
+
+    fn prop_partial_inverse_is_inverse(args: (i8, i8)) -> bool {
+        let a = split(args);
+
+        match PartialInverse::<Multiplicative>::partial_inverse(&a) {
+            Some(inv) => relative_eq!(a * inv, S::one(), epsilon = 1.0e-12),
+            None => a.modulus_squared() == 0.0,
+        }
+    }
+
+    fn prop_modulus_squared_is_multiplicative(args: ((i8, i8), (i8, i8))) -> bool {
+        let (a, b) = (split(args.0), split(args.1));
+        (a * b).modulus_squared() == a.modulus_squared() * b.modulus_squared()
+    }
+
+    fn prop_exp_is_homomorphism(args: ((i8, i8), (i8, i8))) -> bool {
+        let a = split(args.0) * 0.01;
+        let b = split(args.1) * 0.01;
+        relative_eq!(
+            (a + b).exp(),
+            a.exp() * b.exp(),
+            epsilon = 1.0e-12,
+            max_relative = 1.0e-12
+        )
+    }
+
+    fn prop_ln_is_inverse_of_exp(args: (i8, i8)) -> bool {
+        let a = split(args) * 0.01;
+        relative_eq!(a.exp().ln().unwrap(), a, epsilon = 1.0e-12)
+    }
+);
+
+#[test]
+fn split_complex_arithmetic() {
+    let j = S::j();
+    assert_eq!(j * j, SplitComplex::new(1.0, 0.0));
+    assert_eq!(
+        SplitComplex::new(3.0, 2.0).conjugate(),
+        SplitComplex::new(3.0, -2.0)
+    );
+    assert_eq!(SplitComplex::new(3.0, 2.0).modulus_squared(), 5.0);
+    assert_eq!(SplitComplex::new(2.0, 3.0).modulus_squared(), -5.0);
+    assert_eq!(SplitComplex::new(3.0, 4.0).modulus(), 7.0f64.sqrt());
+    assert_eq!(SplitComplex::new(1.5, -2.0).to_string(), "1.5 + -2j");
+
+    // The diagonals are zero divisors.
+    let (a, b) = (SplitComplex::new(1.0, 1.0), SplitComplex::new(1.0, -1.0));
+    assert_eq!(a * b, SplitComplex::new(0.0, 0.0));
+    assert_eq!(PartialInverse::<Multiplicative>::partial_inverse(&a), None);
+    assert_eq!(
+        PartialInverse::<Multiplicative>::partial_inverse(&SplitComplex::new(2.0, 1.0)),
+        Some(SplitComplex::new(2.0 / 3.0, -1.0 / 3.0))
+    );
+
+    assert_eq!(a.multiply_by(2.0), SplitComplex::new(2.0, 2.0));
+}
+
+#[test]
+fn hyperbolic_functions() {
+    let z = S::from_hyperbolic(2.0, 0.5);
+    let (r, phi) = z.to_hyperbolic().unwrap();
+    assert_relative_eq!(r, 2.0, epsilon = 1.0e-12);
+    assert_relative_eq!(phi, 0.5, epsilon = 1.0e-12);
+    assert_eq!(SplitComplex::new(1.0, 2.0).to_hyperbolic(), None);
+    assert_eq!(SplitComplex::new(-2.0, 1.0).ln(), None);
+
+    let w = SplitComplex::new(0.3, -0.7);
+    let exp_w = w.exp();
+    let exp_minus_w = (-w).exp();
+    assert_relative_eq!(w.cosh(), (exp_w + exp_minus_w) * 0.5, epsilon = 1.0e-12);
+    assert_relative_eq!(w.sinh(), (exp_w - exp_minus_w) * 0.5, epsilon = 1.0e-12);
+}
+
+fn is_ring<T: RingCommutative>() {}
+
+#[test]
+fn split_complex_structures_and_conversions() {
+    is_ring::<S>();
+
+    let z: SplitComplex<f64> = convert(2.0f64);
+    assert_eq!(z, SplitComplex::new(2.0, 0.0));
+    assert_eq!(try_convert::<_, f64>(z), Some(2.0));
+    assert_eq!(try_convert::<_, f64>(S::j()), None);
+
+    let w: SplitComplex<f64> = convert(SplitComplex::new(1.5f32, -2.0));
+    assert_eq!(w, SplitComplex::new(1.5, -2.0));
+    assert_eq!(
+        try_convert::<_, SplitComplex<f32>>(w),
+        Some(SplitComplex::new(1.5, -2.0))
+    );
+}
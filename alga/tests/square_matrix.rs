@@ -0,0 +1,98 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractMagma, AbstractModule, AbstractMonoid, AbstractRing,
+    AbstractSemigroup, Additive, Identity, Multiplicative, PartialInverse, MatrixRing,
+};
+
+type M3 = [[i64; 3]; 3];
+
+type Rows = ((i8, i8, i8), (i8, i8, i8), (i8, i8, i8));
+
+fn mat(args: Rows) -> M3 {
+    let row = |r: (i8, i8, i8)| [r.0 as i64, r.1 as i64, r.2 as i64];
+    [row(args.0), row(args.1), row(args.2)]
+}
+
+fn mul<M: AbstractMagma<Multiplicative>>(a: &M, b: &M) -> M {
+    a.operate(b)
+}
+
+// The product of unit lower and upper triangular matrices. All its pivots are ones, so its
+// elimination is exact.
+fn unit_lu(args: ((i8, i8, i8), (i8, i8, i8))) -> [[f64; 3]; 3] {
+    let ((l0, l1, l2), (u0, u1, u2)) = args;
+    let l = [[1, 0, 0], [l0 as i64, 1, 0], [l1 as i64, l2 as i64, 1]];
+    let u = [[1, u0 as i64, u1 as i64], [0, 1, u2 as i64], [0, 0, 1]];
+
+    mul(&l, &u).map(|row| row.map(|x| x as f64))
+}
+
+quickcheck!(
+    fn prop_is_ring(args: (Rows, Rows, Rows)) -> bool {
+        let (a, b, c) = (mat(args.0), mat(args.1), mat(args.2));
+
+        AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((a,))
+    }
+
+    fn prop_transpose_reverses_products(args: (Rows, Rows)) -> bool {
+        let (a, b) = (mat(args.0), mat(args.1));
+
+        mul(&a, &b).transpose() == mul(&b.transpose(), &a.transpose())
+            && a.transpose().transpose() == a
+            && a.transpose().trace() == a.trace()
+    }
+
+    fn prop_trace_is_cyclic(args: (Rows, Rows, i8)) -> bool {
+        let (a, b) = (mat(args.0), mat(args.1));
+        let r = args.2 as i64;
+
+        mul(&a, &b).trace() == mul(&b, &a).trace() && a.multiply_by(r).trace() == a.trace() * r
+    }
+
+    fn prop_partial_inverse_is_inverse(args: ((i8, i8, i8), (i8, i8, i8))) -> bool {
+        let a = unit_lu(args);
+        let one: [[f64; 3]; 3] = Identity::<Multiplicative>::identity();
+
+        match PartialInverse::<Multiplicative>::partial_inverse(&a) {
+            Some(inv) => mul(&a, &inv) == one && mul(&inv, &a) == one,
+            None => false,
+        }
+    }
+
+    fn prop_matrices_with_equal_rows_are_singular(args: ((i8, i8, i8), (i8, i8, i8))) -> bool {
+        let mut a = unit_lu(args);
+        a[2] = a[1];
+
+        PartialInverse::<Multiplicative>::partial_inverse(&a).is_none()
+    }
+);
+
+#[test]
+fn matrices_do_not_commute() {
+    let a = [[0, 1], [0, 0]];
+    let b = [[0, 0], [1, 0]];
+
+    assert_eq!(mul(&a, &b), [[1, 0], [0, 0]]);
+    assert_eq!(mul(&b, &a), [[0, 0], [0, 1]]);
+}
+
+#[test]
+fn inverse() {
+    let a = [[0.0, 2.0], [4.0, 0.0]];
+
+    assert_eq!(
+        PartialInverse::<Multiplicative>::partial_inverse(&a),
+        Some([[0.0, 0.25], [0.5, 0.0]])
+    );
+    assert_eq!(
+        PartialInverse::<Multiplicative>::partial_inverse(&[[1.0, 2.0], [2.0, 4.0]]),
+        None
+    );
+    assert!(<[[f64; 2]; 2] as PartialInverse<Multiplicative>>::prop_identity_is_invertible());
+}
@@ -0,0 +1,162 @@
+#[macro_use]
+extern crate alga;
+extern crate num_complex;
+
+use alga::general::{
+    is_convertible, try_convert, Additive, ExactSubsetOf, Id, LossySubsetOf, Multiplicative,
+    RealField, SubsetOf, SupersetOf,
+};
+use num_complex::Complex;
+
+fn promote<N: RealField + SubsetOf<Complex<N>>>(x: N) -> Complex<N> {
+    x.to_superset()
+}
+
+#[test]
+fn real_embeds_into_complex() {
+    assert_eq!(promote(2.0f64), Complex::new(2.0, 0.0));
+    assert_eq!(
+        SubsetOf::<Complex<f64>>::to_superset(&1.5f32),
+        Complex::new(1.5f64, 0.0)
+    );
+    assert_eq!(Complex::new(3.0f64, 0.0).to_subset(), Some(3.0f32));
+    assert_eq!(
+        SupersetOf::<f64>::to_subset(&Complex::new(3.0f64, 1.0)),
+        None
+    );
+}
+
+#[test]
+fn complex_f32_embeds_into_complex_f64() {
+    let c = Complex::new(1.0f32, -2.0);
+    let d: Complex<f64> = c.to_superset();
+    assert_eq!(d, Complex::new(1.0, -2.0));
+    assert_eq!(d.to_subset(), Some(c));
+}
+
+#[test]
+fn int_from_float_checks_range() {
+    assert_eq!(SupersetOf::<u8>::to_subset(&255.0f32), Some(255u8));
+    assert_eq!(SupersetOf::<u8>::to_subset(&256.0f32), None);
+    assert_eq!(SupersetOf::<u8>::to_subset(&-1.0f64), None);
+    assert_eq!(
+        SupersetOf::<i32>::to_subset(&-2147483648.0f64),
+        Some(i32::MIN)
+    );
+    assert_eq!(SupersetOf::<i32>::to_subset(&2147483648.0f64), None);
+    assert_eq!(
+        SupersetOf::<u64>::to_subset(&18446744073709551616.0f32),
+        None
+    );
+    assert_eq!(SupersetOf::<i64>::to_subset(&f64::NAN), None);
+    assert_eq!(SupersetOf::<i64>::to_subset(&f64::INFINITY), None);
+}
+
+#[test]
+fn int_from_float_rejects_fractional_parts() {
+    assert_eq!(SupersetOf::<i32>::to_subset(&-3.0f64), Some(-3i32));
+    assert_eq!(SupersetOf::<i32>::to_subset(&1.5f64), None);
+    assert_eq!(SupersetOf::<u8>::to_subset(&0.25f32), None);
+    assert_eq!(SupersetOf::<u64>::to_subset(&-0.5f64), None);
+    assert_eq!(
+        SupersetOf::<u64>::to_subset(&1.0e15f64),
+        Some(1_000_000_000_000_000u64)
+    );
+}
+
+#[test]
+fn exact_and_lossy_subsets() {
+    fn round_trip<S: ExactSubsetOf<T> + PartialEq + Copy + std::fmt::Debug, T>(s: S) {
+        assert_eq!(S::from_superset(&s.to_superset()), Some(s));
+    }
+
+    round_trip::<u16, f32>(u16::MAX);
+    round_trip::<i32, f64>(i32::MIN);
+    round_trip::<u64, i128>(u64::MAX);
+    round_trip::<f32, Complex<f64>>(0.1);
+
+    assert_eq!(
+        LossySubsetOf::<f32>::to_superset_lossy(&16777217u64),
+        16777216.0f32
+    );
+    assert_eq!(
+        <u8 as LossySubsetOf<f64>>::from_superset_lossy(&300.0),
+        255u8
+    );
+}
+
+#[test]
+fn int_from_int_checks_range() {
+    assert_eq!(SupersetOf::<u8>::to_subset(&255i32), Some(255u8));
+    assert_eq!(SupersetOf::<u8>::to_subset(&256i32), None);
+    assert_eq!(SupersetOf::<u32>::to_subset(&-1i64), None);
+    assert_eq!(SupersetOf::<i8>::to_subset(&-128i128), Some(-128i8));
+    assert_eq!(SupersetOf::<usize>::to_subset(&u128::MAX), None);
+}
+
+#[test]
+fn bool_and_char_subsets() {
+    assert_eq!(SubsetOf::<u8>::to_superset(&true), 1u8);
+    assert_eq!(SupersetOf::<bool>::to_subset(&0i64), Some(false));
+    assert_eq!(SupersetOf::<bool>::to_subset(&1u128), Some(true));
+    assert_eq!(SupersetOf::<bool>::to_subset(&2u8), None);
+
+    assert_eq!(SubsetOf::<u32>::to_superset(&'a'), 97u32);
+    assert_eq!(SupersetOf::<char>::to_subset(&0x1F600u64), Some('😀'));
+    assert_eq!(SupersetOf::<char>::to_subset(&0xD800u32), None);
+    assert_eq!(SupersetOf::<char>::to_subset(&-1i64), None);
+}
+
+#[test]
+fn convert_helpers() {
+    use alga::general::{convert, convert_ref, is_convertible, try_convert, try_convert_ref};
+
+    let x: f64 = convert(3u8);
+    assert_eq!(x, 3.0);
+    let c: Complex<f64> = convert_ref(&2.0f32);
+    assert_eq!(c, Complex::new(2.0, 0.0));
+    assert_eq!(try_convert::<_, u8>(300i32), None);
+    assert_eq!(try_convert_ref::<_, u8>(&42i32), Some(42u8));
+    assert!(is_convertible::<_, i8>(&-128i64));
+    assert!(!is_convertible::<_, i8>(&128i64));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Scalar(f64);
+
+impl_reflexive_subset!(Scalar);
+
+fn round_trip<T: ExactSubsetOf<T> + PartialEq>(x: T) -> bool {
+    T::from_superset(&x.to_superset()) == Some(x)
+}
+
+#[test]
+fn reflexive_subset_macro() {
+    assert!(<Scalar as SubsetOf<Scalar>>::is_in_subset(&Scalar(-1.0)));
+    assert_eq!(
+        SupersetOf::<Scalar>::to_subset(&Scalar(2.5)),
+        Some(Scalar(2.5))
+    );
+    assert!(round_trip(Scalar(3.0)));
+}
+
+#[test]
+fn int_from_float_rejects_fractions() {
+    assert!(is_convertible::<f64, i8>(&-128.0));
+    assert!(!is_convertible::<f64, i8>(&0.5));
+    assert!(!is_convertible::<f32, u32>(&-0.25));
+    assert!(!is_convertible::<f64, u128>(&f64::NEG_INFINITY));
+    assert!(is_convertible::<f32, u64>(&16777216.0));
+    assert_eq!(try_convert::<f64, i16>(-3.0), Some(-3i16));
+    assert_eq!(try_convert::<f64, i16>(-3.5), None);
+}
+
+#[test]
+fn identity_is_an_exact_subset() {
+    let zero = Id::<Additive>::new();
+
+    assert_eq!(SubsetOf::<f64>::to_superset(&zero), 0.0);
+    assert_eq!(try_convert::<f64, Id<Additive>>(0.0), Some(zero));
+    assert_eq!(try_convert::<f64, Id<Additive>>(1.0), None);
+    assert!(is_convertible::<f64, Id<Multiplicative>>(&1.0));
+}
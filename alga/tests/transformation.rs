@@ -0,0 +1,926 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroup, AbstractLoop, AbstractMagma, AbstractMonoid, AbstractQuasigroup,
+    AbstractSemigroup, Id, Identity, Multiplicative, TwoSidedInverse,
+};
+use alga::linear::{
+    AffineTransformation, DirectIsometry, EuclideanSpace, FiniteDimVectorSpace, FreeModule,
+    Isometry, LieRotation, NormedSpace, OrthogonalTransformation, Point, ProjectiveTransformation,
+    Reflection, Rotation, Similarity, SpecialOrthogonalGroup, Transformation, Translation, Twist,
+};
+use approx::{AbsDiffEq, RelativeEq};
+use std::f64::consts::PI;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
+
+type E2 = Point<[f64; 2]>;
+
+/// A rotation of the plane, represented by its angle in `(-π, π]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rot2(f64);
+
+impl Rot2 {
+    const ONE: Rot2 = Rot2(0.0);
+
+    fn new(angle: f64) -> Self {
+        Rot2(angle.sin().atan2(angle.cos()))
+    }
+
+    fn compose(&self, other: &Self) -> Self {
+        Rot2::new(self.0 + other.0)
+    }
+
+    fn inverse(&self) -> Self {
+        Rot2::new(-self.0)
+    }
+
+    fn rotate(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        let (s, c) = self.0.sin_cos();
+        FreeModule::new([c * v[0] - s * v[1], s * v[0] + c * v[1]])
+    }
+}
+
+impl AbsDiffEq for Rot2 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        1.0e-9
+    }
+
+    // NOTE: compare the unit complex numbers so that angles close to ±π are close.
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.cos().abs_diff_eq(&other.0.cos(), epsilon)
+            && self.0.sin().abs_diff_eq(&other.0.sin(), epsilon)
+    }
+}
+
+impl RelativeEq for Rot2 {
+    fn default_max_relative() -> f64 {
+        1.0e-9
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0
+            .cos()
+            .relative_eq(&other.0.cos(), epsilon, max_relative)
+            && self
+                .0
+                .sin()
+                .relative_eq(&other.0.sin(), epsilon, max_relative)
+    }
+}
+
+/// A translation of the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Shift(FreeModule<f64, 2>);
+
+impl Shift {
+    const ONE: Shift = Shift(FreeModule([0.0, 0.0]));
+
+    fn compose(&self, other: &Self) -> Self {
+        Shift(self.0 + other.0)
+    }
+
+    fn inverse(&self) -> Self {
+        Shift(-self.0)
+    }
+}
+
+impl AbsDiffEq for Shift {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        1.0e-9
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Shift {
+    fn default_max_relative() -> f64 {
+        1.0e-9
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+/// A non-uniform scaling of the plane along its canonical axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stretch([f64; 2]);
+
+impl Stretch {
+    const ONE: Stretch = Stretch([1.0, 1.0]);
+
+    fn compose(&self, other: &Self) -> Self {
+        Stretch([self.0[0] * other.0[0], self.0[1] * other.0[1]])
+    }
+
+    fn inverse(&self) -> Self {
+        Stretch([1.0 / self.0[0], 1.0 / self.0[1]])
+    }
+
+    fn scale(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        FreeModule::new([self.0[0] * v[0], self.0[1] * v[1]])
+    }
+}
+
+impl AbsDiffEq for Stretch {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        1.0e-9
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Stretch {
+    fn default_max_relative() -> f64 {
+        1.0e-9
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+/// An affine transformation `p ↦ m p + t` of the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Affine2 {
+    m: [[f64; 2]; 2],
+    t: FreeModule<f64, 2>,
+}
+
+impl Affine2 {
+    const ONE: Affine2 = Affine2 {
+        m: [[1.0, 0.0], [0.0, 1.0]],
+        t: FreeModule([0.0, 0.0]),
+    };
+
+    fn linear(m: [[f64; 2]; 2]) -> Self {
+        Affine2 { m, ..Affine2::ONE }
+    }
+
+    fn apply(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        let m = &self.m;
+        FreeModule::new([
+            m[0][0] * v[0] + m[0][1] * v[1],
+            m[1][0] * v[0] + m[1][1] * v[1],
+        ])
+    }
+
+    // NOTE: this is `self ∘ other`, i.e., `other` is applied first.
+    fn compose(&self, other: &Self) -> Self {
+        let (a, b) = (&self.m, &other.m);
+        let entry = |i: usize, j: usize| a[i][0] * b[0][j] + a[i][1] * b[1][j];
+
+        Affine2 {
+            m: [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]],
+            t: self.apply(&other.t) + self.t,
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        let [[a, b], [c, d]] = self.m;
+        let det = a * d - b * c;
+        let inv = Affine2::linear([[d / det, -b / det], [-c / det, a / det]]);
+
+        Affine2 {
+            t: -inv.apply(&self.t),
+            ..inv
+        }
+    }
+}
+
+impl From<Rot2> for Affine2 {
+    fn from(r: Rot2) -> Self {
+        let (s, c) = r.0.sin_cos();
+        Affine2::linear([[c, -s], [s, c]])
+    }
+}
+
+impl From<Stretch> for Affine2 {
+    fn from(s: Stretch) -> Self {
+        Affine2::linear([[s.0[0], 0.0], [0.0, s.0[1]]])
+    }
+}
+
+impl From<Shift> for Affine2 {
+    fn from(t: Shift) -> Self {
+        Affine2 {
+            t: t.0,
+            ..Affine2::ONE
+        }
+    }
+}
+
+impl AbsDiffEq for Affine2 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        1.0e-9
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.m[0].abs_diff_eq(&other.m[0], epsilon)
+            && self.m[1].abs_diff_eq(&other.m[1], epsilon)
+            && self.t.abs_diff_eq(&other.t, epsilon)
+    }
+}
+
+impl RelativeEq for Affine2 {
+    fn default_max_relative() -> f64 {
+        1.0e-9
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.m[0].relative_eq(&other.m[0], epsilon, max_relative)
+            && self.m[1].relative_eq(&other.m[1], epsilon, max_relative)
+            && self.t.relative_eq(&other.t, epsilon, max_relative)
+    }
+}
+
+/// Implements the multiplicative group structure of `$T` from its `ONE`, `compose` and `inverse`
+/// inherent items.
+macro_rules! impl_multiplicative_group(
+    ($($T: ident),*) => {$(
+        impl AbstractMagma<Multiplicative> for $T {
+            fn operate(&self, right: &Self) -> Self {
+                self.compose(right)
+            }
+        }
+
+        impl TwoSidedInverse<Multiplicative> for $T {
+            fn two_sided_inverse(&self) -> Self {
+                self.inverse()
+            }
+        }
+
+        impl Identity<Multiplicative> for $T {
+            fn identity() -> Self {
+                $T::ONE
+            }
+        }
+
+        impl AbstractSemigroup<Multiplicative> for $T {}
+        impl AbstractQuasigroup<Multiplicative> for $T {}
+        impl AbstractMonoid<Multiplicative> for $T {}
+        impl AbstractLoop<Multiplicative> for $T {}
+        impl AbstractGroup<Multiplicative> for $T {}
+
+        impl Mul for $T {
+            type Output = $T;
+
+            fn mul(self, rhs: $T) -> $T {
+                self.compose(&rhs)
+            }
+        }
+
+        impl MulAssign for $T {
+            fn mul_assign(&mut self, rhs: $T) {
+                *self = self.compose(&rhs)
+            }
+        }
+
+        impl Div for $T {
+            type Output = $T;
+
+            fn div(self, rhs: $T) -> $T {
+                self.compose(&rhs.inverse())
+            }
+        }
+
+        impl DivAssign for $T {
+            fn div_assign(&mut self, rhs: $T) {
+                *self = self.compose(&rhs.inverse())
+            }
+        }
+
+        impl num_traits::One for $T {
+            fn one() -> Self {
+                $T::ONE
+            }
+        }
+    )*}
+);
+
+impl_multiplicative_group!(Rot2, Shift, Stretch, Affine2);
+
+impl Transformation<E2> for Rot2 {
+    fn transform_point(&self, pt: &E2) -> E2 {
+        E2::from_coordinates(self.rotate(&pt.coordinates()))
+    }
+
+    fn transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.rotate(v)
+    }
+}
+
+impl ProjectiveTransformation<E2> for Rot2 {
+    fn inverse_transform_point(&self, pt: &E2) -> E2 {
+        self.inverse().transform_point(pt)
+    }
+
+    fn inverse_transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.inverse().rotate(v)
+    }
+}
+
+impl AffineTransformation<E2> for Rot2 {
+    type Rotation = Rot2;
+    type NonUniformScaling = Id;
+    type Translation = Id;
+
+    fn decompose(&self) -> (Id, Rot2, Id, Rot2) {
+        (Id::new(), Rot2(0.0), Id::new(), *self)
+    }
+
+    fn append_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn append_rotation(&self, r: &Rot2) -> Self {
+        *r * *self
+    }
+
+    fn prepend_rotation(&self, r: &Rot2) -> Self {
+        *self * *r
+    }
+
+    fn append_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+}
+
+impl Similarity<E2> for Rot2 {
+    type Scaling = Id;
+
+    fn translation(&self) -> Id {
+        Id::new()
+    }
+
+    fn rotation(&self) -> Rot2 {
+        *self
+    }
+
+    fn scaling(&self) -> Id {
+        Id::new()
+    }
+}
+
+impl Isometry<E2> for Rot2 {}
+impl DirectIsometry<E2> for Rot2 {}
+
+impl OrthogonalTransformation<E2> for Rot2 {
+    fn det_is_positive(&self) -> bool {
+        true
+    }
+}
+
+impl Rotation<E2> for Rot2 {
+    fn powf(&self, n: f64) -> Option<Self> {
+        Some(Rot2::new(self.0 * n))
+    }
+
+    fn rotation_between(a: &FreeModule<f64, 2>, b: &FreeModule<f64, 2>) -> Option<Self> {
+        Some(Rot2::new((a[0] * b[1] - a[1] * b[0]).atan2(a.dot(b))))
+    }
+
+    fn scaled_rotation_between(
+        a: &FreeModule<f64, 2>,
+        b: &FreeModule<f64, 2>,
+        s: f64,
+    ) -> Option<Self> {
+        Rotation::<E2>::rotation_between(a, b).and_then(|r| Rotation::<E2>::powf(&r, s))
+    }
+}
+
+impl SpecialOrthogonalGroup<E2> for Rot2 {}
+
+impl LieRotation<E2> for Rot2 {
+    type Generator = f64;
+
+    fn exp(angle: &f64) -> Self {
+        Rot2::new(*angle)
+    }
+
+    fn log(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Transformation<E2> for Shift {
+    fn transform_point(&self, pt: &E2) -> E2 {
+        *pt + self.0
+    }
+
+    fn transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        *v
+    }
+}
+
+impl ProjectiveTransformation<E2> for Shift {
+    fn inverse_transform_point(&self, pt: &E2) -> E2 {
+        *pt - self.0
+    }
+
+    fn inverse_transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        *v
+    }
+}
+
+impl AffineTransformation<E2> for Shift {
+    type Rotation = Id;
+    type NonUniformScaling = Id;
+    type Translation = Shift;
+
+    fn decompose(&self) -> (Shift, Id, Id, Id) {
+        (*self, Id::new(), Id::new(), Id::new())
+    }
+
+    fn append_translation(&self, t: &Shift) -> Self {
+        t.compose(self)
+    }
+
+    fn prepend_translation(&self, t: &Shift) -> Self {
+        self.compose(t)
+    }
+
+    fn append_rotation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_rotation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn append_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+}
+
+impl Similarity<E2> for Shift {
+    type Scaling = Id;
+
+    fn translation(&self) -> Shift {
+        *self
+    }
+
+    fn rotation(&self) -> Id {
+        Id::new()
+    }
+
+    fn scaling(&self) -> Id {
+        Id::new()
+    }
+}
+
+impl Isometry<E2> for Shift {}
+impl DirectIsometry<E2> for Shift {}
+
+impl Translation<E2> for Shift {
+    fn to_vector(&self) -> FreeModule<f64, 2> {
+        self.0
+    }
+
+    fn from_vector(v: FreeModule<f64, 2>) -> Option<Self> {
+        Some(Shift(v))
+    }
+}
+
+impl Transformation<E2> for Stretch {
+    fn transform_point(&self, pt: &E2) -> E2 {
+        E2::from_coordinates(self.scale(&pt.coordinates()))
+    }
+
+    fn transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.scale(v)
+    }
+}
+
+impl ProjectiveTransformation<E2> for Stretch {
+    fn inverse_transform_point(&self, pt: &E2) -> E2 {
+        self.inverse().transform_point(pt)
+    }
+
+    fn inverse_transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.inverse().scale(v)
+    }
+}
+
+impl AffineTransformation<E2> for Stretch {
+    type Rotation = Id;
+    type NonUniformScaling = Stretch;
+    type Translation = Id;
+
+    fn decompose(&self) -> (Id, Id, Stretch, Id) {
+        (Id::new(), Id::new(), *self, Id::new())
+    }
+
+    fn append_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn append_rotation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_rotation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn append_scaling(&self, s: &Stretch) -> Self {
+        s.compose(self)
+    }
+
+    fn prepend_scaling(&self, s: &Stretch) -> Self {
+        self.compose(s)
+    }
+}
+
+impl Transformation<E2> for Affine2 {
+    fn transform_point(&self, pt: &E2) -> E2 {
+        E2::from_coordinates(self.apply(&pt.coordinates()) + self.t)
+    }
+
+    fn transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.apply(v)
+    }
+}
+
+impl ProjectiveTransformation<E2> for Affine2 {
+    fn inverse_transform_point(&self, pt: &E2) -> E2 {
+        self.inverse().transform_point(pt)
+    }
+
+    fn inverse_transform_vector(&self, v: &FreeModule<f64, 2>) -> FreeModule<f64, 2> {
+        self.inverse().apply(v)
+    }
+}
+
+impl AffineTransformation<E2> for Affine2 {
+    type Rotation = Rot2;
+    type NonUniformScaling = Stretch;
+    type Translation = Shift;
+
+    // NOTE: closed-form singular value decomposition `m = r2 * s * r1` of a 2×2 matrix.
+    fn decompose(&self) -> (Shift, Rot2, Stretch, Rot2) {
+        let [[a, b], [c, d]] = self.m;
+        let (e, f, g, h) = ((a + d) / 2.0, (a - d) / 2.0, (c + b) / 2.0, (c - b) / 2.0);
+        let (q, r) = (e.hypot(h), f.hypot(g));
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+
+        (
+            Shift(self.t),
+            Rot2::new((a2 - a1) / 2.0),
+            Stretch([q + r, q - r]),
+            Rot2::new((a2 + a1) / 2.0),
+        )
+    }
+
+    fn append_translation(&self, t: &Shift) -> Self {
+        Affine2::from(*t).compose(self)
+    }
+
+    fn prepend_translation(&self, t: &Shift) -> Self {
+        self.compose(&Affine2::from(*t))
+    }
+
+    fn append_rotation(&self, r: &Rot2) -> Self {
+        Affine2::from(*r).compose(self)
+    }
+
+    fn prepend_rotation(&self, r: &Rot2) -> Self {
+        self.compose(&Affine2::from(*r))
+    }
+
+    fn append_scaling(&self, s: &Stretch) -> Self {
+        Affine2::from(*s).compose(self)
+    }
+
+    fn prepend_scaling(&self, s: &Stretch) -> Self {
+        self.compose(&Affine2::from(*s))
+    }
+}
+
+/// The reflection with respect to the line `⟨p, axis⟩ = bias` of the plane.
+#[derive(Clone, Copy, Debug)]
+struct Mirror {
+    axis: FreeModule<f64, 2>,
+    bias: f64,
+}
+
+impl Mirror {
+    fn new(angle: f64, bias: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+
+        Mirror {
+            axis: FreeModule::new([c, s]),
+            bias,
+        }
+    }
+}
+
+impl Reflection<E2> for Mirror {
+    fn axis(&self) -> FreeModule<f64, 2> {
+        self.axis
+    }
+
+    fn bias(&self) -> f64 {
+        self.bias
+    }
+}
+
+/// An angular velocity about the origin of the plane, without linear part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Spin(f64);
+
+impl Add for Spin {
+    type Output = Spin;
+
+    fn add(self, rhs: Spin) -> Spin {
+        Spin(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Spin {
+    fn add_assign(&mut self, rhs: Spin) {
+        self.0 += rhs.0
+    }
+}
+
+impl Mul<f64> for Spin {
+    type Output = Spin;
+
+    fn mul(self, t: f64) -> Spin {
+        Spin(self.0 * t)
+    }
+}
+
+impl MulAssign<f64> for Spin {
+    fn mul_assign(&mut self, t: f64) {
+        self.0 *= t
+    }
+}
+
+impl Twist<E2> for Spin {
+    type AngularVelocity = f64;
+    type Isometry = Rot2;
+
+    fn angular_velocity(&self) -> f64 {
+        self.0
+    }
+
+    fn linear_velocity(&self) -> FreeModule<f64, 2> {
+        FreeModule::new([0.0, 0.0])
+    }
+
+    fn exp(&self) -> Rot2 {
+        Rot2::new(self.0)
+    }
+
+    fn log(motion: &Rot2) -> Self {
+        Spin(motion.0)
+    }
+}
+
+quickcheck! {
+    fn prop_rot2_exp_and_log(args: (f64, f64)) -> bool {
+        let (angle, t) = args;
+        let r = Rot2::new(angle);
+
+        LieRotation::<E2>::prop_exp_log_is_identity_approx((r,))
+            && LieRotation::<E2>::prop_powf_is_scaled_log_approx((r, t))
+    }
+
+    fn prop_rot2_preserves_orientation(args: (f64, f64)) -> bool {
+        let (a, b) = (Rot2::new(args.0), Rot2::new(args.1));
+
+        SpecialOrthogonalGroup::<E2>::prop_is_orientation_preserving((a,))
+            && OrthogonalTransformation::<E2>::prop_det_sign_is_multiplicative((a, b))
+            && OrthogonalTransformation::<E2>::prop_inverse_preserves_det_sign((a,))
+    }
+
+    fn prop_rot2_slerp_endpoints(args: (f64, f64)) -> bool {
+        let (a, b) = (Rot2::new(args.0), Rot2::new(args.1));
+
+        relative_eq!(a.slerp(&b, 0.0).unwrap(), a) && relative_eq!(a.slerp(&b, 1.0).unwrap(), b)
+    }
+
+    fn prop_spin_screw_motions(args: (f64, i8, i8)) -> bool {
+        let (angle, s, t) = args;
+        let xi = Spin(angle);
+
+        <Spin as Twist<E2>>::prop_exp_log_is_identity_approx((Rot2::new(angle),))
+            && Twist::prop_screw_motion_is_one_parameter_subgroup_approx((xi, s as f64 / 8.0, t as f64 / 8.0))
+    }
+
+    fn prop_isometries_are_their_isometric_part(args: (f64, (i8, i8))) -> bool {
+        let (angle, (x, y)) = args;
+        let r = Rot2::new(angle);
+        let s = Shift(FreeModule::new([x as f64, y as f64]));
+
+        Similarity::<E2>::prop_isometry_and_scaling_round_trip_approx((r,))
+            && Similarity::<E2>::prop_isometry_and_scaling_round_trip_approx((s,))
+    }
+
+    fn prop_shift_is_a_translation(args: ((i8, i8), (i8, i8))) -> bool {
+        let v = |(x, y): (i8, i8)| Shift(FreeModule::new([x as f64, y as f64]));
+        let (a, b) = (v(args.0), v(args.1));
+
+        Translation::prop_vector_round_trip_approx((a,))
+            && Translation::prop_composition_is_vector_addition_approx((a, b))
+            && Translation::<E2>::prop_vector_round_trip_approx((Id::new(),))
+            && Translation::<E2>::prop_composition_is_vector_addition_approx((Id::new(), Id::new()))
+    }
+
+    fn prop_affine2_from_parts_round_trip(args: ((i8, i8, i8, i8), (i8, i8))) -> bool {
+        let ((a, b, c, d), (x, y)) = args;
+        let m = Affine2 {
+            m: [[a as f64, b as f64], [c as f64, d as f64]],
+            t: FreeModule::new([x as f64, y as f64]),
+        };
+
+        AffineTransformation::<E2>::prop_from_parts_round_trip_approx((m,))
+            && AffineTransformation::<E2>::prop_from_parts_round_trip_approx((Stretch([a as f64, d as f64]),))
+    }
+
+    fn prop_mirror_is_involutive(args: (f64, i8, (i8, i8), (i8, i8))) -> bool {
+        let (angle, bias, p, v) = args;
+        let r = Mirror::new(angle, bias as f64);
+        let pt = E2::new([p.0 as f64, p.1 as f64]);
+        let v = FreeModule::new([v.0 as f64, v.1 as f64]);
+
+        Reflection::prop_is_involutive_approx_with((r, pt), 1.0e-9, 1.0e-9)
+            && Reflection::prop_fixes_hyperplane_approx_with((r, pt, v), 1.0e-9, 1.0e-9)
+    }
+}
+
+#[test]
+fn slerp_follows_the_shortest_arc() {
+    assert_relative_eq!(
+        Rot2::new(0.2).slerp(&Rot2::new(1.0), 0.5).unwrap(),
+        Rot2::new(0.6)
+    );
+    assert_relative_eq!(
+        Rot2::new(3.0).slerp(&Rot2::new(-3.0), 0.5).unwrap(),
+        Rot2::new(PI)
+    );
+    assert_relative_eq!(
+        Rot2::new(-0.5).slerp(&Rot2::new(0.5), 0.25).unwrap(),
+        Rot2::new(-0.25)
+    );
+    assert_eq!(
+        Rotation::<E2>::slerp(&Id::new(), &Id::new(), 0.3),
+        Some(Id::new())
+    );
+}
+
+#[test]
+fn exp_and_log_of_rotations() {
+    let r = <Rot2 as LieRotation<E2>>::exp(&(PI / 2.0));
+    let v = r.transform_vector(&FreeModule::new([1.0, 0.0]));
+
+    assert_relative_eq!(v, FreeModule::new([0.0, 1.0]), epsilon = 1.0e-12);
+    assert_relative_eq!(LieRotation::<E2>::log(&r), PI / 2.0);
+    assert_relative_eq!(
+        LieRotation::<E2>::log(&<Rot2 as LieRotation<E2>>::exp(&(2.0 * PI + 0.5))),
+        0.5,
+        epsilon = 1.0e-12
+    );
+    assert_eq!(LieRotation::<E2>::log(&Id::new()), Id::new());
+}
+
+#[test]
+fn reflections_about_lines() {
+    let r = Mirror::new(0.0, 2.0);
+
+    assert_eq!(r.reflect_point(&E2::new([3.0, 1.0])), E2::new([1.0, 1.0]));
+    assert_eq!(r.reflect_point(&E2::new([2.0, -5.0])), E2::new([2.0, -5.0]));
+    assert_eq!(
+        r.reflect_vector(&FreeModule::new([3.0, 1.0])),
+        FreeModule::new([-3.0, 1.0])
+    );
+
+    let diagonal = Mirror::new(PI / 4.0, 0.0);
+    let v = diagonal.reflect_vector(&FreeModule::new([1.0, 0.0]));
+
+    assert_relative_eq!(v, FreeModule::new([0.0, -1.0]), epsilon = 1.0e-12);
+    assert_relative_eq!(v.norm(), 1.0);
+    assert_relative_eq!(
+        diagonal.reflect_point(&E2::new([2.0, 5.0])),
+        E2::new([-5.0, -2.0]),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn screw_motions_of_a_spin() {
+    let xi = Spin(PI / 2.0);
+
+    assert_eq!(xi.angular_velocity(), PI / 2.0);
+    assert_eq!(xi.linear_velocity(), FreeModule::new([0.0, 0.0]));
+    assert_relative_eq!(xi.screw_motion(0.5), Rot2::new(PI / 4.0));
+    assert_relative_eq!(xi.screw_motion(4.0), Rot2(0.0), epsilon = 1.0e-12);
+    assert_eq!(xi.compose_velocity(&Spin(1.0)), Spin(PI / 2.0 + 1.0));
+
+    let v = xi
+        .screw_motion(1.0)
+        .transform_vector(&FreeModule::new([2.0, 0.0]));
+    assert_relative_eq!(v, FreeModule::new([0.0, 2.0]), epsilon = 1.0e-12);
+}
+
+#[test]
+fn special_orthogonal_determinant_sign() {
+    fn orientation<R: SpecialOrthogonalGroup<E2>>(r: &R) -> (bool, bool) {
+        (r.det_is_positive(), r.is_orientation_preserving())
+    }
+
+    assert_eq!(orientation(&Rot2::new(PI)), (true, true));
+    assert_eq!(
+        orientation(&(Rot2::new(2.0) * Rot2::new(-3.0))),
+        (true, true)
+    );
+    assert_eq!(orientation(&Id::new()), (true, true));
+}
+
+#[test]
+fn translations_as_vectors() {
+    let a = Shift(FreeModule::new([1.0, -2.0]));
+    let b = Shift(FreeModule::new([0.5, 4.0]));
+
+    assert_eq!((a * b).to_vector(), FreeModule::new([1.5, 2.0]));
+    assert_eq!(
+        Translation::<E2>::powf(&a, 3.0),
+        Some(Shift(FreeModule::new([3.0, -6.0])))
+    );
+    assert_eq!(
+        Translation::translation_between(&E2::new([1.0, 1.0]), &E2::new([2.0, -1.0])),
+        Some(Shift(FreeModule::new([1.0, -2.0])))
+    );
+    assert_eq!(a.transform_point(&E2::new([0.0, 2.0])), E2::new([1.0, 0.0]));
+    assert_eq!(
+        a.inverse_transform_point(&E2::new([1.0, 0.0])),
+        E2::new([0.0, 2.0])
+    );
+}
+
+#[test]
+fn affine_decomposition_applies_the_shear_first() {
+    let t = Shift(FreeModule::new([1.0, -2.0]));
+    let r1 = Rot2::new(0.3);
+    let s = Stretch([3.0, 0.5]);
+    let r2 = Rot2::new(1.1);
+    let m = Affine2::from(t) * Affine2::from(r2) * Affine2::from(s) * Affine2::from(r1);
+
+    let p = E2::new([2.0, 5.0]);
+    assert_relative_eq!(
+        m.transform_point(&p),
+        t.transform_point(&r2.transform_point(&s.transform_point(&r1.transform_point(&p)))),
+        epsilon = 1.0e-12
+    );
+
+    let (t_, r1_, s_, r2_) = AffineTransformation::<E2>::decompose(&m);
+    assert_relative_eq!(
+        Affine2::from(t_) * Affine2::from(r2_) * Affine2::from(s_) * Affine2::from(r1_),
+        m
+    );
+    assert_relative_eq!(t_, t);
+    assert_relative_eq!(s_, s);
+    assert_relative_eq!(AffineTransformation::<E2>::rotation_part(&m), r2 * r1);
+    assert_relative_eq!(AffineTransformation::<E2>::scaling_part(&m), s);
+    assert_relative_eq!(
+        <Affine2 as AffineTransformation<E2>>::from_parts(&t, &(r2 * r1), &s, &r1),
+        m
+    );
+    assert!(!relative_eq!(
+        Affine2::from(t) * Affine2::from(r1) * Affine2::from(s) * Affine2::from(r2),
+        m
+    ));
+}
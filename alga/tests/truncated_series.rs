@@ -0,0 +1,142 @@
+extern crate alga;
+#[macro_use]
+extern crate approx;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractMonoid, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Multiplicative, PartialInverse, TruncatedSeries,
+};
+
+type S = TruncatedSeries<f64, 6>;
+
+type Args = (i8, i8, i8, i8, i8, i8);
+
+fn series(args: Args) -> S {
+    TruncatedSeries::new([
+        args.0 as f64,
+        args.1 as f64,
+        args.2 as f64,
+        args.3 as f64,
+        args.4 as f64,
+        args.5 as f64,
+    ])
+}
+
+// A series with the given constant term, and small coefficients elsewhere.
+fn with_constant(c: f64, args: Args) -> S {
+    let mut coeffs = series(args).into_coefficients().map(|a| a / 64.0);
+    coeffs[0] = c;
+    TruncatedSeries::new(coeffs)
+}
+
+fn x() -> S {
+    TruncatedSeries::variable(0.0)
+}
+
+quickcheck!(
+    fn prop_is_commutative_ring(args: (Args, Args, Args)) -> bool {
+        let int = |s: S| TruncatedSeries::new(s.into_coefficients().map(|a| a as i64));
+        let (a, b, c) = (
+            int(series(args.0)),
+            int(series(args.1)),
+            int(series(args.2)),
+        );
+
+        AbstractRing::prop_mul_and_add_are_distributive((a, b, c))
+            && AbstractRingCommutative::prop_mul_is_commutative((a, b))
+            && AbstractSemigroup::<Multiplicative>::prop_is_associative((a, b, c))
+            && AbstractGroupAbelian::<Additive>::prop_is_commutative((a, b))
+            && AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop((a,))
+    }
+
+    fn prop_inverse(args: Args) -> bool {
+        let a = with_constant(1.0 + (args.0 as f64).abs(), args);
+        let inv = PartialInverse::<Multiplicative>::partial_inverse(&a).unwrap();
+
+        relative_eq!(a * inv, S::constant(1.0), epsilon = 1.0e-12)
+            && PartialInverse::<Multiplicative>::partial_inverse(&with_constant(0.0, args))
+                .is_none()
+    }
+
+    fn prop_exp_and_ln_are_inverse(args: (Args, Args)) -> bool {
+        let (a, b) = (with_constant(0.0, args.0), with_constant(0.0, args.1));
+        let exp_a = a.exp().unwrap();
+
+        relative_eq!(exp_a.ln().unwrap(), a, epsilon = 1.0e-12)
+            && relative_eq!(
+                (a + b).exp().unwrap(),
+                exp_a * b.exp().unwrap(),
+                epsilon = 1.0e-12
+            )
+            // The derivative drops the last coefficient, which is not compared.
+            && exp_a.derivative().coefficients()[..5]
+                .iter()
+                .zip((a.derivative() * exp_a).coefficients())
+                .all(|(l, r)| relative_eq!(l, r, epsilon = 1.0e-12))
+    }
+
+    fn prop_sqrt(args: Args) -> bool {
+        let a = with_constant(1.0, args);
+        let sqrt = a.sqrt().unwrap();
+
+        relative_eq!(sqrt * sqrt, a, epsilon = 1.0e-12)
+    }
+
+    fn prop_reversion(args: Args) -> bool {
+        // Keep the term of order one away from zero, so that the reversion is well-conditioned.
+        let mut coeffs = with_constant(0.0, args).into_coefficients();
+        coeffs[1] = 1.0 + coeffs[1].abs();
+        let a = S::new(coeffs);
+
+        let rev = a.reversion().unwrap();
+
+        relative_eq!(a.compose(&rev).unwrap(), x(), epsilon = 1.0e-12)
+            && relative_eq!(rev.compose(&a).unwrap(), x(), epsilon = 1.0e-12)
+    }
+);
+
+#[test]
+fn higher_order_derivatives() {
+    // f(x) = x³ around 2: f = 8, f' = 12, f'' / 2 = 6, f''' / 6 = 1.
+    let x = S::variable(2.0);
+    let f = x * x * x;
+
+    assert_eq!(f.into_coefficients(), [8.0, 12.0, 6.0, 1.0, 0.0, 0.0]);
+    assert_eq!(f.derivative().coefficient(0), 12.0);
+}
+
+#[test]
+fn elementary_series() {
+    let exp = x().exp().unwrap();
+    let factorials = [1.0, 1.0, 2.0, 6.0, 24.0, 120.0];
+
+    for (a, f) in exp.coefficients().iter().zip(factorials.iter()) {
+        assert_relative_eq!(*a, 1.0 / f);
+    }
+
+    // ln(1 + x) = x - x²/2 + x³/3 - …
+    let ln = (S::constant(1.0) + x()).ln().unwrap();
+    assert_relative_eq!(ln, S::new([0.0, 1.0, -0.5, 1.0 / 3.0, -0.25, 0.2]));
+
+    // The generating function of the Catalan numbers is the reversion of x - x².
+    let catalan = (x() - x() * x()).reversion().unwrap();
+    assert_eq!(catalan, S::new([0.0, 1.0, 1.0, 2.0, 5.0, 14.0]));
+
+    // The geometric series.
+    let geom = PartialInverse::<Multiplicative>::partial_inverse(&(S::constant(1.0) - x()));
+    assert_eq!(geom, Some(S::new([1.0; 6])));
+}
+
+#[test]
+fn undefined_operations() {
+    let a = S::new([1.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+
+    assert_eq!(a.exp(), None);
+    assert_eq!((a * 2.0).ln(), None);
+    assert_eq!((a * 2.0).sqrt(), None);
+    assert_eq!(a.reversion(), None);
+    assert_eq!(x().compose(&a), None);
+    assert_eq!(S::characteristic(), 0);
+}
@@ -0,0 +1,96 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    ArchimedeanValuation, Modulus, PAdicValuation, UltrametricValuation, Valuation,
+};
+use num_complex::Complex;
+
+type V2 = PAdicValuation<2>;
+type V3 = PAdicValuation<3>;
+
+quickcheck! {
+    fn prop_padic_valuation_is_ultrametric(args: (i32, i32)) -> bool {
+        let args = (args.0 as i64, args.1 as i64);
+
+        V2::prop_is_multiplicative(args)
+            && V2::prop_ultrametric_inequality(args)
+            && V3::prop_is_multiplicative(args)
+            && V3::prop_ultrametric_inequality(args)
+    }
+
+    fn prop_modulus_is_archimedean(args: (i32, i32)) -> bool {
+        let (a, b) = (args.0 as i64, args.1 as i64);
+        let (x, y) = (a as f64, b as f64);
+
+        <Modulus as Valuation<i64>>::prop_is_multiplicative((a, b))
+            && <Modulus as ArchimedeanValuation<i64>>::prop_triangle_inequality((a, b))
+            && <Modulus as Valuation<f64>>::prop_is_multiplicative((x, y))
+            && <Modulus as ArchimedeanValuation<f64>>::prop_triangle_inequality((x, y))
+    }
+
+    fn prop_complex_modulus_is_archimedean(args: (i8, i8, i8, i8)) -> bool {
+        let a = Complex::new(args.0 as f64, args.1 as f64);
+        let b = Complex::new(args.2 as f64, args.3 as f64);
+
+        // NOTE: the modulus is rounded twice, so the default tolerance of one epsilon is too tight.
+        Modulus::prop_is_multiplicative_approx_with((a, b), 1.0e-12, 1.0e-12)
+            && Modulus::prop_triangle_inequality_approx_with((a, b), 1.0e-12, 1.0e-12)
+    }
+}
+
+#[test]
+fn padic_values() {
+    assert_eq!(V2::order(96), Some(5));
+    assert_eq!(V3::order(96), Some(1));
+    assert_eq!(V3::order(0), None);
+    assert_eq!(V2::value(&-96i32), Some(-5));
+    assert_eq!(V3::value(&7i8), Some(0));
+    assert_eq!(V2::value(&0i64), None);
+    assert_eq!(V2::value(&i128::MIN), Some(-127));
+}
+
+#[test]
+fn modulus_values() {
+    assert_eq!(Modulus::value(&-3i32), Some(3));
+    assert_eq!(Modulus::value(&0.0f64), None);
+    assert_eq!(Modulus::value(&Complex::new(3.0f64, 4.0)), Some(5.0));
+}
+
+#[cfg(feature = "alloc")]
+mod degree {
+    use alga::general::{
+        Degree, Monomial, MultivariatePolynomial, UltrametricValuation, Valuation,
+    };
+
+    type P = MultivariatePolynomial<i64>;
+    type Coeffs = (i8, i8, i8, i8);
+
+    // A polynomial in x0 and x1 of degree at most two, with small coefficients.
+    fn polynomial(c: Coeffs) -> P {
+        MultivariatePolynomial::from_terms(vec![
+            (Monomial::one(), c.0 as i64),
+            (Monomial::variable(0), c.1 as i64),
+            (Monomial::new(vec![1, 1]), c.2 as i64),
+            (Monomial::new(vec![0, 2]), c.3 as i64),
+        ])
+    }
+
+    quickcheck! {
+        fn prop_degree_is_ultrametric(args: (Coeffs, Coeffs)) -> bool {
+            let (a, b) = (polynomial(args.0), polynomial(args.1));
+
+            Degree::prop_is_multiplicative((a.clone(), b.clone()))
+                && Degree::prop_ultrametric_inequality((a, b))
+        }
+    }
+
+    #[test]
+    fn degree_values() {
+        assert_eq!(Degree::value(&polynomial((1, 0, 0, 0))), Some(0));
+        assert_eq!(Degree::value(&polynomial((1, 2, 0, 3))), Some(2));
+        assert_eq!(Degree::value(&P::constant(0)), None);
+    }
+}
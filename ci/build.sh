@@ -7,6 +7,7 @@ DIR=`pwd`;
 if [ -z "$NO_STD" ]; then
     cd "$DIR/alga" && cargo build --verbose --features "decimal";
     cd "$DIR/alga" && cargo build --verbose;
+    cd "$DIR/alga" && cargo check --verbose --no-default-features --features alloc;
     cd "$DIR/alga_derive" && cargo build --verbose;
     rustup target install wasm32-unknown-unknown
     cd "$DIR/alga" && cargo build --verbose --target wasm32-unknown-unknown;
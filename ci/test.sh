@@ -6,6 +6,7 @@ DIR=`pwd`;
 
 if [ -z "$NO_STD" ]; then
     cd "$DIR/alga" && cargo test;
+    cd "$DIR/alga" && cargo test --no-default-features --features alloc --test inclusion_alloc;
     cd "$DIR/alga" && cargo run --example vectors;
     if [ "$TRAVIS_RUST_VERSION" == "nightly" ]; then
         cargo clean;